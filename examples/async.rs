@@ -0,0 +1,49 @@
+use std::{io, time::Duration};
+use ratatui::widgets::Paragraph;
+use tundra::{prelude::*, StateAsync};
+
+struct Weather {
+    forecast: Option<String>, 
+}
+
+impl State for Weather {
+    type Result<T> = T;
+    type Out = ();
+    type Global = ();
+
+    fn draw(&self, frame: &mut Frame) {
+        let text = match &self.forecast {
+            Some(forecast) => forecast.as_str(), 
+            None => "Press F to fetch the forecast, or Enter to quit.", 
+        };
+        frame.render_widget(Paragraph::new(text), frame.area());
+    }
+}
+
+impl StateAsync for Weather {
+    async fn input_async(mut self, key: KeyEvent, ctx: &mut Context) -> Signal<Self> {
+        match key.code {
+            KeyCode::Char('f') => {
+                let forecast = dialog::busy_async("Fetching forecast...", &self, ctx, fetch_forecast()).await;
+                self.forecast = Some(forecast);
+            }
+            KeyCode::Enter => return Signal::Return(()), 
+            _ => (), 
+        }
+        Signal::Continue(self)
+    }
+}
+
+async fn fetch_forecast() -> String {
+    // stands in for a network request
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    "Sunny with a chance of snow.".into()
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> io::Result<()> {
+    let ctx = &mut Context::new()?;
+    Weather{ forecast: None }.run_async(ctx).await;
+
+    Ok(())
+}
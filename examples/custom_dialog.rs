@@ -13,10 +13,11 @@ struct NumberSelect<'a> {
 impl Dialog for NumberSelect<'_> {
     /// Value returned from [`Dialog::run_over`]; the selected index. 
     type Out = u8;
+    type Global = ();
 
     /// Instantiate a struct describing how the [`Dialog`] machinery should render the dialog. Analogous to
     /// [`State::draw`]. 
-    fn format(&self) -> DrawInfo {
+    fn format(&self) -> DrawInfo<'_> {
         // renders each label to a Line
         let labels = self.labels
             .iter()
@@ -43,7 +44,7 @@ impl Dialog for NumberSelect<'_> {
     }
 
     /// Conceptually the same as [`State::input`]. 
-    fn input(self, key: KeyEvent) -> Signal<Self> {
+    fn input(self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
         // if a number is entered...
         if let KeyCode::Char(char@'1'..='9') = key.code {
             let number = (char as u8) - b'0';
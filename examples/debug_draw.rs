@@ -0,0 +1,55 @@
+use std::io;
+use ratatui::{layout::*, style::*, widgets::*};
+use tundra::{prelude::*, debug};
+
+/// A state with a deliberately broken layout: the footer is placed using a fixed height, and falls outside
+/// the frame on small terminals. 
+struct Broken {
+    /// Whether the outlines of the marked areas are drawn. 
+    outlines: bool, 
+}
+
+impl State for Broken {
+    type Result<T> = T;
+    type Out = ();
+    type Global = ();
+
+    /// Toggle the outlines with (o), and quit with (esc). 
+    fn input(mut self, key: KeyEvent, ctx: &mut Context) -> Signal<Self> {
+        match key.code {
+            KeyCode::Char('o') => {
+                self.outlines = !self.outlines;
+                ctx.set_draw_outlines(self.outlines);
+            }
+            KeyCode::Esc => return Signal::Return(()), 
+            _ => (), 
+        }
+        Signal::Continue(self)
+    }
+
+    /// Draw a body and a footer, marking both areas for the draw guard. 
+    fn draw(&self, frame: &mut Frame) {
+        let area = frame.area();
+        let body = Rect{ height: area.height.saturating_sub(1), ..area };
+        // bug: assumes a terminal height of at least 40
+        let footer = Rect{ y: 39, height: 1, ..area };
+
+        debug::mark(body, "body");
+        debug::mark(footer, "footer");
+
+        let text = "Press (o) to toggle outlines, (esc) to quit...";
+        frame.render_widget(Paragraph::new(text).italic(), body.inner(Margin::new(2, 1)));
+        frame.render_widget(Paragraph::new("Footer").reversed(), footer.intersection(area));
+    }
+}
+
+fn main() -> io::Result<()> {
+    let mut ctx = Context::new()?;
+
+    // report violations to stderr instead of panicking. run with `2> violations.log` to keep them from
+    // being drawn over the application
+    ctx.set_draw_guard(true);
+    ctx.set_draw_reporter(|violation| eprintln!("{violation}"));
+    Broken{ outlines: false }.run(&mut ctx);
+    Ok(())
+}
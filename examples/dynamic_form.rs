@@ -0,0 +1,52 @@
+use std::io;
+use tundra::{prelude::*, field::{*, any::AnyField}, dialog::FormBuilder};
+
+/// The kinds of fields that may be asked for. 
+enum FieldKind {
+    Text, 
+    Number, 
+    Flag, 
+}
+
+/// The fields to ask for, which could just as well be read from a file at runtime. 
+fn fields() -> Vec<(&'static str, FieldKind)> {
+    vec![
+        ("Name", FieldKind::Text), 
+        ("Age", FieldKind::Number), 
+        ("Subscribe", FieldKind::Flag), 
+    ]
+}
+
+fn build(name: &str, kind: &FieldKind) -> Box<dyn AnyField> {
+    let name = name.to_owned();
+    match kind {
+        FieldKind::Text => Box::new(Textbox::builder().name(name).build()), 
+        FieldKind::Number => Box::new(NumberBox::<i64>::builder().name(name).build()), 
+        FieldKind::Flag => Box::new(Checkbox::builder().name(name).build()), 
+    }
+}
+
+fn main() -> io::Result<()> {
+    let fields = fields();
+    let ctx = &mut Context::new()?;
+    let form = fields
+        .iter()
+        .fold(FormBuilder::new("Dynamic Form"), |form, (name, kind)| form.field(*name, build(name, kind)))
+        .message("The fields of this form are decided at runtime.");
+    let Some(values) = form.run_over(&(), ctx) else {
+        return Ok(())
+    };
+
+    // the type of each value is given by the kind of its field
+    let summary: Vec<String> = fields
+        .iter()
+        .map(|(name, kind)| match kind {
+            FieldKind::Text => format!("{name}: {}", values.get::<String>(name).unwrap()), 
+            FieldKind::Number => format!("{name}: {}", values.get::<i64>(name).unwrap()), 
+            FieldKind::Flag => format!("{name}: {}", values.get::<bool>(name).unwrap()), 
+        })
+        .collect();
+    dialog::info(summary.join("\n"), &(), ctx);
+
+    Ok(())
+}
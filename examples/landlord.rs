@@ -1,8 +1,13 @@
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::io;
 use ratatui::{layout::*, style::*, widgets::*};
-use tundra::prelude::*;
+use tundra::{prelude::*, cookbook};
 use tundra::field::*;
+use tundra::remember::Remembered;
+
+/// Key used to remember the table state of the [`Manager`] while it is not running. 
+const MANAGER_KEY: &str = "manager";
 
 /// Holds rent unit data. 
 struct Unit {
@@ -12,26 +17,29 @@ struct Unit {
 }
 
 /// Interactive manager for adding and removing rent units to some database, here represented as a vector. 
-#[derive(Default)]
-struct Manager {
+struct Manager<'a> {
     /// Database of rent units being managed. 
-    database: Vec<Unit>, 
+    database: &'a mut Vec<Unit>, 
     /// Table state holding what rent unit is currently selected. [`RefCell`] is used for interior mutability
     /// since a mutable reference is required by [`Frame::render_stateful_widget`] in [`State::draw`]. 
     table_state: RefCell<TableState>, 
+    /// Total monthly rent of all units in the database. Derived from the database, and rebuilt with
+    /// [`State::refresh_cache`] whenever the database changes. 
+    income: usize, 
 }
 
-impl Manager {
+impl Manager<'_> {
     /// Show a dialog with available commands using [`dialog::help`]. 
     fn show_help(&self, ctx: &mut Context) {
-        const MSG: &str = "\
-            (ctrl + a) Add new rent unit\n\
-            (ctrl + r) Remove selected rent unit\n\
-            (ctrl + e) Evict tenant at selected rent unit\n\
-            (ctrl + h) Show this help message\n\
-            (escape)   Quit the application\
-        ";
-        dialog::help(MSG, self, ctx)
+        const BINDINGS: &[(&str, &str)] = &[
+            ("ctrl + a", "Add new rent unit"), 
+            ("ctrl + r", "Remove selected rent unit"), 
+            ("ctrl + e", "Evict tenant at selected rent unit"), 
+            ("enter", "Show details of selected rent unit"), 
+            ("ctrl + h", "Show this help message"), 
+            ("escape", "Quit the application"), 
+        ];
+        dialog::help(BINDINGS, self, ctx)
     }
 
     /// Add a new rent unit to the database from values entered in a [`dialog::form!`]. 
@@ -53,6 +61,7 @@ impl Manager {
             };
             self.database.push(unit);
             self.table_state.borrow_mut().select_last();
+            self.refresh_cache(ctx);
         }
     }
 
@@ -67,6 +76,7 @@ impl Manager {
         if dialog::confirm(warning, self, ctx) {
             self.database.remove(selected);
             self.table_state.borrow_mut().select_first();
+            self.refresh_cache(ctx);
         }
     }
 
@@ -81,9 +91,10 @@ impl Manager {
     }
 }
 
-impl State for Manager {
+impl State for Manager<'_> {
     type Result<T> = T;
-    type Out = ();
+    /// Index of the rent unit to show the details of, or `None` if the application should quit. 
+    type Out = Option<usize>;
     type Global = ();
 
     /// Delegate incoming key input events. 
@@ -98,27 +109,37 @@ impl State for Manager {
             (KeyCode::Char('r'), true) => self.remove_unit(ctx), 
             (KeyCode::Char('e'), true) => self.evict_tentant(ctx), 
             (KeyCode::Char('h'), true) => self.show_help(ctx), 
-            // exit the application
-            (KeyCode::Esc, false) => return Signal::Return(()), 
+            // show details of the selected rent unit. the manager is destroyed while the details are shown, so
+            // the table state is remembered in the context until the manager is constructed again
+            (KeyCode::Enter, false) => if let Some(selected) = self.table_state.borrow().selected() {
+                Remembered::store(ctx, MANAGER_KEY, self.table_state.take());
+                return Signal::Return(Some(selected))
+            }
+            // exit the application if the user confirms
+            (KeyCode::Esc, false) if cookbook::confirm_quit(&self, ctx) => return Signal::Return(None), 
             _ => (), 
         };
         Signal::Continue(self)
     }
 
-    /// Draw the table using [`ratatui`]. 
     fn draw(&self, frame: &mut Frame) {
+        self.draw_in(frame, frame.area())
+    }
+
+    /// Draw the table using [`ratatui`]. 
+    fn draw_in(&self, frame: &mut Frame, area: Rect) {
         // if the table is empty, allocate space for the header row and a help message. otherwise, allocate
-        // space for just the table
-        let [table_rect, help_rect] = {
+        // space for the table and the total income below it
+        let [table_rect, footer_rect] = {
             let constraints = match self.database.is_empty() {
                 true => [Constraint::Length(2), Constraint::Min(1)], 
-                false => [Constraint::Min(1), Constraint::Length(0)], 
+                false => [Constraint::Min(1), Constraint::Length(1)], 
             };
             Layout::default()
                 .horizontal_margin(3)
                 .vertical_margin(1)
                 .constraints(constraints)
-                .split(frame.area())
+                .split(area)
                 .as_ref()
                 .try_into()
                 .expect("Two constraints are given")
@@ -146,34 +167,95 @@ impl State for Manager {
                 .reversed();
             let widget = Table::new(rows, widths)
                 .header(header)
-                .highlight_style(highlight_style);
+                .row_highlight_style(highlight_style);
             let table_state = &mut self.table_state.borrow_mut();
             frame.render_stateful_widget(widget, table_rect, table_state);
         }
 
-        // draw help message if the table is empty
-        {
+        // draw help message if the table is empty, and the total income otherwise
+        if self.database.is_empty() {
             const HELP: &str = "Nothing to show here. Press (ctrl + h) to see available commands...";
             let widget = Paragraph::new(HELP)
                 .italic()
                 .dim()
                 .wrap(Wrap{ trim: true });
-            frame.render_widget(widget, help_rect);
+            frame.render_widget(widget, footer_rect);
+        } else {
+            let widget = Paragraph::new(format!("Total monthly income: ${}", self.income))
+                .bold();
+            frame.render_widget(widget, footer_rect);
         }
+    }
+
+    /// Recompute the total income from the database. 
+    fn refresh_cache(&mut self, _ctx: &mut Context) {
+        self.income = self.database
+            .iter()
+            .map(|unit| unit.rent)
+            .sum();
+    }
         
+    /// Show the available commands at the bottom of the screen once the table is non-empty. While the table
+    /// is empty, the help message is shown in its place instead. 
+    fn hint(&self) -> Option<Cow<'_, str>> {
+        (!self.database.is_empty()).then_some("Press (ctrl + h) to see available commands...".into())
+    }
+}
+
+/// Shows the details of a single rent unit. 
+struct Details<'a> {
+    unit: &'a Unit, 
+}
+
+impl State for Details<'_> {
+    type Result<T> = T;
+    type Out = ();
+    type Global = ();
+
+    /// Return to the manager on any key press. 
+    fn input(self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        Signal::Return(())
+    }
+
+    /// Draw the details in a bordered paragraph. 
+    fn draw(&self, frame: &mut Frame) {
+        let Unit{ location, rent, pets_allowed } = self.unit;
+        let pets_allowed = match pets_allowed {
+            true => "Yes", 
+            false => "No", 
+        };
+        let text = format!("Location: {location}\nMonthly rent: ${rent}\nPets allowed: {pets_allowed}");
+        let widget = Paragraph::new(text)
+            .block(Block::bordered().title(" RENT UNIT "));
+        let area = frame.area().inner(Margin{ horizontal: 3, vertical: 1 });
+        frame.render_widget(widget, area);
     }
 }
 
-/// Constructs and runs the [`Manager`] state. 
-fn manager(ctx: &mut Context) {
-    Manager::default().run(ctx)
+/// Constructs and runs the [`Manager`] state, restoring the table state from the last time it was run. 
+fn manager(database: &mut Vec<Unit>, ctx: &mut Context) -> Option<usize> {
+    let table_state = Remembered::<TableState>::take(ctx, MANAGER_KEY);
+    let mut manager = Manager {
+        database, 
+        table_state: RefCell::new(table_state), 
+        income: 0, 
+    };
+    manager.refresh_cache(ctx);
+    manager.run(ctx)
+}
+
+/// Constructs and runs the [`Details`] state. 
+fn details(unit: &Unit, ctx: &mut Context) {
+    Details{ unit }.run(ctx)
 }
 
 fn main() -> io::Result<()> {
     // initialise context
     let mut ctx = Context::new()?;
-    // run the state
-    manager(&mut ctx);
-    // return once the state has finished running (when the user presses escape per State::input)
+    let mut database = Vec::new();
+    // run the manager, showing details of rent units until the user presses escape per State::input
+    while let Some(selected) = manager(&mut database, &mut ctx) {
+        details(&database[selected], &mut ctx);
+    }
     Ok(())
 }
@@ -0,0 +1,85 @@
+//! States intended to be drawn behind [dialogs](crate::dialog) when no application state is available. 
+//! 
+//! The [dummy state](crate::State#dummy-state) `()` can be used as a dialog background, but draws nothing, 
+//! leaving the dialog floating in a black void. The states defined here draw something more presentable. 
+//! 
+//! The following states are defined in this module: 
+//! - [`Placeholder`] draws a dim line of text in the middle of the screen. 
+
+use std::borrow::Cow;
+use ratatui::{
+    layout::{Constraint, Flex, Layout}, 
+    style::Stylize, 
+    text::Line, 
+};
+use crate::prelude::*;
+
+/// Background state drawing a dim line of text --- such as the name of the application --- in the middle of
+/// the screen. 
+/// 
+/// Like the [dummy state](crate::State#dummy-state), it exits as soon as a key is pressed when run on its
+/// own. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, backgrounds::Placeholder};
+/// 
+/// # let ctx = &mut Context::new().unwrap();
+/// // let ctx: &mut Context<_>
+/// let background = Placeholder::new("Svalbard");
+/// dialog::info("Shown above a placeholder!", &background, ctx);
+/// ```
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Placeholder {
+    /// The text drawn in the middle of the screen. 
+    pub text: Cow<'static, str>, 
+}
+
+impl Placeholder {
+    /// Creates a placeholder drawing given text. 
+    pub fn new(text: impl Into<Cow<'static, str>>) -> Placeholder {
+        Placeholder{ text: text.into() }
+    }
+}
+
+impl State for Placeholder {
+    type Result<T> = T;
+    type Out = ();
+    type Global = ();
+
+    fn draw(&self, frame: &mut Frame) {
+        let [area] = Layout::vertical([Constraint::Length(1)])
+            .flex(Flex::Center)
+            .areas(frame.area());
+        let widget = Line::from(self.text.as_ref())
+            .centered()
+            .dim();
+        frame.render_widget(widget, area);
+    }
+
+    fn input(self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        Signal::Return(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, Terminal};
+    use crate::prelude::*;
+    use super::Placeholder;
+
+    #[test]
+    fn centered() {
+        let mut terminal = Terminal::new(TestBackend::new(20, 5)).unwrap();
+        terminal
+            .draw(|frame| Placeholder::new("tundra").draw(frame))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row = |y| (0..20).map(|x| buffer[(x, y)].symbol()).collect::<String>();
+
+        assert_eq!(row(2), "       tundra       ");
+        assert!([0, 1, 3, 4].into_iter().all(|y| row(y).trim().is_empty()));
+    }
+}
@@ -0,0 +1,112 @@
+//! Drawing without colours, for terminals and pipelines where colour is unwanted. 
+//! 
+//! By default, colours are used unless the `NO_COLOR` environment variable is set to a non-empty value (see
+//! [no-color.org](https://no-color.org)). This can be overridden with
+//! [`Context::set_color_mode`](crate::Context::set_color_mode). 
+//! 
+//! When colours are disabled, all colours are removed from the frame after each draw --- including those
+//! drawn by the application states --- while modifiers such as bold and underline are kept. The library
+//! replaces the cues otherwise given by colour with structural ones: the name of the focused field of a form
+//! is underlined, invalid fields are marked with `!`, and toasts are prefixed with their level, e.g. `[!]`. 
+//! States may do the same by checking [`color::enabled`](enabled) while drawing. 
+//! 
+//! 
+//! # Examples
+//! 
+//! ```no_run
+//! use tundra::{prelude::*, color::{self, ColorMode}};
+//! use tundra::ratatui::{text::Span, style::Stylize};
+//! 
+//! # struct Status;
+//! # impl Status {
+//! fn draw(&self, frame: &mut Frame) {
+//!     // a red status is indistinguishable from any other without colours, so it is marked instead
+//!     let status = match color::enabled() {
+//!         true => Span::raw("Offline").red(), 
+//!         false => Span::raw("[!] Offline"), 
+//!     };
+//!     frame.render_widget(status, frame.area());
+//! }
+//! # }
+//! 
+//! # let ctx = &mut Context::new()?;
+//! // let ctx: &mut Context<_>
+//! ctx.set_color_mode(ColorMode::Never);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::{cell::Cell, env};
+use ratatui::{buffer::Buffer, style::Style};
+
+/// Whether colours are used when drawing. See the [module-level](self) documentation for more information. 
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Colours are used unless the `NO_COLOR` environment variable is set to a non-empty value. 
+    #[default]
+    Auto, 
+    /// Colours are always used. 
+    Always, 
+    /// Colours are never used. 
+    Never, 
+}
+
+impl ColorMode {
+    /// Whether colours are used in this mode. For [`ColorMode::Auto`], this reads the environment. 
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorMode::Auto => env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()), 
+            ColorMode::Always => true, 
+            ColorMode::Never => false, 
+        }
+    }
+}
+
+/// Whether colours are used in the frame being drawn. This is meant to be called while drawing a
+/// [state](crate::State::draw) or formatting a [dialog](crate::dialog::Dialog::format) or
+/// [field](crate::field::Field::format); outside of a draw, it gives whether they were used in the most
+/// recent one. 
+pub fn enabled() -> bool {
+    ENABLED.get()
+}
+
+thread_local! {
+    /// Whether colours are used in the current draw. 
+    static ENABLED: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Sets whether colours are used in the coming draw. Called by the context before drawing the state. 
+pub(crate) fn begin(enabled: bool) {
+    ENABLED.set(enabled)
+}
+
+/// Removes all colours from the buffer, keeping the modifiers. Called by the context after drawing the
+/// state if colours are disabled. 
+pub(crate) fn strip(buffer: &mut Buffer) {
+    for cell in &mut buffer.content {
+        let modifier = cell.modifier;
+        cell.set_style(Style::reset().add_modifier(modifier));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{buffer::Buffer, layout::Rect, style::{Color, Modifier, Style, Stylize}};
+    use super::{strip, ColorMode};
+
+    #[test]
+    fn modes() {
+        assert!(ColorMode::Always.enabled());
+        assert!(!ColorMode::Never.enabled());
+    }
+
+    #[test]
+    fn strip_colors() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buffer.set_string(0, 0, "Hi", Style::new().red().on_blue().bold());
+        strip(&mut buffer);
+        assert_eq!(buffer[(0, 0)].symbol(), "H");
+        assert_eq!(buffer[(0, 0)].fg, Color::Reset);
+        assert_eq!(buffer[(0, 0)].bg, Color::Reset);
+        assert_eq!(buffer[(0, 0)].modifier, Modifier::BOLD);
+    }
+}
@@ -1,10 +1,28 @@
 use std::{
-    cell::RefCell, 
+    cell::{Cell, RefCell}, 
     io, 
     ops::{Deref, DerefMut}, 
     rc::Rc, 
+    time::{Duration, Instant}, 
+};
+#[cfg(feature = "tokio")]
+use std::{future, pin::Pin};
+#[cfg(feature = "tokio")]
+use futures_core::Stream;
+use ratatui::{
+    layout::{Constraint, Layout}, 
+    style::Stylize, 
+    text::Line, 
+    widgets::Clear, 
+};
+use crate::{
+    State, 
+    color::{self, ColorMode}, 
+    dialog::Theme, 
+    remember::Memory, 
+    toast::{self, Toasts}, 
+    crossterm::event::{self, Event}, 
 };
-use crate::State;
 use self::managed::Wrapper;
 
 pub type Backend = ratatui::backend::CrosstermBackend<io::Stdout>;
@@ -17,6 +35,9 @@ enum Environment {
     Managed(Wrapper), 
     /// Just stores the [`Terminal`]. 
     Unmanaged(Terminal), 
+    /// Renders into an in-memory buffer and reads events from a script. Used for testing. 
+    #[cfg(test)]
+    Headless(headless::Headless), 
 }
 
 /// Manages the terminal environment. 
@@ -64,6 +85,47 @@ enum Environment {
 /// be avoided. 
 /// 
 /// 
+/// # Mismatched globals
+/// 
+/// A state can only be run with a context whose global has the type given by [`State::Global`]. Running a
+/// state with any other context fails to compile with a type mismatch at the call to [`State::run`], e.g.: 
+/// 
+/// ```text
+/// error[E0308]: mismatched types
+///    |
+///    |     Tally{ value: 0 }.run(ctx)
+///    |                       --- ^^^ expected `&mut Context`, found `&mut Context<User>`
+/// ```
+/// 
+/// Here, the `Tally` state has `type Global = ()`, but is run with a context holding a `User`. There are two
+/// ways of resolving this: 
+/// - Change `type Global` of the state to the type of the global held by the context (here `User`). 
+/// - Run the state with a context holding the right global, e.g. by [chaining](#chaining-with-new-globals)
+/// with `ctx.chain_without_global()` or `ctx.chain_with_global(...)`. 
+/// 
+/// Since this error does not mention [`State::Global`], it can be hard to decipher in larger applications. To
+/// make the intent explicit, the context may be passed through [`Context::expect_global`], which asserts the
+/// type of the global at compile time and has no run-time cost. On mismatch, the error names both globals
+/// and suggests the resolutions above: 
+/// 
+/// ```compile_fail,E0277
+/// # use tundra::prelude::*;
+/// # struct User;
+/// # struct Tally{ value: u32 }
+/// # impl State for Tally {
+/// #     type Result<T> = T;
+/// #     type Out = u32;
+/// #     type Global = ();
+/// #     fn draw(&self, _frame: &mut Frame) {}
+/// #     fn input(self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> { Signal::Return(0) }
+/// # }
+/// fn tally(ctx: &mut Context<User>) -> u32 {
+///     // error: expected a context with global `()`, found a context with global `User`
+///     Tally{ value: 0 }.run(ctx.expect_global::<()>())
+/// }
+/// ```
+/// 
+/// 
 /// # Custom panic handler
 /// 
 /// The installed panic handler will delegate to the previous one after resetting the terminal. If a custom
@@ -172,6 +234,33 @@ pub struct Context<G = ()> {
     /// A reference to the RAII wrapper over the terminal environment. This is reference-counted to allow for
     /// [chaining](Context#chaining-with-new-globals). 
     environment: Rc<RefCell<Environment>>, 
+    /// Values stashed by states between their destruction and re-creation. See the
+    /// [`remember`](crate::remember) module. This is shared between chained contexts. 
+    pub(crate) memory: Rc<RefCell<Memory>>, 
+    /// Whether dialog animations are shown. See [`Context::set_animations`]. 
+    animations: Rc<Cell<bool>>, 
+    /// Whether colours are used when drawing. See [`Context::set_color_mode`]. 
+    color_mode: Rc<Cell<ColorMode>>, 
+    /// Overrides of how dialogs are drawn. See [`Context::set_dialog_theme`]. 
+    theme: Rc<RefCell<Theme>>, 
+    /// Whether the application has been asked to exit. See [`Context::request_exit`]. 
+    exit: Rc<Cell<bool>>, 
+    /// Toasts drawn over the states until they expire. See [`Context::notify`]. 
+    toasts: Rc<RefCell<Toasts>>, 
+    /// The step of the running [wizard](crate::dialog::Wizard), added to the title of the dialogs it shows. 
+    /// This is shared between chained contexts. 
+    pub(crate) dialog_step: Rc<RefCell<Option<String>>>, 
+    /// The stream of events read by [asynchronous states](crate::StateAsync), created once first needed. This
+    /// is shared between chained contexts. 
+    #[cfg(feature = "tokio")]
+    events: Rc<RefCell<Option<event::EventStream>>>, 
+    /// Settings for the draw guard. See the [`debug`](crate::debug) module. 
+    #[cfg(feature = "debug-draw")]
+    guard: Rc<RefCell<crate::debug::Guard>>, 
+    /// Log records captured while the context is alive. See the [`log_capture`](crate::log_capture) module. 
+    /// This is shared between chained contexts. 
+    #[cfg(feature = "log")]
+    pub(crate) logs: Rc<crate::log_capture::Capture>, 
 }
 
 impl<G> Context<G> {
@@ -179,23 +268,44 @@ impl<G> Context<G> {
     pub fn with_global(global: G) -> io::Result<Self> {
         Wrapper::new()
             .map(Environment::Managed)
-            .map(|env| Self::with_global_impl(global, env))
+            .map(|env| Self::with_global_impl(global, env).capture_logs())
     }
 
     /// Creates a new context with given global value without a managed terminal environment. See the
     /// [type-level](Context#unmanaged-terminal-environment) documentation for more information. If no global
     /// is needed, prefer [`Context::new`]. 
     pub fn with_global_unmanaged(global: G, terminal: Terminal) -> Self {
-        Self::with_global_impl(global, Environment::Unmanaged(terminal))
+        Self::with_global_impl(global, Environment::Unmanaged(terminal)).capture_logs()
     }
 
     fn with_global_impl(global: G, environment: Environment) -> Self {
         Context {
             global, 
             environment: Rc::new(RefCell::new(environment)), 
+            memory: Default::default(), 
+            animations: Rc::new(Cell::new(true)), 
+            color_mode: Default::default(), 
+            theme: Default::default(), 
+            exit: Default::default(), 
+            toasts: Default::default(), 
+            dialog_step: Default::default(), 
+            #[cfg(feature = "tokio")]
+            events: Default::default(), 
+            #[cfg(feature = "debug-draw")]
+            guard: Default::default(), 
+            #[cfg(feature = "log")]
+            logs: Default::default(), 
         }
     }
 
+    /// Starts capturing log records into the context if the `log` feature is enabled. Headless contexts
+    /// don't, so that tests running in parallel don't capture each other's records. 
+    fn capture_logs(self) -> Self {
+        #[cfg(feature = "log")]
+        self.logs.attach();
+        self
+    }
+
     /// Applies an arbitrary function to the internal [`Terminal`] handle. 
     /// 
     /// 
@@ -215,6 +325,8 @@ impl<G> Context<G> {
         let term = match env.deref() {
             Environment::Unmanaged(term) => term, 
             Environment::Managed(wrapper) => &wrapper.0, 
+            #[cfg(test)]
+            Environment::Headless(_) => unimplemented!("Headless contexts have no terminal"), 
         };
         f(term)
     }
@@ -238,18 +350,303 @@ impl<G> Context<G> {
         let term = match env.deref_mut() {
             Environment::Unmanaged(term) => term, 
             Environment::Managed(wrapper) => &mut wrapper.0, 
+            #[cfg(test)]
+            Environment::Headless(_) => unimplemented!("Headless contexts have no terminal"), 
         };
         f(term)
     }
 
+    /// Temporarily hands the terminal back to the user while running given function, e.g. to open an external
+    /// program such as `$EDITOR`. 
+    /// 
+    /// For a [managed](Context#unmanaged-terminal-environment) context, the terminal environment is reset
+    /// before calling the function and initialised again afterwards, after which the next draw repaints the
+    /// whole screen. This also happens if the function panics, so that the terminal is still managed if the
+    /// panic is caught. Suspensions may be nested, in which case the terminal is only initialised once the
+    /// outermost one ends. For an unmanaged context, the function is just called. 
+    /// 
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if the terminal environment could not be initialised again. 
+    /// 
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// use std::process::Command;
+    /// # use tundra::Context;
+    /// 
+    /// # let mut ctx = Context::new().unwrap();
+    /// // let ctx: &mut Context<_>
+    /// let status = ctx.suspend(|| Command::new("vi").arg("notes.txt").status())??;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn suspend<T>(&mut self, f: impl FnOnce() -> T) -> io::Result<T> {
+        if !matches!(self.environment.borrow().deref(), Environment::Managed(_)) {
+            return Ok(f())
+        }
+        // the environment must not be borrowed while the function runs, in case it suspends again
+        let suspension = managed::Suspension::new(Rc::clone(&self.environment));
+        let out = f();
+        suspension.resume()?;
+        Ok(out)
+    }
+
     /// Draws a [`State`] using the internal [`Terminal`] handle. 
+    /// 
+    /// If the state has a [hint](State::hint), the bottom row of the frame is reserved for it and the state is
+    /// drawn in the remaining area with [`State::draw_in`]. Any [toasts](Context::notify) are drawn over the
+    /// state. 
     pub fn draw_state(&mut self, state: &impl State) -> io::Result<()> {
+        #[cfg(feature = "debug-draw")]
+        let guard = Rc::clone(&self.guard);
+        let toasts = Rc::clone(&self.toasts);
+        let colors = self.color_mode.get().enabled();
+
+        let draw = |frame: &mut ratatui::Frame| {
+            color::begin(colors);
+            #[cfg(feature = "debug-draw")]
+            guard.borrow().begin();
+
+            match state.hint() {
+                Some(hint) => {
+                    let [area, hint_area] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)])
+                        .areas(frame.area());
+                    state.draw_in(frame, area);
+                    let hint = Line::from(hint)
+                        .italic()
+                        .dim();
+                    frame.render_widget(Clear, hint_area);
+                    frame.render_widget(hint, hint_area);
+                }
+                None => state.draw(frame), 
+            }
+            toasts.borrow_mut().draw(frame);
+
+            #[cfg(feature = "debug-draw")]
+            guard.borrow_mut().end(frame);
+
+            if !colors {
+                color::strip(frame.buffer_mut());
+            }
+        };
+
+        #[cfg(test)]
+        if let Environment::Headless(headless) = self.environment.borrow_mut().deref_mut() {
+            return headless.terminal
+                .draw(draw)
+                .map(|_| ())
+        }
         self.apply_mut(|terminal| terminal
-            .draw(|frame| state.draw(frame))
+            .draw(draw)
             .map(|_| ())
         )
     }
 
+    /// Reads the next event from the terminal environment, blocking until one is available. 
+    pub(crate) fn read_event(&mut self) -> io::Result<Event> {
+        #[cfg(test)]
+        if let Environment::Headless(headless) = self.environment.borrow_mut().deref_mut() {
+            return Ok(headless.read_event())
+        }
+        event::read()
+    }
+
+    /// Waits at most `timeout` for an event to become available, returning whether one is. The state is
+    /// redrawn each time a [toast](Context::notify) expires while waiting, so that it disappears without
+    /// input. Without a timeout, this returns once no toasts remain, leaving it to [`Context::read_event`] to
+    /// block. 
+    pub(crate) fn wait_event(&mut self, state: &impl State, timeout: Option<Duration>) -> io::Result<bool> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            let expiry = self.toasts.borrow().expiry();
+            let wake = match (deadline, expiry) {
+                (None, None) => return Ok(true), 
+                (Some(deadline), None) => deadline, 
+                (None, Some(expiry)) => expiry, 
+                (Some(deadline), Some(expiry)) => Instant::min(deadline, expiry), 
+            };
+            if self.poll_event(wake.saturating_duration_since(Instant::now()))? {
+                return Ok(true)
+            }
+            if Some(wake) == deadline {
+                return Ok(false)
+            }
+            self.draw_state(state)?;
+        }
+    }
+
+    /// Whether an event is available to be read without blocking. 
+    pub(crate) fn event_pending(&mut self) -> io::Result<bool> {
+        self.poll_event(Duration::ZERO)
+    }
+
+    /// Waits at most `timeout` for an event to become available, returning whether one is. Headless contexts
+    /// never wait, since their events are scripted. 
+    pub(crate) fn poll_event(&mut self, timeout: Duration) -> io::Result<bool> {
+        #[cfg(test)]
+        if let Environment::Headless(headless) = self.environment.borrow_mut().deref_mut() {
+            return Ok(!headless.events.is_empty())
+        }
+        event::poll(timeout)
+    }
+
+    /// Enables or disables the open and close animations of [dialogs](crate::dialog::DrawInfo::animate_open). 
+    /// Animations are enabled by default, but are only shown by dialogs that opt in to them. Disabling them
+    /// may be desirable for accessibility, or when running over a slow connection such as SSH. 
+    /// 
+    /// The setting is shared between all contexts [chained](Context#chaining-with-new-globals) from this one. 
+    pub fn set_animations(&mut self, enabled: bool) {
+        self.animations.set(enabled);
+    }
+
+    /// Whether the open and close animations of dialogs are shown. See [`Context::set_animations`]. 
+    pub fn animations(&self) -> bool {
+        self.animations.get()
+    }
+
+    /// Sets whether colours are used when drawing. By default, they are used unless the `NO_COLOR`
+    /// environment variable is set. See the [`color`] module for more information. 
+    /// 
+    /// The setting is shared between all contexts [chained](Context#chaining-with-new-globals) from this one. 
+    pub fn set_color_mode(&mut self, mode: ColorMode) {
+        self.color_mode.set(mode);
+    }
+
+    /// How colours are used when drawing. See [`Context::set_color_mode`]. 
+    pub fn color_mode(&self) -> ColorMode {
+        self.color_mode.get()
+    }
+
+    /// Installs a theme overriding how the library provided [dialogs](crate::dialog) are drawn. See
+    /// [`Theme`] for which dialogs each part of the theme applies to. 
+    /// 
+    /// The setting is shared between all contexts [chained](Context#chaining-with-new-globals) from this one. 
+    pub fn set_dialog_theme(&mut self, theme: Theme) {
+        *self.theme.borrow_mut() = theme;
+    }
+
+    /// The theme of the library provided dialogs. See [`Context::set_dialog_theme`]. 
+    pub fn dialog_theme(&self) -> Theme {
+        self.theme.borrow().clone()
+    }
+
+    /// Shows a toast --- a transient notification such as "Saved ✓" --- over the states for given duration. 
+    /// See the [`toast`] module for more information. 
+    /// 
+    /// The toasts are shared between all contexts [chained](Context#chaining-with-new-globals) from this one. 
+    pub fn notify(&mut self, msg: impl Into<String>, level: toast::Level, duration: Duration) {
+        self.toasts
+            .borrow_mut()
+            .push(msg.into(), level, duration);
+    }
+
+    /// Enables or disables bracketed paste in the managed terminal environment. While enabled, text pasted
+    /// by the user is read as a single [`Event::Paste`] instead of one key press per character, which is
+    /// delivered to [states](State::paste) and [dialogs](crate::dialog::Dialog::paste), and which
+    /// [forms](crate::dialog::form!) pass on to the focused [field](crate::field::Field::paste). 
+    /// 
+    /// Bracketed paste is disabled by default, since states handling only key presses through
+    /// [`State::input`] would otherwise ignore pasted text altogether. The setting is kept while the
+    /// environment is [suspended](Context::suspend), and applies to all contexts. In an
+    /// [unmanaged](Context#unmanaged-terminal-environment) environment, this has no effect; bracketed paste
+    /// is then enabled by the application with [`EnableBracketedPaste`](event::EnableBracketedPaste). 
+    pub fn set_bracketed_paste(&mut self, enabled: bool) -> io::Result<()> {
+        match self.environment.borrow().deref() {
+            Environment::Managed(_) => managed::set_bracketed_paste(enabled), 
+            _ => Ok(()), 
+        }
+    }
+
+    /// Enables or disables focus reporting in the managed terminal environment. While enabled, the terminal
+    /// reports when its window gains or loses focus, which is delivered to [states](State::focus_changed) and
+    /// [dialogs](crate::dialog::Dialog::focus_changed); e.g. to pause an auto-refreshing view while the user
+    /// is looking elsewhere. 
+    /// 
+    /// Focus reporting is disabled by default, and not all terminals support it. The setting is kept while the
+    /// environment is [suspended](Context::suspend), and applies to all contexts. In an
+    /// [unmanaged](Context#unmanaged-terminal-environment) environment, this has no effect; focus reporting
+    /// is then enabled by the application with [`EnableFocusChange`](event::EnableFocusChange). 
+    pub fn set_focus_reporting(&mut self, enabled: bool) -> io::Result<()> {
+        match self.environment.borrow().deref() {
+            Environment::Managed(_) => managed::set_focus_reporting(enabled), 
+            _ => Ok(()), 
+        }
+    }
+
+    /// Asks the application to exit, unwinding all running states. Each [`State::run`] in the call stack then
+    /// asks its state to [exit](State::exit) before handling the next event, which is how an application is
+    /// quit from deep within nested states without threading a flag through the [output](State::Out) of each. 
+    /// 
+    /// The request is shared between all contexts [chained](Context#chaining-with-new-globals) from this one, 
+    /// and stays in effect until cancelled with [`Context::cancel_exit`]. 
+    /// 
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// # use tundra::prelude::*;
+    /// # struct Editor;
+    /// # impl State for Editor {
+    /// #     type Result<T> = T;
+    /// #     type Out = ();
+    /// #     type Global = ();
+    /// #     fn draw(&self, _frame: &mut Frame) {}
+    /// fn input(self, key: KeyEvent, ctx: &mut Context) -> Signal<Self> {
+    ///     if key.code == KeyCode::Char('q') && key.modifiers == KeyModifiers::CONTROL {
+    ///         ctx.request_exit();
+    ///     }
+    ///     Signal::Continue(self)
+    /// }
+    /// # }
+    /// ```
+    pub fn request_exit(&mut self) {
+        self.exit.set(true);
+    }
+
+    /// Whether an exit has been requested with [`Context::request_exit`] and not yet cancelled. 
+    pub fn exit_requested(&self) -> bool {
+        self.exit.get()
+    }
+
+    /// Cancels a request to exit made with [`Context::request_exit`], e.g. once the states that should exit
+    /// have done so, or if the user changes their mind. 
+    pub fn cancel_exit(&mut self) {
+        self.exit.set(false);
+    }
+
+    /// Enables or disables the draw guard, which checks all areas marked with
+    /// [`debug::mark`](crate::debug::mark) against the bounds of the frame after each draw. By default,
+    /// violations cause a panic; see [`Context::set_draw_reporter`] to override this. 
+    /// 
+    /// The draw guard is only active in debug builds. See the [`debug`](crate::debug) module for more
+    /// information. 
+    #[cfg(feature = "debug-draw")]
+    pub fn set_draw_guard(&mut self, enabled: bool) {
+        self.guard.borrow_mut().enabled = enabled;
+    }
+
+    /// Sets the callback invoked for each violation found by the [draw guard](Context::set_draw_guard). 
+    #[cfg(feature = "debug-draw")]
+    pub fn set_draw_reporter(&mut self, report: impl FnMut(crate::debug::Violation) + 'static) {
+        self.guard.borrow_mut().report = Box::new(report);
+    }
+
+    /// Enables or disables drawing the outlines of all areas marked with
+    /// [`debug::mark`](crate::debug::mark). This is only active in debug builds. 
+    #[cfg(feature = "debug-draw")]
+    pub fn set_draw_outlines(&mut self, enabled: bool) {
+        self.guard.borrow_mut().outlines = enabled;
+    }
+
+    /// Removes and returns all [captured log records](crate::log_capture), oldest first. This can be used
+    /// to persist the records when the application exits. 
+    #[cfg(feature = "log")]
+    pub fn drain_logs(&mut self) -> Vec<crate::log_capture::Record> {
+        self.logs.drain()
+    }
+
     /// Creates a new context with a new global from an existing context, reusing the internal [`Terminal`]
     /// handle. This can be used "replace" the global value. See the
     /// [context documentation](Context#chaining-with-new-globals) for more information. 
@@ -257,6 +654,19 @@ impl<G> Context<G> {
         Context {
             global, 
             environment: Rc::clone(&self.environment), 
+            memory: Rc::clone(&self.memory), 
+            animations: Rc::clone(&self.animations), 
+            color_mode: Rc::clone(&self.color_mode), 
+            theme: Rc::clone(&self.theme), 
+            exit: Rc::clone(&self.exit), 
+            toasts: Rc::clone(&self.toasts), 
+            dialog_step: Rc::clone(&self.dialog_step), 
+            #[cfg(feature = "tokio")]
+            events: Rc::clone(&self.events), 
+            #[cfg(feature = "debug-draw")]
+            guard: Rc::clone(&self.guard), 
+            #[cfg(feature = "log")]
+            logs: Rc::clone(&self.logs), 
         }
     }
 
@@ -266,6 +676,121 @@ impl<G> Context<G> {
     pub fn chain_without_global(&self) -> Context {
         self.chain_with_global(())
     }
+
+    /// Asserts at compile time that the global of the context has type `H`, and returns the context
+    /// unchanged. This produces a clearer error than running a state with a context of the wrong global. See
+    /// the [context documentation](Context#mismatched-globals) for more information. 
+    /// 
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// # use tundra::prelude::*;
+    /// # struct User;
+    /// # fn tally(ctx: &mut Context) {}
+    /// # let ctx = &mut Context::with_global(User)?;
+    /// // let ctx: &mut Context<User>
+    /// let ctx: &mut Context<User> = ctx.expect_global::<User>();
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn expect_global<H>(&mut self) -> &mut Context<H>
+    where
+        G: HasGlobal<H>, 
+    {
+        G::cast(self)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<G> Context<G> {
+    /// Waits at most `timeout` for the next event without blocking the thread, giving `None` if none arrives
+    /// in time. Like [`Context::wait_event`], the state is redrawn each time a toast expires while waiting.
+    /// Headless contexts never wait, since their events are scripted. 
+    pub(crate) async fn next_event_async(
+        &mut self, 
+        state: &impl State, 
+        timeout: Option<Duration>, 
+    ) -> io::Result<Option<Event>> {
+        #[cfg(test)]
+        if matches!(self.environment.borrow().deref(), Environment::Headless(_)) {
+            // yielding once like a terminal would, so that futures awaited alongside the state get to run
+            let mut yielded = false;
+            future::poll_fn(|cx| match yielded {
+                true => std::task::Poll::Ready(()), 
+                false => {
+                    yielded = true;
+                    cx.waker().wake_by_ref();
+                    std::task::Poll::Pending
+                }
+            })
+            .await;
+            let mut environment = self.environment.borrow_mut();
+            let Environment::Headless(headless) = environment.deref_mut() else { unreachable!() };
+            return Ok(match (headless.events.is_empty(), timeout) {
+                (true, Some(_)) => None, 
+                _ => Some(headless.read_event()), 
+            })
+        }
+        // the stream is taken while waiting, since nested states may only be run once an event is handled
+        let mut events = self.events.take().unwrap_or_default();
+        let event = self.wait_stream(&mut events, state, timeout).await;
+        *self.events.borrow_mut() = Some(events);
+        event
+    }
+
+    async fn wait_stream(
+        &mut self, 
+        events: &mut event::EventStream, 
+        state: &impl State, 
+        timeout: Option<Duration>, 
+    ) -> io::Result<Option<Event>> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            let expiry = self.toasts.borrow().expiry();
+            let wake = match (deadline, expiry) {
+                (Some(deadline), Some(expiry)) => Some(Instant::min(deadline, expiry)), 
+                (deadline, expiry) => deadline.or(expiry), 
+            };
+            let next = future::poll_fn(|cx| Pin::new(&mut *events).poll_next(cx));
+            let sleep = async {
+                match wake {
+                    Some(wake) => tokio::time::sleep_until(wake.into()).await, 
+                    None => future::pending().await, 
+                }
+            };
+            tokio::select! {
+                event = next => return match event {
+                    Some(event) => event.map(Some), 
+                    None => Err(io::Error::other("The event stream has ended")), 
+                }, 
+                _ = sleep => (), 
+            }
+            if wake == deadline {
+                return Ok(None)
+            }
+            self.draw_state(state)?;
+        }
+    }
+}
+
+/// Implemented by the type of a context global `G` for `H` only when `G` and `H` are the same type. Used by
+/// [`Context::expect_global`] to produce a readable error on mismatch. 
+#[diagnostic::on_unimplemented(
+    message = "expected a context with global `{H}`, found a context with global `{Self}`", 
+    label = "this context holds a global of type `{Self}`", 
+    note = "states can only be run with contexts whose global has the type given by `State::Global`", 
+    note = "either change `type Global` of the state to `{Self}`, or run the state with a chained context \
+        holding the right global using `ctx.chain_with_global(...)` or `ctx.chain_without_global()`", 
+)]
+pub trait HasGlobal<H>: Sized {
+    /// Identity conversion between two contexts holding the same type of global. 
+    fn cast(ctx: &mut Context<Self>) -> &mut Context<H>;
+}
+
+impl<G> HasGlobal<G> for G {
+    fn cast(ctx: &mut Context<G>) -> &mut Context<G> {
+        ctx
+    }
 }
 
 impl Context<()> {
@@ -282,18 +807,120 @@ impl Context<()> {
     }
 }
 
+#[cfg(test)]
+impl Context<()> {
+    /// Creates a new context without a global value that renders into an in-memory buffer of given size, and
+    /// reads events from given script. 
+    pub(crate) fn headless(width: u16, height: u16, events: impl IntoIterator<Item = Event>) -> Context {
+        let environment = Environment::Headless(headless::Headless::new(width, height, events));
+        let ctx = Context::with_global_impl((), environment);
+        // tests should not depend on the environment they run in
+        ctx.color_mode.set(ColorMode::Always);
+        ctx
+    }
+}
+
+#[cfg(test)]
+impl<G> Context<G> {
+    /// The contents of the buffer most recently drawn to a headless context. 
+    pub(crate) fn buffer(&self) -> ratatui::buffer::Buffer {
+        match self.environment.borrow().deref() {
+            Environment::Headless(headless) => headless.terminal.backend().buffer().clone(), 
+            _ => unimplemented!("Only headless contexts can be inspected"), 
+        }
+    }
+
+    /// Whether all scripted events have been read from a headless context. 
+    pub(crate) fn script_finished(&self) -> bool {
+        match self.environment.borrow().deref() {
+            Environment::Headless(headless) => headless.events.is_empty(), 
+            _ => unimplemented!("Only headless contexts are scripted"), 
+        }
+    }
+
+    /// The text most recently drawn to a headless context, with each row on its own line. 
+    pub(crate) fn screen(&self) -> String {
+        let buffer = self.buffer();
+        let area = buffer.area;
+        (area.top()..area.bottom())
+            .map(|y| (area.left()..area.right()).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Events pressing each of given keys without modifiers, for use as the script of a headless context. 
+#[cfg(test)]
+pub(crate) fn script(codes: impl IntoIterator<Item = event::KeyCode>) -> Vec<Event> {
+    use event::{KeyEvent, KeyModifiers};
+    codes
+        .into_iter()
+        .map(|code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+        .collect()
+}
+
+#[cfg(test)]
+mod headless {
+    use std::collections::VecDeque;
+    use ratatui::{backend::TestBackend, layout::Rect, Terminal, TerminalOptions, Viewport};
+    use crate::crossterm::event::Event;
+
+    /// Terminal environment rendering into an in-memory buffer and reading events from a script. 
+    #[derive(Debug)]
+    pub struct Headless {
+        pub terminal: Terminal<TestBackend>, 
+        pub events: VecDeque<Event>, 
+    }
+
+    impl Headless {
+        pub fn new(width: u16, height: u16, events: impl IntoIterator<Item = Event>) -> Headless {
+            let backend = TestBackend::new(width, height);
+            let options = TerminalOptions {
+                viewport: Viewport::Fixed(Rect::new(0, 0, width, height)), 
+            };
+            Headless {
+                terminal: Terminal::with_options(backend, options).expect("The test backend is infallible"), 
+                events: events.into_iter().collect(), 
+            }
+        }
+
+        /// Reads the next scripted event. Resize events resize the in-memory buffer, like they would the
+        /// terminal. 
+        /// 
+        /// 
+        /// # Panics
+        /// 
+        /// When the script has run out of events, since the state under test would otherwise block forever. 
+        pub fn read_event(&mut self) -> Event {
+            let event = self.events
+                .pop_front()
+                .expect("Scripted events ran out");
+            if let Event::Resize(width, height) = event {
+                self.terminal.backend_mut().resize(width, height);
+                self.terminal
+                    .resize(Rect::new(0, 0, width, height))
+                    .expect("The test backend is infallible");
+            }
+            event
+        }
+    }
+}
+
 mod managed {
     use std::{
+        cell::RefCell, 
         io, 
         panic, 
-        sync::atomic::{AtomicBool, Ordering}, 
+        rc::Rc, 
+        sync::atomic::{AtomicBool, AtomicUsize, Ordering}, 
     };
     use crate::crossterm::{
         self, 
         terminal::{self, EnterAlternateScreen, LeaveAlternateScreen}, 
         cursor::{Hide, Show}, 
+        event::{EnableBracketedPaste, DisableBracketedPaste, EnableFocusChange, DisableFocusChange}, 
     };
-    use super::{Terminal, Backend};
+    use super::{Terminal, Backend, Environment};
 
     /// RAII wrapper over [`Terminal`] to initialize/reset the terminal environment. 
     #[derive(Debug)]
@@ -307,7 +934,78 @@ mod managed {
 
     impl Drop for Wrapper {
         fn drop(&mut self) {
-            reset()
+            reset();
+        }
+    }
+
+    /// The number of ongoing suspensions, across all contexts. 
+    static SUSPENDED: AtomicUsize = AtomicUsize::new(0);
+
+    /// Whether bracketed paste is enabled, across all contexts. 
+    static BRACKETED_PASTE: AtomicBool = AtomicBool::new(false);
+
+    /// Enables or disables bracketed paste, taking effect immediately unless suspended. 
+    pub fn set_bracketed_paste(enabled: bool) -> io::Result<()> {
+        BRACKETED_PASTE.store(enabled, Ordering::SeqCst);
+        match (SUSPENDED.load(Ordering::SeqCst), enabled) {
+            (0, true) => crossterm::execute!(io::stdout(), EnableBracketedPaste), 
+            (0, false) => crossterm::execute!(io::stdout(), DisableBracketedPaste), 
+            _ => Ok(()), 
+        }
+    }
+
+    /// Whether focus reporting is enabled, across all contexts. 
+    static FOCUS_REPORTING: AtomicBool = AtomicBool::new(false);
+
+    /// Enables or disables focus reporting, taking effect immediately unless suspended. 
+    pub fn set_focus_reporting(enabled: bool) -> io::Result<()> {
+        FOCUS_REPORTING.store(enabled, Ordering::SeqCst);
+        match (SUSPENDED.load(Ordering::SeqCst), enabled) {
+            (0, true) => crossterm::execute!(io::stdout(), EnableFocusChange), 
+            (0, false) => crossterm::execute!(io::stdout(), DisableFocusChange), 
+            _ => Ok(()), 
+        }
+    }
+
+    /// RAII guard resetting the terminal environment while alive. The environment is initialised again when
+    /// the outermost suspension ends, even if it ends by unwinding. 
+    pub struct Suspension {
+        environment: Rc<RefCell<Environment>>, 
+        resumed: bool, 
+    }
+
+    impl Suspension {
+        pub fn new(environment: Rc<RefCell<Environment>>) -> Suspension {
+            if SUSPENDED.fetch_add(1, Ordering::SeqCst) == 0 {
+                reset();
+            }
+            Suspension{ environment, resumed: false }
+        }
+
+        /// Ends the suspension, reporting any error that occurs while initialising the terminal environment. 
+        pub fn resume(mut self) -> io::Result<()> {
+            self.resumed = true;
+            self.resume_impl()
+        }
+
+        fn resume_impl(&self) -> io::Result<()> {
+            if SUSPENDED.fetch_sub(1, Ordering::SeqCst) != 1 {
+                return Ok(())
+            }
+            enter()?;
+            // forget what was previously drawn, since the external program may have drawn over it
+            match &mut *self.environment.borrow_mut() {
+                Environment::Managed(Wrapper(term)) => term.clear(), 
+                _ => Ok(()), 
+            }
+        }
+    }
+
+    impl Drop for Suspension {
+        fn drop(&mut self) {
+            if !self.resumed {
+                let _ = self.resume_impl();
+            }
         }
     }
 
@@ -332,9 +1030,22 @@ mod managed {
                 prev_hook(info);
             }));
         }
+        enter()?;
+        Ok(term)
+    }
+
+    /// Enables raw mode, hides the cursor, and enters an alternate terminal buffer. Also enables bracketed
+    /// paste and focus reporting, if requested. 
+    fn enter() -> io::Result<()> {
         terminal::enable_raw_mode()?;
         crossterm::execute!(io::stdout(), Hide, EnterAlternateScreen)?;
-        Ok(term)
+        if BRACKETED_PASTE.load(Ordering::SeqCst) {
+            crossterm::execute!(io::stdout(), EnableBracketedPaste)?;
+        }
+        if FOCUS_REPORTING.load(Ordering::SeqCst) {
+            crossterm::execute!(io::stdout(), EnableFocusChange)?;
+        }
+        Ok(())
     }
     
     /// Resets the terminal environment. 
@@ -342,10 +1053,18 @@ mod managed {
     /// - Disables raw mode. 
     /// - Shows the cursor. 
     /// - Leaves the alternate terminal buffer. 
+    /// - Disables bracketed paste, if enabled. 
+    /// - Disables focus reporting, if enabled. 
     fn reset() {
         // if anything goes wrong, try to continue resetting the terminal; the program is probably closing
         // anyways
         let _ = terminal::disable_raw_mode();
         let _ = crossterm::execute!(io::stdout(), Show, LeaveAlternateScreen);
+        if BRACKETED_PASTE.load(Ordering::SeqCst) {
+            let _ = crossterm::execute!(io::stdout(), DisableBracketedPaste);
+        }
+        if FOCUS_REPORTING.load(Ordering::SeqCst) {
+            let _ = crossterm::execute!(io::stdout(), DisableFocusChange);
+        }
     }
 }
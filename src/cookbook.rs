@@ -0,0 +1,195 @@
+//! Ready-made functions for common patterns in applications. 
+//! 
+//! Each function here is small enough to be written by hand, but encodes a pattern that is easy to get
+//! subtly wrong or that is otherwise rewritten in every application. They are built entirely on the public
+//! API of the library, and so double as examples of how to use it. 
+//! 
+//! The following functions are defined in this module: 
+//! - [`cookbook::confirm_quit`](confirm_quit) asks the user to confirm before quitting. 
+//! - [`cookbook::retry_on_error`](retry_on_error) runs a fallible operation, letting the user retry it on
+//! failure. 
+//! - [`cookbook::edit_string`](edit_string) lets the user edit a single string. 
+
+use std::fmt::Display;
+use crate::{dialog::ConfirmOptions, prelude::*};
+
+/// Asks the user to confirm before quitting the application, with the state being quit shown in the
+/// background. 
+/// 
+/// This is typically called when the user presses `escape` in the top-level state. 
+/// 
+/// 
+/// # Returns
+/// 
+/// Whether the user confirmed. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, cookbook};
+/// 
+/// struct Manager;
+/// 
+/// impl State for Manager {
+///     type Result<T> = T;
+///     type Out = ();
+///     type Global = ();
+/// 
+///     fn draw(&self, frame: &mut Frame) {
+///         // ...
+///     }
+/// 
+///     fn input(self, key: KeyEvent, ctx: &mut Context) -> Signal<Self> {
+///         match key.code {
+///             KeyCode::Esc if cookbook::confirm_quit(&self, ctx) => Signal::Return(()), 
+///             _ => Signal::Continue(self), 
+///         }
+///     }
+/// }
+/// ```
+pub fn confirm_quit<G>(state: &impl State, ctx: &mut Context<G>) -> bool {
+    dialog::confirm("Are you sure you want to quit?", state, ctx)
+}
+
+/// Runs a fallible operation until it succeeds or the user gives up. 
+/// 
+/// Each time the operation fails, the error is shown in a red [confirmation dialog](dialog::confirm_with)
+/// over the given background state, and the user is asked whether to retry the operation or to cancel. 
+/// 
+/// 
+/// # Returns
+/// 
+/// - `Some` with the value of the first successful run of the operation. 
+/// - `None` if the user cancelled after a failed run. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, cookbook};
+/// 
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// 
+/// let contents = cookbook::retry_on_error(
+///     || std::fs::read_to_string("units.csv"), 
+///     current_state, 
+///     ctx, 
+/// );
+/// ```
+pub fn retry_on_error<T, E: Display, G>(
+    mut op: impl FnMut() -> Result<T, E>, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> Option<T> {
+    loop {
+        match op() {
+            Ok(value) => break Some(value), 
+            Err(error) => {
+                let options = ConfirmOptions {
+                    yes_label: "Retry".into(), 
+                    no_label: "Cancel".into(), 
+                    default: true, 
+                    danger: true, 
+                    ..Default::default()
+                };
+                if !dialog::confirm_with(error.to_string(), options, over, ctx) {
+                    break None
+                }
+            }
+        }
+    }
+}
+
+/// Lets the user edit a single string in an [input dialog](dialog::input_with) with given message. 
+/// 
+/// 
+/// # Returns
+/// 
+/// - `Some` with the edited string if the user submitted it. 
+/// - `None` if the user cancelled. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, cookbook};
+/// 
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// 
+/// if let Some(location) = cookbook::edit_string("Location?", "Svalbard", current_state, ctx) {
+///     // ...
+/// }
+/// ```
+pub fn edit_string<G>(
+    msg: impl AsRef<str>, 
+    initial: impl Into<String>, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> Option<String> {
+    dialog::input_with(msg, initial, over, ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::script;
+    use super::*;
+
+    #[test]
+    fn quit() {
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Char('y')]));
+        assert!(confirm_quit(&(), ctx));
+
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        assert!(!confirm_quit(&(), ctx));
+    }
+
+    #[test]
+    fn retry() {
+        // fails twice before succeeding, and the user retries both times
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Char('r'), KeyCode::Char('r')]));
+        let mut attempts = 0;
+        let op = || {
+            attempts += 1;
+            match attempts {
+                3 => Ok(attempts), 
+                _ => Err("Connection refused"), 
+            }
+        };
+        assert_eq!(retry_on_error(op, &(), ctx), Some(3));
+        assert!(ctx.script_finished());
+        let screen = ctx.screen();
+        assert!(screen.contains("Connection refused"));
+        assert!(screen.contains("[ Retry ]  [ Cancel ]"));
+
+        // the user cancels after the first failure; the operation is not run again
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        let mut attempts = 0;
+        let op = || {
+            attempts += 1;
+            Err::<(), _>("Connection refused")
+        };
+        assert_eq!(retry_on_error(op, &(), ctx), None);
+        assert_eq!(attempts, 1);
+
+        // succeeding immediately shows nothing
+        let ctx = &mut Context::headless(80, 24, []);
+        assert_eq!(retry_on_error(|| Ok::<_, &str>(1), &(), ctx), Some(1));
+    }
+
+    #[test]
+    fn edit() {
+        let keys = [KeyCode::Backspace, KeyCode::Char('d'), KeyCode::Enter];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        assert_eq!(edit_string("Rename", "Svalbarx", &(), ctx).as_deref(), Some("Svalbard"));
+
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        assert_eq!(edit_string("Rename", "Svalbard", &(), ctx), None);
+    }
+}
@@ -0,0 +1,178 @@
+//! Developer tools for debugging the layout of [states](crate::State). 
+//! 
+//! When computing the areas of widgets by hand, it is easy to accidentally render a widget into an area that
+//! falls partially outside the frame (e.g. due to a stale size), which silently clips the widget. To catch
+//! this, states may register the areas they intend to render into with [`debug::mark`](mark). With the draw
+//! guard enabled through [`Context::set_draw_guard`](crate::Context::set_draw_guard), all marked areas are
+//! checked against the bounds of the frame after each draw, and any violation is reported. 
+//! 
+//! The marked areas can also be visualised by outlining them with
+//! [`Context::set_draw_outlines`](crate::Context::set_draw_outlines), which is useful for debugging layouts
+//! in general. 
+//! 
+//! This module requires the `debug-draw` feature. The draw guard is only active in debug builds; in release
+//! builds, marking an area is a no-op. 
+//! 
+//! 
+//! # Examples
+//! 
+//! ```no_run
+//! use tundra::{prelude::*, debug};
+//! use tundra::ratatui::{layout::Rect, widgets::Paragraph};
+//! 
+//! # struct MyState;
+//! # impl MyState {
+//! fn draw(&self, frame: &mut Frame) {
+//!     let area = Rect::new(0, 0, 80, 5);
+//!     debug::mark(area, "title");
+//!     frame.render_widget(Paragraph::new("Hello!"), area);
+//! }
+//! # }
+//! 
+//! # let mut ctx = Context::new()?;
+//! // enable the draw guard, reporting any marked area that falls outside the frame
+//! ctx.set_draw_guard(true);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::{
+    borrow::Cow, 
+    cell::RefCell, 
+    fmt, 
+};
+use ratatui::{
+    layout::Rect, 
+    style::{Color, Stylize}, 
+    widgets::Block, 
+    Frame, 
+};
+
+/// An area that was marked with [`debug::mark`](mark) but falls partially outside the frame. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Violation {
+    /// The label given when the area was marked. 
+    pub label: Cow<'static, str>, 
+    /// The marked area. 
+    pub area: Rect, 
+    /// The area of the frame. 
+    pub frame: Rect, 
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Violation{ label, area, frame } = self;
+        write!(f, "Area `{label}` ({area}) falls outside the frame ({frame})")
+    }
+}
+
+/// Registers an area that a widget is intended to be rendered into. 
+/// 
+/// The label is used to identify the area when reporting violations and when drawing outlines. See the
+/// [module-level](self) documentation for more information. 
+pub fn mark(area: Rect, label: impl Into<Cow<'static, str>>) {
+    if cfg!(debug_assertions) {
+        MARKS.with_borrow_mut(|marks| marks.push((area, label.into())))
+    }
+}
+
+/// Whether the inner area lies entirely within the outer area. 
+pub(crate) fn contains(outer: Rect, inner: Rect) -> bool {
+    inner.x >= outer.x
+        && inner.y >= outer.y
+        && inner.right() <= outer.right()
+        && inner.bottom() <= outer.bottom()
+}
+
+thread_local! {
+    /// Areas marked since the start of the current draw. 
+    static MARKS: RefCell<Vec<(Rect, Cow<'static, str>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Settings for the draw guard, stored inside the [context](crate::Context). 
+pub(crate) struct Guard {
+    /// Whether marked areas are checked against the frame bounds. 
+    pub enabled: bool, 
+    /// Whether marked areas are outlined. 
+    pub outlines: bool, 
+    /// Callback invoked for each violation. 
+    pub report: Box<dyn FnMut(Violation)>, 
+}
+
+impl Default for Guard {
+    fn default() -> Self {
+        Guard {
+            enabled: false, 
+            outlines: false, 
+            report: Box::new(|violation| panic!("{violation}")), 
+        }
+    }
+}
+
+impl fmt::Debug for Guard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Guard")
+            .field("enabled", &self.enabled)
+            .field("outlines", &self.outlines)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Guard {
+    /// Forgets all areas marked during the previous draw. Called before drawing the state. 
+    pub fn begin(&self) {
+        MARKS.with_borrow_mut(Vec::clear)
+    }
+
+    /// Checks the marked areas and draws their outlines, if enabled. Called after drawing the state. 
+    pub fn end(&mut self, frame: &mut Frame) {
+        if !cfg!(debug_assertions) || !(self.enabled || self.outlines) {
+            return
+        }
+        let frame_area = frame.area();
+        let marks = MARKS.take();
+
+        if self.outlines {
+            for (area, label) in &marks {
+                let area = area.intersection(frame_area);
+                let block = Block::bordered()
+                    .title_top(label.as_ref())
+                    .fg(Color::Magenta);
+                frame.render_widget(block, area);
+            }
+        }
+        if self.enabled {
+            marks
+                .into_iter()
+                .filter(|(area, _)| !contains(frame_area, *area))
+                .map(|(area, label)| Violation{ label, area, frame: frame_area })
+                .for_each(&mut self.report);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Rect;
+    use super::contains;
+
+    #[test]
+    fn bounds() {
+        let frame = Rect::new(0, 0, 80, 24);
+
+        assert!(contains(frame, frame));
+        assert!(contains(frame, Rect::new(10, 5, 20, 10)));
+        assert!(contains(frame, Rect::new(0, 0, 0, 0)));
+        assert!(contains(frame, Rect::new(79, 23, 1, 1)));
+
+        // partially outside to the right and bottom
+        assert!(!contains(frame, Rect::new(70, 0, 11, 1)));
+        assert!(!contains(frame, Rect::new(0, 20, 1, 5)));
+        // entirely outside
+        assert!(!contains(frame, Rect::new(80, 24, 1, 1)));
+
+        // frames need not be positioned at the origin
+        let frame = Rect::new(5, 5, 10, 10);
+        assert!(!contains(frame, Rect::new(4, 5, 2, 2)));
+        assert!(contains(frame, Rect::new(5, 5, 10, 10)));
+    }
+}
@@ -0,0 +1,162 @@
+//! Open and close animations of dialog boxes. See [`DrawInfo::animate_open`] and [`DrawInfo::animate_close`]. 
+
+use std::{thread, time::Duration};
+use super::*;
+
+/// Describes a grow-in or shrink-out animation of a dialog box. Used by [`DrawInfo::animate_open`] and
+/// [`DrawInfo::animate_close`]. 
+/// 
+/// Each intermediate frame draws an empty box with the [border preset](DrawInfo::border) (thick if none is
+/// given) and [colour](DrawInfo::color) of the dialog, scaled around the centre of the final dialog box. No
+/// input is processed while the animation runs. With the [default](AnimationSpec::default) specification
+/// this amounts to 40 ms, which is short enough to go unnoticed. 
+/// 
+/// Animations can be disabled for all dialogs with [`Context::set_animations`]; e.g. for accessibility or
+/// over slow connections. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct AnimationSpec {
+    /// The size of the dialog box in each intermediate frame, as a percentage (between `0` and `100`) of its
+    /// final size. The steps are given in the order of an open animation, and are reversed when closing. 
+    /// Default: `[40, 70]`. 
+    pub steps: Cow<'static, [u8]>, 
+    /// How long each intermediate frame is shown. Default: 20 ms. 
+    pub frame_time: Duration, 
+}
+
+impl Default for AnimationSpec {
+    fn default() -> Self {
+        AnimationSpec {
+            steps: Cow::Borrowed(&[40, 70]), 
+            frame_time: Duration::from_millis(20), 
+        }
+    }
+}
+
+/// Scales an area around its centre by a percentage (between `0` and `100`). The scaled area is at least
+/// 2x2 cells --- the smallest box with a border --- unless the area itself is smaller. 
+pub(super) fn interpolate(area: Rect, percentage: u8) -> Rect {
+    let scale = |length: u16| {
+        let scaled = (length as u32 * percentage.min(100) as u32 / 100) as u16;
+        scaled.max(2).min(length)
+    };
+    let [width, height] = [scale(area.width), scale(area.height)];
+    Rect {
+        x: area.x + (area.width - width) / 2, 
+        y: area.y + (area.height - height) / 2, 
+        width, 
+        height, 
+    }
+}
+
+/// Draws the empty box shown in the intermediate frames of an animation. 
+pub(super) fn draw_outline(frame: &mut Frame, area: Rect, color: Color, border: BorderPreset) {
+    let block = border.create_block()().fg(color);
+    frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+}
+
+/// Everything needed to animate the closing of a dialog after its contents have been consumed. Recorded by
+/// the [`Container`] each time the dialog is drawn. 
+#[derive(Clone, Debug)]
+pub(super) struct Closing {
+    pub spec: AnimationSpec, 
+    /// The area of the dialog box as last drawn. 
+    pub area: Rect, 
+    pub color: Color, 
+    pub border: BorderPreset, 
+}
+
+impl Closing {
+    /// Shows the shrinking dialog box over the background. 
+    pub fn run<G>(self, background: &impl State, ctx: &mut Context<G>) {
+        for percentage in self.spec.steps.iter().rev().copied() {
+            ctx.draw_state(&Step{ closing: &self, background, percentage }).unwrap();
+            thread::sleep(self.spec.frame_time);
+        }
+    }
+
+    /// Same as [`Closing::run`], but without blocking the thread between frames. 
+    #[cfg(feature = "tokio")]
+    pub async fn run_async<G>(self, background: &impl State, ctx: &mut Context<G>) {
+        for percentage in self.spec.steps.iter().rev().copied() {
+            ctx.draw_state(&Step{ closing: &self, background, percentage }).unwrap();
+            tokio::time::sleep(self.spec.frame_time).await;
+        }
+    }
+}
+
+/// A single intermediate frame of a closing dialog box. 
+struct Step<'a, U> {
+    closing: &'a Closing, 
+    background: &'a U, 
+    percentage: u8, 
+}
+
+impl<U: State> State for Step<'_, U> {
+    type Result<T> = T;
+    type Out = ();
+    type Global = ();
+
+    fn draw(&self, frame: &mut Frame) {
+        let Closing{ area, color, border, .. } = *self.closing;
+        self.background.draw(frame);
+        draw_outline(frame, interpolate(area, self.percentage), color, border);
+    }
+}
+
+/// Shows the growing dialog box of a container over its background. 
+pub(super) fn open<T, U>(container: &Container<T, U>, spec: &AnimationSpec, ctx: &mut Context<T::Global>)
+where
+    T: Dialog, 
+    U: State, 
+{
+    for &percentage in spec.steps.iter() {
+        container.scale.set(percentage);
+        ctx.draw_state(container).unwrap();
+        thread::sleep(spec.frame_time);
+    }
+    container.scale.set(100);
+}
+
+/// Same as [`open`], but without blocking the thread between frames. 
+#[cfg(feature = "tokio")]
+pub(super) async fn open_async<T, U>(
+    container: &Container<'_, T, U>, 
+    spec: &AnimationSpec, 
+    ctx: &mut Context<T::Global>, 
+)
+where
+    T: Dialog, 
+    U: State, 
+{
+    for &percentage in spec.steps.iter() {
+        container.scale.set(percentage);
+        ctx.draw_state(container).unwrap();
+        tokio::time::sleep(spec.frame_time).await;
+    }
+    container.scale.set(100);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolation() {
+        let area = Rect::new(20, 6, 40, 12);
+        let sequence: Vec<Rect> = [40, 70, 100]
+            .into_iter()
+            .map(|percentage| interpolate(area, percentage))
+            .collect();
+        assert_eq!(sequence, [
+            Rect::new(32, 10, 16, 4), 
+            Rect::new(26, 8, 28, 8), 
+            area, 
+        ]);
+
+        // never smaller than a bordered box, nor larger than the area
+        assert_eq!(interpolate(area, 0), Rect::new(39, 11, 2, 2));
+        assert_eq!(interpolate(Rect::new(0, 0, 1, 1), 50), Rect::new(0, 0, 1, 1));
+        assert_eq!(interpolate(area, 200), area);
+    }
+}
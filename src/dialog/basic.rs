@@ -1,134 +1,878 @@
 //! Defines simple, mainly informational dialogs. 
 
-use ratatui::text::Line;
-use super::*;
+use std::{borrow::Cow, error::Error, time::Instant};
+use ratatui::text::{Line, Span, Text};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use crate::field::{Build, Field, InputResult, Textbox, Toggle};
+use super::{*, form::internal::format_buttons};
 
-/// Displays a yellow dialog asking the user to confirm an action before proceeding. 
+/// Displays a yellow dialog asking the user to confirm an action before proceeding, with the answers
+/// labelled "Yes" and "No". See [`dialog::confirm_with`] for the key bindings. 
 /// 
 /// 
 /// # Returns
 /// 
-/// - `true` if the user pressed `y`. 
-/// - `false` if the user pressed `n` or `escape`. 
+/// - `true` if the user pressed `y`, or `enter` with "Yes" selected. 
+/// - `false` if the user pressed `n` or `escape`, or `enter` with "No" selected. 
 pub fn confirm<G>(msg: impl AsRef<str>, over: &impl State, ctx: &mut Context<G>) -> bool {
-    let msg = msg.as_ref();
-    Confirm{ msg }.run_over(over, ctx)
+    confirm_with(msg, ConfirmOptions::default(), over, ctx)
+}
+
+/// Options of a confirmation dialog shown with [`dialog::confirm_with`]. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ConfirmOptions<'a> {
+    /// The label of the answer confirming the action. Default: `"Yes"`. 
+    pub yes_label: Cow<'a, str>, 
+    /// The label of the answer cancelling the action. Default: `"No"`. 
+    pub no_label: Cow<'a, str>, 
+    /// The answer that is initially selected, and therefore given by `enter` unless the user selects the
+    /// other one. Default: `false`. 
+    pub default: bool, 
+    /// Whether the action is destructive, drawing the dialog red instead of yellow. Default: `false`. 
+    pub danger: bool, 
+    /// Overrides of how the dialog is drawn, e.g. its width. These take precedence over both `danger` and
+    /// the [theme](Theme). Default: none. 
+    pub theme: DrawInfoDefaults, 
+}
+
+impl Default for ConfirmOptions<'_> {
+    fn default() -> Self {
+        Self {
+            yes_label: "Yes".into(), 
+            no_label: "No".into(), 
+            default: false, 
+            danger: false, 
+            theme: DrawInfoDefaults::default(), 
+        }
+    }
+}
+
+/// Displays a dialog asking the user to confirm an action before proceeding, with custom labels and
+/// appearance. 
+/// 
+/// The two answers are shown as buttons below the message. Each answer is given by pressing the first
+/// letter of its label, case-insensitively, unless both labels start with the same letter. `Left` and
+/// `Right` select an answer, and `enter` gives the selected one. `Esc` always cancels. 
+/// 
+/// 
+/// # Returns
+/// 
+/// - `true` if the user confirmed the action. 
+/// - `false` if the user cancelled the action. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, dialog::ConfirmOptions};
+/// 
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// let options = ConfirmOptions {
+///     yes_label: "Delete".into(), 
+///     no_label: "Keep".into(), 
+///     danger: true, 
+///     ..Default::default()
+/// };
+/// if dialog::confirm_with("Delete the selected unit?", options, current_state, ctx) {
+///     // delete
+/// }
+/// ```
+pub fn confirm_with<G>(
+    msg: impl AsRef<str>, 
+    options: ConfirmOptions, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> bool {
+    let ConfirmOptions{ yes_label, no_label, default, danger, theme } = options;
+    let danger = DrawInfoDefaults{ color: danger.then_some(Color::Red), ..Default::default() };
+    let defaults = theme
+        .or(danger)
+        .or(ctx.dialog_theme().resolve().confirm);
+    let answers = Answers {
+        msg: msg.as_ref(), 
+        labels: [yes_label, no_label], 
+        selected: match default {
+            true => 0, 
+            false => 1, 
+        }, 
+        esc: 1, 
+    };
+    Themed{ dialog: Confirm{ answers }, defaults }.run_over(over, &mut ctx.chain_without_global())
+}
+
+/// The answer given to a [three-option confirmation dialog](dialog::confirm3). 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Confirm3 {
+    /// The first answer, e.g. "Save". 
+    Yes, 
+    /// The second answer, e.g. "Discard". 
+    No, 
+    /// The third answer, e.g. "Cancel", also given by `esc`. 
+    Cancel, 
+}
+
+/// Displays a yellow dialog asking the user to confirm an action with three outcomes, e.g. whether to save
+/// changes before exiting, not save them, or not exit at all. 
+/// 
+/// The `labels` are given in the order of [`Confirm3`], and are shown as buttons below the message. Each
+/// answer is given by pressing the first letter of its label, case-insensitively, unless another label starts
+/// with the same letter. `Left`/`Right` and `Tab`/`BackTab` select an answer, and `enter` gives the selected
+/// one, initially the first. `Esc` gives [`Confirm3::Cancel`]. 
+/// 
+/// 
+/// # Examples
+/// 
+/// Asking whether to save unsaved changes before quitting: 
+/// ```no_run
+/// use tundra::{prelude::*, dialog::Confirm3};
+/// 
+/// struct Editor {
+///     unsaved: bool, 
+///     // ...
+/// }
+/// 
+/// impl Editor {
+///     fn save(&mut self) {
+///         // ...
+///     }
+/// }
+/// 
+/// impl State for Editor {
+///     // ...
+///     # type Result<T> = T;
+///     # type Out = ();
+///     # type Global = ();
+///     # fn draw(&self, _: &mut Frame) {}
+/// 
+///     fn input(mut self, key: KeyEvent, ctx: &mut Context) -> Signal<Self> {
+///         match key.code {
+///             KeyCode::Esc if !self.unsaved => Signal::Return(()), 
+///             KeyCode::Esc => {
+///                 let msg = "Save changes before quitting?";
+///                 match dialog::confirm3(msg, ["Save", "Discard", "Cancel"], &self, ctx) {
+///                     Confirm3::Yes => {
+///                         self.save();
+///                         Signal::Return(())
+///                     }
+///                     Confirm3::No => Signal::Return(()), 
+///                     Confirm3::Cancel => Signal::Continue(self), 
+///                 }
+///             }
+///             _ => Signal::Continue(self), 
+///         }
+///     }
+/// }
+/// ```
+pub fn confirm3<G>(
+    msg: impl AsRef<str>, 
+    labels: [&str; 3], 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> Confirm3 {
+    let answers = Answers {
+        msg: msg.as_ref(), 
+        labels: labels.map(Cow::from), 
+        selected: 0, 
+        esc: 2, 
+    };
+    let defaults = ctx.dialog_theme().resolve().confirm;
+    Themed{ dialog: ConfirmThree{ answers }, defaults }.run_over(over, &mut ctx.chain_without_global())
+}
+
+/// Options of a selection dialog shown with e.g. [`dialog::select_index_with`]. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct SelectOptions {
+    /// The index of the item that is initially selected, e.g. the item selected when the dialog was last
+    /// shown. The index is clamped to the last item. Default: `0`. 
+    pub default: usize, 
+    /// Whether the items are numbered when there are at most nine of them, so that pressing `1`-`9`
+    /// immediately selects the corresponding item. This may be disabled when the labels themselves start with
+    /// digits. Default: `true`. 
+    pub hotkeys: bool, 
+}
+
+impl Default for SelectOptions {
+    fn default() -> Self {
+        Self {
+            default: 0, 
+            hotkeys: true, 
+        }
+    }
 }
 
 /// Displays a blue dialog asking the user to select one item among a set. 
 /// 
+/// `Up` and `down` select an item, and `enter` gives the selected one. If there are at most nine items, they
+/// are numbered, and pressing the number of an item immediately gives it. See [`dialog::select_index_with`]
+/// to start from another item than the first, or to not number the items. 
+/// 
 /// 
 /// # Returns
 /// 
-/// The selected index. 
+/// - `Some` with the selected index if the user pressed `enter` or the number of an item. 
+/// - `None` if the user pressed `escape`. 
 pub fn select_index<T: AsRef<str>, G>(
     msg: impl AsRef<str>, 
     items: impl AsRef<[T]>, 
     over: &impl State, 
     ctx: &mut Context<G>, 
-) -> usize {
+) -> Option<usize> {
+    select_index_with(msg, items, SelectOptions::default(), over, ctx)
+}
+
+/// Same as [`dialog::select_index`], but with the item at index `default` initially selected, e.g. the item
+/// selected when the dialog was last shown. The index is clamped to the last item. 
+pub fn select_index_with_default<T: AsRef<str>, G>(
+    msg: impl AsRef<str>, 
+    items: impl AsRef<[T]>, 
+    default: usize, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> Option<usize> {
+    select_index_with(msg, items, SelectOptions{ default, ..Default::default() }, over, ctx)
+}
+
+/// Same as [`dialog::select_index`], but with custom [options](SelectOptions). 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, dialog::SelectOptions};
+/// 
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// let years = ["1999", "2000", "2001"];
+/// let options = SelectOptions{ hotkeys: false, ..Default::default() };
+/// let year = dialog::select_index_with("Year?", years, options, current_state, ctx);
+/// ```
+pub fn select_index_with<T: AsRef<str>, G>(
+    msg: impl AsRef<str>, 
+    items: impl AsRef<[T]>, 
+    options: SelectOptions, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> Option<usize> {
     let labels = items.as_ref();
-    let dialog = Select {
-        msg: msg.as_ref(), 
-        get_label: |i: usize| labels[i].as_ref(), 
-        get_value: std::convert::identity, 
-        item_count: labels.len(), 
-        selected: 0
-    };
-    dialog.run_over(over, ctx)
+    let dialog = Select::new(
+        msg.as_ref(), 
+        |i: usize| labels[i].as_ref(), 
+        std::convert::identity, 
+        labels.len(), 
+        options, 
+    );
+    let defaults = ctx.dialog_theme().all;
+    Themed{ dialog, defaults }.run_over(over, &mut ctx.chain_without_global())
 }
 
 /// Displays a blue dialog asking the user to select one value among a set. 
 /// 
-/// The items are given as an array of `(user-visible label, value)`. 
+/// The items are given as an array of `(user-visible label, value)`. See [`dialog::select_index`] for the key
+/// bindings. 
 /// 
 /// 
 /// # Returns
 /// 
-/// The value associated with the item. 
+/// - `Some` with the value associated with the selected item if the user pressed `enter` or the number of
+/// an item. 
+/// - `None` if the user pressed `escape`. 
 pub fn select_value<'a, T, G>(
     msg: impl AsRef<str>, 
     items: &'a [(impl AsRef<str>, T)], 
     over: &impl State, 
     ctx: &mut Context<G>, 
-) -> &'a T {
-    let dialog = Select {
-        msg: msg.as_ref(), 
-        get_label: |i: usize| items[i].0.as_ref(), 
-        get_value: |i: usize| &items[i].1, 
-        item_count: items.len(), 
-        selected: 0, 
-    };
-    dialog.run_over(over, ctx)
+) -> Option<&'a T> {
+    select_value_with(msg, items, SelectOptions::default(), over, ctx)
+}
+
+/// Same as [`dialog::select_value`], but with the item at index `default` initially selected. The index is
+/// clamped to the last item. 
+pub fn select_value_with_default<'a, T, G>(
+    msg: impl AsRef<str>, 
+    items: &'a [(impl AsRef<str>, T)], 
+    default: usize, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> Option<&'a T> {
+    select_value_with(msg, items, SelectOptions{ default, ..Default::default() }, over, ctx)
+}
+
+/// Same as [`dialog::select_value`], but with custom [options](SelectOptions). 
+pub fn select_value_with<'a, T, G>(
+    msg: impl AsRef<str>, 
+    items: &'a [(impl AsRef<str>, T)], 
+    options: SelectOptions, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> Option<&'a T> {
+    let dialog = Select::new(
+        msg.as_ref(), 
+        |i: usize| items[i].0.as_ref(), 
+        |i: usize| &items[i].1, 
+        items.len(), 
+        options, 
+    );
+    let defaults = ctx.dialog_theme().all;
+    Themed{ dialog, defaults }.run_over(over, &mut ctx.chain_without_global())
 }
 
 /// Displays a blue dialog asking the user to select one action among a set. 
 /// 
-/// The items are given as an array of `(user-visible label, callback)`. 
+/// The items are given as an array of `(user-visible label, callback)`. See [`dialog::select_index`] for the
+/// key bindings. 
 /// 
 /// 
 /// # Returns
 /// 
-/// The value returned from the selected callback. 
+/// - `Some` with the value returned from the selected callback if the user pressed `enter` or the number of
+/// an item. 
+/// - `None` if the user pressed `escape`, in which case no callback is called. 
+#[allow(clippy::type_complexity)]
 pub fn select_action<T, U: State, G>(
     msg: impl AsRef<str>, 
     items: &[(impl AsRef<str>, fn(state: &U, ctx: &mut Context<G>) -> T)], 
     state: &U, 
     ctx: &mut Context<G>, 
-) -> T {
-    select_value(msg, items, state, ctx)(state, ctx)
+) -> Option<T> {
+    select_action_with(msg, items, SelectOptions::default(), state, ctx)
+}
+
+/// Same as [`dialog::select_action`], but with the item at index `default` initially selected. The index is
+/// clamped to the last item. 
+#[allow(clippy::type_complexity)]
+pub fn select_action_with_default<T, U: State, G>(
+    msg: impl AsRef<str>, 
+    items: &[(impl AsRef<str>, fn(state: &U, ctx: &mut Context<G>) -> T)], 
+    default: usize, 
+    state: &U, 
+    ctx: &mut Context<G>, 
+) -> Option<T> {
+    select_action_with(msg, items, SelectOptions{ default, ..Default::default() }, state, ctx)
+}
+
+/// Same as [`dialog::select_action`], but with custom [options](SelectOptions). 
+#[allow(clippy::type_complexity)]
+pub fn select_action_with<T, U: State, G>(
+    msg: impl AsRef<str>, 
+    items: &[(impl AsRef<str>, fn(state: &U, ctx: &mut Context<G>) -> T)], 
+    options: SelectOptions, 
+    state: &U, 
+    ctx: &mut Context<G>, 
+) -> Option<T> {
+    select_value_with(msg, items, options, state, ctx).map(|action| action(state, ctx))
 }
 
 /// Displays a blue dialog asking the user to select one action among a set. 
 /// 
-/// The items are given as an array of `(user-visible label, callback)`. 
+/// The items are given as an array of `(user-visible label, callback)`. See [`dialog::select_index`] for the
+/// key bindings. 
 /// 
 /// 
 /// # Returns
 /// 
-/// The value returned from the selected callback. 
+/// - `Some` with the value returned from the selected callback if the user pressed `enter` or the number of
+/// an item. 
+/// - `None` if the user pressed `escape`, in which case no callback is called. 
+#[allow(clippy::type_complexity)]
 pub fn select_action_mut<T, U: State, G>(
     msg: impl AsRef<str>, 
     items: &[(impl AsRef<str>, fn(state: &mut U, ctx: &mut Context<G>) -> T)], 
     state: &mut U, 
     ctx: &mut Context<G>, 
-) -> T {
-    select_value(msg, items, state, ctx)(state, ctx)
+) -> Option<T> {
+    select_action_mut_with(msg, items, SelectOptions::default(), state, ctx)
+}
+
+/// Same as [`dialog::select_action_mut`], but with the item at index `default` initially selected. The index
+/// is clamped to the last item. 
+#[allow(clippy::type_complexity)]
+pub fn select_action_mut_with_default<T, U: State, G>(
+    msg: impl AsRef<str>, 
+    items: &[(impl AsRef<str>, fn(state: &mut U, ctx: &mut Context<G>) -> T)], 
+    default: usize, 
+    state: &mut U, 
+    ctx: &mut Context<G>, 
+) -> Option<T> {
+    select_action_mut_with(msg, items, SelectOptions{ default, ..Default::default() }, state, ctx)
+}
+
+/// Same as [`dialog::select_action_mut`], but with custom [options](SelectOptions). 
+#[allow(clippy::type_complexity)]
+pub fn select_action_mut_with<T, U: State, G>(
+    msg: impl AsRef<str>, 
+    items: &[(impl AsRef<str>, fn(state: &mut U, ctx: &mut Context<G>) -> T)], 
+    options: SelectOptions, 
+    state: &mut U, 
+    ctx: &mut Context<G>, 
+) -> Option<T> {
+    select_value_with(msg, items, options, state, ctx).map(|action| action(state, ctx))
+}
+
+/// Displays a blue dialog asking the user to select any number of items among a set, e.g. "check all that
+/// apply". The items at the `preselected` indices are initially selected. 
+/// 
+/// See [`dialog::multi_select_with`] to limit the number of selected items. 
+/// 
+/// 
+/// # Key bindings
+/// 
+/// `Up` and `down` move the focused item, and `space` selects or deselects it. The
+/// [bulk keys](crate::field::toggle::BulkKeys) of the [`Toggle`] field also apply: `a` selects all items, `n`
+/// deselects all items, and `i` inverts the selection. `Enter` submits the selection, and `esc` cancels. 
+/// 
+/// 
+/// # Returns
+/// 
+/// - `Some` with the indices of the selected items in ascending order if the user pressed `enter`. 
+/// - `None` if the user pressed `escape`. 
+/// 
+/// 
+/// # Panics
+/// 
+/// When there are no items, or when any preselected index is out of bounds. 
+pub fn multi_select<T: AsRef<str>, G>(
+    msg: impl AsRef<str>, 
+    items: impl AsRef<[T]>, 
+    preselected: &[usize], 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> Option<Vec<usize>> {
+    multi_select_with(msg, items, preselected, MultiSelectOptions::default(), over, ctx)
+}
+
+/// Options of a multiple selection dialog shown with [`dialog::multi_select_with`]. 
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct MultiSelectOptions {
+    /// The least number of items that must be selected. Default: `0`. 
+    pub min: usize, 
+    /// The greatest number of items that may be selected, if limited. Default: `None`. 
+    pub max: Option<usize>, 
+}
+
+/// Same as [`dialog::multi_select`], but with custom [options](MultiSelectOptions). 
+/// 
+/// If the number of selected items is outside the limits when the user presses `enter`, the dialog stays
+/// open and shows an error under the items until the selection is changed. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, dialog::MultiSelectOptions};
+/// 
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// let toppings = ["Cheese", "Ham", "Pineapple", "Olives"];
+/// let options = MultiSelectOptions{ min: 1, max: Some(3) };
+/// let msg = "Choose up to three toppings:";
+/// let chosen = dialog::multi_select_with(msg, toppings, &[0], options, current_state, ctx);
+/// ```
+pub fn multi_select_with<T: AsRef<str>, G>(
+    msg: impl AsRef<str>, 
+    items: impl AsRef<[T]>, 
+    preselected: &[usize], 
+    options: MultiSelectOptions, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> Option<Vec<usize>> {
+    let toggle = Toggle::builder()
+        .name("")
+        .items(items
+            .as_ref()
+            .iter()
+            .map(|item| item.as_ref().to_owned())
+        )
+        .set(preselected.iter().copied())
+        .build();
+    let dialog = MultiSelect {
+        msg: msg.as_ref(), 
+        toggle, 
+        options, 
+        error: None, 
+    };
+    let defaults = ctx.dialog_theme().all;
+    Themed{ dialog, defaults }.run_over(over, &mut ctx.chain_without_global())
+}
+
+/// Displays a blue dialog asking the user to select one row of a table, e.g. a file among several described
+/// by their name, size, and date. 
+/// 
+/// The `header` names each column, and each row gives one cell per column; missing cells are left empty, 
+/// and extra cells are ignored. The columns are as wide as their contents, except that the widest columns
+/// are narrowed, cutting off their contents, when the table would otherwise not fit in the dialog. Long
+/// tables are scrolled to keep the selected row in view. 
+/// 
+/// `Up` and `down` select a row, and `enter` gives the selected one. 
+/// 
+/// 
+/// # Returns
+/// 
+/// - `Some` with the index of the selected row if the user pressed `enter`. 
+/// - `None` if the user pressed `escape`, or if there are no rows. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// # use tundra::prelude::*;
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// let rows = [
+///     ["notes.txt", "2 KB", "2024-03-01"], 
+///     ["photo.jpg", "3.1 MB", "2023-12-24"], 
+/// ].map(|row| row.map(String::from).to_vec());
+/// let file = dialog::select_row("Open", &["Name", "Size", "Modified"], &rows, current_state, ctx);
+/// ```
+pub fn select_row<G>(
+    title: impl AsRef<str>, 
+    header: &[&str], 
+    rows: &[Vec<String>], 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> Option<usize> {
+    if rows.is_empty() {
+        return None
+    }
+    let dialog = SelectRow {
+        title: title.as_ref(), 
+        header, 
+        rows, 
+        selected: 0, 
+        offset: Cell::new(0), 
+    };
+    let defaults = ctx.dialog_theme().all;
+    Themed{ dialog, defaults }.run_over(over, &mut ctx.chain_without_global())
+}
+
+/// Displays a blue dialog asking the user to enter a string. 
+/// 
+/// See [`dialog::input_with`] to provide an initial value. 
+/// 
+/// 
+/// # Returns
+/// 
+/// - `Some` with the entered string if the user pressed `enter`. This may be empty. 
+/// - `None` if the user pressed `escape`. 
+pub fn input<G>(msg: impl AsRef<str>, over: &impl State, ctx: &mut Context<G>) -> Option<String> {
+    input_with(msg, "", over, ctx)
+}
+
+/// Displays a blue dialog asking the user to enter a string, starting from an initial value. 
+/// 
+/// 
+/// # Returns
+/// 
+/// - `Some` with the entered string if the user pressed `enter`. This may be empty. 
+/// - `None` if the user pressed `escape`. 
+pub fn input_with<G>(
+    msg: impl AsRef<str>, 
+    initial: impl Into<String>, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> Option<String> {
+    let textbox = Textbox::builder()
+        .name("")
+        .value(initial.into())
+        .build();
+    let defaults = ctx.dialog_theme().all;
+    let dialog = Themed{ dialog: Input{ msg: msg.as_ref(), textbox }, defaults };
+    dialog.run_over(over, &mut ctx.chain_without_global())
 }
 
-/// Displays a blue dialog showing a message. 
+/// Displays a blue dialog showing a message. See [`dialog::info_with`] for more options. 
 pub fn info<G>(msg: impl AsRef<str>, over: &impl State, ctx: &mut Context<G>) {
-    message(msg, "Info", Color::Cyan, over, ctx)
+    let options = MessageOptions{ body: msg.as_ref().into(), ..Default::default() };
+    info_with(options, over, ctx)
+}
+
+/// Displays a blue dialog showing a message, which closes itself after given duration unless a key is
+/// pressed first. The remaining time is counted down in the hint. See [`dialog::info_with`]. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// # use std::time::Duration;
+/// # use tundra::prelude::*;
+/// # let ctx = &mut Context::new().unwrap();
+/// // let ctx: &mut Context<_>
+/// dialog::info_timeout("Thank you for your order!", Duration::from_secs(5), &(), ctx);
+/// ```
+pub fn info_timeout<G>(msg: impl AsRef<str>, timeout: Duration, over: &impl State, ctx: &mut Context<G>) {
+    let options = MessageOptions {
+        body: msg.as_ref().into(), 
+        timeout: Some(timeout), 
+        ..Default::default()
+    };
+    info_with(options, over, ctx)
+}
+
+/// Options of a message dialog shown with [`dialog::message_with`] or [`dialog::info_with`]. These map onto
+/// the fields of the [`DrawInfo`] of the dialog. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct MessageOptions<'a> {
+    /// The message, which may be styled, e.g. to highlight part of it. Default: empty. 
+    pub body: Text<'a>, 
+    /// Default: `"Info"`. 
+    pub title: Cow<'a, str>, 
+    /// Default: `Color::Cyan`. 
+    pub color: Color, 
+    /// Default: `None`; the hint says that any key closes the dialog, or counts down the `timeout`. 
+    pub hint: Option<Cow<'a, str>>, 
+    /// Width of the dialog as a percentage of the frame. Default: `None`; that of the theme, or
+    /// [`DrawInfo::width_percentage`]. 
+    pub width_percentage: Option<u8>, 
+    /// See [`DrawInfo::wrap`]. Default: wrapping without trimming. 
+    pub wrap: Option<Wrap>, 
+    /// How long the dialog is shown before it closes itself, unless a key is pressed first. The remaining
+    /// time is counted down in the hint. Default: `None`; the dialog stays open until a key is pressed. 
+    pub timeout: Option<Duration>, 
+    /// Where the dialog is placed in the frame, e.g. at the bottom to keep the selected row of a table in
+    /// view. Default: that of the theme, or centred. 
+    pub anchor: Option<Anchor>, 
+    /// Overrides of how the dialog is drawn, e.g. its border. These take precedence over the
+    /// [theme](Theme), but not over `hint`, `width_percentage`, or `anchor`. Default: none. 
+    pub theme: DrawInfoDefaults, 
+}
+
+impl Default for MessageOptions<'_> {
+    fn default() -> Self {
+        Self {
+            body: Text::default(), 
+            title: "Info".into(), 
+            color: Color::Cyan, 
+            hint: None, 
+            width_percentage: None, 
+            wrap: Some(Wrap{ trim: false }), 
+            timeout: None, 
+            anchor: None, 
+            theme: DrawInfoDefaults::default(), 
+        }
+    }
+}
+
+/// Displays a blue dialog showing a message, with custom options. The [theme](Theme) for info dialogs
+/// applies, whereas only that for all dialogs applies with [`dialog::message_with`]. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// # use std::time::Duration;
+/// # use tundra::{prelude::*, dialog::MessageOptions};
+/// # let ctx = &mut Context::new().unwrap();
+/// // let ctx: &mut Context<_>
+/// let options = MessageOptions {
+///     body: "Thank you for your order!".into(), 
+///     timeout: Some(Duration::from_secs(5)), 
+///     ..Default::default()
+/// };
+/// dialog::info_with(options, &(), ctx);
+/// ```
+pub fn info_with<G>(options: MessageOptions, over: &impl State, ctx: &mut Context<G>) {
+    let defaults = ctx.dialog_theme().resolve().info;
+    show_message(options, defaults, over, ctx);
+}
+
+/// Displays a blue dialog showing a table of key bindings. 
+/// 
+/// The bindings are given as an array of `(key, description)`, and are drawn in two aligned columns. Long
+/// descriptions are wrapped within their column. See [`dialog::help_with_message`] to show a message above
+/// the table. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// # use tundra::prelude::*;
+/// # let ctx = &mut Context::new().unwrap();
+/// // let ctx: &mut Context<_>
+/// dialog::help(&[
+///     ("ctrl + a", "Add a new item"), 
+///     ("enter", "Show details of the selected item"), 
+///     ("escape", "Quit the application"), 
+/// ], &(), ctx);
+/// ```
+pub fn help<G>(bindings: &[(&str, &str)], over: &impl State, ctx: &mut Context<G>) {
+    let defaults = ctx.dialog_theme().all;
+    Themed{ dialog: Help{ msg: None, bindings }, defaults }.run_over(over, &mut ctx.chain_without_global());
+}
+
+/// Displays a blue dialog showing a message followed by a table of key bindings. See [`dialog::help`]. 
+pub fn help_with_message<G>(
+    msg: impl AsRef<str>, 
+    bindings: &[(&str, &str)], 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) {
+    let msg = Some(msg.as_ref());
+    let defaults = ctx.dialog_theme().all;
+    Themed{ dialog: Help{ msg, bindings }, defaults }.run_over(over, &mut ctx.chain_without_global());
 }
 
-/// Displays a blue dialog showing a help message. 
-pub fn help<G>(msg: impl AsRef<str>, over: &impl State, ctx: &mut Context<G>) {
-    message(msg, "Help", Color::Cyan, over, ctx)
+/// Displays a blue dialog showing a long text, e.g. a license or an excerpt of a log, which the user can
+/// scroll through. The dialog takes up most of the frame regardless of the length of the text, and lines
+/// that are too wide are wrapped. 
+/// 
+/// 
+/// # Key bindings
+/// 
+/// `Up` and `down` scroll by one line, `page up` and `page down` by one page, and `home` and `end` to the
+/// start and end of the text. `Q` or `esc` closes the dialog. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// # use tundra::prelude::*;
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// let license = std::fs::read_to_string("LICENSE")?;
+/// dialog::view("License", license, current_state, ctx);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn view<'a, G>(
+    title: impl AsRef<str>, 
+    text: impl Into<Text<'a>>, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) {
+    let dialog = View {
+        title: title.as_ref(), 
+        text: text.into(), 
+        offset: Cell::new(0), 
+        page: Cell::new(1), 
+        max_offset: Cell::new(usize::MAX), 
+    };
+    let defaults = ctx.dialog_theme().all;
+    Themed{ dialog, defaults }.run_over(over, &mut ctx.chain_without_global())
 }
 
 /// Displays a yellow dialog showing a warning. 
 pub fn warning<G>(msg: impl AsRef<str>, over: &impl State, ctx: &mut Context<G>) {
-    message(msg, "Warning", Color::Yellow, over, ctx)
+    let defaults = ctx.dialog_theme().resolve().warning;
+    themed_message(msg.as_ref(), "Warning", Color::Yellow, defaults, over, ctx);
 }
 
-/// Displays a red dialog showing an error message. 
+/// Displays a red dialog showing an error message. The background state is dimmed to draw attention to the
+/// message. 
 pub fn error<G>(msg: impl AsRef<str>, over: &impl State, ctx: &mut Context<G>) {
-    message(msg, "Error", Color::Red, over, ctx)
+    let defaults = ctx.dialog_theme().resolve().error.or(dim_background());
+    themed_message(msg.as_ref(), "Error", Color::Red, defaults, over, ctx);
+}
+
+/// Displays a red dialog showing an error message, with details that the user may choose to see, e.g. the
+/// chain of errors that caused it or a backtrace. The background state is dimmed like for
+/// [`dialog::error`]. 
+/// 
+/// At first, only the summary is shown. Pressing `d` expands the dialog to show the summary followed by the
+/// details, which can be scrolled like in [`dialog::view`]. Any other key closes the dialog, as do `q` and
+/// `esc` once expanded. See [`dialog::report`] to build the details from the sources of an error. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// # use tundra::prelude::*;
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// let backtrace = std::backtrace::Backtrace::force_capture();
+/// dialog::error_details("Failed to save the file", backtrace.to_string(), current_state, ctx);
+/// ```
+pub fn error_details<'a, G>(
+    summary: impl AsRef<str>, 
+    details: impl Into<Text<'a>>, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) {
+    let summary = summary.as_ref();
+    let mut text = Text::from(summary);
+    text.lines.push(Line::default());
+    text.lines.extend(details.into().lines);
+    let view = View {
+        title: "Error", 
+        text, 
+        offset: Cell::new(0), 
+        page: Cell::new(1), 
+        max_offset: Cell::new(usize::MAX), 
+    };
+    let dialog = ErrorDetails{ summary, view, expanded: false };
+    let defaults = ctx.dialog_theme().resolve().error.or(dim_background());
+    Themed{ dialog, defaults }.run_over(over, &mut ctx.chain_without_global())
+}
+
+/// Displays a red dialog showing an error, with the chain of errors that caused it as
+/// [details](dialog::error_details). The chain is built by following [`Error::source`]. If the error has no
+/// source, it is shown like with [`dialog::error`]. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// # use tundra::prelude::*;
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// if let Err(err) = std::fs::read_to_string("config.toml") {
+///     dialog::report(&err, current_state, ctx);
+/// }
+/// ```
+pub fn report<E: Error + ?Sized, G>(err: &E, over: &impl State, ctx: &mut Context<G>) {
+    let mut causes = std::iter::successors(err.source(), |&err| err.source()).peekable();
+    if causes.peek().is_none() {
+        return error(err.to_string(), over, ctx)
+    }
+    let causes = causes
+        .enumerate()
+        .map(|(i, cause)| Line::from(format!("  {}. {cause}", i + 1)));
+    let details: Vec<Line> = std::iter::once(Line::from("Caused by:"))
+        .chain(causes)
+        .collect();
+    error_details(err.to_string(), details, over, ctx)
 }
 
 /// Displays a red dialog showing a fatal error message. 
 /// 
 /// No background state is drawn upon displaying a fatal error message, following the assumption that the
-/// the program is about to close. 
+/// the program is about to close. To draw a background state, use [`dialog::fatal_over`]. 
 pub fn fatal<G>(msg: impl AsRef<str>, ctx: &mut Context<G>) {
-    message(msg, "Fatal error", Color::Red, &(), ctx)
+    fatal_over(msg, &(), ctx)
+}
+
+/// Displays a red dialog showing a fatal error message over a background state, which is dimmed. 
+/// 
+/// This is useful when a fatal error occurs in the middle of the application, where the current state can
+/// still be drawn. 
+pub fn fatal_over<G>(msg: impl AsRef<str>, over: &impl State, ctx: &mut Context<G>) {
+    let defaults = ctx.dialog_theme().resolve().error.or(dim_background());
+    themed_message(msg.as_ref(), "Fatal error", Color::Red, defaults, over, ctx);
+}
+
+/// Overrides dimming the background, used by the error dialogs unless the [theme](Theme) says otherwise. 
+fn dim_background() -> DrawInfoDefaults {
+    DrawInfoDefaults{ dim_background: Some(true), ..Default::default() }
 }
 
 /// Displays a dialog showing a generic message. 
 /// 
 /// This is lower level than the other message dialog functions. Prefer the more specialised 
 /// [`dialog::info`], [`dialog::warning`], [`dialog::error`], or [`dialog::fatal`] unless you need the 
-/// customisation. 
+/// customisation. Only the overrides of the [theme](Theme) for [all](Theme::all) dialogs apply, since the
+/// kind of the dialog is not known. 
 pub fn message<G>(
     msg: impl AsRef<str>, 
     title: impl AsRef<str>, 
@@ -136,80 +880,465 @@ pub fn message<G>(
     over: &impl State, 
     ctx: &mut Context<G>, 
 ) {
-    let msg = msg.as_ref();
-    let title = title.as_ref();
-    Message{ msg, title, color }.run_over(over, ctx)
+    let defaults = ctx.dialog_theme().all;
+    themed_message(msg.as_ref(), title.as_ref(), color, defaults, over, ctx);
 }
 
-/// Dialog to confirm an action before proceeding. 
-struct Confirm<'a> {
-    msg: &'a str, 
+/// Same as [`dialog::message`], but with all the options of the dialog, e.g. its hint, width, or a styled
+/// body. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// # use tundra::{prelude::*, dialog::MessageOptions};
+/// # use tundra::ratatui::{style::{Color, Stylize}, text::Line};
+/// # let ctx = &mut Context::new().unwrap();
+/// // let ctx: &mut Context<_>
+/// let options = MessageOptions {
+///     body: Line::from(vec!["Deleted ".into(), "3 files".bold()]).into(), 
+///     title: "Done".into(), 
+///     color: Color::Green, 
+///     width_percentage: Some(30), 
+///     ..Default::default()
+/// };
+/// dialog::message_with(options, &(), ctx);
+/// ```
+pub fn message_with<G>(options: MessageOptions, over: &impl State, ctx: &mut Context<G>) {
+    let defaults = ctx.dialog_theme().all;
+    show_message(options, defaults, over, ctx);
 }
 
-impl Dialog for Confirm<'_> {
-    type Out = bool;
+/// Displays a message dialog with given overrides from the [theme](Theme), returning the key that was
+/// pressed to close it. 
+pub(crate) fn themed_message<G>(
+    msg: &str, 
+    title: &str, 
+    color: Color, 
+    defaults: DrawInfoDefaults, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> KeyEvent {
+    let options = MessageOptions {
+        body: msg.into(), 
+        title: title.into(), 
+        color, 
+        ..Default::default()
+    };
+    show_message(options, defaults, over, ctx)
+}
 
-    fn format(&self) -> DrawInfo {
-        DrawInfo {
-            title: "Confirm".into(), 
-            color: Color::Yellow, 
-            body: self.msg.into(), 
-            hint: "Press (y) to confirm, (n) or (esc) to cancel...".into(), 
-            ..Default::default()
-        }
+/// Displays a message dialog with given options, over the overrides from the [theme](Theme). Returns the
+/// key that was pressed to close it, or `escape` if it closed itself. 
+fn show_message<G>(
+    options: MessageOptions, 
+    defaults: DrawInfoDefaults, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+) -> KeyEvent {
+    let MessageOptions{ body, title, color, hint, width_percentage, wrap, timeout, anchor, theme } = options;
+    let mut defaults = DrawInfoDefaults{ width_percentage, anchor, ..Default::default() }
+        .or(theme)
+        .or(defaults);
+    if hint.is_some() {
+        defaults.hint = None;
     }
-
-    fn input(self, key: KeyEvent) -> Signal<Self> {
-        match key.code {
-            KeyCode::Char('y') |
-            KeyCode::Char('Y') => Signal::Return(true), 
-            KeyCode::Esc       |
-            KeyCode::Char('n') |
-            KeyCode::Char('N') => Signal::Return(false), 
-            _ => Signal::Continue(self), 
+    let message = Message{ body, title, color, hint, wrap };
+    let ctx = &mut ctx.chain_without_global();
+    match timeout {
+        Some(timeout) => {
+            let dialog = TimedMessage{ message, deadline: Instant::now() + timeout };
+            Themed{ dialog, defaults }.run_over(over, ctx)
         }
+        None => Themed{ dialog: message, defaults }.run_over(over, ctx), 
     }
 }
 
-/// Dialog to select one item among a set. 
-struct Select<'a, T, U> {
+/// A message followed by a row of answers, shared by the confirmation dialogs. 
+struct Answers<'a, const N: usize> {
     msg: &'a str, 
-    get_label: T, 
-    get_value: U, 
+    labels: [Cow<'a, str>; N], 
+    /// The index of the selected answer in `labels`. 
+    selected: usize, 
+    /// The index of the answer given by `esc`. 
+    esc: usize, 
+}
+
+impl<const N: usize> Answers<'_, N> {
+    /// The keys giving each answer: the first letters of the labels in lowercase, except for letters shared by
+    /// several labels. 
+    fn keys(&self) -> [Option<char>; N] {
+        let first = self.labels
+            .each_ref()
+            .map(|label| label.chars().next().and_then(|c| c.to_lowercase().next()));
+        first.map(|key| key.filter(|key| first.iter().filter(|&&other| other == Some(*key)).count() == 1))
+    }
+
+    fn format(&self, color: Color) -> DrawInfo<'_> {
+        let hints = self.keys()
+            .into_iter()
+            .zip(&self.labels)
+            .enumerate()
+            .fold(Hints::new(), |hints, (i, (key, label))| {
+                let keys: Vec<String> = key
+                    .map(String::from)
+                    .into_iter()
+                    .chain((i == self.esc).then(|| "esc".to_owned()))
+                    .collect();
+                match keys.is_empty() {
+                    true => hints, 
+                    false => hints.answer(keys, label.as_ref()), 
+                }
+            })
+            .key("←/→", "move")
+            .enter("choose");
+        let body: Vec<Line> = Text::from(self.msg).lines
+            .into_iter()
+            .chain([Line::default(), format_buttons(&self.labels, Some(self.selected))])
+            .collect();
+        DrawInfo {
+            title: "Confirm".into(), 
+            color, 
+            body: body.into(), 
+            hint: hints.into(), 
+            ..Default::default()
+        }
+    }
+
+    /// Handles a key press, returning the index of the answer if one was given. 
+    fn input(&mut self, key: KeyEvent) -> Option<usize> {
+        if let KeyCode::Char(c) = key.code {
+            let answer = self.keys()
+                .into_iter()
+                .position(|key| key.is_some_and(|key| c.to_lowercase().eq(key.to_lowercase())));
+            if answer.is_some() {
+                return answer
+            }
+        }
+        match key.code {
+            KeyCode::Esc => return Some(self.esc), 
+            KeyCode::Enter => return Some(self.selected), 
+            KeyCode::Left => self.selected = self.selected.saturating_sub(1), 
+            KeyCode::Right => self.selected = usize::min(self.selected + 1, N - 1), 
+            KeyCode::Tab => self.selected = (self.selected + 1) % N, 
+            KeyCode::BackTab => self.selected = (self.selected + N - 1) % N, 
+            _ => (), 
+        }
+        None
+    }
+}
+
+/// Dialog to confirm an action before proceeding. The answers are confirming and cancelling the action, in
+/// that order. 
+struct Confirm<'a> {
+    answers: Answers<'a, 2>, 
+}
+
+impl Dialog for Confirm<'_> {
+    type Out = bool;
+    type Global = ();
+
+    fn format(&self) -> DrawInfo<'_> {
+        self.answers.format(Color::Yellow)
+    }
+
+    fn input(mut self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        match self.answers.input(key) {
+            Some(answer) => Signal::Return(answer == 0), 
+            None => Signal::Continue(self), 
+        }
+    }
+
+    fn exit(self) -> Signal<Self> {
+        Signal::Return(false)
+    }
+}
+
+/// Dialog to confirm an action with three outcomes. The answers are given in the order of [`Confirm3`]. 
+struct ConfirmThree<'a> {
+    answers: Answers<'a, 3>, 
+}
+
+impl Dialog for ConfirmThree<'_> {
+    type Out = Confirm3;
+    type Global = ();
+
+    fn format(&self) -> DrawInfo<'_> {
+        self.answers.format(Color::Yellow)
+    }
+
+    fn input(mut self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        match self.answers.input(key) {
+            Some(0) => Signal::Return(Confirm3::Yes), 
+            Some(1) => Signal::Return(Confirm3::No), 
+            Some(_) => Signal::Return(Confirm3::Cancel), 
+            None => Signal::Continue(self), 
+        }
+    }
+
+    fn exit(self) -> Signal<Self> {
+        Signal::Return(Confirm3::Cancel)
+    }
+}
+
+/// Dialog to enter a string. 
+struct Input<'a> {
+    msg: &'a str, 
+    textbox: Textbox, 
+}
+
+impl Dialog for Input<'_> {
+    type Out = Option<String>;
+    type Global = ();
+
+    fn format(&self) -> DrawInfo<'_> {
+        let body: Vec<Line> = [self.msg.into(), Line::default()]
+            .into_iter()
+            .chain(self.textbox.format(true).lines)
+            .collect();
+        DrawInfo {
+            title: "Input".into(), 
+            color: Color::Cyan, 
+            body: body.into(), 
+            hint: Hints::new().enter("submit").esc("cancel").into(), 
+            ..Default::default()
+        }
+    }
+
+    fn input(mut self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        match key.code {
+            KeyCode::Enter => Signal::Return(Some(self.textbox.into_value())), 
+            KeyCode::Esc => Signal::Return(None), 
+            _ => {
+                self.textbox.input(key);
+                Signal::Continue(self)
+            }
+        }
+    }
+
+    fn exit(self) -> Signal<Self> {
+        Signal::Return(None)
+    }
+}
+
+/// Dialog to show a table of key bindings. 
+struct Help<'a> {
+    msg: Option<&'a str>, 
+    bindings: &'a [(&'a str, &'a str)], 
+}
+
+impl Help<'_> {
+    /// Spaces between the key and description columns. 
+    const GAP: usize = 2;
+
+    /// Lays out the bindings in two columns, wrapping each description to fit within the given width. 
+    fn table(&self, width: u16) -> Vec<Line<'_>> {
+        let key_width = self.bindings
+            .iter()
+            .map(|(key, _)| Span::raw(*key).width())
+            .max()
+            .unwrap_or(0);
+        let desc_width = (width as usize)
+            .saturating_sub(key_width + Self::GAP)
+            .max(1);
+
+        let mut lines = Vec::new();
+        for &(key, desc) in self.bindings {
+            let padding = key_width - Span::raw(key).width() + Self::GAP;
+            let mut key = Some(key);
+            for chunk in wrap_words(desc, desc_width) {
+                // only the first line of the description is preceded by the key
+                let [key, padding] = match key.take() {
+                    Some(key) => [Span::raw(key).bold(), Span::raw(" ".repeat(padding))], 
+                    None => [Span::raw(" ".repeat(key_width + Self::GAP)), Span::default()], 
+                };
+                lines.push(Line::from(vec![key, padding, Span::raw(chunk)]));
+            }
+        }
+        lines
+    }
+}
+
+/// Greedily splits a string into lines of at most given width, breaking between words. A word wider than
+/// the width is put on a line of its own. Always returns at least one line. 
+fn wrap_words(string: &str, width: usize) -> Vec<String> {
+    let mut lines = vec![String::new()];
+    for word in string.split_whitespace() {
+        let line = lines
+            .last_mut()
+            .expect("There is always a line");
+        let line_width = Span::raw(line.as_str()).width();
+        let word_width = Span::raw(word).width();
+        if line.is_empty() {
+            line.push_str(word);
+        } else if line_width + 1 + word_width <= width {
+            line.push(' ');
+            line.push_str(word);
+        } else {
+            lines.push(word.to_owned());
+        }
+    }
+    lines
+}
+
+impl Dialog for Help<'_> {
+    type Out = ();
+    type Global = ();
+
+    fn format(&self) -> DrawInfo<'_> {
+        DrawInfo {
+            title: "Help".into(), 
+            color: Color::Cyan, 
+            hint: Hints::new().any("close").into(), 
+            ..Default::default()
+        }
+    }
+
+    fn format_in(&self, area: Rect) -> DrawInfo<'_> {
+        let info = self.format();
+        let table = self.table(info.body_width(area.width));
+        let body: Vec<Line> = match self.msg {
+            Some(msg) => [msg.into(), Line::default()]
+                .into_iter()
+                .chain(table)
+                .collect(), 
+            None => table, 
+        };
+        DrawInfo{ body: body.into(), ..info }
+    }
+
+    fn input(self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        Signal::Return(())
+    }
+
+    fn exit(self) -> Signal<Self> {
+        Signal::Return(())
+    }
+}
+
+/// Dialog to select one item among a set. 
+/// 
+/// If the items don't fit in the frame, a window of them around the selected item is shown; see [`window`]. 
+struct Select<'a, T, U> {
+    msg: &'a str, 
+    get_label: T, 
+    get_value: U, 
     item_count: usize, 
     selected: usize, 
+    /// Whether the items are numbered and selected by pressing their number. 
+    hotkeys: bool, 
+    /// The index of the first item shown when the items don't fit. This is kept between draws so that the
+    /// window only moves once the selected item would leave it. 
+    offset: Cell<usize>, 
 }
 
-impl<'a, T: Fn(usize) -> &'a str, U: Fn(usize) -> V, V> Dialog for Select<'a, T, U> {
-    type Out = V;
+/// A line indicating whether there are more items in some direction than those shown, or an empty line. 
+fn more(more: bool, indicator: &'static str) -> Line<'static> {
+    match more {
+        true => Line::from(indicator).dim(), 
+        false => Line::default(), 
+    }
+}
+
+/// The range of items shown when `visible` out of `count` items fit, keeping the `selected` item in view. 
+/// The window starts at `offset` if possible, and otherwise moves just far enough to show the selected item. 
+fn window(count: usize, selected: usize, visible: usize, offset: usize) -> std::ops::Range<usize> {
+    let visible = visible.clamp(1, count.max(1));
+    let start = offset
+        .clamp(selected.saturating_sub(visible - 1), selected)
+        .min(count.saturating_sub(visible));
+    start..usize::min(start + visible, count)
+}
+
+impl<'a, T: Fn(usize) -> &'a str, U: Fn(usize) -> V, V> Select<'a, T, U> {
+    fn new(msg: &'a str, get_label: T, get_value: U, item_count: usize, options: SelectOptions) -> Self {
+        Self {
+            msg, 
+            get_label, 
+            get_value, 
+            item_count, 
+            selected: options.default.min(item_count.saturating_sub(1)), 
+            hotkeys: options.hotkeys && (1..=9).contains(&item_count), 
+            offset: Cell::new(0), 
+        }
+    }
 
-    fn format(&self) -> DrawInfo {
-        let format_action = |(i, action)| {
-            let prefix = match i == self.selected {
-                true => '→', 
-                false => '·', 
+    /// Formats the dialog showing given range of items, with a line above and below them indicating whether
+    /// there are more items in either direction. All items are shown without indicators if no range is given. 
+    fn format_items(&self, range: Option<std::ops::Range<usize>>) -> DrawInfo<'_> {
+        // the selected item is reversed in addition to its arrow, so that it stands out among many items
+        let format_action = |i: usize| {
+            let action = match self.hotkeys {
+                true => format!("{}. {}", i + 1, (self.get_label)(i)), 
+                false => (self.get_label)(i).to_owned(), 
             };
-            format!("{prefix} {action}").into()
+            match i == self.selected {
+                true => Line::from(Span::raw(format!("→ {action}")).reversed()), 
+                false => Line::from(format!("· {action}")), 
+            }
+        };
+        let keys = match (self.hotkeys, self.item_count) {
+            (true, 1) => vec!["1".to_owned(), "enter".to_owned()], 
+            (true, count) => vec![format!("1-{count}"), "enter".to_owned()], 
+            (false, _) => vec!["enter".to_owned()], 
+        };
+        let hints = Hints::new()
+            .keys(keys, "select item")
+            .esc("cancel");
+        let (items, hint): (Vec<Line>, _) = match range {
+            None => (
+                (0..self.item_count).map(format_action).collect(), 
+                hints.to_string(), 
+            ), 
+            Some(range) => (
+                [more(range.start > 0, "↑ more")]
+                    .into_iter()
+                    .chain(range.clone().map(format_action))
+                    .chain([more(range.end < self.item_count, "↓ more")])
+                    .collect(), 
+                format!("{hints} ({}/{})", self.selected + 1, self.item_count), 
+            ), 
         };
-        let labels = (0..self.item_count)
-            .map(&self.get_label)
-            .enumerate()
-            .map(format_action);
         let body: Vec<Line> = [self.msg.into(), Line::default()]
             .into_iter()
-            .chain(labels)
+            .chain(items)
             .collect();
         DrawInfo {
             title: "Select".into(), 
             color: Color::Cyan, 
             body: body.into(), 
-            hint: "Press (enter) to select item...".into(), 
+            hint: hint.into(), 
             wrap: Some(Wrap{ trim: false }), 
             ..Default::default()
         }
     }
+}
+
+impl<'a, T: Fn(usize) -> &'a str, U: Fn(usize) -> V, V> Dialog for Select<'a, T, U> {
+    type Out = Option<V>;
+    type Global = ();
 
-    fn input(mut self, key: KeyEvent) -> Signal<Self> {
+    fn format(&self) -> DrawInfo<'_> {
+        self.format_items(None)
+    }
+
+    fn format_in(&self, area: Rect) -> DrawInfo<'_> {
+        // the height left for the items once the message and the hint with the position are accounted for. 
+        // items are assumed not to wrap
+        let scrolling = self.format_items(Some(0..0));
+        let message_height = Paragraph::new(self.msg)
+            .wrap(Wrap{ trim: false })
+            .line_count(scrolling.body_width(area.width)) + 1;
+        let height = (scrolling.max_body_height(area) as usize).saturating_sub(message_height);
+        if self.item_count <= height {
+            return self.format()
+        }
+        let range = window(self.item_count, self.selected, height.saturating_sub(2), self.offset.get());
+        self.offset.set(range.start);
+        self.format_items(Some(range))
+    }
+
+    fn input(mut self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
         match key.code {
             KeyCode::Up => {
                 self.selected = self.selected.saturating_sub(1);
@@ -217,34 +1346,1094 @@ impl<'a, T: Fn(usize) -> &'a str, U: Fn(usize) -> V, V> Dialog for Select<'a, T,
             KeyCode::Down => {
                 self.selected = usize::min(self.selected + 1, self.item_count - 1);
             }
-            KeyCode::Enter => return Signal::Return((self.get_value)(self.selected)), 
+            KeyCode::Enter => return Signal::Return(Some((self.get_value)(self.selected))), 
+            KeyCode::Esc => return Signal::Return(None), 
+            KeyCode::Char(char@'1'..='9') if self.hotkeys => {
+                let index = char as usize - '1' as usize;
+                if index < self.item_count {
+                    return Signal::Return(Some((self.get_value)(index)))
+                }
+            }
             _ => (), 
         };
         Signal::Continue(self)
     }
+
+    fn exit(self) -> Signal<Self> {
+        Signal::Return(None)
+    }
 }
 
-/// Dialog to simply show a message to the user. 
-struct Message<'a> {
+/// Dialog to select one row of a table. 
+struct SelectRow<'a> {
+    title: &'a str, 
+    header: &'a [&'a str], 
+    rows: &'a [Vec<String>], 
+    selected: usize, 
+    /// The index of the first row shown when the rows don't fit. See [`Select::offset`]. 
+    offset: Cell<usize>, 
+}
+
+impl SelectRow<'_> {
+    /// Columns between each column of the table. 
+    const GAP: usize = 2;
+    /// Columns before each row, holding the arrow of the selected row. 
+    const PREFIX: usize = 2;
+
+    /// Formats the header and the rows in given range as aligned columns fitting in given width. 
+    fn table(&self, range: std::ops::Range<usize>, width: u16) -> Vec<Line<'_>> {
+        let cell = |row: &Vec<String>, i: usize| row.get(i).map_or("", String::as_str).width();
+        let content: Vec<usize> = self.header
+            .iter()
+            .enumerate()
+            .map(|(i, title)| self.rows
+                .iter()
+                .map(|row| cell(row, i))
+                .fold(title.width(), usize::max)
+            )
+            .collect();
+        let widths = column_widths(content, (width as usize).saturating_sub(Self::PREFIX), Self::GAP);
+        let format_row = |cells: &mut dyn Iterator<Item = &str>| widths
+            .iter()
+            .map(|&width| fit(cells.next().unwrap_or(""), width))
+            .collect::<Vec<_>>()
+            .join(&" ".repeat(Self::GAP));
+
+        let header = Line::from(format!("  {}", format_row(&mut self.header.iter().copied()))).bold();
+        let rows = range.map(|i| {
+            let row = format_row(&mut self.rows[i].iter().map(String::as_str));
+            match i == self.selected {
+                true => Line::from(Span::raw(format!("→ {row}")).reversed()), 
+                false => Line::from(format!("  {row}")), 
+            }
+        });
+        std::iter::once(header).chain(rows).collect()
+    }
+
+    fn hint(&self, scrolling: bool) -> Cow<'static, str> {
+        let hint = Hints::new().enter("select row").esc("cancel");
+        match scrolling {
+            true => format!("{hint} ({}/{})", self.selected + 1, self.rows.len()).into(), 
+            false => hint.into(), 
+        }
+    }
+}
+
+/// Widths of the columns of a table given the widths of their contents, narrowing the widest columns until
+/// the table fits in `width` columns along with `gap` columns between each column. Each column keeps a width
+/// of at least one. 
+fn column_widths(mut widths: Vec<usize>, width: usize, gap: usize) -> Vec<usize> {
+    let gaps = gap * widths.len().saturating_sub(1);
+    while widths.iter().sum::<usize>() + gaps > width {
+        let widest = (0..widths.len())
+            .max_by_key(|&i| (widths[i], std::cmp::Reverse(i)))
+            .filter(|&i| widths[i] > 1);
+        match widest {
+            Some(i) => widths[i] -= 1, 
+            None => break, 
+        }
+    }
+    widths
+}
+
+/// Pads a string with spaces to given width, or cuts it off with an ellipsis if wider. 
+fn fit(string: &str, width: usize) -> String {
+    let string_width = string.width();
+    if string_width <= width {
+        return format!("{string}{}", " ".repeat(width - string_width))
+    }
+    let mut fitted = String::new();
+    let mut fitted_width = 0;
+    for char in string.chars() {
+        let char_width = char.width().unwrap_or(0);
+        if fitted_width + char_width >= width {
+            break
+        }
+        fitted.push(char);
+        fitted_width += char_width;
+    }
+    fitted.push('…');
+    fitted + &" ".repeat(width.saturating_sub(fitted_width + 1))
+}
+
+impl Dialog for SelectRow<'_> {
+    type Out = Option<usize>;
+    type Global = ();
+
+    fn format(&self) -> DrawInfo<'_> {
+        self.format_in(Rect::new(0, 0, 80, 24))
+    }
+
+    fn format_in(&self, area: Rect) -> DrawInfo<'_> {
+        let info = DrawInfo {
+            title: self.title.into(), 
+            color: Color::Cyan, 
+            hint: self.hint(true), 
+            width_percentage: 80, 
+            wrap: None, 
+            ..Default::default()
+        };
+        let width = info.body_width(area.width);
+
+        // the height left for the rows once the header is accounted for
+        let height = (info.max_body_height(area) as usize).saturating_sub(1);
+        let count = self.rows.len();
+        if count <= height {
+            return DrawInfo {
+                body: self.table(0..count, width).into(), 
+                hint: self.hint(false), 
+                ..info
+            }
+        }
+        let range = window(count, self.selected, height.saturating_sub(2), self.offset.get());
+        self.offset.set(range.start);
+        let mut table = self.table(range.clone(), width);
+        table.insert(1, more(range.start > 0, "  ↑ more"));
+        table.push(more(range.end < count, "  ↓ more"));
+        DrawInfo{ body: table.into(), ..info }
+    }
+
+    fn input(mut self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        match key.code {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1), 
+            KeyCode::Down => self.selected = usize::min(self.selected + 1, self.rows.len() - 1), 
+            KeyCode::Enter => return Signal::Return(Some(self.selected)), 
+            KeyCode::Esc => return Signal::Return(None), 
+            _ => (), 
+        }
+        Signal::Continue(self)
+    }
+
+    fn exit(self) -> Signal<Self> {
+        Signal::Return(None)
+    }
+}
+
+/// Dialog to select any number of items among a set, using a [`Toggle`] for the items. 
+struct MultiSelect<'a> {
     msg: &'a str, 
+    toggle: Toggle, 
+    options: MultiSelectOptions, 
+    /// Why the selection couldn't be submitted, shown until the selection changes. 
+    error: Option<String>, 
+}
+
+impl MultiSelect<'_> {
+    /// Checks the number of selected items against the limits, giving an error message if outside them. 
+    fn check(&self) -> Result<(), String> {
+        let count = self.toggle.value().count_ones();
+        let MultiSelectOptions{ min, max } = self.options;
+        let items = |n: usize| match n {
+            1 => "1 item".to_owned(), 
+            n => format!("{n} items"), 
+        };
+        match max {
+            Some(max) if min == max && count != min => Err(format!("Select exactly {}", items(min))), 
+            Some(max) if count > max => Err(format!("Select at most {}", items(max))), 
+            _ if count < min => Err(format!("Select at least {}", items(min))), 
+            _ => Ok(()), 
+        }
+    }
+}
+
+impl Dialog for MultiSelect<'_> {
+    type Out = Option<Vec<usize>>;
+    type Global = ();
+
+    fn format(&self) -> DrawInfo<'_> {
+        let error = self.error
+            .as_deref()
+            .map(|error| [Line::default(), Line::from(error).red()]);
+        let body: Vec<Line> = [self.msg.into(), Line::default()]
+            .into_iter()
+            .chain(self.toggle.format(true).lines)
+            .chain(error.into_iter().flatten())
+            .collect();
+        DrawInfo {
+            title: "Select".into(), 
+            color: Color::Cyan, 
+            body: body.into(), 
+            hint: Hints::new().key("space", "toggle item").enter("submit").esc("cancel").into(), 
+            wrap: Some(Wrap{ trim: false }), 
+            ..Default::default()
+        }
+    }
+
+    fn input(mut self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        // every other key toggles the focused item, so only the documented ones are passed on
+        let bulk_keys = self.toggle.bulk_keys;
+        let passed_on = matches!(key.code, KeyCode::Up | KeyCode::Down | KeyCode::Char(' '))
+            || [bulk_keys.select_all, bulk_keys.clear_all, bulk_keys.invert].contains(&Some(key.code));
+        match key.code {
+            KeyCode::Enter => match self.check() {
+                Ok(()) => return Signal::Return(Some(self.toggle.value().iter_ones().collect())), 
+                Err(error) => self.error = Some(error), 
+            }
+            KeyCode::Esc => return Signal::Return(None), 
+            _ if passed_on && self.toggle.input(key) == InputResult::Updated => self.error = None, 
+            _ => (), 
+        }
+        Signal::Continue(self)
+    }
+
+    fn exit(self) -> Signal<Self> {
+        Signal::Return(None)
+    }
+}
+
+/// Dialog to scroll through a long text. 
+/// 
+/// The scroll position is in lines of the text rather than rows, since wrapping depends on the width of the
+/// frame; the page size and the last position are therefore only known once drawn. 
+struct View<'a> {
     title: &'a str, 
+    text: Text<'a>, 
+    /// The index of the first line shown. 
+    offset: Cell<usize>, 
+    /// The number of rows shown at once, as of the last draw. 
+    page: Cell<usize>, 
+    /// The greatest offset at which the rest of the text still fills the page, as of the last draw. 
+    max_offset: Cell<usize>, 
+}
+
+impl View<'_> {
+    /// Rows between the dialog box and the edges of the frame. 
+    const MARGIN: u16 = 1;
+
+    fn hint(&self, scrollable: bool) -> Cow<'static, str> {
+        let hint = Hints::new()
+            .keys(["↑/↓", "pgup/pgdn", "home/end"], "scroll")
+            .keys(["q", "esc"], "close");
+        match scrollable {
+            true => format!("{hint} ({}/{})", self.offset.get() + 1, self.text.lines.len()).into(), 
+            false => hint.into(), 
+        }
+    }
+}
+
+/// The greatest offset into lines of given heights at which the remaining lines still fill `height` rows. 
+/// The last line is always reachable, even if it is taller than the page. 
+fn max_offset(heights: &[usize], height: usize) -> usize {
+    let mut rows = 0;
+    for (i, line_height) in heights.iter().enumerate().rev() {
+        rows += line_height;
+        if rows > height {
+            return usize::min(i + 1, heights.len() - 1)
+        }
+    }
+    0
+}
+
+impl Dialog for View<'_> {
+    type Out = ();
+    type Global = ();
+
+    fn format(&self) -> DrawInfo<'_> {
+        self.format_in(Rect::new(0, 0, 80, 24))
+    }
+
+    fn format_in(&self, area: Rect) -> DrawInfo<'_> {
+        let info = DrawInfo {
+            title: self.title.into(), 
+            color: Color::Cyan, 
+            hint: self.hint(true), 
+            width_percentage: 80, 
+            max_height_percentage: 100, 
+            ..Default::default()
+        };
+        let width = info.body_width(area.width);
+        let height = info.max_body_height(area).saturating_sub(Self::MARGIN * 2);
+        let heights: Vec<usize> = self.text.lines
+            .iter()
+            .map(|line| Paragraph::new(line.clone()).wrap(Wrap{ trim: false }).line_count(width).max(1))
+            .collect();
+        let max_offset = max_offset(&heights, height as usize);
+        let offset = self.offset.get().min(max_offset);
+        self.offset.set(offset);
+        self.page.set(height.max(1) as usize);
+        self.max_offset.set(max_offset);
+
+        DrawInfo {
+            body: self.text.lines[offset..].to_vec().into(), 
+            body_height: Some(height), 
+            hint: self.hint(max_offset > 0), 
+            ..info
+        }
+    }
+
+    fn input(self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        let offset = self.offset.get();
+        let offset = match key.code {
+            KeyCode::Up => offset.saturating_sub(1), 
+            KeyCode::Down => offset + 1, 
+            KeyCode::PageUp => offset.saturating_sub(self.page.get()), 
+            KeyCode::PageDown => offset + self.page.get(), 
+            KeyCode::Home => 0, 
+            KeyCode::End => usize::MAX, 
+            KeyCode::Char('q') | KeyCode::Esc => return Signal::Return(()), 
+            _ => offset, 
+        };
+        self.offset.set(offset.min(self.max_offset.get()));
+        Signal::Continue(self)
+    }
+
+    fn exit(self) -> Signal<Self> {
+        Signal::Return(())
+    }
+}
+
+/// Dialog to simply show a message to the user. 
+struct Message<'a> {
+    body: Text<'a>, 
+    title: Cow<'a, str>, 
     color: Color, 
+    /// Replaces the default hint if given. 
+    hint: Option<Cow<'a, str>>, 
+    wrap: Option<Wrap>, 
 }
 
 impl Dialog for Message<'_> {
-    type Out = ();
+    /// The key that was pressed to close the dialog. 
+    type Out = KeyEvent;
+    type Global = ();
 
-    fn format(&self) -> DrawInfo {
+    fn format(&self) -> DrawInfo<'_> {
         DrawInfo {
-            title: self.title.into(), 
+            title: self.title.as_ref().into(), 
             color: self.color, 
-            body: self.msg.into(), 
-            hint: "Press any key to close...".into(), 
+            body: self.body.clone(), 
+            hint: match &self.hint {
+                Some(hint) => hint.as_ref().into(), 
+                None => Hints::new().any("close").into(), 
+            }, 
+            wrap: self.wrap, 
             ..Default::default()
         }
     }
 
-    fn input(self, _key: KeyEvent) -> Signal<Self> {
+    fn input(self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        Signal::Return(key)
+    }
+
+    /// Closes the dialog as if `escape` was pressed. 
+    fn exit(self) -> Signal<Self> {
+        Signal::Return(KeyEvent::from(KeyCode::Esc))
+    }
+}
+
+/// Error dialog showing a summary, which expands to show scrollable details on request. 
+struct ErrorDetails<'a> {
+    summary: &'a str, 
+    /// Views the summary followed by the details once expanded. 
+    view: View<'a>, 
+    expanded: bool, 
+}
+
+impl Dialog for ErrorDetails<'_> {
+    type Out = ();
+    type Global = ();
+
+    fn format(&self) -> DrawInfo<'_> {
+        self.format_in(Rect::new(0, 0, 80, 24))
+    }
+
+    fn format_in(&self, area: Rect) -> DrawInfo<'_> {
+        match self.expanded {
+            true => DrawInfo{ color: Color::Red, ..self.view.format_in(area) }, 
+            false => DrawInfo {
+                title: "Error".into(), 
+                color: Color::Red, 
+                body: self.summary.into(), 
+                hint: Hints::new().answer(['d'], "details").any("close").into(), 
+                ..Default::default()
+            }, 
+        }
+    }
+
+    fn input(self, key: KeyEvent, ctx: &mut Context) -> Signal<Self> {
+        match (self.expanded, key.code) {
+            (false, KeyCode::Char('d')) => Signal::Continue(ErrorDetails{ expanded: true, ..self }), 
+            (false, _) => Signal::Return(()), 
+            (true, _) => match Dialog::input(self.view, key, ctx) {
+                Signal::Return(()) => Signal::Return(()), 
+                Signal::Continue(view) => Signal::Continue(ErrorDetails{ view, ..self }), 
+            }, 
+        }
+    }
+
+    fn exit(self) -> Signal<Self> {
         Signal::Return(())
     }
 }
+
+/// Message dialog closing itself once a deadline has passed. 
+struct TimedMessage<'a> {
+    message: Message<'a>, 
+    deadline: Instant, 
+}
+
+impl Dialog for TimedMessage<'_> {
+    /// The key that was pressed to close the dialog, or `escape` if it closed itself. 
+    type Out = KeyEvent;
+    type Global = ();
+    const POLL_RATE: Option<Duration> = Some(Duration::from_millis(100));
+
+    fn format(&self) -> DrawInfo<'_> {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        let seconds = remaining.as_millis().div_ceil(1000).max(1);
+        let info = self.message.format();
+        match self.message.hint {
+            Some(_) => info, 
+            None => DrawInfo{ hint: format!("Closing in {seconds}s, or press any key...").into(), ..info }, 
+        }
+    }
+
+    fn input(self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        Signal::Return(key)
+    }
+
+    fn poll(&mut self) -> Option<Self::Out> {
+        (Instant::now() >= self.deadline).then(|| KeyCode::Esc.into())
+    }
+
+    /// Closes the dialog as if `escape` was pressed. 
+    fn exit(self) -> Signal<Self> {
+        Signal::Return(KeyEvent::from(KeyCode::Esc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{style::Color, text::Line};
+    use crate::{prelude::*, context::script, crossterm::event::Event};
+    use crate::dialog::{Confirm3, ConfirmOptions, MultiSelectOptions, SelectOptions};
+
+    /// Background state drawing a marker on the top line, outside of any dialog. 
+    struct Banner;
+
+    impl State for Banner {
+        type Result<T> = T;
+        type Out = ();
+        type Global = ();
+
+        fn draw(&self, frame: &mut Frame) {
+            frame.render_widget(Line::from("SVALBARD"), frame.area());
+        }
+
+        fn input(self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+            Signal::Return(())
+        }
+    }
+
+    /// Runs a dialog on a headless context, returning whether the background was drawn behind it. 
+    fn background_drawn(show: impl FnOnce(&mut Context)) -> bool {
+        let key = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        let ctx = &mut Context::headless(80, 24, [key]);
+        show(ctx);
+        assert!(ctx.script_finished());
+
+        ctx.screen().contains("SVALBARD")
+    }
+
+    #[test]
+    fn input() {
+        let keys = [KeyCode::Char('o'), KeyCode::Char('k'), KeyCode::Enter];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        assert_eq!(dialog::input("Name?", &(), ctx).as_deref(), Some("ok"));
+
+        // empty input is still submitted
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Enter]));
+        assert_eq!(dialog::input("Name?", &(), ctx).as_deref(), Some(""));
+
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Char('x'), KeyCode::Esc]));
+        assert_eq!(dialog::input("Name?", &(), ctx), None);
+
+        // the initial value is edited from its end, with the caret drawn after it
+        let keys = [KeyCode::Backspace, KeyCode::Char('d')];
+        let ctx = &mut Context::headless(80, 24, script(keys.into_iter().chain([KeyCode::Enter])));
+        assert_eq!(dialog::input_with("Name?", "Svalbarx", &(), ctx).as_deref(), Some("Svalbard"));
+        let screen = ctx.screen();
+        assert!(screen.contains("Svalbard "));
+    }
+
+    #[test]
+    fn info_timeout() {
+        use std::time::{Duration, Instant};
+
+        // a key closes the dialog before the deadline
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Enter]));
+        dialog::info_timeout("Hello", Duration::from_secs(60), &(), ctx);
+        assert!(ctx.screen().contains("Closing in 60s"));
+        assert!(ctx.script_finished());
+
+        // otherwise, the dialog closes itself once the deadline has passed, counting down until then
+        let ctx = &mut Context::headless(80, 24, []);
+        let start = Instant::now();
+        dialog::info_timeout("Hello", Duration::from_millis(200), &(), ctx);
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert!(ctx.screen().contains("Closing in 1s, or press any key..."));
+    }
+
+    #[test]
+    fn info_anchor() {
+        use crate::dialog::{Anchor, MessageOptions};
+
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        let options = MessageOptions {
+            body: "Hello".into(), 
+            anchor: Some(Anchor::BottomRight), 
+            ..Default::default()
+        };
+        dialog::info_with(options, &(), ctx);
+        let buffer = ctx.buffer();
+        assert_eq!(buffer[(79, 23)].symbol(), "┛");
+        assert_eq!(buffer[(32, 23)].symbol(), "┗");
+    }
+
+    #[test]
+    fn message_with() {
+        use ratatui::style::{Modifier, Stylize};
+        use crate::dialog::{DrawInfoDefaults, MessageOptions, Theme};
+
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        let theme = DrawInfoDefaults{ hint: Some("Themed hint".into()), ..Default::default() };
+        ctx.set_dialog_theme(Theme{ info: theme.clone(), ..Default::default() });
+        let options = MessageOptions {
+            body: Line::from(vec!["Deleted ".into(), "3 files".bold()]).into(), 
+            title: "Done".into(), 
+            hint: Some("Press (q) to leave...".into()), 
+            width_percentage: Some(30), 
+            theme, 
+            ..Default::default()
+        };
+        dialog::message_with(options, &(), ctx);
+        let (buffer, screen) = (ctx.buffer(), ctx.screen());
+        let rows: Vec<&str> = screen.lines().collect();
+
+        // the hint given in the options takes precedence over the theme
+        assert!(rows.iter().any(|row| row.contains(" DONE ")));
+        assert!(rows.iter().any(|row| row.contains("Press (q) to leave...")));
+        assert!(!rows.iter().any(|row| row.contains("Themed hint")));
+
+        // the body keeps its styling, and the width is that of the options
+        let column = |row: &str, pat| row[..row.find(pat).unwrap()].chars().count() as u16;
+        let (y, row) = rows.iter().enumerate().find(|(_, row)| row.contains("3 files")).unwrap();
+        assert!(buffer[(column(row, "3 files"), y as u16)].modifier.contains(Modifier::BOLD));
+        assert!(!buffer[(column(row, "Deleted"), y as u16)].modifier.contains(Modifier::BOLD));
+        let top = rows.iter().find(|row| row.contains('┏')).unwrap();
+        // 30% of the frame is the width of the body, within the border and margins
+        assert_eq!(column(top, "┓") - column(top, "┏") + 1, 24 + 8);
+    }
+
+    #[test]
+    fn help() {
+        let bindings = [
+            ("ctrl + a", "Add a new rent unit"), 
+            ("enter", "Show details of the selected rent unit, including its tenant and deposit"), 
+        ];
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        dialog::help_with_message("Commands:", &bindings, &(), ctx);
+        assert!(ctx.script_finished());
+
+        let screen = ctx.screen();
+        let find = |needle: &str| screen
+            .lines()
+            .find_map(|row| row.find(needle).map(|x| (row, x)))
+            .unwrap_or_else(|| panic!("{needle:?} is not drawn"));
+
+        // the descriptions start in the same column, also when wrapped onto a continuation line
+        let (_, key) = find("ctrl + a");
+        let (_, add) = find("Add a new");
+        let (first, details) = find("Show details");
+        assert_eq!(find("enter").1, key);
+        assert_eq!(find("Commands:").1, key);
+        assert_eq!(add, details);
+        assert_eq!(add - key, "ctrl + a".len() + 2);
+        assert!(!first.contains("deposit"));
+        let (continued, _) = find("deposit");
+        assert_eq!(continued.find(|c: char| c.is_alphabetic()), Some(details));
+    }
+
+    #[test]
+    fn select() {
+        let items = ["Balcony", "Garage", "Garden"];
+        let select = |keys: Vec<KeyCode>| {
+            let ctx = &mut Context::headless(80, 24, script(keys));
+            let index = dialog::select_index("Pick one", items, &(), ctx);
+            assert!(ctx.script_finished());
+            index
+        };
+        assert_eq!(select(vec![KeyCode::Enter]), Some(0));
+        assert_eq!(select(vec![KeyCode::Down, KeyCode::Down, KeyCode::Enter]), Some(2));
+        assert_eq!(select(vec![KeyCode::Down, KeyCode::Esc]), None);
+
+        // navigation stops at either end
+        assert_eq!(select(vec![KeyCode::Up, KeyCode::Enter]), Some(0));
+        assert_eq!(select([vec![KeyCode::Down; 5], vec![KeyCode::Enter]].concat()), Some(2));
+
+        // actions are only called when selected
+        type Action = fn(&(), &mut Context) -> &'static str;
+        let actions: [(&str, Action); 2] = [
+            ("Rent", |_, _| "rented"), 
+            ("Sell", |_, _| "sold"), 
+        ];
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Down, KeyCode::Enter]));
+        assert_eq!(dialog::select_action("Pick one", &actions, &(), ctx), Some("sold"));
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        assert_eq!(dialog::select_action("Pick one", &actions, &(), ctx), None);
+    }
+
+    #[test]
+    fn fatal() {
+        assert!(background_drawn(|ctx| dialog::fatal_over("Out of memory", &Banner, ctx)));
+        assert!(!background_drawn(|ctx| dialog::fatal("Out of memory", ctx)));
+    }
+
+    #[test]
+    fn dim_background() {
+        use ratatui::style::Modifier;
+        use crate::dialog::{DrawInfoDefaults, Theme};
+
+        // whether the marker of the background is dimmed while the dialog is shown
+        let dimmed = |ctx: &mut Context, show: fn(&mut Context)| {
+            show(ctx);
+            ctx.buffer()[(0, 0)].modifier.contains(Modifier::DIM)
+        };
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc; 4]));
+        assert!(dimmed(ctx, |ctx| dialog::error("Disk full", &Banner, ctx)));
+        assert!(!dimmed(ctx, |ctx| dialog::info("Saved", &Banner, ctx)));
+
+        // the theme may turn it off for errors, and on for other dialogs
+        ctx.set_dialog_theme(Theme {
+            info: DrawInfoDefaults{ dim_background: Some(true), ..Default::default() }, 
+            error: DrawInfoDefaults{ dim_background: Some(false), ..Default::default() }, 
+            ..Default::default()
+        });
+        assert!(!dimmed(ctx, |ctx| dialog::error("Disk full", &Banner, ctx)));
+        assert!(dimmed(ctx, |ctx| dialog::info("Saved", &Banner, ctx)));
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn error_details() {
+        let details = (1..=100)
+            .map(|i| format!("frame {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // only the summary is shown until expanded, and any other key closes the dialog
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Char('x')]));
+        dialog::error_details("Failed to save", details.as_str(), &(), ctx);
+        let collapsed = ctx.screen();
+        assert!(collapsed.contains("Failed to save"));
+        assert!(collapsed.contains("Press (d) for details"));
+        assert!(!collapsed.contains("frame 1 "));
+        assert!(ctx.script_finished());
+
+        // once expanded, the summary and details are scrolled until closed
+        let keys = [KeyCode::Char('d'), KeyCode::Char('x'), KeyCode::Down, KeyCode::Down, KeyCode::Esc];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        dialog::error_details("Failed to save", details.as_str(), &(), ctx);
+        let expanded = ctx.screen();
+        assert!(!expanded.contains("Failed to save"));
+        assert!(expanded.contains("frame 1 "));
+        assert!(expanded.contains("(3/102)"));
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn report() {
+        #[derive(Debug)]
+        struct Failure(&'static str, Option<Box<Failure>>);
+
+        impl std::fmt::Display for Failure {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.0)
+            }
+        }
+
+        impl std::error::Error for Failure {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                self.1.as_deref().map(|source| source as _)
+            }
+        }
+
+        let cause = Failure("Disk full", Some(Box::new(Failure("No space", None))));
+        let chain = Failure("Failed to save", Some(Box::new(cause)));
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Char('d'), KeyCode::Esc]));
+        dialog::report(&chain, &(), ctx);
+        let expanded = ctx.screen();
+        assert!(expanded.contains("Caused by:"));
+        assert!(expanded.contains("1. Disk full"));
+        assert!(expanded.contains("2. No space"));
+
+        // without a source, the error is shown as is
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Char('d')]));
+        dialog::report(&Failure("Disk full", None) as &dyn std::error::Error, &(), ctx);
+        assert!(!ctx.screen().contains("(d) for details"));
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn confirm() {
+        let confirm = |keys: &[KeyCode], options: ConfirmOptions| {
+            let ctx = &mut Context::headless(80, 24, script(keys.iter().copied()));
+            let confirmed = dialog::confirm_with("Sure?", options, &(), ctx);
+            assert!(ctx.script_finished());
+            confirmed
+        };
+        let delete = || ConfirmOptions {
+            yes_label: "Delete".into(), 
+            no_label: "Keep".into(), 
+            ..Default::default()
+        };
+
+        // the answers are given by the first letters of the labels, case-insensitively
+        assert!(confirm(&[KeyCode::Char('y')], ConfirmOptions::default()));
+        assert!(!confirm(&[KeyCode::Char('N')], ConfirmOptions::default()));
+        assert!(confirm(&[KeyCode::Char('x'), KeyCode::Char('D')], delete()));
+        assert!(!confirm(&[KeyCode::Char('y'), KeyCode::Char('k')], delete()));
+        assert!(!confirm(&[KeyCode::Esc], delete()));
+
+        // enter gives the selected answer, initially the default
+        assert!(!confirm(&[KeyCode::Enter], delete()));
+        assert!(confirm(&[KeyCode::Enter], ConfirmOptions{ default: true, ..delete() }));
+        assert!(confirm(&[KeyCode::Left, KeyCode::Enter], delete()));
+        assert!(!confirm(&[KeyCode::Left, KeyCode::Right, KeyCode::Enter], delete()));
+
+        // labels starting with the same letter have no key
+        let same = ConfirmOptions{ yes_label: "Do it".into(), no_label: "Don't".into(), ..Default::default() };
+        assert!(confirm(&[KeyCode::Char('d'), KeyCode::Left, KeyCode::Enter], same));
+    }
+
+    #[test]
+    fn confirm_format() {
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        let options = ConfirmOptions {
+            yes_label: "Delete".into(), 
+            no_label: "Keep".into(), 
+            danger: true, 
+            ..Default::default()
+        };
+        dialog::confirm_with("Sure?", options, &(), ctx);
+        let screen = ctx.screen();
+        assert!(screen.contains("[ Delete ]  [ Keep ]"));
+        assert!(screen.contains("Press (d) for Delete, (k) or (esc) for"));
+
+        // danger draws the dialog red
+        let corner = ctx.buffer().content
+            .iter()
+            .find(|cell| cell.symbol() == "┏")
+            .map(|cell| cell.fg);
+        assert_eq!(corner, Some(Color::Red));
+    }
+
+    #[test]
+    fn confirm3() {
+        let confirm3 = |keys: &[KeyCode]| {
+            let ctx = &mut Context::headless(80, 24, script(keys.iter().copied()));
+            let answer = dialog::confirm3("Save changes?", ["Save", "Discard", "Cancel"], &(), ctx);
+            assert!(ctx.script_finished());
+            answer
+        };
+        assert_eq!(confirm3(&[KeyCode::Char('S')]), Confirm3::Yes);
+        assert_eq!(confirm3(&[KeyCode::Char('d')]), Confirm3::No);
+        assert_eq!(confirm3(&[KeyCode::Char('c')]), Confirm3::Cancel);
+        assert_eq!(confirm3(&[KeyCode::Esc]), Confirm3::Cancel);
+
+        // enter gives the selected answer, initially the first. tab wraps around, while left and right stop at
+        // either end
+        assert_eq!(confirm3(&[KeyCode::Enter]), Confirm3::Yes);
+        assert_eq!(confirm3(&[KeyCode::Right, KeyCode::Enter]), Confirm3::No);
+        let keys = [KeyCode::Right, KeyCode::Right, KeyCode::Right, KeyCode::Enter];
+        assert_eq!(confirm3(&keys), Confirm3::Cancel);
+        assert_eq!(confirm3(&[KeyCode::Left, KeyCode::Enter]), Confirm3::Yes);
+        assert_eq!(confirm3(&[KeyCode::BackTab, KeyCode::Enter]), Confirm3::Cancel);
+        assert_eq!(confirm3(&[KeyCode::Tab, KeyCode::Tab, KeyCode::Tab, KeyCode::Enter]), Confirm3::Yes);
+
+        // letters shared by several labels give no answer, and are left out of the hint
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Char('s'), KeyCode::Char('n')]));
+        let answer = dialog::confirm3("Save changes?", ["Save", "Skip", "Never mind"], &(), ctx);
+        assert_eq!(answer, Confirm3::Cancel);
+        let screen = ctx.screen();
+        assert!(screen.contains("[ Save ]  [ Skip ]  [ Never mind ]"));
+        assert!(screen.contains("Press (n) or (esc) for Never mind"));
+    }
+
+    #[test]
+    fn select_default() {
+        let items = ["North", "East", "South", "West"];
+        let select = |keys: &[KeyCode], default: usize| {
+            let ctx = &mut Context::headless(80, 24, script(keys.iter().copied()));
+            let selected = dialog::select_index_with_default("Direction?", items, default, &(), ctx);
+            assert!(ctx.script_finished());
+            selected
+        };
+        assert_eq!(select(&[KeyCode::Enter], 2), Some(2));
+        assert_eq!(select(&[KeyCode::Up, KeyCode::Enter], 2), Some(1));
+        assert_eq!(select(&[KeyCode::Enter], 0), Some(0));
+
+        // the default is clamped to the last item
+        assert_eq!(select(&[KeyCode::Enter], 10), Some(3));
+        let values = [("Low", 1), ("High", 10)];
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Enter]));
+        assert_eq!(dialog::select_value_with_default("Level?", &values, 5, &(), ctx), Some(&10));
+    }
+
+    #[test]
+    fn select_highlight() {
+        use ratatui::style::Modifier;
+
+        // the selected item is reversed, and the others are not
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        dialog::select_index_with_default("Direction?", ["North", "East"], 1, &(), ctx);
+        let buffer = ctx.buffer();
+        let screen = ctx.screen();
+        let row_of = |label: &str| screen
+            .lines()
+            .position(|row| row.contains(label))
+            .unwrap() as u16;
+        let reversed = |y: u16| (0..80).any(|x| buffer[(x, y)].modifier.contains(Modifier::REVERSED));
+        assert!(reversed(row_of("→ 2. East")));
+        assert!(!reversed(row_of("· 1. North")));
+    }
+
+    #[test]
+    fn select_hotkeys() {
+        let items = ["North", "East", "South", "West"];
+
+        // numbers immediately select the corresponding item, and are ignored past the last item
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Char('5'), KeyCode::Char('3')]));
+        assert_eq!(dialog::select_index("Direction?", items, &(), ctx), Some(2));
+        assert!(ctx.script_finished());
+        assert!(ctx.screen().contains("· 3. South"));
+        assert!(ctx.screen().contains("Press (1-4) or (enter) to select item"));
+        let values = [("Low", 1), ("High", 10)];
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Char('2')]));
+        assert_eq!(dialog::select_value("Level?", &values, &(), ctx), Some(&10));
+
+        // the hotkeys may be disabled
+        let options = SelectOptions{ hotkeys: false, ..Default::default() };
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Char('3'), KeyCode::Enter]));
+        assert_eq!(dialog::select_index_with("Direction?", items, options, &(), ctx), Some(0));
+        assert!(ctx.screen().contains("· South"));
+        assert!(ctx.screen().contains("Press (enter) to select item"));
+
+        // and are not given to more than nine items
+        let items: Vec<String> = (1..=10).map(|i| format!("item {i}")).collect();
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Char('3'), KeyCode::Enter]));
+        assert_eq!(dialog::select_index("Item?", &items, &(), ctx), Some(0));
+        assert!(ctx.screen().contains("· item 3"));
+    }
+
+    #[test]
+    fn window() {
+        use super::window;
+
+        // the window stays put while the selected item is within it
+        assert_eq!(window(100, 0, 10, 0), 0..10);
+        assert_eq!(window(100, 9, 10, 0), 0..10);
+        assert_eq!(window(100, 5, 10, 3), 3..13);
+
+        // and moves just far enough to show the selected item otherwise
+        assert_eq!(window(100, 10, 10, 0), 1..11);
+        assert_eq!(window(100, 2, 10, 3), 2..12);
+
+        // the window doesn't extend past the last item
+        assert_eq!(window(100, 99, 10, 95), 90..100);
+        assert_eq!(window(5, 4, 10, 0), 0..5);
+
+        // at least the selected item is shown
+        assert_eq!(window(100, 42, 0, 0), 42..43);
+        assert_eq!(window(0, 0, 10, 0), 0..0);
+    }
+
+    #[test]
+    fn select_scroll() {
+        let items: Vec<String> = (1..=300).map(|i| format!("item {i}")).collect();
+
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        dialog::select_index("Item?", &items, &(), ctx);
+        assert!(ctx.screen().contains("→ item 1 "));
+        assert!(ctx.screen().contains("↓ more"));
+        assert!(!ctx.screen().contains("↑ more"));
+        assert!(ctx.screen().contains("(1/300)"));
+
+        // the selected item stays in view when moving past the bottom of the window
+        let keys = std::iter::repeat_n(KeyCode::Down, 40).chain([KeyCode::Esc]);
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        dialog::select_index("Item?", &items, &(), ctx);
+        assert!(ctx.screen().contains("→ item 41 "));
+        assert!(ctx.screen().contains("↑ more"));
+        assert!(ctx.screen().contains("↓ more"));
+        assert!(ctx.screen().contains("(41/300)"));
+
+        // no indicators are shown when all items fit
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        dialog::select_index("Item?", &items[..5], &(), ctx);
+        assert!(!ctx.screen().contains("more"));
+        assert!(!ctx.screen().contains("/5)"));
+    }
+
+    #[test]
+    fn multi_select() {
+        let items = ["Cheese", "Ham", "Pineapple", "Olives"];
+        let select = |keys: &[KeyCode], preselected: &[usize]| {
+            let ctx = &mut Context::headless(80, 24, script(keys.iter().copied()));
+            let selected = dialog::multi_select("Toppings?", items, preselected, &(), ctx);
+            assert!(ctx.script_finished());
+            selected
+        };
+        let space = KeyCode::Char(' ');
+        assert_eq!(select(&[KeyCode::Enter], &[]), Some(vec![]));
+        assert_eq!(select(&[KeyCode::Enter], &[2, 0]), Some(vec![0, 2]));
+        let keys = [space, KeyCode::Down, KeyCode::Down, space, KeyCode::Enter];
+        assert_eq!(select(&keys, &[1]), Some(vec![0, 1, 2]));
+        assert_eq!(select(&[space, KeyCode::Esc], &[]), None);
+
+        // only the documented keys change the selection
+        assert_eq!(select(&[KeyCode::Char('x'), KeyCode::Enter], &[]), Some(vec![]));
+        assert_eq!(select(&[KeyCode::Char('a'), KeyCode::Enter], &[]), Some(vec![0, 1, 2, 3]));
+        assert_eq!(select(&[KeyCode::Char('i'), KeyCode::Enter], &[1, 3]), Some(vec![0, 2]));
+    }
+
+    #[test]
+    fn multi_select_limits() {
+        let items = ["Cheese", "Ham", "Pineapple", "Olives"];
+        let space = KeyCode::Char(' ');
+
+        // submitting outside the limits shows an error instead
+        let options = MultiSelectOptions{ min: 1, max: Some(2) };
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Enter, KeyCode::Esc]));
+        assert_eq!(dialog::multi_select_with("Toppings?", items, &[], options.clone(), &(), ctx), None);
+        assert!(ctx.screen().contains("Select at least 1 item"));
+
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Enter, KeyCode::Esc]));
+        assert_eq!(dialog::multi_select_with("Toppings?", items, &[1, 2, 3], options.clone(), &(), ctx), None);
+        assert!(ctx.screen().contains("Select at most 2 items"));
+
+        // the error is cleared once the selection changes, and the selection may then be submitted
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Enter, KeyCode::Down, space, KeyCode::Esc]));
+        dialog::multi_select_with("Toppings?", items, &[1, 2, 3], options.clone(), &(), ctx);
+        assert!(!ctx.screen().contains("Select at most"));
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Enter, space, KeyCode::Enter]));
+        let selected = dialog::multi_select_with("Toppings?", items, &[0, 2, 3], options, &(), ctx);
+        assert_eq!(selected, Some(vec![2, 3]));
+
+        let options = MultiSelectOptions{ min: 2, max: Some(2) };
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Enter, KeyCode::Esc]));
+        dialog::multi_select_with("Toppings?", items, &[0], options, &(), ctx);
+        assert!(ctx.screen().contains("Select exactly 2 items"));
+    }
+
+    #[test]
+    fn column_widths() {
+        use super::{column_widths, fit};
+
+        // columns fitting are left as is, otherwise the widest ones are narrowed first
+        assert_eq!(column_widths(vec![4, 10, 6], 30, 2), vec![4, 10, 6]);
+        assert_eq!(column_widths(vec![4, 10, 6], 20, 2), vec![4, 6, 6]);
+        assert_eq!(column_widths(vec![4, 10, 6], 14, 2), vec![3, 3, 4]);
+        assert_eq!(column_widths(vec![4, 10, 6], 3, 2), vec![1, 1, 1]);
+
+        assert_eq!(fit("abc", 5), "abc  ");
+        assert_eq!(fit("abcdef", 4), "abc…");
+        assert_eq!(fit("日本語", 4), "日… ");
+    }
+
+    #[test]
+    fn select_row() {
+        let header = ["Name", "Size"];
+        let rows = [["notes.txt", "2 KB"], ["photo.jpg", "3.1 MB"], ["a", "1 B"]]
+            .map(|row| row.map(String::from).to_vec());
+
+        // the columns are aligned, and the selected row is marked
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Down, KeyCode::Enter]));
+        assert_eq!(dialog::select_row("Open", &header, &rows, &(), ctx), Some(1));
+        let screen_text = ctx.screen();
+        assert!(screen_text.contains("  Name       Size  "));
+        assert!(screen_text.contains("  notes.txt  2 KB  "));
+        assert!(screen_text.contains("→ photo.jpg  3.1 MB"));
+        assert!(screen_text.contains("  a          1 B   "));
+
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Up, KeyCode::Esc]));
+        assert_eq!(dialog::select_row("Open", &header, &rows, &(), ctx), None);
+        assert_eq!(dialog::select_row("Open", &header, &[], &(), ctx), None);
+
+        // too wide cells are cut off
+        let rows = [vec!["x".repeat(100), "end".into()]];
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Enter]));
+        assert_eq!(dialog::select_row("Open", &header, &rows, &(), ctx), Some(0));
+        assert!(ctx.screen().contains("x…  end "));
+
+        // long tables are scrolled
+        let rows: Vec<_> = (0..50).map(|i| vec![format!("Row {i}"), i.to_string()]).collect();
+        let mut codes = vec![KeyCode::Down; 30];
+        codes.push(KeyCode::Enter);
+        let ctx = &mut Context::headless(80, 24, script(codes));
+        assert_eq!(dialog::select_row("Open", &header, &rows, &(), ctx), Some(30));
+        let screen_text = ctx.screen();
+        assert!(screen_text.contains("→ Row 30"));
+        assert!(screen_text.contains("↑ more"));
+        assert!(screen_text.contains("↓ more"));
+        assert!(screen_text.contains("(31/50)"));
+        assert!(!screen_text.contains("Row 0 "));
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn max_offset() {
+        use super::max_offset;
+
+        // the offset stops once the last lines fill the page
+        assert_eq!(max_offset(&[1; 10], 4), 6);
+        assert_eq!(max_offset(&[1; 10], 10), 0);
+        assert_eq!(max_offset(&[1; 10], 20), 0);
+
+        // wrapped lines take up several rows
+        assert_eq!(max_offset(&[1, 1, 1, 3, 1], 4), 3);
+        assert_eq!(max_offset(&[1, 1, 1, 1, 3], 4), 3);
+        assert_eq!(max_offset(&[1, 1, 5], 4), 2);
+        assert_eq!(max_offset(&[], 4), 0);
+    }
+
+    #[test]
+    fn view() {
+        let text = (1..=100)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let view = |keys: &[KeyCode]| {
+            let keys = keys.iter().copied().chain([KeyCode::Esc]);
+            let ctx = &mut Context::headless(80, 24, script(keys));
+            dialog::view("Log", text.as_str(), &(), ctx);
+            assert!(ctx.script_finished());
+            ctx.screen()
+        };
+
+        let first = view(&[]);
+        assert!(first.contains("line 1 "));
+        assert!(!first.contains("line 15 "));
+        assert!(first.contains("(1/100)"));
+        assert!(view(&[KeyCode::Down, KeyCode::Down]).contains("(3/100)"));
+        assert!(view(&[KeyCode::Down, KeyCode::Up, KeyCode::Up]).contains("(1/100)"));
+        assert!(view(&[KeyCode::Home]).contains("(1/100)"));
+
+        // scrolling stops at the last page
+        let last = view(&[KeyCode::End]);
+        assert!(last.contains("line 100 "));
+        assert!(!last.contains("line 1 "));
+        assert_eq!(last, view(&[KeyCode::End, KeyCode::Down, KeyCode::PageDown]));
+        assert_eq!(last, view(&[KeyCode::PageDown; 20]));
+        assert!(view(&[KeyCode::End, KeyCode::PageUp, KeyCode::PageUp]).contains("line 60 "));
+
+        // short texts take up most of the frame as well, without a position
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Char('q')]));
+        dialog::view("Log", "Hello", &(), ctx);
+        let screen = ctx.screen();
+        let row_of = |corner: &str| screen.lines().position(|row| row.contains(corner));
+        assert_eq!(row_of("┏"), Some(1));
+        assert_eq!(row_of("┗"), Some(22));
+        assert!(!ctx.screen().contains("(1/1)"));
+    }
+}
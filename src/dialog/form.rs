@@ -1,5 +1,9 @@
 #![doc(hidden)]
 
+mod builder;
+
+pub use builder::{FormBuilder, FormValues};
+
 /// Displays a user input form with specified [fields](crate::field) in a [dialog](crate::dialog) to the
 /// user. 
 /// 
@@ -14,11 +18,13 @@
 /// 
 /// A field consists of:
 /// - An identifier; used to reference the entered value. 
-/// - A field type; any type that implements [`Field`](crate::field::Field). 
+/// - A field type; any type that implements [`Field`](crate::field::Field), given by its path, e.g.
+/// `Slider<u8>` or `field::Textbox`. 
 /// - A set of parameters used when instantiating the field; these are translated into methods on the
 /// [field builder](crate::field::Build). There are two kinds of parameters allowed: those with one argument
 /// and those with none. Those with one argument are specified as `IDENTIFIER: VALUE`. Those with no argument
-/// are specified simply as `IDENTIFIER`. 
+/// are specified simply as `IDENTIFIER`. Alternatively, an instance of the field; see
+/// [below](#pre-built-fields). 
 /// - (Optional) a set of control statements. A more detailed description of these are given
 /// [below](#field-validation). 
 /// 
@@ -50,6 +56,165 @@
 /// 
 /// See the [`field::Build`](crate::field::Build) module for more information on builders. 
 /// 
+/// The library provided fields may be shown without allowing them to be edited with the parameter
+/// `disabled: true`, e.g. for showing the identifier of a record being edited. Disabled fields are dimmed, 
+/// skipped when moving the focus, and not [validated](#field-validation). See
+/// [`Field::enabled`](crate::field::Field::enabled). 
+/// 
+/// 
+/// ### Sections
+/// 
+/// Long forms may be split into sections by preceding the first field of each section with
+/// `#[section = TITLE]`, where `TITLE` is a value that implements `Into<Cow<str>>`. The title is shown as a
+/// header above the field. Headers are not fields; they cannot be focused and do not affect the alignment of
+/// the field names. 
+/// 
+/// For example, to group the fields of a connection: 
+/// ```no_run
+/// # use tundra::{prelude::*, field::*};
+/// # dialog::form!{
+/// #[section = "Server"]
+/// host: Textbox{ name: "Host" }, 
+/// port: Textbox{ name: "Port" }, 
+/// #[section = "Account"]
+/// user: Textbox{ name: "User" }, 
+/// password: Textbox{ name: "Password", hidden }, 
+/// # [title]: "", 
+/// # [context]: &mut Context::new().unwrap(), 
+/// # [background]: &(), 
+/// # };
+/// ```
+/// 
+/// 
+/// ### Referencing earlier fields
+/// 
+/// The fields are built in declaration order, and the parameters of a field may reference the fields
+/// declared before it through `built!(IDENTIFIER)`, which evaluates to a reference to the built field. This
+/// is useful when the initial value of a field is derived from that of another, since the derivation sees
+/// the value as built; e.g. after any clamping or normalisation applied by the builder. 
+/// 
+/// For example, to suggest a slug derived from the initial title: 
+/// ```no_run
+/// # use tundra::{prelude::*, field::{Field, Textbox}};
+/// # let title = String::new();
+/// # dialog::form!{
+/// title: Textbox{ name: "Title", value: title }, 
+/// slug: Textbox{ name: "Slug", value: built!(title).value().to_lowercase().replace(' ', "-") }, 
+/// # [title]: "", 
+/// # [context]: &mut Context::new().unwrap(), 
+/// # [background]: &(), 
+/// # };
+/// ```
+/// 
+/// Only fields declared before the field being built may be referenced. Referencing any other field is a
+/// compile error: 
+/// ```compile_fail
+/// # use tundra::{prelude::*, field::{Field, Textbox}};
+/// # dialog::form!{
+/// slug: Textbox{ name: "Slug", value: built!(title).value().to_lowercase() }, 
+/// title: Textbox{ name: "Title" }, 
+/// # [title]: "", 
+/// # [context]: &mut Context::new().unwrap(), 
+/// # [background]: &(), 
+/// # };
+/// ```
+/// 
+/// 
+/// ### Pre-built fields
+/// 
+/// Instead of parameters, a field may be given an instance of the field type with `IDENTIFIER: TYPE = FIELD`, 
+/// which is used as is instead of being built inside the macro. This is useful when the field is built from
+/// runtime data, or when the [type-state](crate::field::Build) of its builder makes conditional construction
+/// awkward. Control statements and everything else apply as usual. As with
+/// [control messages](#field-validation), `FIELD` is a literal or variable; other expressions must be
+/// wrapped in parentheses or braces. 
+/// 
+/// For example, to build a toggle whose items depend on the user: 
+/// ```no_run
+/// # use tundra::{prelude::*, field::*};
+/// # let is_admin = false;
+/// let mut builder = Toggle::builder()
+///     .name("Permissions")
+///     .items(["Read", "Write"]);
+/// if is_admin {
+///     builder = builder.items(["Read", "Write", "Administer"]);
+/// }
+/// let permissions = builder.build();
+/// # dialog::form!{
+/// permissions: Toggle = permissions, 
+/// # [title]: "", 
+/// # [context]: &mut Context::new().unwrap(), 
+/// # [background]: &(), 
+/// # };
+/// ```
+/// 
+/// A field is given either parameters or an instance. Giving both, or neither, is a compile error: 
+/// ```compile_fail
+/// # use tundra::{prelude::*, field::*};
+/// # let name = Textbox::builder().name("Name").build();
+/// # dialog::form!{
+/// name: Textbox{ name: "Name" } = name, 
+/// # [title]: "", 
+/// # [context]: &mut Context::new().unwrap(), 
+/// # [background]: &(), 
+/// # };
+/// ```
+/// 
+/// 
+/// ### Reacting to changes
+/// 
+/// A field may be given an on-change callback with the statement `on change => |FIELDS| { BODY }`, following
+/// any [control statements](#field-validation). The callback is invoked whenever the user changes the value of
+/// the field, and receives a struct holding a mutable reference to every field of the form through the
+/// identifier of the field. This lets the callback adjust the other fields, e.g. applying a preset. Fields
+/// changed by the callback are validated anew if they have been validated before. 
+/// 
+/// Only changes made by the user invoke callbacks; changes made by a callback do not invoke the callbacks of
+/// the fields changed, so callbacks cannot trigger each other in a loop. 
+/// 
+/// For example, to clear the name when the user chooses to stay anonymous: 
+/// ```no_run
+/// # use tundra::{prelude::*, field::*};
+/// # dialog::form!{
+/// name: Textbox{ name: "Name" }, 
+/// anonymous: Checkbox{ name: "Anonymous" } on change => |fields| {
+///     if *fields.anonymous.value() {
+///         fields.name.set_value("");
+///     }
+/// }, 
+/// # [title]: "", 
+/// # [context]: &mut Context::new().unwrap(), 
+/// # [background]: &(), 
+/// # };
+/// ```
+/// 
+/// 
+/// ### Conditional fields
+/// 
+/// A field may be shown only under some condition with the statement `show if PREDICATE`, following any
+/// other statements of the field. The predicate is a closure over the values of all fields, like the
+/// [form validation](#form-validation) function, and is evaluated each time the form is drawn. While the
+/// predicate returns `false`, the field is neither shown nor focusable, and its
+/// [control statements](#field-validation) are not checked. Hidden fields still have a value when the form
+/// is submitted. 
+/// 
+/// If the focused field is hidden, the focus moves to the first shown field. If the predicates hide every
+/// field, the first field is shown regardless. 
+/// 
+/// For example, to only ask for a password when authentication is enabled: 
+/// ```no_run
+/// # use tundra::{prelude::*, field::*};
+/// # dialog::form!{
+/// host: Textbox{ name: "Host" }, 
+/// auth: Checkbox{ name: "Authentication" }, 
+/// password: Textbox{ name: "Password", hidden } if str::is_empty => "Password required"
+///     show if |values| *values.auth, 
+/// # [title]: "", 
+/// # [context]: &mut Context::new().unwrap(), 
+/// # [background]: &(), 
+/// # };
+/// ```
+/// 
 /// 
 /// # Metadata
 /// 
@@ -70,9 +235,133 @@
 /// The following metadata can be defined in any order: 
 /// - `title` (required); the user-visible title of the dialog box. Should be `impl Into<Cow<str>>`. 
 /// - `context` (required); the current [context](crate::Context). Should be `&mut Context<_>`. 
-/// - `background` (required); the state shown underneath the dialog box. Should be `&impl State`. 
+/// - `background` (required unless `background_mut` is given); the state shown underneath the dialog box. 
+/// Should be `&impl State`. 
+/// - `background_mut`; alternative to `background` for when the form changes something the background
+/// state has derived data from. Once the form closes, [`State::refresh_cache`](crate::State::refresh_cache)
+/// is invoked on the background state. Should be `&mut impl State`, where the global of the state matches
+/// that of `context`. 
 /// - `message`; user-visible string of text displayed above the fields. Should be `impl Into<Cow<str>>`. 
 /// - `validate`; validation function over the values entered by the user. See [below](#form-validation). 
+/// - `max_field_height`; the maximum number of lines shown per field. Any field taller than this is
+/// truncated, with the last line replaced by a count of the hidden lines. The focused field instead shows a
+/// window over its lines that follows the [internal focus](crate::field::Field::focused_line) of the field. 
+/// Should be `usize`. Default: unlimited. 
+/// - `describe`; a [`FormDescription`](crate::dialog::FormDescription) filled with a description of the
+/// form before it is shown, e.g. for generating documentation of what the user is asked. The description
+/// reflects the fields as built from the given parameters. Should be `&mut FormDescription`. 
+/// - `progress`; whether to show how many fields pass [field validation](#field-validation) below the
+/// fields, e.g. "3/7 fields valid". Fields without control statements and disabled fields always count as
+/// valid, while fields with control statements count as invalid until they have been validated. Nothing is
+/// shown if no field has control statements. Should be `bool`. Default: `false`. 
+/// - `apply`; a function invoked with the [returned values](#returns) once the form is submitted, but not
+/// when it is cancelled. This is useful for writing the values back to the record being edited. See
+/// [below](#editing-records). Should be `impl FnOnce(&Values)`, where `Values` is the unspellable type of
+/// the returned values. 
+/// - `buttons`; the user-visible labels of a row of buttons shown below the fields. See [below](#buttons). 
+/// Should be `impl IntoIterator<Item = impl Into<Cow<str>>>`. Default: none. 
+/// - `theme`; overrides of how the form is drawn, e.g. its colour and hint, taking precedence over the
+/// [theme](crate::dialog::Theme) installed in the context. Should be
+/// [`DrawInfoDefaults`](crate::dialog::DrawInfoDefaults). Default: none. 
+/// - `width`; the width of the form as a percentage of the width of the terminal, clamped between `1` and
+/// `100`. See [`DrawInfo::width_percentage`](crate::dialog::DrawInfo::width_percentage). Should be `u8`. 
+/// Default: that of the theme, or `50`. 
+/// - `width_cols`; the width of the body of the form in columns, taking precedence over `width` if the
+/// terminal is wide enough. See [`DrawInfo::width_columns`](crate::dialog::DrawInfo::width_columns). Should
+/// be `u16`. Default: none. 
+/// - `submit`; the key that submits the form. Unless it is `Enter`, `Enter` is passed to the focused field
+/// like any other key, which is useful for fields where `Enter` is meaningful. `Enter` still presses the
+/// selected [button](#buttons). Should be `impl Into<KeyEvent>`, e.g. [`KeyCode`](crate::KeyCode) or
+/// [`KeyEvent`](crate::KeyEvent) for a key with modifiers. Default: `KeyCode::Enter`. 
+/// - `quick_jump`; whether to number the fields, letting the user move the focus directly to a field with
+/// `alt+1` through `alt+9`. See [below](#quick-jump). Should be `bool`. Default: `false`. 
+/// - `anchor`; where the form is placed in the frame, e.g. at the top to keep the selected row of a table
+/// in view. See [`DrawInfo::anchor`](crate::dialog::DrawInfo::anchor). Should be
+/// `impl Into<Option<`[`Anchor`](crate::dialog::Anchor)`>>`. Default: that of the theme, or centred. 
+/// 
+/// If the fields don't fit on the screen, the form scrolls to keep the focused field in view, with `▲` and
+/// `▼` indicating fields hidden above and below. Setting `max_field_height` keeps tall fields from taking up
+/// the whole view. 
+/// 
+/// 
+/// ### Buttons
+/// 
+/// By default, the form is submitted with `Enter` and cancelled with `Esc`. The `buttons` metadatum adds a
+/// row of buttons below the fields, which is focused by moving down past the last field. While focused, 
+/// `Left` and `Right` select a button, and `Enter` activates it. Activating a button submits the form, and
+/// its index is available as the `Button` member of the returned values. Pressing `Enter` while a field is
+/// focused activates the first button. 
+/// 
+/// Since activating any button submits the form, the form is validated regardless of which button is
+/// activated. `Esc` still cancels the form without validation. 
+/// 
+/// For example, to let the user either send or save a draft: 
+/// ```no_run
+/// # use tundra::{prelude::*, field::Textbox};
+/// let values = dialog::form!{
+///     draft: Textbox{ name: "Draft" }, 
+///     [title]: "Compose", 
+///     [buttons]: ["Send", "Save draft"], 
+/// #   [context]: &mut Context::new().unwrap(), 
+/// #   [background]: &(), 
+/// };
+/// match values.map(|values| values.Button) {
+///     Some(0) => { /* send */ }
+///     Some(_) => { /* save draft */ }
+///     None => { /* cancelled */ }
+/// }
+/// ```
+/// 
+/// 
+/// ### Quick jump
+/// 
+/// Long forms are slow to navigate one field at a time. With `[quick_jump]: true`, the focusable fields are
+/// numbered from `1` in a dim column before their names, and `alt+1` through `alt+9` move the focus directly
+/// to the field with that number. Disabled, hidden, and [display](crate::field::Display) fields are not
+/// numbered, nor are the fields past the ninth numbered field. 
+/// 
+/// The quick jump keys are handled by the form before the focused field sees them, even if the field
+/// [captures](crate::field::Field::captures) them or no field has the number. Fields that use `alt+1`
+/// through `alt+9` themselves, such as a [`KeyCapture`](crate::field::KeyCapture) in capture mode, can
+/// therefore not receive those keys while quick jump is enabled; other keys, including other `alt` chords, 
+/// reach the field as usual. The field that loses the focus through a quick jump is told so through
+/// [`Field::focus_lost`](crate::field::Field::focus_lost), just like when the focus is moved with the arrow
+/// keys, so that e.g. a key capture field leaves capture mode. 
+/// 
+/// 
+/// ### Editing records
+/// 
+/// Every library provided field takes its initial value through the `value` parameter, in the same form as
+/// the value of the field; e.g. the index of the selected item for a [`Radio`](crate::field::Radio). Fields
+/// selecting among items take it after their `items`. Together with `apply`, this lets a form edit an
+/// existing record, writing the entered values back only if the form is submitted: 
+/// ```no_run
+/// # use tundra::{prelude::*, field::*};
+/// struct User {
+///     name: String, 
+///     admin: bool, 
+///     role: usize, 
+/// }
+/// 
+/// # let user = &mut User{ name: String::new(), admin: false, role: 0 };
+/// # let ctx = &mut Context::new().unwrap();
+/// // let user: &mut User
+/// // let ctx: &mut Context<_>
+/// 
+/// dialog::form!{
+///     name: Textbox{ name: "Name", value: &user.name }, 
+///     admin: Checkbox{ name: "Administrator", value: user.admin }, 
+///     role: Radio{ name: "Role", items: ["Guest", "Member", "Owner"], value: user.role }, 
+///     [title]: "Edit user", 
+///     [context]: ctx, 
+///     [background]: &(), 
+///     [apply]: |values| {
+///         user.name.clone_from(&values.name);
+///         user.admin = values.admin;
+///         user.role = values.role;
+///     }, 
+/// };
+/// ```
 /// 
 /// 
 /// # Validation
@@ -83,7 +372,7 @@
 /// whenever the user attempts to submit the form and has global access to all fields. 
 /// 
 /// Since field validation is more localised, it can be used to provide more intuitive feedback by turning
-/// the name of the offending field red. 
+/// the name of the offending field red and showing the error message right below it. 
 /// 
 /// Prefer field validation for simple checks that require only local knowledge of the fields, and form
 /// validation for checks that are either more complicated or require global knowledge of the fields (such
@@ -100,16 +389,21 @@
 /// 
 /// Whenever the value of a field is changed or the form is submitted (whichever happens first), it is
 /// checked against the error condition. If the error condition triggers, the name of the field turns red,
-/// and the error message is displayed if the user attempts to submit the form. For some fields (textboxes in
+/// and the error message is shown below the field, prefixed with `✗`. The message updates as the user edits
+/// the field, and is also displayed if the user attempts to submit the form. For some fields (textboxes in
 /// particular), the error condition could be checked quite frequently and should therefore be fairly fast.
 /// For more complicated validation, prefer [form validation](#form-validation), which is only checked once
 /// the form is submitted. 
 /// 
 /// The syntax of a control statement follows the form `if ERR_CONDITION => MESSAGE`, where `ERR_CONDITION`
 /// is either a path to a function (e.g. `str::is_empty`) or a closure (e.g. `|&value| value == 123`), and
-/// `MESSAGE` is a value that implements `Into<Cow<str>>`. Several control statements are given by repeating
-/// the syntax, delimited by a space or newline. Note that the comma that separates different fields in the
-/// macro is given after all control statements. 
+/// `MESSAGE` is a value that implements `Into<Cow<str>>` and [`Clone`]. Several control statements are given
+/// by repeating the syntax, delimited by a space or newline. Note that the comma that separates different
+/// fields in the macro is given after all control statements. 
+/// 
+/// The message is either a literal, a variable or constant, or any other expression wrapped in parentheses
+/// or braces. It is only evaluated once the error condition triggers, so it may be composed on the fly, e.g. 
+/// `if str::is_empty => (format!("{label} is required"))`. 
 /// 
 /// For example, to require that the password in the example from before is non-empty and not equal to
 /// "password1": 
@@ -126,14 +420,20 @@
 /// ```
 /// 
 /// 
+/// Some fields also report invalid input by themselves through [`Field::error`](crate::field::Field::error), 
+/// e.g. a [`NumberBox`](crate::field::NumberBox) whose text does not parse. This is handled the same way as a
+/// triggered control statement, and takes precedence over the control statements of the field. 
+/// 
+/// 
 /// ### Form validation
 /// 
 /// Form validation is provided through a function over the values of all fields. It can be used to place
 /// requirements on the relationships between fields or in cases where field validation is too complex to be
 /// performed each time a field is updated. 
 /// 
-/// The validation function accepts as argument a struct containing a reference to the values of all fields. 
-/// Since this struct is unspellable by application code, the function must be a closure. It should return a
+/// The validation function accepts two arguments: a struct containing a reference to the values of all
+/// fields, and a struct containing the metadata of all fields. Since these structs are unspellable by
+/// application code, the function must be a closure. It should return a
 /// value of `Result<T, impl ToString>`; `Ok(T)` on validation success, and `Err` with a given error
 /// otherwise. The `Ok` value may be used to store values computed during validation (e.g. the result of
 /// parsing an entered string), and is available via the `Validated` field of the values returned from the
@@ -142,18 +442,29 @@
 /// Note that the macro has special handling of [`str`] and [`String`] error types such that they are not
 /// needlessly reallocated. 
 /// 
+/// The metadata of each field is accessed through the identifier of the field, and holds the following: 
+/// - `name`; the name of the field as displayed to the user. 
+/// - `focused`; whether the field is currently focused. 
+/// 
+/// Using the field names when composing error messages keeps the messages in sync with the names given to
+/// the fields. Closures not needing the metadata may ignore it with `|values, _|`. 
+/// 
+/// If either kind of validation fails when the form is submitted, the error message is shown in an
+/// [error dialog](crate::dialog::error). If the user dismisses the error dialog by typing a printable
+/// character, the character is forwarded to the focused field so that the keystroke isn't lost. 
+/// 
 /// To enable form validation, supply a closure as the `validate` metadatum. For example, to validate that
 /// the value of slider `foo` is less than the value of slider `bar`: 
 /// ```no_run
 /// # use tundra::{prelude::*, field::Slider};
 /// # dialog::form!{
-/// # foo: Slider<u8>{ name: "" }, 
-/// # bar: Slider<u8>{ name: "" }, 
+/// # foo: Slider<u8>{ name: "Foo" }, 
+/// # bar: Slider<u8>{ name: "Bar" }, 
 /// # [title]: "", 
 /// # [context]: &mut Context::new().unwrap(), 
 /// # [background]: &(), 
-/// [validate]: |values| if values.foo >= values.bar {
-///     Err("Foo must be less than bar!")
+/// [validate]: |values, meta| if values.foo >= values.bar {
+///     Err(format!("{} must be less than {}!", meta.foo.name, meta.bar.name))
 /// } else {
 ///     Ok(())
 /// }
@@ -167,8 +478,10 @@
 /// 
 /// The return value of the macro is an [`Option`]: 
 /// - `Some` if the form was submitted. Contains the values of all fields as members of an unspellable
-/// struct. The identifiers of the values are the same as the corresponding fields. 
-/// - `None` if the form was cancelled. 
+/// struct. The identifiers of the values are the same as the corresponding fields. The index of the
+/// activated [button](#buttons) is given by `Button`, which is always zero if no buttons are declared. 
+/// - `None` if the form was cancelled, including when an [exit](crate::State#exiting) is requested while the
+/// form is shown. 
 /// 
 /// 
 /// # Examples
@@ -216,7 +529,7 @@
 ///     [title]: "Enter IP", 
 ///     [context]: ctx, 
 ///     [background]: current_state, 
-///     [validate]: |values| Ipv4Addr::from_str(values.ip), 
+///     [validate]: |values, _| Ipv4Addr::from_str(values.ip), 
 /// };
 /// if let Some(values) = values {
 ///    // type annotation is not required
@@ -239,7 +552,7 @@
 ///     [title]: "Login", 
 ///     [context]: ctx, 
 ///     [background]: current_state, 
-///     [validate]: |form| if form.username == "admin" && form.password == "password1" {
+///     [validate]: |form, _| if form.username == "admin" && form.password == "password1" {
 ///         Ok(())
 ///     } else {
 ///         Err("Invalid credentials. Try again.")
@@ -252,24 +565,83 @@
 /// ```
 #[macro_export]
 macro_rules! form {
+    // Entry point. The type of each field is matched as a path rather than as `ty`, since a `ty` may not be
+    // followed by the optional parameters and control statements. The type is then wrapped in parentheses
+    // and forwarded to the rule below, where it is matched as `ty`
     [
-        // A comma-separated list of fields
         $(
-            $id:ident: $type:ty {
-                // Parameters for each field using builder pattern methods
+            $(
+                #[section = $section:expr]
+            )?
+            $id:ident: $($type:ident)? $(:: $type_seg:ident)* $(<$($type_arg:ty),+>)?
+            $({
                 $(
                     $arg_id:ident $(: $arg_val:expr)?
                 ),+
                 $(,)?
-            }
+            })?
+            $(
+                = $field:tt
+            )?
+            $(
+                if $control:expr => $control_err:tt
+            )*
+            $(
+                on change => |$on_change_arg:pat_param| $on_change:block
+            )?
+            $(
+                show if $show_if:expr
+            )?
+        ),+, 
+        $([$meta_id:ident]: $meta_expr:expr),*
+        $(,)?
+    ] => {
+        $crate::form!{@fields
+            $(
+                $(#[section = $section])?
+                $id: ($($type)? $(:: $type_seg)* $(<$($type_arg),+>)?)
+                $({ $($arg_id $(: $arg_val)?),+ })?
+                $(= $field)?
+                $(if $control => $control_err)*
+                $(on change => |$on_change_arg| $on_change)?
+                $(show if $show_if)?
+            ),+, 
+            $([$meta_id]: $meta_expr),*
+        }
+    };
+    [@fields
+        // A comma-separated list of fields
+        $(
+            // Optional header of a section starting at the field
+            $(
+                #[section = $section:expr]
+            )?
+            $id:ident: ($type:ty)
+            // Parameters for each field using builder pattern methods
+            $({
+                $(
+                    $arg_id:ident $(: $arg_val:expr)?
+                ),+
+            })?
+            // Alternatively, an instance of the field used as is
+            $(
+                = $field:tt
+            )?
             // Optional set of control statements for the field, implementing field validation
             $(
-                if $control:expr => $control_err:literal
+                if $control:expr => $control_err:tt
             )*
+            // Optional callback invoked when the user changes the field
+            $(
+                on change => |$on_change_arg:pat_param| $on_change:block
+            )?
+            // Optional predicate deciding whether the field is shown
+            $(
+                show if $show_if:expr
+            )?
         ),+, 
         // Form meta data
         $([$meta_id:ident]: $meta_expr:expr),*
-        $(,)?
     ] => {{
         use std::{
             convert::Into as __Into, 
@@ -289,10 +661,10 @@ macro_rules! form {
         )*}
 
         // holds the owned values of all fields once the form is submitted. 
-        #[allow(dead_code)]
+        #[allow(dead_code, non_snake_case)]
         struct __Values<T> {
-            #[allow(non_snake_case)]
             Validated: T, 
+            Button: usize, 
             $(
                 $id: <$type as __Field>::Value,
             )*
@@ -304,6 +676,46 @@ macro_rules! form {
             $id: &'a <$type as __Field>::Value,
         )*}
 
+        // holds the metadata of all fields for form validation.
+        #[allow(dead_code)]
+        struct __FieldMeta<'a> {$(
+            $id: __internal::FieldMeta<'a>, 
+        )*}
+
+        // holds mutable references to all fields, passed to the on-change callbacks. 
+        #[allow(dead_code)]
+        struct __FieldsMut<'a> {$(
+            $id: &'a mut $type, 
+        )*}
+
+        // holds the on-change callbacks of all fields; `None` for fields without one. 
+        #[allow(clippy::type_complexity)]
+        struct __OnChange<'a> {$(
+            $id: __Option<::std::boxed::Box<dyn std::ops::FnMut(__FieldsMut) + 'a>>, 
+        )*}
+
+        // boxes an on-change callback, letting the types of its arguments be inferred. 
+        #[allow(dead_code)]
+        fn __on_change<'a>(callback: impl std::ops::FnMut(__FieldsMut) + 'a)
+            -> ::std::boxed::Box<dyn std::ops::FnMut(__FieldsMut) + 'a>
+        {
+            ::std::boxed::Box::new(callback)
+        }
+
+        // holds the predicates of the conditional fields; `None` for fields that are always shown. 
+        #[allow(clippy::type_complexity)]
+        struct __ShowIf<'a> {$(
+            $id: __Option<::std::boxed::Box<dyn std::ops::Fn(__BorrowedValues) -> bool + 'a>>, 
+        )*}
+
+        // boxes the predicate of a conditional field, letting the types of its arguments be inferred. 
+        #[allow(dead_code)]
+        fn __show_if<'a>(predicate: impl std::ops::Fn(__BorrowedValues) -> bool + 'a)
+            -> ::std::boxed::Box<dyn std::ops::Fn(__BorrowedValues) -> bool + 'a>
+        {
+            ::std::boxed::Box::new(predicate)
+        }
+
         // holds control callbacks and state for all fields, for implementing field validation. 
         struct __Control<'a> {$(
             $id: __internal::Control<'a, $type>, 
@@ -314,8 +726,24 @@ macro_rules! form {
         struct __Form<'a> {
             __focus: usize, 
             __control: __Control<'a>, 
+            __on_change: __OnChange<'a>, 
+            __show_if: __ShowIf<'a>, 
             __title: __Cow<'a, str>, 
             __message: __Cow<'a, str>, 
+            __progress: bool, 
+            __max_field_height: usize, 
+            // the labels of the buttons below the fields, and the index of the selected button. the row of
+            // buttons is focused when `__focus` is `__FIELDS`
+            __buttons: ::std::vec::Vec<__Cow<'a, str>>, 
+            __button: usize, 
+            // the number of body lines scrolled past when the form is taller than the frame. this is kept
+            // between draws so that the view only moves once the focused field would leave it
+            __scroll: ::std::cell::Cell<usize>, 
+            __sections: [__Option<__Cow<'a, str>>; __FIELDS], 
+            __theme: $crate::dialog::DrawInfoDefaults, 
+            __fields: $crate::dialog::FieldStyles, 
+            __submit: $crate::KeyEvent, 
+            __quick_jump: bool, 
             $(
                 $id: $type, 
             )*
@@ -325,47 +753,192 @@ macro_rules! form {
         const __FIELDS: usize = [$(__Indices::$id),*].len();
 
         impl __Form<'_> {
-            fn values(&self) -> __BorrowedValues {
+            fn values(&self) -> __BorrowedValues<'_> {
                 __BorrowedValues {$(
                     $id: __Field::value(&self.$id), 
                 )*}
             }
 
-            fn into_values<T>(self, validated: T) -> __Values<T> {
-                __Values {
-                    Validated: validated, 
-                    $(
-                        $id: __Field::into_value(self.$id), 
-                    )*
+            fn fields_mut(&mut self) -> __FieldsMut<'_> {
+                __FieldsMut {$(
+                    $id: &mut self.$id, 
+                )*}
+            }
+
+            // invokes the on-change callback of the field at given index, if any, after which the fields
+            // that have already been validated are validated anew. 
+            fn changed(&mut self, index: usize) {
+                const ON_CHANGE: [fn(&mut __Form); __FIELDS] = [$(
+                    |form| if let __Option::Some(mut callback) = form.__on_change.$id.take() {
+                        callback(form.fields_mut());
+                        form.__on_change.$id = __Option::Some(callback);
+                        form.revalidate();
+                    }
+                ),*];
+                ON_CHANGE[index](self);
+                self.refocus();
+            }
+
+            // moves the focus to the first focusable field if the focused field is no longer focusable, e.g. 
+            // after it was hidden
+            fn refocus(&mut self) {
+                let focusable = self.focusable();
+                if self.__focus < __FIELDS && !focusable[self.__focus] {
+                    self.set_focus(__internal::first_focus(&focusable, !self.__buttons.is_empty()));
                 }
             }
-        }
 
-        impl $crate::dialog::Dialog for __Form<'_> {
-            type Out = __Option<Self>;
+            // moves the focus, letting the field that loses it know through `Field::focus_lost`
+            fn set_focus(&mut self, focus: usize) {
+                const FOCUS_LOST: [fn(&mut __Form); __FIELDS] = [$(
+                    |form| __Field::focus_lost(&mut form.$id)
+                ),*];
+                if focus != self.__focus && self.__focus < __FIELDS {
+                    FOCUS_LOST[self.__focus](self);
+                }
+                self.__focus = focus;
+            }
+
+            fn revalidate(&mut self) {$(
+                self.__control.$id.refresh(&self.$id);
+            )*}
+
+            // whether each field is shown, indexed like `__focus`. 
+            fn visible(&self) -> [bool; __FIELDS] {
+                let mut visible = [$(
+                    match &self.__show_if.$id {
+                        __Option::Some(show_if) => show_if(self.values()), 
+                        __Option::None => true, 
+                    }, 
+                )*];
+                // at least one field is always shown
+                if !visible.contains(&true) {
+                    visible[0] = true;
+                }
+                visible
+            }
+
+            // whether each field can be focused, i.e. is both shown and enabled, indexed like `__focus`. 
+            fn focusable(&self) -> [bool; __FIELDS] {
+                let visible = self.visible();
+                [$(visible[__Indices::$id as usize] && __Field::enabled(&self.$id)),*]
+            }
+
+            // the index of the button activated by submitting the form; the selected button if the row of
+            // buttons is focused, and the first button otherwise. 
+            fn button(&self) -> usize {
+                match self.__focus == __FIELDS {
+                    true => self.__button, 
+                    false => 0, 
+                }
+            }
+
+            fn meta(&self) -> __FieldMeta<'_> {
+                __FieldMeta {$(
+                    $id: __internal::FieldMeta {
+                        name: __Field::name(&self.$id), 
+                        focused: __Indices::$id as usize == self.__focus, 
+                    }, 
+                )*}
+            }
 
-            fn format(&self) -> $crate::dialog::DrawInfo {
+            fn format_in_area(&self, area: __Option<$crate::ratatui::layout::Rect>)
+                -> $crate::dialog::DrawInfo<'_>
+            {
+                // hidden fields are left out, including from the alignment of the names
+                let visible = self.visible();
                 let name_lengths = [$(
-                    __Field::name(&self.$id).len(), 
+                    match visible[__Indices::$id as usize] {
+                        true => __Field::name(&self.$id).len(), 
+                        false => 0, 
+                    }, 
                 )*];
                 let max_name = name_lengths
                     .into_iter()
                     .max()
                     .unwrap_or(0);
+                // the quick jump indices are shown before the names, and are aligned along with them
+                let focusable = self.focusable();
+                let index_width = match self.__quick_jump {
+                    true => __internal::QUICK_JUMP_WIDTH, 
+                    false => 0, 
+                };
+                let align_to = max_name + index_width;
+                let width = area.map(|area| __internal::field_width(area, align_to, &self.__theme));
                 let mut fields = [
-                    $({
-                        let focus = __Indices::$id as usize == self.__focus;
-                        let name = __Field::name(&self.$id);
-                        let body = __Field::format(&self.$id, focus);
-                        let error = self.__control.$id.is_err();
-                        __internal::format_field(name, body, focus, max_name, error)
+                    $(match visible[__Indices::$id as usize] {
+                        false => $crate::ratatui::text::Text::default(), 
+                        true => {
+                            let enabled = __Field::enabled(&self.$id);
+                            let focus = __Indices::$id as usize == self.__focus && enabled;
+                            let name = __Field::name(&self.$id);
+                            let body = match width {
+                                __Option::Some(width) => __Field::format_width(&self.$id, focus, width), 
+                                __Option::None => __Field::format(&self.$id, focus), 
+                            };
+                            let focused_line = focus
+                                .then(|| __Field::focused_line(&self.$id))
+                                .flatten();
+                            let body = __internal::cap_height(body, self.__max_field_height, focused_line);
+                            let error = self.__control.$id.error();
+                            let styles = &self.__fields;
+                            let text = __internal::format_field(
+                                name, body, focus, max_name, error, enabled, styles, 
+                            );
+                            match self.__quick_jump {
+                                true => __internal::with_quick_jump_index(
+                                    text, 
+                                    __internal::quick_jump_index(&focusable, __Indices::$id as usize), 
+                                ), 
+                                false => text, 
+                            }
+                        }
                     },)*
                 ];
-                __internal::format_dialog(&mut fields, self.__message.as_ref(), self.__title.as_ref())
+                let progress = match self.__progress {
+                    true => __internal::progress(&[$(
+                        match visible[__Indices::$id as usize] {
+                            true => self.__control.$id.summary(&self.$id), 
+                            false => __internal::ControlSummary::Uncontrolled, 
+                        }, 
+                    )*]), 
+                    false => __Option::None, 
+                };
+                let message = self.__message.as_ref();
+                let title = self.__title.as_ref();
+                let buttons = (&self.__buttons[..], (self.__focus == __FIELDS).then_some(self.__button));
+                let scroll = area.map(|area| (area, self.__focus, &self.__scroll));
+                let (sections, theme, submit) = (&self.__sections[..], &self.__theme, self.__submit);
+                __internal::format_dialog(
+                    &mut fields, sections, message, title, theme, submit, progress, buttons, scroll, 
+                )
+            }
+
+            fn into_values<T>(self, validated: T) -> __Values<T> {
+                __Values {
+                    Validated: validated, 
+                    Button: self.button(), 
+                    $(
+                        $id: __Field::into_value(self.$id), 
+                    )*
+                }
+            }
+        }
+
+        impl $crate::dialog::Dialog for __Form<'_> {
+            type Out = __Option<Self>;
+            type Global = ();
+
+            fn format(&self) -> $crate::dialog::DrawInfo<'_> {
+                self.format_in_area(__Option::None)
+            }
+
+            fn format_in(&self, area: $crate::ratatui::layout::Rect) -> $crate::dialog::DrawInfo<'_> {
+                self.format_in_area(__Option::Some(area))
             }
             
-            fn input(mut self, key: $crate::KeyEvent) -> $crate::Signal<Self> {
-                use $crate::{Signal, KeyEvent, KeyCode, KeyModifiers, field::InputResult};
+            fn input(mut self, key: $crate::KeyEvent, _ctx: &mut $crate::Context) -> $crate::Signal<Self> {
+                use $crate::{Signal, KeyEvent, KeyCode, field::InputResult};
 
                 type Dispatch<'a> = fn(&mut __Form, KeyEvent) -> InputResult;
 
@@ -376,78 +949,193 @@ macro_rules! form {
                     |form, key| __internal::input_dispatch(&mut form.$id, &mut form.__control.$id, key)
                 ),*];
 
-                let focus_up = self.__focus.saturating_sub(1);
-                let focus_down = usize::min(self.__focus + 1, __FIELDS - 1);
+                // likewise for `Field::captures`, letting the focused field take keys that are otherwise
+                // handled by the form itself. disabled fields capture nothing
+                const CAPTURES: [fn(&__Form, &KeyEvent) -> bool; __FIELDS] = [$(
+                    |form, key| __Field::enabled(&form.$id) && __Field::captures(&form.$id, key)
+                ),*];
 
-                match key.code {
-                    KeyCode::Esc => Signal::Return(None), 
-                    KeyCode::Enter => Signal::Return(Some(self)), 
-                    KeyCode::BackTab => {
-                        self.__focus = focus_up;
-                        Signal::Continue(self)
+                // the focus skips over disabled and hidden fields, and moves from the last field to the row of
+                // buttons
+                let focusable = self.focusable();
+                let buttons = !self.__buttons.is_empty();
+                let focus_up = __internal::move_focus(&focusable, buttons, self.__focus, false);
+                let focus_down = __internal::move_focus(&focusable, buttons, self.__focus, true);
+
+                // quick jumps take precedence over the focused field, including keys it captures. the keys are
+                // swallowed even if there is no field with the index
+                let quick_jump = self.__quick_jump
+                    .then(|| __internal::quick_jump_key(&key))
+                    .flatten();
+                if let __Option::Some(index) = quick_jump {
+                    if let __Option::Some(field) = __internal::quick_jump_field(&focusable, index) {
+                        self.set_focus(field);
                     }
-                    KeyCode::Tab => {
-                        self.__focus = focus_down;
-                        Signal::Continue(self)
+                    return Signal::Continue(self)
+                }
+                if self.__focus == __FIELDS {
+                    let last_button = self.__buttons.len() - 1;
+                    // enter presses the selected button even if another key submits the form
+                    if key.code == KeyCode::Enter || __internal::is_submit(&key, &self.__submit) {
+                        return Signal::Return(Some(self))
+                    }
+                    match key.code {
+                        KeyCode::Esc => return Signal::Return(None), 
+                        KeyCode::Left => self.__button = self.__button.saturating_sub(1), 
+                        KeyCode::Right => self.__button = usize::min(self.__button + 1, last_button), 
+                        KeyCode::Up | KeyCode::BackTab => self.set_focus(focus_up), 
+                        _ => (), 
+                    }
+                    return Signal::Continue(self)
+                }
+                if CAPTURES[self.__focus](&self, &key) {
+                    if JUMP_TABLE[self.__focus](&mut self, key) == InputResult::Updated {
+                        self.changed(self.__focus);
                     }
+                    return Signal::Continue(self)
+                }
+                if __internal::is_submit(&key, &self.__submit) {
+                    return Signal::Return(Some(self))
+                }
+                match key.code {
+                    KeyCode::Esc => Signal::Return(None), 
                     _ => {
                         let dispatch_result = JUMP_TABLE[self.__focus](&mut self, key);
-                        self.__focus = match (dispatch_result, key.code) {
-                            (InputResult::Ignored, KeyCode::Up) => focus_up,  
-                            (InputResult::Ignored, KeyCode::Down) => focus_down, 
+                        if dispatch_result == InputResult::Updated {
+                            self.changed(self.__focus);
+                        }
+                        let focus = match (dispatch_result, key.code) {
+                            (InputResult::Ignored, KeyCode::Up | KeyCode::BackTab) => focus_up,  
+                            (InputResult::Ignored, KeyCode::Down | KeyCode::Tab) => focus_down, 
                             _ => self.__focus, 
                         };
+                        self.set_focus(focus);
                         Signal::Continue(self)
                     }
                 }
             }
+
+            fn paste(mut self, text: &str) -> $crate::Signal<Self> {
+                // likewise for `Field::paste`
+                const PASTE_TABLE: [fn(&mut __Form, &str) -> $crate::field::InputResult; __FIELDS] = [$(
+                    |form, text| __internal::paste_dispatch(&mut form.$id, &mut form.__control.$id, text)
+                ),*];
+                if let __Option::Some(paste) = PASTE_TABLE.get(self.__focus) {
+                    if paste(&mut self, text) == $crate::field::InputResult::Updated {
+                        self.changed(self.__focus);
+                    }
+                }
+                $crate::Signal::Continue(self)
+            }
+
+            fn exit(self) -> $crate::Signal<Self> {
+                $crate::Signal::Return(__Option::None)
+            }
         }
 
         fn __run<'a, T, U>(
             mut form: __Form<'a>, 
             bg: &impl $crate::State, 
             ctx: &mut $crate::Context<T>, 
-            mut validate: impl std::ops::FnMut(__BorrowedValues) -> __Result<U, __Cow<'a, str>>, 
+            mut validate: impl std::ops::FnMut(__BorrowedValues, __FieldMeta) -> __Result<U, __Cow<'a, str>>, 
         ) -> __Option<__Values<U>> {
             use $crate::dialog::Dialog as _;
 
+            // whether the form was submitted by a key replayed into it, and should be validated right away
+            let mut submitted = false;
             loop {
-                // run form dialog; if the user cancels, exit immediately
-                let __Option::Some(out) = form.run_over(bg, ctx) else {
-                    break None
-                };
-                form = out;
+                // run form dialog unless it was just submitted; if the user cancels, exit immediately
+                if !std::mem::take(&mut submitted) {
+                    let __Option::Some(out) = form.run_over(bg, &mut ctx.chain_without_global()) else {
+                        break None
+                    };
+                    form = out;
+                }
 
-                // perform field validation
+                // perform field validation, except for hidden fields
+                let visible = form.visible();
                 let control_result = __internal::format_control_error(&[$(
-                    (__Field::name(&form.$id), form.__control.$id.updated_result(&form.$id)), 
+                    (__Field::name(&form.$id), match visible[__Indices::$id as usize] {
+                        true => form.__control.$id.updated_result(&form.$id), 
+                        false => __Result::Ok(()), 
+                    }), 
                 )*]);
                 // if field validation passes, perform form validation
                 let validation_result = match control_result {
-                    __Result::Ok(()) => validate(form.values()), 
+                    __Result::Ok(()) => validate(form.values(), form.meta()), 
                     __Result::Err(e) => __Result::Err(__Cow::from(e)), 
                 };
                 // if either validation fails, show error message and continue. otherwise, return values
-                match validation_result {
+                let key = match validation_result {
                     __Result::Ok(ok) => break __Option::Some(form.into_values(ok)), 
-                    __Result::Err(e) => $crate::dialog::error(e, bg, ctx), 
+                    __Result::Err(e) => __internal::error(e.as_ref(), bg, ctx), 
+                };
+                // if an exit was requested while the error message was shown, the form is cancelled
+                if ctx.exit_requested() {
+                    break None
+                }
+                // if the error message was dismissed by the user starting to type, replay the key into the
+                // form so the keystroke isn't lost
+                if __internal::is_replayable(&key) {
+                    form = match $crate::dialog::Dialog::input(form, key, &mut ctx.chain_without_global()) {
+                        $crate::Signal::Continue(form) => form, 
+                        $crate::Signal::Return(__Option::Some(form)) => {
+                            submitted = true;
+                            form
+                        }
+                        $crate::Signal::Return(__Option::None) => break None, 
+                    };
                 }
             }
         }
 
+        // initialise fields with builder pattern using given arguments, in declaration order so that each
+        // field can reference those declared before it. see [`build_form_fields!`]. this is done before the
+        // metadata are evaluated, so that the arguments may read from data that the metadata borrow mutably, 
+        // e.g. a record that `apply` writes back to
+        struct __Built {$(
+            $id: __Option<$type>, 
+        )*}
+        let mut built = __Built {$(
+            $id: __Option::None, 
+        )*};
+        $crate::build_form_fields!{($) built []
+            $($id: $type $({ $($arg_id $(: $arg_val)?),+ })? $(= $field)?;)*
+        }
+
         // temporary container for all metadata, used for parsing. see [`parse_form_meta!`]
-        struct __Meta<'a, A, B, C, D, E, X, Y>
+        struct __Meta<'a, A, B, C, D, E, F, G, H, I, J, K, L, M, X, Y>
         where
             A: __Into<__Cow<'a, str>>, 
             D: __Into<__Cow<'a, str>>, 
-            E: std::ops::FnMut(__BorrowedValues) -> __Result<X, Y>, 
+            E: std::ops::FnMut(__BorrowedValues, __FieldMeta) -> __Result<X, Y>, 
+            F: __internal::Describe, 
+            H: std::iter::IntoIterator, 
+            H::Item: __Into<__Cow<'a, str>>, 
+            I: std::ops::FnOnce(&__Values<X>), 
+            J: __Into<__Option<u8>>, 
+            K: __Into<__Option<u16>>, 
+            L: __Into<$crate::KeyEvent>, 
+            M: __Into<__Option<$crate::dialog::Anchor>>, 
             Y: std::string::ToString, 
         {
             title: A, 
             context: &'a mut $crate::Context<B>, 
             background: &'a C, 
+            background_mut: &'a mut G, 
             message: D, 
             validate: E, 
+            progress: bool, 
+            max_field_height: usize, 
+            describe: F, 
+            buttons: H, 
+            apply: I, 
+            theme: $crate::dialog::DrawInfoDefaults, 
+            width: J, 
+            width_cols: K, 
+            submit: L, 
+            quick_jump: bool, 
+            anchor: M, 
         }
 
         // instantiates the struct above with the given metadata, using the defaults defined under `else` for
@@ -456,26 +1144,42 @@ macro_rules! form {
             __Meta {
                 $($meta_id: $meta_expr,)*
             } else {
+                background: &__internal::NoBackground, 
+                background_mut: &mut __internal::NoBackground, 
                 message: "", 
-                validate: |_| __Result::<(), __Cow<'_, str>>::Ok(()), 
+                validate: |_, _| __Result::<(), __Cow<'_, str>>::Ok(()), 
+                progress: false, 
+                max_field_height: usize::MAX, 
+                describe: (), 
+                buttons: [""; 0], 
+                apply: |_| (), 
+                theme: $crate::dialog::DrawInfoDefaults::default(), 
+                width: __Option::<u8>::None, 
+                width_cols: __Option::<u16>::None, 
+                submit: $crate::KeyCode::Enter, 
+                quick_jump: false, 
+                anchor: __Option::<$crate::dialog::Anchor>::None, 
             }
         };
 
         // field validation. for each field, creates a callback `Control::callback` bundling all
         // control-statements for the field. this callback is invoked each time the field is updated. if the
-        // callback results in error, it is saved in `Control::state`. 
+        // callback results in error, it is saved in `Control::state`. the error message is only evaluated
+        // once its condition triggers, and is cloned from a reference so that messages held in local
+        // variables can be used repeatedly without being moved into the callback
         let control = __Control {
             $($id: __internal::Control {
                 callback: &|value: &<$type as __Field>::Value| {
                     $(
                         if $control(value) {
-                            return __Result::Err(__Cow::from($control_err))
+                            return __Result::Err(__Into::into(::std::clone::Clone::clone(&$control_err)))
                         }
                     )*
                     let _ = value;
                     __Result::Ok(())
                 }, 
                 state: __internal::ControlState::Unknown, 
+                controlled: !<[&str]>::is_empty(&[$(stringify!($control)),*]), 
             },)*
         };
 
@@ -484,40 +1188,176 @@ macro_rules! form {
         // on dtolnay's guide at https://github.com/dtolnay/case-studies/tree/master/autoref-specialization. 
         // note that the bound ToString on the error type in __Meta is not strictly needed but is used for
         // nicer error handling (which works since Into<Cow<str>> typically implies ToString)
-        let validate = |values: __BorrowedValues| (meta.validate)(values).map_err(|e| {
-            use __internal::make_cow::{ViaIntoCow, ViaToString};
+        let validate = |values: __BorrowedValues, fields: __FieldMeta| {
+            (meta.validate)(values, fields).map_err(|e| {
+                #[allow(unused_imports)]
+                use __internal::make_cow::{ViaIntoCow, ViaToString};
 
-            (&e).tag().make_cow(e)
-        });
+                (&e).tag().make_cow(e)
+            })
+        };
 
-        let form = __Form {
+        // on-change callbacks. see [`__OnChange`]
+        let on_change = __OnChange {$(
+            $id: [$(__on_change(|$on_change_arg| $on_change))?].into_iter().next(), 
+        )*};
+
+        // predicates of conditional fields. see [`__ShowIf`]
+        let show_if = __ShowIf {$(
+            $id: [$(__show_if($show_if))?].into_iter().next(), 
+        )*};
+
+        let mut form = __Form {
             __focus: 0, 
             __control: control, 
+            __on_change: on_change, 
+            __show_if: show_if, 
             __title: __Cow::from(meta.title), 
             __message: __Cow::from(meta.message), 
-            // initialise fields with builder pattern using given arguments
-            $($id: {
-                let builder = <$type as __Field>::builder()
-                $(
-                    .$arg_id($($arg_val)?)
-                )*;
-                $crate::field::Build::build(builder)
-            },)*
+            __progress: meta.progress, 
+            __max_field_height: meta.max_field_height, 
+            __buttons: meta.buttons
+                .into_iter()
+                .map(__Into::into)
+                .collect(), 
+            __button: 0, 
+            __scroll: ::std::cell::Cell::new(0), 
+            __sections: [$(
+                [$(__Cow::from($section))?].into_iter().next(), 
+            )*], 
+            __theme: $crate::dialog::DrawInfoDefaults {
+                width_percentage: __Into::into(meta.width), 
+                width_columns: __Into::into(meta.width_cols), 
+                anchor: __Into::into(meta.anchor), 
+                ..::std::default::Default::default()
+            }
+                .or(meta.theme)
+                .or(meta.context.dialog_theme().resolve().form), 
+            __fields: meta.context.dialog_theme().fields, 
+            __submit: __Into::into(meta.submit), 
+            __quick_jump: meta.quick_jump, 
+            $($id: built.$id.expect("All fields are built"),)*
         };
-        __run(form, meta.background, meta.context, validate)
+        form.__focus = __internal::first_focus(&form.focusable(), !form.__buttons.is_empty());
+        __internal::Describe::describe(meta.describe, || $crate::dialog::FormDescription {
+            title: form.__title.to_string(), 
+            message: form.__message.to_string(), 
+            fields: std::vec![$(
+                $crate::dialog::FieldDescription {
+                    identifier: stringify!($id), 
+                    name: __Field::name(&form.$id).to_owned(), 
+                    type_name: std::any::type_name::<$type>(), 
+                    has_validation: form.__control.$id.controlled, 
+                    enabled: __Field::enabled(&form.$id), 
+                    conditional: form.__show_if.$id.is_some(), 
+                    section: form.__sections[__Indices::$id as usize]
+                        .as_deref()
+                        .map(::std::borrow::ToOwned::to_owned), 
+                }, 
+            )*], 
+        });
+        let backgrounds = (meta.background, meta.background_mut);
+        let values = __run(form, __internal::Backgrounds::get(&backgrounds), meta.context, validate);
+        if let __Option::Some(values) = &values {
+            (meta.apply)(values);
+        }
+        __internal::Refresh::refresh(backgrounds, meta.context);
+        values
     }}
 }
 
-/// Utility macro for parsing form metadata as a struct instantiation. 
+/// Utility macro for building the fields of a [form](form!) in declaration order. 
 /// 
-/// The problem being solved is (a) having a set of required fields and a set of optional fields --- the
-/// latter having defined default values --- and (b) allowing them to be given in any order. Hard-coding the
-/// metadata in the [`form`] macro arguments provides (a), but not (b). Making the metadata translate
-/// directly to a struct instantiation provides (b), but not (a). 
+/// Before building each field, a local macro `built!` is defined, mapping the identifier of each field
+/// declared before it to a reference to the built field. Since `built!` is redefined for each field, it only
+/// has rules for the fields declared before it, and referencing any other field is a compile error. 
 /// 
-/// This macro attempts to solve this by:
-/// 1. Taking the metadata given by the application along with the defaults for all optional metadata. 
-/// 2. Recursively removing the defaults for the optional metadata that were given by the application. This
+/// The built fields are stored in `$store`, a struct defined by the [form macro](form!) with an `Option` per
+/// field. A macro is used instead of exposing `$store` directly since local variables defined by a macro are
+/// invisible to the expressions given by the application. 
+#[macro_export]
+#[doc(hidden)]
+macro_rules! build_form_fields {
+    // Base case: all fields have been built.
+    [($s:tt) $store:ident [$($done:ident)*]] => {};
+    // Recursive case: builds the next field from its parameters.
+    [($s:tt) $store:ident [$($done:ident)*]
+        $id:ident: $type:ty { $($arg_id:ident $(: $arg_val:expr)?),+ };
+        $($tail:tt)*
+    ] => {
+        $crate::build_form_fields!{@store ($s) $store [$($done)*] $id {
+            let builder = <$type as $crate::field::Field>::builder()
+            $(
+                .$arg_id($($arg_val)?)
+            )*;
+            $crate::field::Build::build(builder)
+        }}
+        $crate::build_form_fields!{($s) $store [$($done)* $id] $($tail)*}
+    };
+    // Recursive case: uses the given instance of the next field.
+    [($s:tt) $store:ident [$($done:ident)*]
+        $id:ident: $type:ty = $field:tt;
+        $($tail:tt)*
+    ] => {
+        $crate::build_form_fields!{@store ($s) $store [$($done)*] $id {
+            // instances that are not literals or variables are wrapped in parentheses or braces
+            #[allow(unused_parens, unused_braces)]
+            let field: $type = $field;
+            field
+        }}
+        $crate::build_form_fields!{($s) $store [$($done)* $id] $($tail)*}
+    };
+    // Error cases: each field is given either parameters or an instance.
+    [($s:tt) $store:ident [$($done:ident)*]
+        $id:ident: $type:ty { $($params:tt)* } = $field:tt;
+        $($tail:tt)*
+    ] => {
+        compile_error!(concat!(
+            "field `", stringify!($id), "` is given both parameters and an instance; only one is allowed", 
+        ));
+    };
+    [($s:tt) $store:ident [$($done:ident)*]
+        $id:ident: $type:ty;
+        $($tail:tt)*
+    ] => {
+        compile_error!(concat!(
+            "field `", stringify!($id), "` is given neither parameters `{ ... }` nor an instance `= ...`", 
+        ));
+    };
+    // Stores the field evaluated by `$build`, with `built!` covering the fields before it.
+    [@store ($s:tt) $store:ident [$($done:ident)*] $id:ident $build:block] => {
+        {
+            #[allow(unused_macros)]
+            macro_rules! built {
+                $(
+                    ($done) => {
+                        $store.$done
+                            .as_ref()
+                            .expect("Fields are built in declaration order")
+                    };
+                )*
+                ($s other:ident) => {
+                    compile_error!(concat!(
+                        "field `", stringify!($s other), "` cannot be referenced from field `", stringify!($id), 
+                        "`; fields can only reference the fields declared before them", 
+                    ))
+                };
+            }
+            $store.$id = ::std::option::Option::Some($build);
+        }
+    };
+}
+
+/// Utility macro for parsing form metadata as a struct instantiation. 
+/// 
+/// The problem being solved is (a) having a set of required fields and a set of optional fields --- the
+/// latter having defined default values --- and (b) allowing them to be given in any order. Hard-coding the
+/// metadata in the [`form`] macro arguments provides (a), but not (b). Making the metadata translate
+/// directly to a struct instantiation provides (b), but not (a). 
+/// 
+/// This macro attempts to solve this by:
+/// 1. Taking the metadata given by the application along with the defaults for all optional metadata. 
+/// 2. Recursively removing the defaults for the optional metadata that were given by the application. This
 /// provides (a). 
 /// 3. Taking the defined metadata and the remaining defaults (those that were left undefined by the
 /// application) and using them to instantiate a struct. This provides (b). 
@@ -664,11 +1504,90 @@ macro_rules! parse_form_meta {
 /// 
 /// Most of this consists of stuff that could be factored out from the form macro body to reduce codegen. 
 pub mod internal {
+    use std::{cell::Cell, ops::Range};
     use ratatui::{
+        layout::Rect, 
         style::{Style, Stylize}, 
         text::{Line, Span}, 
     };
-    use crate::{dialog::*, field::{Field, InputResult}};
+    use crate::{dialog::*, field::{Field, InputResult, keybind::format_keyevent}};
+
+    /// Placeholder for whichever of the `background` and `background_mut` metadata was not given. 
+    pub struct NoBackground;
+
+    /// Selects the background among the `background` and `background_mut` metadata, exactly one of which
+    /// must be given. 
+    #[diagnostic::on_unimplemented(
+        message = "exactly one of the `background` and `background_mut` metadata must be given", 
+        label = "the form is shown here", 
+    )]
+    pub trait Backgrounds {
+        type State: State;
+
+        /// The background state to draw the form over. 
+        fn get(&self) -> &Self::State;
+    }
+
+    impl<S: State> Backgrounds for (&S, &mut NoBackground) {
+        type State = S;
+
+        fn get(&self) -> &S {
+            self.0
+        }
+    }
+
+    impl<S: State> Backgrounds for (&NoBackground, &mut S) {
+        type State = S;
+
+        fn get(&self) -> &S {
+            self.1
+        }
+    }
+
+    /// Invokes [`State::refresh_cache`] on the background state once the form closes, if it was given by
+    /// `background_mut`. 
+    #[diagnostic::on_unimplemented(
+        message = "exactly one of the `background` and `background_mut` metadata must be given, and the global of a \
+            `background_mut` state must match that of the context", 
+        label = "the form is shown here", 
+    )]
+    pub trait Refresh<G> {
+        fn refresh(self, ctx: &mut Context<G>);
+    }
+
+    impl<S: State, G> Refresh<G> for (&S, &mut NoBackground) {
+        fn refresh(self, _ctx: &mut Context<G>) {}
+    }
+
+    impl<S: State<Global = G>, G> Refresh<G> for (&NoBackground, &mut S) {
+        fn refresh(self, ctx: &mut Context<G>) {
+            self.1.refresh_cache(ctx)
+        }
+    }
+
+    /// Metadata of a field, passed to the form validation function. 
+    pub struct FieldMeta<'a> {
+        /// The name of the field as displayed to the user. 
+        pub name: &'a str, 
+        /// Whether the field is currently focused. 
+        pub focused: bool, 
+    }
+
+    /// Destination of the `describe` metadatum. Implemented by `()` as a no-op for when the metadatum is
+    /// absent. 
+    pub trait Describe {
+        fn describe(self, create: impl FnOnce() -> FormDescription);
+    }
+
+    impl Describe for () {
+        fn describe(self, _create: impl FnOnce() -> FormDescription) {}
+    }
+
+    impl Describe for &mut FormDescription {
+        fn describe(self, create: impl FnOnce() -> FormDescription) {
+            *self = create();
+        }
+    }
 
     /// Holds the last known control state; [`ControlState::Unknown`] if it has never been tested. 
     pub enum ControlState<'a> {
@@ -677,15 +1596,43 @@ pub mod internal {
         Err(Cow<'a, str>), 
     }
 
+    impl ControlState<'_> {
+        /// The error message if the state is [`ControlState::Err`]. 
+        pub fn error(&self) -> Option<&str> {
+            match self {
+                ControlState::Err(e) => Some(e), 
+                _ => None, 
+            }
+        }
+    }
+
     /// Stores the callback to validate a field and the last known result of that callback. 
     pub struct Control<'a, T: Field> {
+        #[allow(clippy::type_complexity)]
         pub callback: &'a dyn Fn(&T::Value) -> Result<(), Cow<'a, str>>, 
         pub state: ControlState<'a>, 
+        /// Whether the field has any control statements. 
+        pub controlled: bool, 
+    }
+
+    /// Summary of the control state of a field, used to compute the [progress](fn@progress) of the form. 
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ControlSummary {
+        /// The field has no control statements. 
+        Uncontrolled, 
+        /// The field has control statements but has not yet been validated. 
+        Unknown, 
+        Ok, 
+        Err, 
     }
 
     impl<'a, T: Field> Control<'a, T> {
-        /// Makes sure that the field has been validated and returns the last known error. 
+        /// Makes sure that the field has been validated and returns the last known error. Disabled fields are
+        /// not validated and always pass. 
         pub fn updated_result<'b>(&'b mut self, field: &T) -> Result<(), &'b str> {
+            if !field.enabled() {
+                return Ok(())
+            }
             if let ControlState::Unknown = self.state {
                 self.update(field);
             }
@@ -696,40 +1643,217 @@ pub mod internal {
             }
         }
 
-        /// Validates a field by updating [`Control::state`]. 
+        /// Validates a field by updating [`Control::state`]. Errors reported by the field itself take
+        /// precedence over the control statements. 
         pub fn update(&mut self, field: &T) {
-            self.state = match (self.callback)(field.value()) {
+            let result = match field.error() {
+                Some(err) => Err(err), 
+                None => (self.callback)(field.value()), 
+            };
+            self.state = match result {
                 Ok(()) => ControlState::Ok, 
                 Err(err) => ControlState::Err(err), 
             };
         }
 
-        /// Whether the field is *known* to be invalid. 
-        pub const fn is_err(&self) -> bool {
-            match self.state {
-                ControlState::Unknown => false,
-                ControlState::Ok => false,
-                ControlState::Err(_) => true,
+        /// Validates the field anew if it has been validated before, e.g. after it was changed by an on-change
+        /// callback. 
+        pub fn refresh(&mut self, field: &T) {
+            if !matches!(self.state, ControlState::Unknown) {
+                self.update(field);
+            }
+        }
+
+        /// Summarises the control state of the field. Disabled fields count as uncontrolled. 
+        pub fn summary(&self, field: &T) -> ControlSummary {
+            match (self.controlled, &self.state) {
+                _ if !field.enabled() => ControlSummary::Uncontrolled, 
+                (_, ControlState::Err(_)) => ControlSummary::Err, 
+                (false, _) => ControlSummary::Uncontrolled, 
+                (true, ControlState::Unknown) => ControlSummary::Unknown, 
+                (true, ControlState::Ok) => ControlSummary::Ok, 
             }
         }
+
+        /// The error message of the field if it is *known* to be invalid. 
+        pub fn error(&self) -> Option<&str> {
+            self.state.error()
+        }
     }
 
-    /// Delegates to [`Field::input`] and updates the [`Control::state`]. 
+    /// Delegates to [`Field::input`] and updates the [`Control::state`]. Disabled fields ignore all input. 
     #[inline(never)]
     pub fn input_dispatch<T: Field>(field: &mut T, control: &mut Control<T>, key: KeyEvent) -> InputResult {
+        if !field.enabled() {
+            return InputResult::Ignored
+        }
         let result = field.input(key);
         
         if let InputResult::Updated = result {
-            control.update(&field);
+            control.update(field);
         }
         result
     }
 
-    /// Formats a field for use in a form. 
+    /// Delegates to [`Field::paste`] and updates the [`Control::state`]. Disabled fields ignore all pastes. 
     #[inline(never)]
-    pub fn format_field<'a>(name: &'a str, mut body: Text<'a>, focused: bool, align_to: usize, error: bool)
-        -> Text<'a>
-    {
+    pub fn paste_dispatch<T: Field>(field: &mut T, control: &mut Control<T>, text: &str) -> InputResult {
+        if !field.enabled() {
+            return InputResult::Ignored
+        }
+        let result = field.paste(text);
+
+        if let InputResult::Updated = result {
+            control.update(field);
+        }
+        result
+    }
+
+    /// The index of the first enabled field, which is initially focused. If all fields are disabled, the row
+    /// of buttons is focused if there is one, and the first field otherwise, so that the form can still be
+    /// submitted or cancelled. 
+    pub fn first_focus(enabled: &[bool], buttons: bool) -> usize {
+        enabled
+            .iter()
+            .position(|&enabled| enabled)
+            .unwrap_or(match buttons {
+                true => enabled.len(), 
+                false => 0, 
+            })
+    }
+
+    /// The index of the nearest enabled field above or below the focused field, skipping disabled fields. If
+    /// `buttons` is given, the row of buttons follows the last field at index `enabled.len()`. The focus stays
+    /// put if there is no such field. 
+    pub fn move_focus(enabled: &[bool], buttons: bool, focus: usize, down: bool) -> usize {
+        let len = enabled.len();
+        let next = match down {
+            true => (focus + 1..len)
+                .find(|&i| enabled[i])
+                .or((buttons && focus < len).then_some(len)), 
+            false => (0..focus.min(len))
+                .rev()
+                .find(|&i| enabled[i]), 
+        };
+        next.unwrap_or(focus)
+    }
+
+    /// The width of the column of quick jump indices shown before the field names, e.g. `1. `. 
+    pub const QUICK_JUMP_WIDTH: usize = 3;
+
+    /// The quick jump index of given field, counting the focusable fields from `1`. Fields that can't be
+    /// focused, and those past the ninth focusable field, have no index. 
+    pub fn quick_jump_index(focusable: &[bool], field: usize) -> Option<usize> {
+        let index = focusable[..=field]
+            .iter()
+            .filter(|&&focusable| focusable)
+            .count();
+        (focusable[field] && index <= 9).then_some(index)
+    }
+
+    /// The quick jump index given by a key press, if it is one of `alt+1` through `alt+9`. 
+    pub fn quick_jump_key(key: &KeyEvent) -> Option<usize> {
+        match (key.code, key.modifiers) {
+            (KeyCode::Char(c @ '1'..='9'), KeyModifiers::ALT) => c.to_digit(10).map(|index| index as usize), 
+            _ => None, 
+        }
+    }
+
+    /// The field with given quick jump index, if any. See [`quick_jump_index`]. 
+    pub fn quick_jump_field(focusable: &[bool], index: usize) -> Option<usize> {
+        (0..focusable.len()).find(|&field| quick_jump_index(focusable, field) == Some(index))
+    }
+
+    /// Puts the quick jump index of a formatted field in front of its first line, indenting the remaining
+    /// lines to match. Fields without an index are indented as well. 
+    pub fn with_quick_jump_index(mut text: Text<'_>, index: Option<usize>) -> Text<'_> {
+        let indent = " ".repeat(QUICK_JUMP_WIDTH);
+        for (i, line) in text.lines.iter_mut().enumerate() {
+            let prefix = match (i, index) {
+                (0, Some(index)) => Span::raw(format!("{index}. ")).dim(), 
+                _ => Span::raw(indent.clone()), 
+            };
+            line.spans.insert(0, prefix);
+        }
+        text
+    }
+
+    /// Displays an error message over the form, returning the key that was pressed to dismiss it. 
+    pub fn error<G>(msg: &str, over: &impl State, ctx: &mut Context<G>) -> KeyEvent {
+        let defaults = ctx.dialog_theme().resolve().error;
+        basic::themed_message(msg, "Error", Color::Red, defaults, over, ctx)
+    }
+
+    /// Whether the key that dismissed an error message should be replayed into the form. This is the case
+    /// for printable characters (excluding space), since the user has likely started typing to fix the
+    /// error. 
+    pub fn is_replayable(key: &KeyEvent) -> bool {
+        let modified = key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        match key.code {
+            KeyCode::Char(c) => !modified && !c.is_whitespace(), 
+            _ => false, 
+        }
+    }
+
+    /// Whether a key press is the submit key of a form, comparing only its code and modifiers. 
+    pub fn is_submit(key: &KeyEvent, submit: &KeyEvent) -> bool {
+        key.code == submit.code && key.modifiers == submit.modifiers
+    }
+
+    /// Caps the height of a formatted field to `max` lines. 
+    /// 
+    /// If `focused_line` is given, a window of `max` lines around it is kept. Otherwise, the first `max - 1`
+    /// lines are kept, followed by a line counting the remaining lines. 
+    #[inline(never)]
+    pub fn cap_height(mut body: Text<'_>, max: usize, focused_line: Option<usize>) -> Text<'_> {
+        let len = body.lines.len();
+        if len <= max {
+            return body
+        }
+        let max = max.max(1);
+        match focused_line {
+            Some(focused) => {
+                let start = focused
+                    .saturating_sub(max / 2)
+                    .min(len - max);
+                body.lines.truncate(start + max);
+                body.lines.drain(..start);
+            }
+            None => {
+                body.lines.truncate(max - 1);
+                body.lines.push(Line::from(format!("… (+{} more)", len - (max - 1))).dim());
+            }
+        }
+        body
+    }
+
+    /// The width available to the body of each field in a form drawn in given area; that of the dialog body
+    /// less the name of the field, aligned to `align_to`, and its delimiter. The width of the dialog body is
+    /// that given by the `theme` of the form, if any. 
+    pub fn field_width(area: Rect, align_to: usize, theme: &DrawInfoDefaults) -> u16 {
+        let name_width = u16::try_from(align_to + 3).unwrap_or(u16::MAX);
+        theme
+            .apply(DrawInfo::default())
+            .body_width(area.width)
+            .saturating_sub(name_width)
+    }
+
+    /// Formats a field for use in a form. Disabled fields are dimmed. 
+    /// 
+    /// If the field is known to be invalid, its name is shown in the error style of `styles`, and the `error`
+    /// message is shown below it, indented like the body of the field. Without [colours](crate::color), the
+    /// name of the focused field is also underlined, and invalid fields are marked with `!` in place of the
+    /// delimiter. 
+    #[inline(never)]
+    pub fn format_field<'a>(
+        name: &'a str, 
+        mut body: Text<'a>, 
+        focused: bool, 
+        align_to: usize, 
+        error: Option<&'a str>, 
+        enabled: bool, 
+        styles: &FieldStyles, 
+    ) -> Text<'a> {
         // make sure we have at least one line to put the title in
         if body.lines.is_empty() {
             body.lines.push(Line::default())
@@ -737,26 +1861,25 @@ pub mod internal {
 
         // add title to first line
         {
-            let delimiter = match focused {
-                true => " : ", 
-                false => " │ ", 
+            let colors = crate::color::enabled();
+            let delimiter = match (focused, error.is_some() && !colors) {
+                (_, true) => " ! ", 
+                (true, false) => " : ", 
+                (false, false) => " │ ", 
             };
             let style = {
                 let style = Style::default();
-                let style = match focused {
-                    true => style.bold(), 
-                    false => style, 
-                };
-                let style = match error {
-                    true => style.red(), 
-                    false => style, 
+                let style = match (focused, colors) {
+                    (true, true) => style.patch(styles.focused), 
+                    (true, false) => style.patch(styles.focused).underlined(), 
+                    (false, _) => style, 
                 };
-                style
+                match error {
+                    Some(_) => style.patch(styles.error), 
+                    None => style, 
+                }
             };
-            let padding: Span = std::iter::repeat(' ')
-                .take(align_to.saturating_sub(name.len()))
-                .collect::<String>()
-                .into();
+            let padding: Span = " ".repeat(align_to.saturating_sub(name.len())).into();
             let name = Span::styled(name, style);
             let delimiter = Span::raw(delimiter);
             let title = [padding, name, delimiter];
@@ -764,37 +1887,215 @@ pub mod internal {
         };
 
         // indent remaining lines
+        let indent = format!("{} │ ", " ".repeat(align_to));
         for line in &mut body.lines[1..] {
-            let indent: String = std::iter::repeat(' ')
-                .take(align_to)
-                .chain(" │ ".chars())
-                .collect();
-            line.spans.insert(0, indent.into());
+            line.spans.insert(0, indent.clone().into());
         }
-        body
+
+        // add error message below the body, one line per line of the message
+        let error_lines = error
+            .into_iter()
+            .flat_map(str::lines)
+            .enumerate()
+            .map(|(i, line)| {
+                let prefix = match i {
+                    0 => "✗ ", 
+                    _ => "  ", 
+                };
+                let message = Span::styled(format!("{prefix}{line}"), styles.error);
+                Line::from(vec![indent.clone().into(), message])
+            });
+        body.lines.extend(error_lines);
+        match enabled {
+            true => body, 
+            false => body.dim(), 
+        }
+    }
+
+    /// Counts the number of valid fields, returning `(valid, total)`. Returns `None` if no field has any
+    /// control statements. 
+    pub fn progress(fields: &[ControlSummary]) -> Option<(usize, usize)> {
+        let controlled = fields
+            .iter()
+            .any(|field| *field != ControlSummary::Uncontrolled);
+        let valid = fields
+            .iter()
+            .filter(|field| matches!(field, ControlSummary::Uncontrolled | ControlSummary::Ok))
+            .count();
+        controlled.then_some((valid, fields.len()))
+    }
+
+    /// Formats the row of buttons below the fields. The selected button is highlighted if the row is focused; 
+    /// otherwise, the first button is emphasised since it is activated by submitting the form from a field. 
+    pub fn format_buttons<'a>(labels: &'a [Cow<'a, str>], selected: Option<usize>) -> Line<'a> {
+        let mut spans = Vec::with_capacity(labels.len() * 2);
+        for (i, label) in labels.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw("  "));
+            }
+            let style = match (selected, i) {
+                (Some(selected), _) if selected == i => Style::new().reversed().bold(), 
+                (None, 0) => Style::new().bold(), 
+                _ => Style::new(), 
+            };
+            spans.push(Span::styled(format!("[ {label} ]"), style));
+        }
+        Line::from(spans).centered()
+    }
+
+    /// Formats the header of a section of fields. 
+    pub fn format_section(title: &str) -> Line<'static> {
+        Line::from(format!("── {title} ──")).bold()
     }
 
     /// Formats the form dialog from the formatted fields. 
+    /// 
+    /// The `sections` hold the title of the section starting at each field, if any, whose header is shown
+    /// above the field; see [`format_section`]. Fields beyond the end of `sections` start no section. 
+    /// 
+    /// The `buttons` are given as `(labels, selected)`, where `selected` is the selected button if the row of
+    /// buttons is focused; see [`format_buttons`]. No row is shown if there are no labels. 
+    /// 
+    /// If `scroll` is given as `(frame area, focused field, offset)`, the body is clipped to fit the frame; 
+    /// see [`scroll`]. A focused field beyond the last field refers to the row of buttons. 
+    /// 
+    /// The `theme` is applied before the body is clipped, so that the height of the body accounts for the
+    /// border and hint it gives. 
     #[inline(never)]
-    pub fn format_dialog<'a>(fields: &mut [Text<'a>], message: &'a str, title: &'a str) -> DrawInfo<'a> {
-        let message = (message.len() != 0)
+    #[allow(clippy::too_many_arguments)]
+    pub fn format_dialog<'a>(
+        fields: &mut [Text<'a>], 
+        sections: &[Option<Cow<'_, str>>], 
+        message: &'a str, 
+        title: &'a str, 
+        theme: &DrawInfoDefaults, 
+        submit: KeyEvent, 
+        progress: Option<(usize, usize)>, 
+        buttons: (&'a [Cow<'a, str>], Option<usize>), 
+        scroll: Option<(Rect, usize, &Cell<usize>)>, 
+    ) -> DrawInfo<'a> {
+        let message: Vec<Line> = (!message.is_empty())
             .then(|| [Line::from(message), Line::default()])
             .into_iter()
-            .flatten();
+            .flatten()
+            .collect();
+
+        // section headers are put above the first field of each section, separated from the previous field, 
+        // so that they are covered by the focused field and scrolled into view along with it
+        for (i, (field, section)) in fields.iter_mut().zip(sections).enumerate() {
+            if let Some(section) = section {
+                let separator = (i > 0).then(Line::default);
+                let header = separator
+                    .into_iter()
+                    .chain([format_section(section)]);
+                field.lines.splice(0..0, header);
+            }
+        }
+
+        // the range of body lines covered by the focused field, if a field is focused
+        let focused_lines = scroll
+            .map(|(_, focus, _)| focus)
+            .filter(|&focus| focus < fields.len())
+            .map(|focus| {
+                let start = message.len() + fields[..focus]
+                    .iter()
+                    .map(|text| text.lines.len())
+                    .sum::<usize>();
+                start..start + fields[focus].lines.len()
+            });
+
         let fields = fields
-            .into_iter()
+            .iter_mut()
             .map(std::mem::take)
             .flat_map(|text| text.lines);
-        let body = message
+        let progress = progress
+            .map(|(valid, total)| [Line::default(), Line::from(format!("{valid}/{total} fields valid")).dim()])
+            .into_iter()
+            .flatten();
+        let (labels, selected) = buttons;
+        let buttons = (!labels.is_empty())
+            .then(|| [Line::default(), format_buttons(labels, selected)])
+            .into_iter()
+            .flatten();
+        let body: Vec<Line> = message
+            .into_iter()
             .chain(fields)
+            .chain(progress)
+            .chain(buttons)
             .collect();
-        DrawInfo {
+        // otherwise, the row of buttons is focused, which is the last line
+        let focused_lines = focused_lines.or((!labels.is_empty()).then(|| body.len() - 1..body.len()));
+        let mut info = theme.apply(DrawInfo {
             title: Cow::from(title), 
-            body, 
-            hint: Cow::from("Press (enter) to submit, (esc) to cancel..."), 
+            hint: Cow::from(Hints::new().key(format_keyevent(submit), "submit").esc("cancel")), 
             wrap: Some(Wrap{ trim: false }), 
             ..DrawInfo::default()
+        });
+        info.body = match (scroll, focused_lines) {
+            (Some((area, _, offset)), Some(focused_lines)) => {
+                let height = info.max_body_height(area) as usize;
+                self::scroll(body, height, focused_lines, offset).into()
+            }
+            _ => body.into(), 
+        };
+        info
+    }
+
+    /// Clips the lines of a form body to given height, keeping the lines of the focused field visible. 
+    /// 
+    /// The view starts `offset` lines into the body, and only moves once the focused field would leave it, 
+    /// after which `offset` is updated. Clipped lines above or below the view are indicated by `▲` and `▼`, 
+    /// respectively, each taking up one line of the view. 
+    /// 
+    /// Lines are assumed not to be wrapped when drawn. Form fields are formatted within the width of the
+    /// dialog, so this holds unless the terminal is exceptionally narrow. 
+    #[inline(never)]
+    pub fn scroll<'a>(mut body: Vec<Line<'a>>, height: usize, focused: Range<usize>, offset: &Cell<usize>)
+        -> Vec<Line<'a>>
+    {
+        let len = body.len();
+        if len <= height {
+            offset.set(0);
+            return body
+        }
+        // room for both indicators and at least one line in between
+        let height = height.max(3);
+
+        // the number of body lines shown when the view starts at given line
+        let visible = |start: usize| {
+            let above = (start > 0) as usize;
+            match start + height - above >= len {
+                true => len - start, 
+                false => height - above - 1, 
+            }
+        };
+
+        // follow the focused field, preferring its first line if it is taller than the view
+        let start = offset.get();
+        let start = match (focused.start < start, focused.end > start + visible(start)) {
+            (true, _) => focused.start, 
+            (_, true) => (start..=focused.start)
+                .find(|&start| focused.end <= start + visible(start))
+                .unwrap_or(focused.start), 
+            _ => start, 
+        };
+        // don't scroll further than needed to show the last line
+        let start = start.min(len.saturating_sub(height - 1));
+        offset.set(start);
+
+        let end = start + visible(start);
+        let indicator = |symbol| Line::from(symbol)
+            .centered()
+            .dim();
+        body.truncate(end);
+        body.drain(..start);
+        if start > 0 {
+            body.insert(0, indicator("▲"));
+        }
+        if end < len {
+            body.push(indicator("▼"));
         }
+        body
     }
 
     /// Takes a set of control states and constructs an error message from them. 
@@ -870,4 +2171,1199 @@ pub mod internal {
     }
 }
 
+/// Machine-readable description of a [form](crate::dialog::form!), filled through its `describe`
+/// metadatum. 
+/// 
+/// This can be used by external tooling, e.g. to generate documentation of what the user is asked in each
+/// form. The description reflects the form as it will be shown to the user. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, field::*, dialog::FormDescription};
+/// 
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// 
+/// let mut description = FormDescription::default();
+/// let values = dialog::form!{
+///     location: Textbox{ name: "Location" } if str::is_empty => "Value required", 
+///     pets_allowed: Checkbox{ name: "Pets allowed" }, 
+///     [title]: "Register Rent Unit", 
+///     [context]: ctx, 
+///     [background]: current_state, 
+///     [describe]: &mut description, 
+/// };
+/// assert_eq!(description.fields[0].identifier, "location");
+/// assert!(description.fields[0].has_validation);
+/// ```
+/// 
+/// With the `serde` feature, the description implements `serde::Serialize`, e.g. for exporting it as JSON. 
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FormDescription {
+    /// User-visible title of the form. 
+    pub title: String, 
+    /// User-visible message displayed above the fields. Empty if none was given. 
+    pub message: String, 
+    /// Descriptions of the fields, in the order they are shown. 
+    pub fields: Vec<FieldDescription>, 
+}
+
+/// Machine-readable description of a field in a [form](crate::dialog::form!). See [`FormDescription`]. 
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FieldDescription {
+    /// The identifier used to reference the value of the field in the form. 
+    pub identifier: &'static str, 
+    /// The user-visible name of the field, as returned by [`Field::name`](crate::field::Field::name). 
+    pub name: String, 
+    /// The name of the type of the field, as returned by [`std::any::type_name`]. Note that the exact
+    /// contents are not guaranteed to be stable across compiler versions. 
+    pub type_name: &'static str, 
+    /// Whether the field has any control statements for
+    /// [field validation](crate::dialog::form!#field-validation). 
+    pub has_validation: bool, 
+    /// Whether the field can be edited, as returned by [`Field::enabled`](crate::field::Field::enabled). 
+    pub enabled: bool, 
+    /// Whether the field is only shown under a condition, given with
+    /// [`show if`](crate::dialog::form!#conditional-fields). 
+    pub conditional: bool, 
+    /// The title of the [section](crate::dialog::form!#sections) starting at the field, if any. 
+    pub section: Option<String>, 
+}
+
 pub use form;
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use crate::{prelude::*, field::*, color::ColorMode, context::script, crossterm::event::Event};
+    use crate::dialog::FieldStyles;
+    use ratatui::{style::{Color, Modifier}, text::{Line, Text}};
+    use super::internal::{cap_height, format_field, is_replayable, progress, scroll, ControlSummary};
+    use super::{FieldDescription, FormDescription};
+
+    #[test]
+    fn replayable() {
+        let key = |code: KeyCode, modifiers: KeyModifiers| KeyEvent::new(code, modifiers);
+
+        assert!(is_replayable(&key(KeyCode::Char('a'), KeyModifiers::NONE)));
+        assert!(is_replayable(&key(KeyCode::Char('A'), KeyModifiers::SHIFT)));
+        assert!(is_replayable(&key(KeyCode::Char('1'), KeyModifiers::NONE)));
+
+        // these would otherwise submit, cancel, or modify the form in unexpected ways
+        assert!(!is_replayable(&key(KeyCode::Enter, KeyModifiers::NONE)));
+        assert!(!is_replayable(&key(KeyCode::Esc, KeyModifiers::NONE)));
+        assert!(!is_replayable(&key(KeyCode::Char(' '), KeyModifiers::NONE)));
+        assert!(!is_replayable(&key(KeyCode::Char('w'), KeyModifiers::CONTROL)));
+        assert!(!is_replayable(&key(KeyCode::Up, KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn replay() {
+        // type, submit while the name is too short, and immediately type a fix. the first key of the fix
+        // dismisses the error message and is replayed into the field, so no character is lost
+        let keys = [
+            KeyCode::Char('a'), KeyCode::Char('b'), KeyCode::Enter, 
+            KeyCode::Char('c'), KeyCode::Char('d'), KeyCode::Enter, 
+        ];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let values = dialog::form!{
+            name: Textbox{ name: "Name" } if |name: &String| name.len() < 4 => "Name too short", 
+            [title]: "Register", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        assert_eq!(values.unwrap().name, "abcd");
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn replay_submit() {
+        // the error message is dismissed by the submit key, which is replayed and submits the form again. it
+        // is validated anew, showing the error again rather than the form
+        let keys = [KeyCode::Char('#'), KeyCode::Char('#'), KeyCode::Esc, KeyCode::Esc];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let mut validated = 0;
+        let values = dialog::form!{
+            name: Textbox{ name: "Name" }, 
+            [title]: "Register", 
+            [context]: ctx, 
+            [background]: &(), 
+            [submit]: KeyCode::Char('#'), 
+            [validate]: |_, _| {
+                validated += 1;
+                Err::<(), _>("Registration closed")
+            }, 
+        };
+        assert!(values.is_none());
+        assert_eq!(validated, 2);
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn validate_meta() {
+        // submit, dismiss the error message, then cancel
+        let keys = [KeyCode::Enter, KeyCode::Esc, KeyCode::Esc];
+
+        // composes the error message from the names of the fields, returning the message shown
+        macro_rules! run {
+            ($rent:literal, $deposit:literal) => {{
+                let ctx = &mut Context::headless(80, 24, script(keys));
+                let mut msg = String::new();
+                let values = dialog::form!{
+                    rent: Slider<u32>{ name: $rent, range: 0..=10, step: 1 }, 
+                    deposit: Slider<u32>{ name: $deposit, range: 0..=10, step: 1, value: 5 }, 
+                    [title]: "Register", 
+                    [context]: ctx, 
+                    [background]: &(), 
+                    [validate]: |values, meta| if values.rent <= values.deposit {
+                        assert!(meta.rent.focused && !meta.deposit.focused);
+                        msg = format!("{} must exceed {}", meta.rent.name, meta.deposit.name);
+                        Err(msg.clone())
+                    } else {
+                        Ok(())
+                    }, 
+                };
+                assert!(values.is_none());
+                assert!(ctx.script_finished());
+
+                // the form was drawn last, showing the same names as the message
+                let screen = ctx.screen();
+                assert!(screen.contains($rent) && screen.contains($deposit));
+                msg
+            }};
+        }
+
+        assert_eq!(run!("Monthly rent", "Deposit"), "Monthly rent must exceed Deposit");
+        // renaming a field is reflected in the message
+        assert_eq!(run!("Rent", "Security deposit"), "Rent must exceed Security deposit");
+    }
+
+    #[test]
+    fn field_error() {
+        // clearing the number box makes it invalid, so submitting shows an error. the error is dismissed by
+        // typing a digit, which is replayed into the form and makes it valid again
+        let keys = [KeyCode::Backspace, KeyCode::Enter, KeyCode::Char('8'), KeyCode::Enter];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let values = dialog::form!{
+            port: NumberBox<u16>{ name: "Port" }, 
+            [title]: "Connect", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        assert_eq!(values.map(|values| values.port), Some(8));
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn captured_keys() {
+        // the key capture field takes enter and escape from the form, so it is left with tab before the form
+        // is submitted
+        let keys = [KeyCode::Enter, KeyCode::Esc, KeyCode::Enter, KeyCode::Tab, KeyCode::Tab, KeyCode::Enter];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let values = dialog::form!{
+            key: KeyCapture{ name: "Key" }, 
+            confirm: Checkbox{ name: "Confirm" }, 
+            [title]: "Rebind", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        let values = values.unwrap();
+        assert_eq!(values.key, (KeyCode::Tab, KeyModifiers::NONE));
+        assert!(!values.confirm);
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn paste() {
+        // the pasted text is validated once as a whole, and its line breaks are replaced
+        let validations = Cell::new(0);
+        let events = [Event::Paste("Ada\nLovelace\n".into())]
+            .into_iter()
+            .chain(script([KeyCode::Enter]));
+        let ctx = &mut Context::headless(80, 24, events);
+        let values = dialog::form!{
+            name: Textbox{ name: "Name" } if |_| {
+                validations.set(validations.get() + 1);
+                false
+            } => "", 
+            [title]: "Register", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        assert_eq!(values.unwrap().name, "Ada Lovelace");
+        assert_eq!(validations.get(), 1);
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn paste_number() {
+        // the pasted text is filtered by the number box, just like typed characters are
+        let events = [Event::Paste("1,500".into())]
+            .into_iter()
+            .chain(script([KeyCode::Enter]));
+        let ctx = &mut Context::headless(80, 24, events);
+        let values = dialog::form!{
+            rent: NumberBox<u32>{ name: "Rent" }, 
+            [title]: "Listing", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        assert_eq!(values.unwrap().rent, 1500);
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn disabled_fields() {
+        // the focus starts at the first enabled field and skips disabled fields in both directions. the
+        // control statement of the disabled field would fail, but is never executed
+        let keys = [
+            KeyCode::Char('a'), KeyCode::Down, KeyCode::Char('b'), KeyCode::Down, 
+            KeyCode::Up, KeyCode::Char('c'), KeyCode::Enter, 
+        ];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let values = dialog::form!{
+            id: Textbox{ name: "ID", value: "42", disabled: true } if |_| true => "Not validated", 
+            name: Textbox{ name: "Name" } if str::is_empty => "Name required", 
+            archived: Checkbox{ name: "Archived", disabled: true }, 
+            notes: Textbox{ name: "Notes" }, 
+            [title]: "Edit record", 
+            [context]: ctx, 
+            [background]: &(), 
+            [progress]: true, 
+        };
+        let values = values.unwrap();
+        assert_eq!(values.id, "42");
+        assert_eq!(values.name, "ac");
+        assert!(!values.archived);
+        assert_eq!(values.notes, "b");
+        assert!(ctx.script_finished());
+
+        let screen = ctx.screen();
+        assert!(screen.contains("4/4 fields valid"));
+    }
+
+    #[test]
+    fn display_rows() {
+        // display rows are never focused, so typing goes straight to the only real field
+        let keys = [KeyCode::Char('a'), KeyCode::Up, KeyCode::Char('b'), KeyCode::Enter];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let values = dialog::form!{
+            account: Display{ name: "Account", text: "alice@example.com" }, 
+            plan: Display{ name: "Plan", text: ratatui::style::Stylize::bold("Premium") }, 
+            nickname: Textbox{ name: "Nickname" }, 
+            [title]: "Edit account", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        let values = values.unwrap();
+        assert_eq!(values.nickname, "ab");
+        assert!(ctx.script_finished());
+
+        let screen = ctx.screen();
+        assert!(screen.contains("Account │ alice@example.com"));
+        assert!(screen.contains("   Plan │ Premium"));
+    }
+
+    #[test]
+    fn submit_key() {
+        // enter toggles the focused item rather than submitting the form
+        let ctrl_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+        let events = script([KeyCode::Enter, KeyCode::Down, KeyCode::Down, KeyCode::Enter])
+            .into_iter()
+            .chain([Event::Key(ctrl_s)]);
+        let ctx = &mut Context::headless(80, 24, events);
+        let values = dialog::form!{
+            tags: Toggle{ name: "Tags", items: ["New", "Urgent", "Stale"] }, 
+            [title]: "Tag record", 
+            [context]: ctx, 
+            [background]: &(), 
+            [submit]: ctrl_s, 
+        };
+        let values = values.unwrap();
+        assert_eq!(values.tags.iter_ones().collect::<Vec<_>>(), [0, 2]);
+        assert!(ctx.script_finished());
+
+        // the hint names the submit key
+        let screen = ctx.screen();
+        assert!(screen.contains("Press (ctrl+s) to submit"));
+
+        // enter still presses the selected button
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Down, KeyCode::Right, KeyCode::Enter]));
+        let values = dialog::form!{
+            name: Textbox{ name: "Name" }, 
+            [title]: "Rename", 
+            [context]: ctx, 
+            [background]: &(), 
+            [buttons]: ["Save", "Discard"], 
+            [submit]: KeyCode::F(2), 
+        };
+        assert_eq!(values.unwrap().Button, 1);
+    }
+
+    #[test]
+    fn prebuilt_fields() {
+        // the error message is dismissed by typing, which is replayed into the field
+        let keys = [
+            KeyCode::Backspace, KeyCode::Backspace, KeyCode::Enter, KeyCode::Char('c'), KeyCode::Enter, 
+        ];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let name = Textbox::builder()
+            .name("Name")
+            .value("ab")
+            .build();
+        let values = dialog::form!{
+            name: Textbox = name if String::is_empty => "Name required", 
+            archived: crate::field::Checkbox = (Checkbox::builder().name("Archived").value(true).build()), 
+            slug: Textbox{ name: "Slug", value: built!(name).value().to_uppercase() }, 
+            [title]: "Edit record", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        let values = values.unwrap();
+        assert_eq!(values.name, "c");
+        assert!(values.archived);
+        assert_eq!(values.slug, "AB");
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn quick_jump() {
+        // the disabled field is not numbered, and a jump to a missing field is ignored
+        let alt = |c: char| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT));
+        let events = [alt('3')]
+            .into_iter()
+            .chain(script([KeyCode::Char('x')]))
+            .chain([alt('1')])
+            .chain(script([KeyCode::Char('y')]))
+            .chain([alt('9')])
+            .chain(script([KeyCode::Char('z'), KeyCode::Enter]));
+        let ctx = &mut Context::headless(80, 24, events);
+        let values = dialog::form!{
+            first: Textbox{ name: "First" }, 
+            id: Textbox{ name: "ID", value: "42", disabled: true }, 
+            second: Textbox{ name: "Second" }, 
+            third: Textbox{ name: "Third" }, 
+            [title]: "Quick", 
+            [context]: ctx, 
+            [background]: &(), 
+            [quick_jump]: true, 
+        };
+        let values = values.unwrap();
+        assert_eq!(values.first, "yz");
+        assert_eq!(values.second, "");
+        assert_eq!(values.third, "x");
+        assert!(ctx.script_finished());
+
+        let screen = ctx.screen();
+        assert!(screen.contains("1.  First : yz"));
+        assert!(screen.contains("        ID │ 42"));
+        assert!(screen.contains("2. Second │"));
+        assert!(screen.contains("3.  Third │ x"));
+    }
+
+    #[test]
+    fn quick_jump_capture() {
+        // jumping away from a key capture field leaves capture mode, so that the key pressed after jumping
+        // back moves the focus on rather than being stored
+        let alt = |c: char| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT));
+        let events = script([KeyCode::Enter])
+            .into_iter()
+            .chain([alt('2'), alt('1')])
+            .chain(script([KeyCode::Down, KeyCode::Char('a'), KeyCode::Enter]));
+        let ctx = &mut Context::headless(80, 24, events);
+        let values = dialog::form!{
+            key: KeyCapture{ name: "Key" }, 
+            name: Textbox{ name: "Name" }, 
+            [title]: "Bind", 
+            [context]: ctx, 
+            [background]: &(), 
+            [quick_jump]: true, 
+        };
+        let values = values.unwrap();
+        assert_eq!(values.key, (KeyCode::Null, KeyModifiers::NONE));
+        assert_eq!(values.name, "a");
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn all_disabled() {
+        // the focus has nowhere to go and input is ignored, but the form can still be submitted or cancelled
+        macro_rules! run {
+            ($keys:expr) => {{
+                let ctx = &mut Context::headless(80, 24, script($keys));
+                let values = dialog::form!{
+                    id: Textbox{ name: "ID", value: "42", disabled: true }, 
+                    rating: Rating{ name: "Rating", value: 3, disabled: true }, 
+                    [title]: "View record", 
+                    [context]: ctx, 
+                    [background]: &(), 
+                };
+                assert!(ctx.script_finished());
+                values.map(|values| (values.id, values.rating))
+            }};
+        }
+
+        let keys = [KeyCode::Char('x'), KeyCode::Down, KeyCode::Right, KeyCode::Up, KeyCode::Enter];
+        assert_eq!(run!(keys), Some(("42".to_owned(), 3)));
+        assert_eq!(run!([KeyCode::Down, KeyCode::Esc]), None);
+    }
+
+    #[test]
+    fn buttons() {
+        macro_rules! run {
+            ($keys:expr) => {{
+                let ctx = &mut Context::headless(80, 24, script($keys));
+                let values = dialog::form!{
+                    name: Textbox{ name: "Name" }, 
+                    public: Checkbox{ name: "Public" }, 
+                    [title]: "Share", 
+                    [context]: ctx, 
+                    [background]: &(), 
+                    [buttons]: ["Save", "Cancel", "Apply"], 
+                };
+                assert!(ctx.script_finished());
+                let screen = ctx.screen();
+                assert!(screen.contains("[ Save ]  [ Cancel ]  [ Apply ]"));
+                values.map(|values| (values.name, values.public, values.Button))
+            }};
+        }
+
+        // the row of buttons follows the last field, and the selection is clamped to the buttons
+        let keys = [
+            KeyCode::Char('a'), KeyCode::Down, KeyCode::Down, KeyCode::Down, KeyCode::Right, KeyCode::Right, 
+            KeyCode::Right, KeyCode::Left, KeyCode::Enter, 
+        ];
+        assert_eq!(run!(keys), Some(("a".to_owned(), false, 1)));
+
+        // moving back up to a field and submitting from there activates the first button
+        let keys = [
+            KeyCode::Tab, KeyCode::Down, KeyCode::Right, KeyCode::Up, KeyCode::Char(' '), KeyCode::Enter, 
+        ];
+        assert_eq!(run!(keys), Some(("".to_owned(), true, 0)));
+
+        assert_eq!(run!([KeyCode::Down, KeyCode::Down, KeyCode::Esc]), None);
+    }
+
+    #[test]
+    fn on_change() {
+        // choosing a preset updates the slider, while moving the slider leaves the preset alone
+        let keys = [KeyCode::Right, KeyCode::Down, KeyCode::Left, KeyCode::Enter];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let values = dialog::form!{
+            preset: Radio{ name: "Preset", items: ["Quiet", "Loud"] } on change => |fields| {
+                fields.volume.value = [10, 90][*fields.preset.value()];
+            }, 
+            volume: Slider<u8>{ name: "Volume", range: 0..=100, step: 5, value: 10 }, 
+            [title]: "Sound", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        let values = values.unwrap();
+        assert_eq!((values.preset, values.volume), (1, 85));
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn on_change_revalidates() {
+        // clearing the validated name makes it invalid, so submitting shows an error that is dismissed before
+        // a new name is entered
+        let keys = [
+            KeyCode::Char('a'), KeyCode::Down, KeyCode::Char(' '), KeyCode::Enter, KeyCode::Esc, KeyCode::Up, 
+            KeyCode::Char('b'), KeyCode::Enter, 
+        ];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let values = dialog::form!{
+            name: Textbox{ name: "Name" } if str::is_empty => "Name required", 
+            anonymous: Checkbox{ name: "Anonymous" } on change => |fields| { fields.name.set_value("") }, 
+            [title]: "Register", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        let values = values.unwrap();
+        assert_eq!(values.name, "b");
+        assert!(values.anonymous);
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn control_messages() {
+        const TOO_LONG: &str = "Too long";
+
+        // each message is shown inline once its field is invalid; cancelling leaves the last form drawn
+        let keys = [
+            KeyCode::Char('a'), KeyCode::Backspace, KeyCode::Down, KeyCode::Char('a'), KeyCode::Char('b'), 
+            KeyCode::Down, KeyCode::Char('c'), KeyCode::Esc, 
+        ];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let label = "Name";
+        let reserved = String::from("Reserved");
+        let values = dialog::form!{
+            name: Textbox{ name: label } if str::is_empty => (format!("{label} is required")), 
+            code: Textbox{ name: "Code" } if |code: &String| code.len() > 1 => TOO_LONG, 
+            user: Textbox{ name: "User" } if |user| user == "c" => reserved, 
+            [title]: "Register", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        assert!(values.is_none());
+        let screen = ctx.screen();
+        assert!(screen.contains("✗ Name is required"));
+        assert!(screen.contains("✗ Too long"));
+        assert!(screen.contains("✗ Reserved"));
+
+        // the variable is only borrowed by the form
+        assert_eq!(reserved, "Reserved");
+    }
+
+    #[test]
+    fn inline_errors() {
+        // the message is shown below the body, one line per line of the message
+        let styles = &FieldStyles::default();
+        let text = format_field("Name", Text::from("x"), false, 6, Some("Required\nor else"), true, styles);
+        let lines: Vec<String> = text.lines
+            .iter()
+            .map(Line::to_string)
+            .collect();
+        assert_eq!(lines, ["  Name │ x", "       │ ✗ Required", "       │   or else"]);
+        let text = format_field("Name", Text::from("x"), false, 6, None, true, styles);
+        assert_eq!(text.lines.len(), 1);
+
+        // without colours, invalid fields are marked in place of the delimiter
+        let keys = [KeyCode::Char('a'), KeyCode::Backspace, KeyCode::Esc];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        ctx.set_color_mode(ColorMode::Never);
+        let values = dialog::form!{
+            name: Textbox{ name: "Name" } if str::is_empty => "Name required", 
+            [title]: "Register", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        assert!(values.is_none());
+        let screen = ctx.screen();
+        assert!(screen.contains("Name ! "));
+        let name = ctx.buffer().content
+            .iter()
+            .find(|cell| cell.symbol() == "N")
+            .map(|cell| (cell.fg, cell.modifier.contains(Modifier::UNDERLINED)));
+        assert_eq!(name, Some((Color::Reset, true)));
+
+        // the message appears as soon as the field becomes invalid, before the form is submitted
+        let keys = [KeyCode::Char('a'), KeyCode::Backspace, KeyCode::Esc];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let values = dialog::form!{
+            name: Textbox{ name: "Name" } if str::is_empty => "Name required", 
+            [title]: "Register", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        assert!(values.is_none());
+        let screen = ctx.screen();
+        assert!(screen.contains("✗ Name required"));
+    }
+
+    #[test]
+    fn sections() {
+        // the focus moves straight from the first field to the second, past the header between them
+        let keys = [KeyCode::Char('a'), KeyCode::Down, KeyCode::Char('b'), KeyCode::Esc];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let mut description = FormDescription::default();
+        let values = dialog::form!{
+            #[section = "Server"]
+            host: Textbox{ name: "Host" }, 
+            #[section = String::from("Account")]
+            user: Textbox{ name: "User" }, 
+            [title]: "Connect", 
+            [context]: ctx, 
+            [background]: &(), 
+            [describe]: &mut description, 
+        };
+        assert!(values.is_none());
+        assert_eq!(description.fields.len(), 2);
+
+        let screen = ctx.screen();
+        let row = |text: &str| screen
+            .lines()
+            .position(|line| line.contains(text))
+            .unwrap_or_else(|| panic!("{text:?} is not shown"));
+        // the headers are shown above their fields, separated from the previous field
+        assert_eq!(row("── Server ──") + 1, row("Host │ a"));
+        assert_eq!(row("Host │ a") + 2, row("── Account ──"));
+        assert_eq!(row("── Account ──") + 1, row("User : b"));
+    }
+
+    #[test]
+    fn theme() {
+        use crate::dialog::{DrawInfoDefaults, Theme};
+        use ratatui::style::Color;
+
+        // the metadatum takes precedence over the theme of the context, overriding only what it gives
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        ctx.set_dialog_theme(Theme {
+            form: DrawInfoDefaults {
+                color: Some(Color::Green), 
+                hint: Some("Submit with enter".into()), 
+                ..Default::default()
+            }, 
+            ..Default::default()
+        });
+        let values = dialog::form!{
+            name: Textbox{ name: "Name" }, 
+            [title]: "Register", 
+            [context]: ctx, 
+            [background]: &(), 
+            [theme]: DrawInfoDefaults{ hint: Some("Enter to register".into()), ..Default::default() }, 
+        };
+        assert!(values.is_none());
+        let screen = ctx.screen();
+        assert!(screen.contains("Enter to register"));
+        let corner = ctx.buffer().content
+            .iter()
+            .find(|cell| cell.symbol() == "┏")
+            .map(|cell| cell.fg);
+        assert_eq!(corner, Some(Color::Green));
+    }
+
+    #[test]
+    fn width() {
+        // the width of the dialog box, including the margin and border on either side
+        macro_rules! run {
+            ($($meta_id:ident: $meta_expr:expr),*) => {{
+                let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+                dialog::form!{
+                    name: Textbox{ name: "Name" }, 
+                    [title]: "Register", 
+                    [context]: ctx, 
+                    [background]: &(), 
+                    $([$meta_id]: $meta_expr,)*
+                };
+                let screen = ctx.screen();
+                let top = screen
+                    .lines()
+                    .find(|row| row.contains('┏'))
+                    .unwrap();
+                top.trim().chars().count()
+            }};
+        }
+        assert_eq!(run!(), 40 + 8);
+        assert_eq!(run!(width: 75), 60 + 8);
+        assert_eq!(run!(width: 75, width_cols: 30), 30 + 8);
+        // too wide to fit, so the percentage is used
+        assert_eq!(run!(width: 75, width_cols: 73), 60 + 8);
+    }
+
+    #[test]
+    fn anchor() {
+        use crate::dialog::{Anchor, DrawInfoDefaults, Theme};
+
+        // the row of the top border of the dialog box
+        let top = |ctx: &Context| ctx.buffer().content
+            .chunks(80)
+            .position(|row| row.iter().any(|cell| cell.symbol() == "┏"))
+            .unwrap();
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc, KeyCode::Esc]));
+        dialog::form!{
+            name: Textbox{ name: "Name" }, 
+            [title]: "Register", 
+            [context]: ctx, 
+            [background]: &(), 
+            [anchor]: Anchor::Top, 
+        };
+        assert_eq!(top(ctx), 0);
+
+        // the metadatum takes precedence over the theme, including its offset
+        ctx.set_dialog_theme(Theme {
+            form: DrawInfoDefaults {
+                anchor: Some(Anchor::Bottom), 
+                offset: Some([0, 2]), 
+                ..Default::default()
+            }, 
+            ..Default::default()
+        });
+        dialog::form!{
+            name: Textbox{ name: "Name" }, 
+            [title]: "Register", 
+            [context]: ctx, 
+            [background]: &(), 
+            [anchor]: Anchor::Top, 
+        };
+        assert_eq!(top(ctx), 2);
+    }
+
+    #[test]
+    fn conditional_fields() {
+        macro_rules! run {
+            ($keys:expr) => {{
+                let ctx = &mut Context::headless(80, 24, script($keys));
+                let values = dialog::form!{
+                    auth: Checkbox{ name: "Authentication" }, 
+                    password: Textbox{ name: "Authentication password" } if str::is_empty => "Password required"
+                        show if |values| *values.auth, 
+                    port: Textbox{ name: "Port" }, 
+                    [title]: "Connect", 
+                    [context]: ctx, 
+                    [background]: &(), 
+                };
+                assert!(ctx.script_finished());
+                let screen = ctx.screen();
+                values.map(|values| (values.auth, values.password, values.port, screen))
+            }};
+        }
+
+        // the hidden password is skipped by the focus, left out of the alignment, and not validated
+        let keys = [KeyCode::Down, KeyCode::Char('1'), KeyCode::Enter];
+        let (auth, password, port, screen) = run!(keys).unwrap();
+        assert_eq!((auth, password.as_str(), port.as_str()), (false, "", "1"));
+        assert!(!screen.contains("password"));
+        assert!(screen.contains("Authentication │ "));
+        assert!(screen.contains("          Port : 1"));
+
+        // once shown, the password is focusable and validated
+        let keys = [
+            KeyCode::Char(' '), KeyCode::Down, KeyCode::Down, KeyCode::Enter, KeyCode::Esc, KeyCode::Up, 
+            KeyCode::Char('p'), KeyCode::Enter, 
+        ];
+        let (auth, password, port, screen) = run!(keys).unwrap();
+        assert_eq!((auth, password.as_str(), port.as_str()), (true, "p", ""));
+        assert!(screen.contains("Authentication password : p"));
+    }
+
+    #[test]
+    fn hidden_focus() {
+        // hiding the focused field moves the focus to the first shown field, and hiding all fields shows the
+        // first one regardless
+        let keys = [KeyCode::Down, KeyCode::Char('a'), KeyCode::Char('b'), KeyCode::Char('c'), KeyCode::Enter];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let values = dialog::form!{
+            first: Textbox{ name: "First" } show if |values| values.second.len() < 2, 
+            second: Textbox{ name: "Second" } show if |values| values.second.len() < 2, 
+            [title]: "Hide", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        let values = values.unwrap();
+        assert_eq!((values.first.as_str(), values.second.as_str()), ("c", "ab"));
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn apply() {
+        use bitvec::prelude::{bitbox, BitBox, Lsb0};
+
+        struct Record {
+            name: String, 
+            role: usize, 
+            tags: BitBox, 
+        }
+
+        // the fields are populated from the record, which is only written back to if the form is submitted
+        macro_rules! run {
+            ($record:expr, $keys:expr) => {{
+                let record: &mut Record = $record;
+                let ctx = &mut Context::headless(80, 24, script($keys));
+                dialog::form!{
+                    name: Textbox{ name: "Name", value: &record.name }, 
+                    role: Radio{ name: "Role", items: ["Guest", "Member"], value: record.role }, 
+                    tags: Toggle{ name: "Tags", items: ["New", "Urgent"], value: &record.tags }, 
+                    [title]: "Edit", 
+                    [context]: ctx, 
+                    [background]: &(), 
+                    [apply]: |values| {
+                        record.name.clone_from(&values.name);
+                        record.role = values.role;
+                        record.tags.clone_from(&values.tags);
+                    }, 
+                };
+                assert!(ctx.script_finished());
+            }};
+        }
+
+        let record = &mut Record{ name: "Ada".into(), role: 1, tags: bitbox![0, 1] };
+        run!(record, [KeyCode::Char('m'), KeyCode::Down, KeyCode::Left, KeyCode::Esc]);
+        assert_eq!((record.name.as_str(), record.role), ("Ada", 1));
+
+        let keys = [
+            KeyCode::Char('m'), KeyCode::Down, KeyCode::Left, KeyCode::Down, KeyCode::Char(' '), 
+            KeyCode::Enter, 
+        ];
+        run!(record, keys);
+        assert_eq!((record.name.as_str(), record.role), ("Adam", 0));
+        assert_eq!(record.tags, bitbox![1, 1]);
+    }
+
+    #[test]
+    fn clipped_field() {
+        // the focused textbox is scrolled to show the caret at the end of its value rather than being wrapped
+        let ctx = &mut Context::headless(60, 14, script([KeyCode::Esc]));
+        let values = dialog::form!{
+            path: Textbox{ name: "Path", value: format!("/{}/end", "long".repeat(20)) }, 
+            [title]: "Open", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        assert!(values.is_none());
+        let screen = ctx.screen();
+        assert!(screen.contains("Path : …glonglonglonglong/end "));
+    }
+
+    #[test]
+    fn scroll_window() {
+        let body = || (0..10).map(|i| Line::from(i.to_string())).collect::<Vec<_>>();
+        let view = |focused, offset: &Cell<usize>| scroll(body(), 5, focused, offset)
+            .into_iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>();
+        let offset = &Cell::new(0);
+
+        // the view only moves once the focused lines would leave it
+        assert_eq!(view(0..1, offset), ["0", "1", "2", "3", "▼"]);
+        assert_eq!(view(3..4, offset), ["0", "1", "2", "3", "▼"]);
+        assert_eq!(view(4..6, offset), ["▲", "3", "4", "5", "▼"]);
+        assert_eq!(view(3..4, offset), ["▲", "3", "4", "5", "▼"]);
+        assert_eq!(view(2..3, offset), ["▲", "2", "3", "4", "▼"]);
+        assert_eq!(view(9..10, offset), ["▲", "6", "7", "8", "9"]);
+        assert_eq!(offset.get(), 6);
+
+        // a body that fits is left as is
+        let fits = scroll(body(), 10, 9..10, offset);
+        assert_eq!(fits.len(), 10);
+        assert_eq!(offset.get(), 0);
+    }
+
+    #[test]
+    fn scroll_form() {
+        let screen = |keys: Vec<KeyCode>| {
+            let ctx = &mut Context::headless(60, 14, script(keys.into_iter().chain([KeyCode::Esc])));
+            let values = dialog::form!{
+                a: Checkbox{ name: "Field a" }, 
+                b: Checkbox{ name: "Field b" }, 
+                c: Checkbox{ name: "Field c" }, 
+                d: Checkbox{ name: "Field d" }, 
+                e: Checkbox{ name: "Field e" }, 
+                f: Checkbox{ name: "Field f" }, 
+                g: Checkbox{ name: "Field g" }, 
+                h: Checkbox{ name: "Field h" }, 
+                i: Checkbox{ name: "Field i" }, 
+                j: Checkbox{ name: "Field j" }, 
+                [title]: "Register", 
+                [context]: ctx, 
+                [background]: &(), 
+            };
+            assert!(values.is_none());
+            ctx.screen()
+        };
+
+        // the body is clipped to keep the hint on screen
+        let top = screen(vec![]);
+        assert!(top.contains("Field a") && !top.contains("Field j"));
+        assert!(top.contains('▼') && !top.contains('▲'));
+        assert!(top.contains("Press (enter) to submit"));
+
+        // moving the focus down scrolls the last field into view
+        let bottom = screen(vec![KeyCode::Down; 9]);
+        assert!(!bottom.contains("Field a") && bottom.contains("Field j"));
+        assert!(bottom.contains('▲') && !bottom.contains('▼'));
+        assert!(bottom.contains("Press (enter) to submit"));
+    }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    fn derive() {
+        use crate::dialog::Form;
+
+        #[derive(Clone, Debug, PartialEq, Form)]
+        struct Listing {
+            #[field(name = "Location")]
+            address: String, 
+            #[field(slider, range = "1..=5000", step = 50, prefix = "$")]
+            monthly_rent: u32, 
+            pets_allowed: bool, 
+            #[field(range = "1..=10")]
+            bedrooms: u8, 
+            #[field(skip)]
+            id: u64, 
+        }
+
+        let listing = Listing {
+            address: "Longyearbyen".to_owned(), 
+            monthly_rent: 1500, 
+            pets_allowed: false, 
+            bedrooms: 3, 
+            id: 7, 
+        };
+        let edit = |keys: Vec<KeyCode>| {
+            let ctx = &mut Context::headless(80, 24, script(keys));
+            let edited = listing.edit("Edit listing", &(), ctx);
+            assert!(ctx.script_finished());
+            let screen = ctx.screen();
+            (edited, screen)
+        };
+
+        // the form is pre-populated, with names given or derived from the identifiers
+        let (edited, screen) = edit(vec![KeyCode::Esc]);
+        assert_eq!(edited, None);
+        assert!(screen.contains("Location : Longyearbyen"));
+        assert!(screen.contains("Monthly rent │ <$1500>"));
+        assert!(screen.contains("Pets allowed │"));
+        assert!(screen.contains("Bedrooms │ <3>"));
+        assert!(!screen.contains("Id"));
+
+        // edit every field, then submit
+        let keys = [
+            KeyCode::Char('!'), KeyCode::Down, KeyCode::Right, KeyCode::Down, KeyCode::Char(' '), 
+            KeyCode::Down, KeyCode::Left, 
+        ];
+        let (edited, _) = edit(keys.into_iter().chain([KeyCode::Enter]).collect());
+        assert_eq!(edited, Some(Listing {
+            address: "Longyearbyen!".to_owned(), 
+            monthly_rent: 1550, 
+            pets_allowed: true, 
+            bedrooms: 2, 
+            id: 7, 
+        }));
+    }
+
+    #[test]
+    fn progress_count() {
+        use ControlSummary::*;
+
+        assert_eq!(progress(&[Uncontrolled, Ok, Err, Unknown]), Some((2, 4)));
+        assert_eq!(progress(&[Ok, Ok]), Some((2, 2)));
+        // nothing is shown without controlled fields
+        assert_eq!(progress(&[Uncontrolled, Uncontrolled]), None);
+        assert_eq!(progress(&[]), None);
+    }
+
+    #[test]
+    fn progress_footer() {
+        let type_key = |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        let footer = |events: Vec<Event>| {
+            let ctx = &mut Context::headless(80, 24, events.into_iter().chain(script([KeyCode::Esc])));
+            let values = dialog::form!{
+                location: Textbox{ name: "Location" } if str::is_empty => "Required", 
+                pets_allowed: Checkbox{ name: "Pets allowed" }, 
+                [title]: "Register", 
+                [context]: ctx, 
+                [background]: &(), 
+                [progress]: true, 
+            };
+            assert!(values.is_none());
+            let screen = ctx.screen();
+            ["0/2", "1/2", "2/2"].into_iter().find(|count| screen.contains(&format!("{count} fields valid")))
+        };
+        // the textbox counts as invalid until it has been validated
+        assert_eq!(footer(vec![]), Some("1/2"));
+        // the count updates in the same frame as the input
+        assert_eq!(footer(vec![type_key('a')]), Some("2/2"));
+        assert_eq!(footer(vec![type_key('a'), Event::Key(KeyCode::Backspace.into())]), Some("1/2"));
+    }
+
+    #[test]
+    fn height_cap() {
+        let text = |len: usize| -> Text {
+            (0..len)
+                .map(|i| Line::from(i.to_string()))
+                .collect::<Vec<_>>()
+                .into()
+        };
+        let lines = |text: Text| -> Vec<String> {
+            text.lines
+                .iter()
+                .map(Line::to_string)
+                .collect()
+        };
+
+        assert_eq!(lines(cap_height(text(3), 3, None)), ["0", "1", "2"]);
+        assert_eq!(lines(cap_height(text(5), 3, None)), ["0", "1", "… (+3 more)"]);
+
+        // the window follows the focused line, and stays within bounds
+        assert_eq!(lines(cap_height(text(10), 3, Some(0))), ["0", "1", "2"]);
+        assert_eq!(lines(cap_height(text(10), 3, Some(5))), ["4", "5", "6"]);
+        assert_eq!(lines(cap_height(text(10), 3, Some(9))), ["7", "8", "9"]);
+    }
+
+    #[test]
+    fn capped_toggle() {
+        let screen = |keys: Vec<KeyCode>| {
+            let ctx = &mut Context::headless(80, 40, script(keys.into_iter().chain([KeyCode::Esc])));
+            let values = dialog::form!{
+                first: Toggle{ name: "First", items: (0..100).map(|i| format!("item {i}")) }, 
+                second: Toggle{ name: "Second", items: (0..100).map(|i| format!("item {i}")) }, 
+                [title]: "Capped", 
+                [context]: ctx, 
+                [background]: &(), 
+                [max_field_height]: 8, 
+            };
+            assert!(values.is_none());
+            ctx.screen()
+                .lines()
+                .map(str::trim)
+                .filter(|row| !row.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        // the unfocused field is truncated with a trailer
+        let initial = screen(vec![]);
+        assert!(initial.contains("… (+93 more)"));
+        assert!(initial.contains("item 7") && !initial.contains("item 8"));
+
+        // the focused field follows its internal focus
+        let scrolled = screen(vec![KeyCode::Down; 50]);
+        assert!(scrolled.contains("< > item 50"));
+        assert!(scrolled.contains("item 46") && !scrolled.contains("item 45"));
+        assert!(scrolled.contains("item 53") && !scrolled.contains("item 54"));
+    }
+
+    #[test]
+    fn capped_descriptions() {
+        let keys = [KeyCode::Down; 10].into_iter().chain([KeyCode::Esc]);
+        let ctx = &mut Context::headless(80, 40, script(keys));
+        let values = dialog::form!{
+            extras: Toggle {
+                name: "Extras", 
+                items_with_descriptions: (0..20).map(|i| (format!("item {i}"), format!("about {i}"))), 
+            }, 
+            [title]: "Capped", 
+            [context]: ctx, 
+            [background]: &(), 
+            [max_field_height]: 6, 
+        };
+        assert!(values.is_none());
+        let screen = ctx.screen();
+        let rows: Vec<&str> = screen
+            .lines()
+            .map(|row| row.trim().trim_matches('┃').trim())
+            .filter(|row| !row.is_empty())
+            .collect();
+
+        // the window is centred on the focused item, counting description lines, which are indented
+        let focused = rows
+            .iter()
+            .position(|row| row.ends_with("< > item 10"))
+            .unwrap();
+        assert_eq!(rows[focused + 1], "│     about 10");
+        assert!(rows.iter().any(|row| row.ends_with("about 8")));
+        assert!(!rows.iter().any(|row| row.ends_with("item 8")));
+    }
+
+    /// Out-of-range indices from runtime data are clamped in release builds, and caught in debug builds. 
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "out of range for 3 items"))]
+    fn radio_selected_out_of_range() {
+        let stored_index = "5".parse::<usize>().unwrap();
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Enter]));
+        let values = dialog::form!{
+            kind: Radio{ name: "Kind", items: ["Flat", "House", "Cabin"], selected: stored_index }, 
+            [title]: "Edit Unit", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        assert_eq!(values.map(|values| values.kind), Some(2));
+    }
+
+    #[test]
+    fn built() {
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Enter]));
+        let values = dialog::form!{
+            title: Textbox{ name: "Title", value: "Polar Station" }, 
+            slug: Textbox{ name: "Slug", value: built!(title).value().to_lowercase().replace(' ', "-") }, 
+            // the derivation sees the clamped value
+            rooms: Slider<u8>{ name: "Rooms", range: 1..=4, value: 9 }, 
+            beds: Slider<u8>{ name: "Beds", range: 1..=20, value: built!(rooms).value() * 2 }, 
+            [title]: "Edit Unit", 
+            [context]: ctx, 
+            [background]: &(), 
+        }.unwrap();
+        assert_eq!(values.slug, "polar-station");
+        assert_eq!(values.rooms, 4);
+        assert_eq!(values.beds, 8);
+    }
+
+    #[test]
+    fn describe() {
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        let mut description = FormDescription::default();
+        let values = dialog::form!{
+            id: Textbox{ name: "ID", value: "U-42", disabled: true }, 
+            #[section = "Details"]
+            location: Textbox{ name: String::from("Loc") + "ation" } if str::is_empty => "Required", 
+            rent: Slider<u32>{ name: "Rent", range: 1..=5000 } show if |values| !values.location.is_empty(), 
+            [title]: "Register", 
+            [message]: "Enter the details of the unit", 
+            [context]: ctx, 
+            [background]: &(), 
+            [describe]: &mut description, 
+        };
+        assert!(values.is_none());
+
+        assert_eq!(description.title, "Register");
+        assert_eq!(description.message, "Enter the details of the unit");
+        assert_eq!(description.fields, [
+            FieldDescription {
+                identifier: "id", 
+                name: "ID".into(), 
+                type_name: std::any::type_name::<Textbox>(), 
+                has_validation: false, 
+                enabled: false, 
+                conditional: false, 
+                section: None, 
+            }, 
+            FieldDescription {
+                identifier: "location", 
+                // the name as built, not as written in the source
+                name: "Location".into(), 
+                type_name: std::any::type_name::<Textbox>(), 
+                has_validation: true, 
+                enabled: true, 
+                conditional: false, 
+                section: Some("Details".into()), 
+            }, 
+            FieldDescription {
+                identifier: "rent", 
+                name: "Rent".into(), 
+                type_name: std::any::type_name::<Slider<u32>>(), 
+                has_validation: false, 
+                enabled: true, 
+                conditional: true, 
+                section: None, 
+            }, 
+        ]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn describe_serialize() {
+        let description = FormDescription {
+            title: "Register".into(), 
+            message: "".into(), 
+            fields: vec![FieldDescription {
+                identifier: "location", 
+                name: "Location".into(), 
+                type_name: "Textbox", 
+                has_validation: true, 
+                enabled: true, 
+                conditional: false, 
+                section: Some("Details".into()), 
+            }], 
+        };
+        let json = serde_json::to_value(&description).unwrap();
+        assert_eq!(json, serde_json::json!({
+            "title": "Register", 
+            "message": "", 
+            "fields": [{
+                "identifier": "location", 
+                "name": "Location", 
+                "type_name": "Textbox", 
+                "has_validation": true, 
+                "enabled": true, 
+                "conditional": false, 
+                "section": "Details", 
+            }], 
+        }));
+    }
+}
@@ -0,0 +1,482 @@
+use std::{any::Any, borrow::Cow, cell::Cell, mem};
+use ratatui::{layout::Rect, text::Text};
+use crate::{
+    prelude::*, 
+    field::{any::AnyField, InputResult}, 
+    dialog::{Dialog, DrawInfo, DrawInfoDefaults, FieldStyles}, 
+};
+use super::internal::{self, ControlState};
+
+/// Constructs a form at runtime, for when the set of fields is not known at compile time. 
+/// 
+/// The [form macro](crate::dialog::form!) should be preferred whenever possible, since it gives typed access
+/// to the entered values. Here, fields are instead added as [`Box<dyn AnyField>`](AnyField), and the entered
+/// values are retrieved by identifier from the returned [`FormValues`]. The form is drawn exactly like one
+/// created with the macro, and supports the same focus navigation and
+/// [field validation](crate::dialog::form!#field-validation) through [`FormBuilder::control`]. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, field::*, dialog::FormBuilder};
+/// 
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// 
+/// let values = FormBuilder::new("Register Rent Unit")
+///     .field("location", Box::new(Textbox::builder().name("Location").build()))
+///     .control(String::is_empty, "Value required")
+///     .field("pets_allowed", Box::new(Checkbox::builder().name("Pets allowed").build()))
+///     .run_over(current_state, ctx);
+/// 
+/// if let Some(values) = values {
+///     let location: &String = values.get("location").unwrap();
+///     let pets_allowed: bool = *values.get("pets_allowed").unwrap();
+/// }
+/// ```
+pub struct FormBuilder<'a> {
+    title: Cow<'a, str>, 
+    message: Cow<'a, str>, 
+    submit: KeyEvent, 
+    entries: Vec<Entry<'a>>, 
+}
+
+/// A control statement of a field in a [`FormBuilder`]: a predicate over the value of the field that is
+/// `true` on error, and the error message. 
+type Control<'a> = (Box<dyn Fn(&dyn Any) -> bool + 'a>, Cow<'a, str>);
+
+/// A field of a [`FormBuilder`], along with its identifier and control statements. 
+struct Entry<'a> {
+    identifier: String, 
+    field: Box<dyn AnyField>, 
+    controls: Vec<Control<'a>>, 
+    state: ControlState<'a>, 
+}
+
+impl<'a> FormBuilder<'a> {
+    /// Creates a form with given user-visible title and no fields. 
+    pub fn new(title: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            title: title.into(), 
+            message: Cow::default(), 
+            submit: KeyCode::Enter.into(), 
+            entries: Vec::new(), 
+        }
+    }
+
+    /// User-visible string of text displayed above the fields. Default: none. 
+    pub fn message(self, message: impl Into<Cow<'a, str>>) -> Self {
+        let message = message.into();
+        Self{ message, ..self }
+    }
+
+    /// The key that submits the form, corresponding to the `submit` metadatum of the
+    /// [form macro](crate::dialog::form!#metadata). Unless it is `Enter`, `Enter` is passed to the focused
+    /// field like any other key. Default: `Enter`. 
+    pub fn submit(self, key: impl Into<KeyEvent>) -> Self {
+        let submit = key.into();
+        Self{ submit, ..self }
+    }
+
+    /// Adds a field below those previously added. Its value is retrieved from [`FormValues`] by
+    /// `identifier`. 
+    pub fn field(mut self, identifier: impl Into<String>, field: Box<dyn AnyField>) -> Self {
+        self.entries.push(Entry {
+            identifier: identifier.into(), 
+            field, 
+            controls: Vec::new(), 
+            state: ControlState::Unknown, 
+        });
+        self
+    }
+
+    /// Adds a control statement to the field that was added last, corresponding to
+    /// `if PREDICATE => MESSAGE` in the [form macro](crate::dialog::form!#field-validation); the field is
+    /// invalid with given error message if `predicate` returns `true` for its value. Several control
+    /// statements are given by calling this repeatedly. 
+    /// 
+    /// 
+    /// # Panics
+    /// 
+    /// If no field has been added, or if the value of the last field is not of type `T`. 
+    pub fn control<T: Any>(mut self, predicate: impl Fn(&T) -> bool + 'a, message: impl Into<Cow<'a, str>>)
+        -> Self
+    {
+        let entry = self.entries
+            .last_mut()
+            .expect("A field is added before its control statements");
+        assert!(
+            entry.field.value_any().is::<T>(), 
+            "The value of field `{}` is not of type `{}`", 
+            entry.identifier, 
+            std::any::type_name::<T>(), 
+        );
+        let predicate = move |value: &dyn Any| value
+            .downcast_ref()
+            .is_some_and(&predicate);
+        entry.controls.push((Box::new(predicate), message.into()));
+        self
+    }
+
+    /// Displays the form over given background state. 
+    /// 
+    /// 
+    /// # Returns
+    /// 
+    /// - `Some` with the entered values if the user submitted the form and all fields are valid. 
+    /// - `None` if the user cancelled the form. 
+    pub fn run_over<G>(self, over: &impl State, ctx: &mut Context<G>) -> Option<FormValues> {
+        let mut form = Form {
+            title: self.title, 
+            message: self.message, 
+            submit: self.submit, 
+            entries: self.entries, 
+            focus: 0, 
+            scroll: Cell::new(0), 
+            theme: ctx.dialog_theme().resolve().form, 
+            fields: ctx.dialog_theme().fields, 
+        };
+        form.focus = internal::first_focus(&form.enabled(), false);
+
+        // whether the form was submitted by a key replayed into it, and should be validated right away
+        let mut submitted = false;
+        loop {
+            // run form dialog unless it was just submitted; if the user cancels, exit immediately
+            if !mem::take(&mut submitted) {
+                let Some(out) = form.run_over(over, &mut ctx.chain_without_global()) else {
+                    break None
+                };
+                form = out;
+            }
+
+            // perform field validation
+            for entry in &mut form.entries {
+                entry.validate();
+            }
+            let results: Vec<_> = form.entries
+                .iter()
+                .map(|entry| (entry.field.name(), entry.result()))
+                .collect();
+            // if validation fails, show error message and continue. otherwise, return values
+            let key = match internal::format_control_error(&results) {
+                Ok(()) => break Some(form.into_values()), 
+                Err(e) => internal::error(&e, over, ctx), 
+            };
+            // if an exit was requested while the error message was shown, the form is cancelled
+            if ctx.exit_requested() {
+                break None
+            }
+            // if the error message was dismissed by the user starting to type, replay the key into the form
+            // so the keystroke isn't lost
+            if internal::is_replayable(&key) {
+                form = match Dialog::input(form, key, &mut ctx.chain_without_global()) {
+                    Signal::Continue(form) => form, 
+                    Signal::Return(Some(form)) => {
+                        submitted = true;
+                        form
+                    }
+                    Signal::Return(None) => break None, 
+                };
+            }
+        }
+    }
+}
+
+impl Entry<'_> {
+    /// Validates the field by updating [`Entry::state`]. Errors reported by the field itself take precedence
+    /// over the control statements. 
+    fn update(&mut self) {
+        let value = self.field.value_any();
+        let error = self.field
+            .error()
+            .or_else(|| self.controls
+                .iter()
+                .find(|(predicate, _)| predicate(value))
+                .map(|(_, message)| message.clone())
+            );
+        self.state = match error {
+            Some(err) => ControlState::Err(err), 
+            None => ControlState::Ok, 
+        };
+    }
+
+    /// Makes sure that the field has been validated. 
+    fn validate(&mut self) {
+        if let ControlState::Unknown = self.state {
+            self.update();
+        }
+    }
+
+    /// The last known result of validating the field. Disabled fields always pass. 
+    fn result(&self) -> Result<(), &str> {
+        match (self.field.enabled(), &self.state) {
+            (true, ControlState::Err(e)) => Err(e), 
+            _ => Ok(()), 
+        }
+    }
+
+    /// Delegates to [`AnyField::input`] and validates the field if it was updated. Disabled fields ignore all
+    /// input. 
+    fn input(&mut self, key: KeyEvent) -> InputResult {
+        if !self.field.enabled() {
+            return InputResult::Ignored
+        }
+        let result = self.field.input(key);
+        if let InputResult::Updated = result {
+            self.update();
+        }
+        result
+    }
+
+    /// Delegates to [`AnyField::paste`] and validates the field if it was updated. Disabled fields ignore all
+    /// pastes. 
+    fn paste(&mut self, text: &str) -> InputResult {
+        if !self.field.enabled() {
+            return InputResult::Ignored
+        }
+        let result = self.field.paste(text);
+        if let InputResult::Updated = result {
+            self.update();
+        }
+        result
+    }
+}
+
+/// The dialog shown by [`FormBuilder::run_over`]. 
+struct Form<'a> {
+    title: Cow<'a, str>, 
+    message: Cow<'a, str>, 
+    submit: KeyEvent, 
+    entries: Vec<Entry<'a>>, 
+    focus: usize, 
+    scroll: Cell<usize>, 
+    theme: DrawInfoDefaults, 
+    fields: FieldStyles, 
+}
+
+impl Form<'_> {
+    /// Whether each field is enabled, indexed like `focus`. 
+    fn enabled(&self) -> Vec<bool> {
+        self.entries
+            .iter()
+            .map(|entry| entry.field.enabled())
+            .collect()
+    }
+
+    fn format_in_area(&self, area: Option<Rect>) -> DrawInfo<'_> {
+        let max_name = self.entries
+            .iter()
+            .map(|entry| entry.field.name().len())
+            .max()
+            .unwrap_or(0);
+        let width = area.map(|area| internal::field_width(area, max_name, &self.theme));
+        let mut fields: Vec<Text> = self.entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let enabled = entry.field.enabled();
+                let focus = i == self.focus && enabled;
+                let body = match width {
+                    Some(width) => entry.field.format_width(focus, width), 
+                    None => entry.field.format(focus), 
+                };
+                let error = entry.state.error();
+                let name = entry.field.name();
+                internal::format_field(name, body, focus, max_name, error, enabled, &self.fields)
+            })
+            .collect();
+        let scroll = area.map(|area| (area, self.focus, &self.scroll));
+        let (message, title, submit) = (&self.message, &self.title, self.submit);
+        let theme = &self.theme;
+        internal::format_dialog(&mut fields, &[], message, title, theme, submit, None, (&[], None), scroll)
+    }
+
+    fn into_values(self) -> FormValues {
+        let values = self.entries
+            .into_iter()
+            .map(|entry| (entry.identifier, entry.field.into_value_any()))
+            .collect();
+        FormValues(values)
+    }
+}
+
+impl Dialog for Form<'_> {
+    type Out = Option<Self>;
+    type Global = ();
+
+    fn format(&self) -> DrawInfo<'_> {
+        self.format_in_area(None)
+    }
+
+    fn format_in(&self, area: Rect) -> DrawInfo<'_> {
+        self.format_in_area(Some(area))
+    }
+
+    fn input(mut self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        // the focus skips over disabled fields
+        let enabled = self.enabled();
+        let focus_up = internal::move_focus(&enabled, false, self.focus, false);
+        let focus_down = internal::move_focus(&enabled, false, self.focus, true);
+
+        // a form without fields can still be submitted or cancelled
+        let Some(entry) = self.entries.get_mut(self.focus) else {
+            return match key.code {
+                _ if internal::is_submit(&key, &self.submit) => Signal::Return(Some(self)), 
+                KeyCode::Esc => Signal::Return(None), 
+                _ => Signal::Continue(self), 
+            }
+        };
+        if entry.field.enabled() && entry.field.captures(&key) {
+            entry.input(key);
+            return Signal::Continue(self)
+        }
+        if internal::is_submit(&key, &self.submit) {
+            return Signal::Return(Some(self))
+        }
+        match key.code {
+            KeyCode::Esc => Signal::Return(None), 
+            _ => {
+                let focus = match (entry.input(key), key.code) {
+                    (InputResult::Ignored, KeyCode::Up | KeyCode::BackTab) => focus_up, 
+                    (InputResult::Ignored, KeyCode::Down | KeyCode::Tab) => focus_down, 
+                    _ => self.focus, 
+                };
+                if focus != self.focus {
+                    entry.field.focus_lost();
+                    self.focus = focus;
+                }
+                Signal::Continue(self)
+            }
+        }
+    }
+
+    fn paste(mut self, text: &str) -> Signal<Self> {
+        if let Some(entry) = self.entries.get_mut(self.focus) {
+            entry.paste(text);
+        }
+        Signal::Continue(self)
+    }
+
+    fn exit(self) -> Signal<Self> {
+        Signal::Return(None)
+    }
+}
+
+/// The values entered into a form built with [`FormBuilder`], in the order the fields were added. 
+/// 
+/// Values are retrieved by the identifier given in [`FormBuilder::field`], along with the value type of the
+/// field, i.e. [`Field::Value`](crate::field::Field::Value). 
+#[derive(Debug)]
+pub struct FormValues(Vec<(String, Box<dyn Any>)>);
+
+impl FormValues {
+    /// Borrows the value of the field with given identifier. Returns `None` if there is no such field, or if
+    /// its value is not of type `T`. 
+    pub fn get<T: Any>(&self, identifier: &str) -> Option<&T> {
+        self.0
+            .iter()
+            .find(|(id, _)| id == identifier)
+            .and_then(|(_, value)| value.downcast_ref())
+    }
+
+    /// Removes and returns the value of the field with given identifier. Returns `None`, leaving the values
+    /// untouched, if there is no such field, or if its value is not of type `T`. 
+    pub fn remove<T: Any>(&mut self, identifier: &str) -> Option<T> {
+        let index = self.0
+            .iter()
+            .position(|(id, value)| id == identifier && value.is::<T>())?;
+        let (_, value) = self.0.remove(index);
+        value
+            .downcast()
+            .ok()
+            .map(|value| *value)
+    }
+
+    /// The identifiers and values of all fields, in the order the fields were added. 
+    pub fn into_vec(self) -> Vec<(String, Box<dyn Any>)> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, field::*, context::script};
+    use super::FormBuilder;
+
+    #[test]
+    fn values() {
+        // edit the name, skip the disabled field, toggle the checkbox, and submit
+        let keys = [KeyCode::Char('x'), KeyCode::Down, KeyCode::Char(' '), KeyCode::Enter];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let values = FormBuilder::new("Register")
+            .message("Enter details")
+            .field("name", Box::new(Textbox::builder().name("Name").value("a").build()))
+            .field("rent", Box::new(Slider::<u32>::builder().name("Rent").range(0..=9).disabled(true).build()))
+            .field("pets", Box::new(Checkbox::builder().name("Pets").build()))
+            .run_over(&(), ctx);
+        assert!(ctx.script_finished());
+
+        let mut values = values.unwrap();
+        assert_eq!(values.get::<String>("name").map(String::as_str), Some("ax"));
+        assert_eq!(values.get::<bool>("pets"), Some(&true));
+        assert_eq!(values.get::<u32>("rent"), Some(&0));
+        // wrong types and unknown identifiers are not found
+        assert_eq!(values.get::<bool>("name"), None);
+        assert_eq!(values.get::<bool>("owner"), None);
+
+        assert_eq!(values.remove::<bool>("pets"), Some(true));
+        assert_eq!(values.remove::<bool>("pets"), None);
+        let identifiers: Vec<String> = values
+            .into_vec()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(identifiers, ["name", "rent"]);
+    }
+
+    #[test]
+    fn control() {
+        // submit the invalid form, dismiss the error by typing, which is replayed into the field, and submit
+        let keys = [KeyCode::Enter, KeyCode::Char('a'), KeyCode::Enter];
+        let ctx = &mut Context::headless(80, 24, script(keys));
+        let values = FormBuilder::new("Register")
+            .field("name", Box::new(Textbox::builder().name("Name").build()))
+            .control(String::is_empty, "Value required")
+            .control(|name: &String| name.len() > 3, "Too long")
+            .run_over(&(), ctx);
+        assert!(ctx.script_finished());
+        assert_eq!(values.unwrap().get::<String>("name").map(String::as_str), Some("a"));
+
+        // cancelling returns nothing
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc]));
+        let values = FormBuilder::new("Register")
+            .field("name", Box::new(Textbox::builder().name("Name").build()))
+            .control(String::is_empty, "Value required")
+            .run_over(&(), ctx);
+        assert!(values.is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn control_type() {
+        let _ = FormBuilder::new("Register")
+            .field("name", Box::new(Textbox::builder().name("Name").build()))
+            .control(|value: &bool| *value, "Checked");
+    }
+
+    #[test]
+    fn submit() {
+        // enter reaches the checkbox, and F2 submits
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Enter, KeyCode::F(2)]));
+        let values = FormBuilder::new("Settings")
+            .field("enabled", Box::new(Checkbox::builder().name("Enabled").build()))
+            .submit(KeyCode::F(2))
+            .run_over(&(), ctx)
+            .unwrap();
+        assert!(*values.get::<bool>("enabled").unwrap());
+        assert!(ctx.script_finished());
+    }
+}
@@ -0,0 +1,204 @@
+use std::{borrow::Cow, fmt};
+use ratatui::{style::Stylize, text::{Line, Span, Text}};
+
+/// Builder of the [hint](super::DrawInfo::hint) of a dialog from the keys it responds to, e.g. "Press (y) to
+/// confirm, (n) to cancel...". 
+/// 
+/// Generating the hint from the bindings keeps the wording consistent between dialogs. The hint renders to
+/// a string through [`Display`](fmt::Display), and can be assigned to `DrawInfo::hint` with `into()`. When
+/// the dialog is drawn, the keys in parentheses are shown in bold, which also applies to hints written by
+/// hand. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```
+/// use tundra::dialog::{DrawInfo, Hints};
+/// 
+/// let hints = Hints::new()
+///     .key('y', "confirm")
+///     .key('n', "cancel")
+///     .esc("close");
+/// assert_eq!(hints.to_string(), "Press (y) to confirm, (n) to cancel, (esc) to close...");
+/// 
+/// let info = DrawInfo {
+///     hint: hints.into(), 
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Hints<'a> {
+    entries: Vec<Entry<'a>>, 
+}
+
+/// One of the comma-separated parts of a hint. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+enum Entry<'a> {
+    /// Any of the keys performs the action: "(a) or (b) to action". 
+    Action(Vec<String>, Cow<'a, str>), 
+    /// Any of the keys gives the answer: "(a) or (b) for answer". 
+    Answer(Vec<String>, Cow<'a, str>), 
+    /// Any key not mentioned performs the action: "any other key to action". 
+    Any(Cow<'a, str>), 
+}
+
+impl<'a> Hints<'a> {
+    /// Creates a hint without any keys, which renders to an empty string. 
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a key performing an action, e.g. `.key('y', "confirm")` for "(y) to confirm". 
+    pub fn key(self, key: impl fmt::Display, action: impl Into<Cow<'a, str>>) -> Self {
+        self.keys([key], action)
+    }
+
+    /// Adds keys that all perform the same action, e.g. `.keys(['q', 'x'], "quit")` for "(q) or (x) to
+    /// quit". 
+    pub fn keys<T>(self, keys: impl IntoIterator<Item = T>, action: impl Into<Cow<'a, str>>) -> Self
+    where
+        T: fmt::Display, 
+    {
+        self.push(Entry::Action(format_keys(keys), action.into()))
+    }
+
+    /// Adds keys giving an answer, e.g. `.answer(['y'], "Yes")` for "(y) for Yes". 
+    pub fn answer<T>(self, keys: impl IntoIterator<Item = T>, answer: impl Into<Cow<'a, str>>) -> Self
+    where
+        T: fmt::Display, 
+    {
+        self.push(Entry::Answer(format_keys(keys), answer.into()))
+    }
+
+    /// Adds the enter key performing an action. 
+    pub fn enter(self, action: impl Into<Cow<'a, str>>) -> Self {
+        self.key("enter", action)
+    }
+
+    /// Adds the escape key performing an action. 
+    pub fn esc(self, action: impl Into<Cow<'a, str>>) -> Self {
+        self.key("esc", action)
+    }
+
+    /// Adds that any key performs an action, e.g. "any key to close". If other keys are given, this reads
+    /// "any other key to close". 
+    pub fn any(self, action: impl Into<Cow<'a, str>>) -> Self {
+        self.push(Entry::Any(action.into()))
+    }
+
+    /// Whether no keys have been added. 
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn push(mut self, entry: Entry<'a>) -> Self {
+        self.entries.push(entry);
+        self
+    }
+}
+
+impl fmt::Display for Hints<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return Ok(())
+        }
+        let other = match self.entries.iter().all(|entry| matches!(entry, Entry::Any(_))) {
+            true => "", 
+            false => " other", 
+        };
+        let entries: Vec<String> = self.entries
+            .iter()
+            .map(|entry| match entry {
+                Entry::Action(keys, action) => format!("{} to {action}", join(keys)), 
+                Entry::Answer(keys, answer) => format!("{} for {answer}", join(keys)), 
+                Entry::Any(action) => format!("any{other} key to {action}"), 
+            })
+            .collect();
+        write!(f, "Press {}...", entries.join(", "))
+    }
+}
+
+impl<'a> From<Hints<'_>> for Cow<'a, str> {
+    fn from(hints: Hints) -> Self {
+        hints.to_string().into()
+    }
+}
+
+/// Wraps each key in parentheses. 
+fn format_keys<T: fmt::Display>(keys: impl IntoIterator<Item = T>) -> Vec<String> {
+    keys.into_iter()
+        .map(|key| format!("({key})"))
+        .collect()
+}
+
+/// Joins keys as alternatives, e.g. "(a)", "(a) or (b)", or "(a), (b), or (c)". 
+fn join(keys: &[String]) -> String {
+    match keys {
+        [] => String::new(), 
+        [key] => key.clone(), 
+        [first, second] => format!("{first} or {second}"), 
+        [init @ .., last] => format!("{}, or {last}", init.join(", ")), 
+    }
+}
+
+/// Styles a hint for drawing, with the keys in parentheses in bold. 
+pub(crate) fn styled(hint: &str) -> Text<'_> {
+    hint.lines()
+        .map(|line| {
+            let mut spans = Vec::new();
+            let mut rest = line;
+            let key = |rest: &str| {
+                let start = rest.find('(')?;
+                Some((start, start + rest[start..].find(')')?))
+            };
+            while let Some((start, end)) = key(rest) {
+                spans.push(Span::raw(&rest[..start]));
+                spans.push(Span::raw(&rest[start..=end]).bold());
+                rest = &rest[end + 1..];
+            }
+            spans.push(Span::raw(rest));
+            Line::from(spans)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Modifier;
+    use super::{styled, Hints};
+
+    #[test]
+    fn display() {
+        assert_eq!(Hints::new().to_string(), "");
+        assert_eq!(Hints::new().any("close").to_string(), "Press any key to close...");
+        assert_eq!(
+            Hints::new().answer(['d'], "details").any("close").to_string(), 
+            "Press (d) for details, any other key to close...", 
+        );
+        assert_eq!(
+            Hints::new()
+                .keys(["↑/↓", "pgup/pgdn", "home/end"], "scroll")
+                .keys(['q', 'x'], "close")
+                .to_string(), 
+            "Press (↑/↓), (pgup/pgdn), or (home/end) to scroll, (q) or (x) to close...", 
+        );
+        assert_eq!(
+            Hints::new().enter("submit").esc("cancel").to_string(), 
+            "Press (enter) to submit, (esc) to cancel...", 
+        );
+    }
+
+    #[test]
+    fn style() {
+        let text = styled("Press (y) to confirm, (n) to cancel (or not...");
+        let spans = &text.lines[0].spans;
+        let bold: Vec<&str> = spans
+            .iter()
+            .filter(|span| span.style.add_modifier.contains(Modifier::BOLD))
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert_eq!(bold, ["(y)", "(n)"]);
+        assert_eq!(text.to_string(), "Press (y) to confirm, (n) to cancel (or not...");
+        assert_eq!(styled("one\ntwo").lines.len(), 2);
+    }
+}
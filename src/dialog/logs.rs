@@ -0,0 +1,98 @@
+//! Defines a dialog for viewing [captured log records](crate::log_capture). 
+
+use ratatui::{style::Style, text::Span};
+use log::Level;
+use crate::log_capture::Record;
+use super::*;
+
+/// Displays a blue dialog showing the [captured log records](crate::log_capture), most recent last. 
+/// 
+/// The records are colored by level, and can be scrolled with the arrow keys. The dialog is closed by
+/// pressing `escape`, `enter`, or `q`. 
+pub fn logs<G>(over: &impl State, ctx: &mut Context<G>) {
+    let records = ctx.logs.records();
+    let offset = records.len().saturating_sub(Logs::VISIBLE);
+    let defaults = ctx.dialog_theme().all;
+    Themed{ dialog: Logs{ records, offset }, defaults }.run_over(over, &mut ctx.chain_without_global())
+}
+
+/// Dialog to view captured log records. 
+struct Logs {
+    records: Vec<Record>, 
+    /// Index of the first visible record. 
+    offset: usize, 
+}
+
+impl Logs {
+    /// Maximum number of records visible at once. 
+    const VISIBLE: usize = 16;
+
+    fn max_offset(&self) -> usize {
+        self.records.len().saturating_sub(Self::VISIBLE)
+    }
+}
+
+impl Dialog for Logs {
+    type Out = ();
+    type Global = ();
+
+    fn format(&self) -> DrawInfo<'_> {
+        fn format_record(record: &Record) -> Line<'_> {
+            let color = match record.level {
+                Level::Error => Color::Red, 
+                Level::Warn => Color::Yellow, 
+                Level::Info => Color::Cyan, 
+                Level::Debug | Level::Trace => Color::DarkGray, 
+            };
+            Line::from(vec![
+                Span::styled(format!("{:<5} ", record.level), Style::new().fg(color).bold()), 
+                Span::styled(format!("{}: ", record.target), Style::new().dim()), 
+                Span::raw(record.message.as_str()), 
+            ])
+        }
+        let body: Vec<Line> = match self.records.is_empty() {
+            true => vec![Line::from("No records have been captured.").italic()], 
+            false => self.records
+                .iter()
+                .skip(self.offset)
+                .take(Self::VISIBLE)
+                .map(format_record)
+                .collect(), 
+        };
+        DrawInfo {
+            title: "Logs".into(), 
+            color: Color::Cyan, 
+            body: body.into(), 
+            hint: match self.records.len() {
+                0 => Hints::new().esc("close").into(), 
+                count => format!(
+                    "Showing {}-{} of {count}. {}", 
+                    self.offset + 1, 
+                    usize::min(self.offset + Self::VISIBLE, count), 
+                    Hints::new().key("↑/↓", "scroll").esc("close"), 
+                ).into(), 
+            }, 
+            width_percentage: 80, 
+            ..Default::default()
+        }
+    }
+
+    fn input(mut self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        let page = Self::VISIBLE;
+        self.offset = match key.code {
+            KeyCode::Up => self.offset.saturating_sub(1), 
+            KeyCode::Down => self.offset + 1, 
+            KeyCode::PageUp => self.offset.saturating_sub(page), 
+            KeyCode::PageDown => self.offset + page, 
+            KeyCode::Home => 0, 
+            KeyCode::End => self.max_offset(), 
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => return Signal::Return(()), 
+            _ => self.offset, 
+        }.min(self.max_offset());
+        Signal::Continue(self)
+    }
+
+    fn exit(self) -> Signal<Self> {
+        Signal::Return(())
+    }
+}
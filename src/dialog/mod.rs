@@ -2,27 +2,75 @@
 //! 
 //! The following dialogs are defined in this module: 
 //! - [`dialog::confirm`] asks the user to confirm an action before proceeding. 
+//! - [`dialog::confirm_with`] asks the user to confirm an action, with custom labels and appearance. 
+//! - [`dialog::confirm3`] asks the user to confirm an action with three outcomes, e.g. yes, no, or cancel. 
 //! - [`dialog::select_index`] asks the user to select one item among a set. 
+//! - [`dialog::select_index_with_default`] asks the user to select one item among a set, starting from a
+//! given one. 
+//! - [`dialog::select_index_with`] asks the user to select one item among a set, with custom options. 
 //! - [`dialog::select_value`] asks the user to select one value among a set. 
+//! - [`dialog::select_value_with_default`] asks the user to select one value among a set, starting from a
+//! given one. 
+//! - [`dialog::select_value_with`] asks the user to select one value among a set, with custom options. 
 //! - [`dialog::select_action`] asks the user to select one action among a set. 
+//! - [`dialog::select_action_with_default`] asks the user to select one action among a set, starting from a
+//! given one. 
+//! - [`dialog::select_action_with`] asks the user to select one action among a set, with custom options. 
 //! - [`dialog::select_action_mut`] asks the user to select one action among a set. 
+//! - [`dialog::select_action_mut_with_default`] asks the user to select one action among a set, starting
+//! from a given one. 
+//! - [`dialog::select_action_mut_with`] asks the user to select one action among a set, with custom options. 
+//! - [`dialog::select_row`] asks the user to select one row of a table. 
+//! - [`dialog::multi_select`] asks the user to select any number of items among a set. 
+//! - [`dialog::multi_select_with`] asks the user to select any number of items among a set, with limits on
+//! the number of items. 
+//! - [`dialog::input`] asks the user to enter a string. 
+//! - [`dialog::input_with`] asks the user to enter a string, starting from an initial value. 
 //! - [`dialog::info`] displays a message. 
+//! - [`dialog::info_timeout`] displays a message that closes itself after a while. 
+//! - [`dialog::info_with`] displays a message, with custom options. 
+//! - [`dialog::help`] displays a table of key bindings. 
+//! - [`dialog::help_with_message`] displays a message followed by a table of key bindings. 
+//! - [`dialog::view`] displays a long text that can be scrolled, e.g. a license. 
 //! - [`dialog::warning`] displays a warning. 
 //! - [`dialog::error`] displays an error. 
+//! - [`dialog::error_details`] displays an error, with details shown on request. 
+//! - [`dialog::report`] displays an error, with the chain of errors that caused it shown on request. 
 //! - [`dialog::fatal`] displays a fatal error. 
+//! - [`dialog::fatal_over`] displays a fatal error over a background state. 
 //! - [`dialog::message`] displays any kind of message. 
+//! - [`dialog::message_with`] displays any kind of message, with custom options, e.g. a styled body. 
+//! - [`dialog::progress`] displays the progress of work running in the background. 
+//! - [`dialog::progress_with`] displays the progress of work running in the background, which may be
+//! cancellable. 
+//! - [`dialog::busy`] displays a spinner while work without measurable progress runs in the background. 
+//! - `dialog::progress_async` and `dialog::busy_async` are the counterparts of the above for work that is a
+//! future, for use from asynchronous states (requires the `tokio` feature). 
 //! - [`dialog::form!`] allows the user to enter information through a set of input fields. 
+//! - [`dialog::FormBuilder`] constructs a form at runtime, for when the fields are not known at compile time. 
+//! - [`dialog::Wizard`] guides the user through a sequence of dialogs, with navigation back and forth. 
+//! - `dialog::logs` displays the captured log records (requires the `log` feature). 
+//! 
+//! Forms for editing a struct can be derived with `dialog::Form` (requires the `derive` feature). 
+//! 
+//! The colours, borders, and hints of these dialogs can be changed throughout the application with a
+//! [`Theme`], installed with [`Context::set_dialog_theme`]. 
 //! 
 //! 
 //! # Custom dialogs
 //! 
 //! Custom dialogs may be created by implementing the [`Dialog`] trait. See its documentation for more
-//! information. 
+//! information. Their hints can be generated from the keys they respond to with [`Hints`], to match the
+//! wording of the library dialogs. 
+//! 
+//! See also the [cookbook](crate::cookbook) for ready-made functions combining dialogs in common patterns, 
+//! such as retrying a failed operation. 
 //! 
 //! 
 //! # Examples
 //! 
-//! To show a dialog without any background, provide the [dummy state](crate::State#dummy-state) `()`: 
+//! To show a dialog without any background, provide the [dummy state](crate::State#dummy-state) `()`, or
+//! one of the states in the [backgrounds](crate::backgrounds) module: 
 //! ```no_run
 //! # use tundra::prelude::*;
 //! # let ctx = &mut Context::new().unwrap();
@@ -30,20 +78,40 @@
 //! dialog::info("Shown without a background!", &(), ctx);
 //! ```
 
+mod animation;
 mod basic;
 pub mod form;
+mod hints;
+#[cfg(feature = "log")]
+mod logs;
+mod progress;
+mod theme;
+mod wizard;
 
-use std::borrow::Cow;
+use std::{borrow::Cow, cell::{Cell, RefCell}, time::Duration};
 use ratatui::{
+    buffer::Buffer, 
     layout::*, 
     widgets::*, Frame, 
-    style::{Color, Stylize}, 
+    style::{Color, Modifier, Style, Stylize}, 
     text::{Line, Text}, 
 };
 use crate::prelude::*;
 
+pub use animation::AnimationSpec;
 pub use basic::*;
-pub use form::form;
+pub use form::{form, FormBuilder, FormValues, FormDescription, FieldDescription};
+pub use hints::Hints;
+#[cfg(feature = "log")]
+pub use logs::logs;
+pub use progress::{busy, progress, progress_with, ProgressHandle, ProgressOptions};
+#[cfg(feature = "tokio")]
+pub use progress::{busy_async, progress_async};
+pub use theme::{DrawInfoDefaults, FieldStyles, Theme};
+pub(crate) use theme::Themed;
+pub use wizard::{StepResult, Wizard, WizardState};
+#[cfg(feature = "derive")]
+pub use tundra_derive::Form;
 
 /// Interface for content displayed inside a dialog. 
 /// 
@@ -60,7 +128,7 @@ pub use form::form;
 /// [`dialog::confirm`]): 
 /// ```no_run
 /// use tundra::ratatui::style::Color;
-/// use tundra::{prelude::*, dialog::{Dialog, DrawInfo}};
+/// use tundra::{prelude::*, dialog::{Dialog, DrawInfo, Hints}};
 /// 
 /// struct Confirm {
 ///     msg: String, 
@@ -68,18 +136,19 @@ pub use form::form;
 /// 
 /// impl Dialog for Confirm {
 ///     type Out = bool;
+///     type Global = ();
 /// 
-///     fn format(&self) -> DrawInfo {
+///     fn format(&self) -> DrawInfo<'_> {
 ///         DrawInfo {
 ///             title: "Confirm".into(), 
 ///             color: Color::Yellow, 
 ///             body: self.msg.clone().into(), 
-///             hint: "Press (y) to confirm, (n) to cancel...".into(), 
+///             hint: Hints::new().key('y', "confirm").key('n', "cancel").into(), 
 ///             ..Default::default()
 ///         }
 ///     }
 /// 
-///     fn input(self, key: KeyEvent) -> Signal<Self> {
+///     fn input(self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
 ///         match key.code {
 ///             KeyCode::Char('y') => Signal::Return(true),
 ///             KeyCode::Char('n') => Signal::Return(false),
@@ -106,33 +175,197 @@ pub trait Dialog: Sized {
     /// value being returned is given by [`Signal::Return`] from [`Dialog::input`]. 
     type Out;
 
+    /// Type of the [global](Context#application-defined-global) of the context the dialog is run with. This
+    /// is the same as the [`State::Global`] of the surrounding states, and can be read from the context given
+    /// to [`Dialog::input`]. As with [`State::draw`], drawing doesn't have access to the context, so whatever
+    /// the dialog shows from the global should be stored in the dialog, e.g. when it is constructed. 
+    /// 
+    /// Dialogs that don't use the global should set this to `()`, and are then run over states with another
+    /// global by [chaining](Context#chaining-with-new-globals) with `ctx.chain_without_global()`. 
+    type Global;
+
+    /// Interval at which the dialog is [polled](Dialog::poll) and redrawn while no input arrives, e.g. to
+    /// show the progress of some background work. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// `None`; the dialog is only polled before blocking on input. 
+    const POLL_RATE: Option<Duration> = None;
+
     /// Defines the information needed to draw the dialog. See [`DrawInfo`] for the required fields. 
-    fn format(&self) -> DrawInfo;
+    fn format(&self) -> DrawInfo<'_>;
+    
+    /// Same as [`Dialog::format`], but given the area of the frame the dialog is drawn in. This is what the
+    /// dialog state calls when drawing, and may be overridden for dialogs whose contents depend on the
+    /// available space, e.g. to lay out the body in columns using [`DrawInfo::body_width`]. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Calls [`Dialog::format`]. 
+    #[allow(unused_variables)]
+    fn format_in(&self, area: Rect) -> DrawInfo<'_> {
+        self.format()
+    }
     
     /// Update the dialog with a key press input. 
-    fn input(self, key: KeyEvent) -> Signal<Self>;
+    /// 
+    /// The context is that of the dialog state, and may be used to e.g. open a nested dialog over this
+    /// one, or to access the global state. 
+    /// 
+    /// 
+    /// # Migrating
+    /// 
+    /// Earlier versions of this method didn't take a context. Existing implementations only need to add the
+    /// parameter, e.g. `fn input(self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self>`, along with
+    /// `type Global = ();` (see [`Dialog::Global`]). 
+    fn input(self, key: KeyEvent, ctx: &mut Context<Self::Global>) -> Signal<Self>;
+
+    /// Update the dialog with text pasted by the user. This is only called if
+    /// [bracketed paste](Context::set_bracketed_paste) is enabled; otherwise, pasted text arrives as
+    /// individual key presses, if at all. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Ignores the pasted text. 
+    #[allow(unused_variables)]
+    fn paste(self, text: &str) -> Signal<Self> {
+        Signal::Continue(self)
+    }
+
+    /// Update the dialog with the new size of the terminal, e.g. to recompute a cached layout. The dialog is
+    /// redrawn at the new size afterwards. See [`State::resize`]. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Always returns `Signal::Continue(self)`. 
+    #[allow(unused_variables)]
+    fn resize(self, width: u16, height: u16, ctx: &mut Context<Self::Global>) -> Signal<Self> {
+        Signal::Continue(self)
+    }
+
+    /// Update the dialog when the terminal window gains or loses focus. See [`State::focus_changed`]. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Always returns `Signal::Continue(self)`. 
+    #[allow(unused_variables)]
+    fn focus_changed(self, focused: bool, ctx: &mut Context<Self::Global>) -> Signal<Self> {
+        Signal::Continue(self)
+    }
+
+    /// Checks whether the dialog should close without user input, e.g. once a deadline has passed or some
+    /// external condition has become true. Returning `Some` closes the dialog with the contained value, as
+    /// if [`Signal::Return`] had been returned from [`Dialog::input`]. 
+    /// 
+    /// This is called by [`Dialog::run_over`] each time it is about to block waiting for input. Since no
+    /// events arrive while waiting, this means that it is checked once before the first input event, and
+    /// then once after each input event. In particular, a condition becoming true while the dialog is idle
+    /// is only noticed after the next input event, unless a [poll rate](Dialog::POLL_RATE) is set. If input
+    /// events are already pending, they are handled before the dialog is polled; i.e. input wins if both are
+    /// ready. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Never closes the dialog. 
+    fn poll(&mut self) -> Option<Self::Out> {
+        None
+    }
+
+    /// Ask the dialog to close since an [exit](crate::State#exiting) has been requested. This is called by
+    /// [`Dialog::run_over`] before each input event while the exit is requested. Returning
+    /// [`Signal::Return`] closes the dialog with the contained value, whereas returning [`Signal::Continue`]
+    /// keeps it open. 
+    /// 
+    /// Dialogs that can be cancelled should return the same value as when cancelled, so that the code
+    /// showing the dialog can treat the two alike. All dialogs provided by the library do so, and the
+    /// [form macro](dialog::form!) returns `None`. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Always returns `Signal::Continue(self)`; the dialog stays open. 
+    fn exit(self) -> Signal<Self> {
+        Signal::Continue(self)
+    }
 
     /// Runs the dialog to fruition over some background state. 
     /// 
-    /// This is a wrapper over [`State::run`] with added logic to draw the dialog box and background state.
-    fn run_over<G>(self, background: &impl State, ctx: &mut Context<G>) -> Self::Out {
-        Container{ content: self, background }
-            .run(&mut ctx.chain_without_global())
+    /// This is a wrapper over [`State::run`] with added logic to draw the dialog box and background state, 
+    /// to [poll](Dialog::poll) the dialog, and to ask it to [exit](Dialog::exit). 
+    /// 
+    /// While a [wizard](Wizard) runs, its current step is added to the title of the dialog. Dialogs shown
+    /// over this one, e.g. error messages, are left as is. 
+    fn run_over(self, background: &impl State, ctx: &mut Context<Self::Global>) -> Self::Out {
+        let step = ctx.dialog_step.take();
+        let container = Container{ step: step.clone(), ..Container::new(self, background) };
+        let out = container.run(ctx);
+        *ctx.dialog_step.borrow_mut() = step;
+        out
+    }
+
+    /// Same as [`Dialog::run_over`], but takes the background state mutably in order to invoke
+    /// [`State::refresh_cache`] on it once the dialog has closed, before returning. 
+    /// 
+    /// This is useful when the dialog changes something the background state has derived data from, such as
+    /// the global of the context. The background state is only drawn while the dialog is shown. 
+    fn run_over_refresh<S>(self, background: &mut S, ctx: &mut Context<Self::Global>) -> Self::Out
+    where
+        S: State<Global = Self::Global>, 
+    {
+        let out = self.run_over(&*background, ctx);
+        background.refresh_cache(ctx);
+        out
+    }
+
+    /// Same as [`Dialog::run_over`], but waits for input without blocking the thread, for use from
+    /// [asynchronous states](crate::StateAsync). 
+    /// 
+    /// This requires the `tokio` feature. 
+    #[cfg(feature = "tokio")]
+    #[allow(async_fn_in_trait)]
+    async fn run_over_async(self, background: &impl State, ctx: &mut Context<Self::Global>) -> Self::Out {
+        let step = ctx.dialog_step.take();
+        let container = Container{ step: step.clone(), ..Container::new(self, background) };
+        let out = container.run_async(ctx).await;
+        *ctx.dialog_step.borrow_mut() = step;
+        out
     }
 }
 
 impl<T: Dialog> State for T {
     type Result<U> = U;
     type Out = T::Out;
-    type Global = ();
+    type Global = T::Global;
 
     fn draw(&self, frame: &mut Frame) {
-        let draw_info = self.format();
-        draw_dialog(draw_info, frame)
+        let draw_info = self.format_in(frame.area());
+        draw_dialog(draw_info, frame, 100);
     }
 
-    fn input(self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
-        self.input(key)
+    fn input(self, key: KeyEvent, ctx: &mut Context<T::Global>) -> Signal<Self> {
+        Dialog::input(self, key, ctx)
+    }
+
+    fn resize(self, width: u16, height: u16, ctx: &mut Context<T::Global>) -> Signal<Self> {
+        Dialog::resize(self, width, height, ctx)
+    }
+
+    fn focus_changed(self, focused: bool, ctx: &mut Context<T::Global>) -> Signal<Self> {
+        Dialog::focus_changed(self, focused, ctx)
+    }
+
+    fn paste(self, text: String, _ctx: &mut Context<T::Global>) -> Signal<Self> {
+        Dialog::paste(self, &text)
+    }
+
+    fn exit(self, _ctx: &mut Context<T::Global>) -> Signal<Self> {
+        Dialog::exit(self)
     }
 }
 
@@ -163,6 +396,7 @@ impl<T: Dialog> State for T {
 /// # ;
 /// ```
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[allow(unpredictable_function_pointer_comparisons)]
 pub struct DrawInfo<'a> {
     /// User-visible title of the dialog box. Default: `""`. 
     pub title: Cow<'a, str>, 
@@ -170,27 +404,92 @@ pub struct DrawInfo<'a> {
     pub color: Color, 
     /// Dialog payload. Default: `""`. 
     pub body: Text<'a>, 
-    /// String displayed at the bottom in italics, for example for displaying the dialog key binds. Default: 
-    /// `""`. 
+    /// String displayed at the bottom, for example for displaying the dialog key binds. Keys in parentheses
+    /// are shown in bold; see [`Hints`] for generating this from the key binds. Default: `""`. 
     pub hint: Cow<'a, str>, 
+    /// Horizontal alignment of the [`hint`](DrawInfo::hint). Default: `Alignment::Left`. 
+    pub hint_alignment: Alignment, 
+    /// Style of the [`hint`](DrawInfo::hint). Default: italic. 
+    pub hint_style: Style, 
     /// Margin `[horizontal, vertical]` between the border and the body. Default: `[3, 1]`. 
     pub inner_margin: [u16; 2], 
-    /// Width of the dialog as a percentage (between `0` and `100`) of the total width of the terminal. 
-    /// Default: `50`. 
+    /// Width of the dialog as a percentage (between `1` and `100`) of the total width of the terminal. 
+    /// Values outside this range are clamped. Default: `50`. 
     pub width_percentage: u8, 
+    /// Minimum width of the [body](DrawInfo::body) in columns when sized per
+    /// [`width_percentage`](DrawInfo::width_percentage), so that short messages don't wrap on narrow
+    /// terminals. This is capped so that the dialog box fits in the frame. Default: `20`. 
+    pub min_width: u16, 
+    /// Maximum width of the [body](DrawInfo::body) in columns when sized per
+    /// [`width_percentage`](DrawInfo::width_percentage), so that dialogs don't stretch across wide terminals. 
+    /// This takes precedence over [`min_width`](DrawInfo::min_width). Default: `None`. 
+    pub max_width: Option<u16>, 
+    /// Width of the [body](DrawInfo::body) in columns. If set, this overrides
+    /// [`width_percentage`](DrawInfo::width_percentage) whenever the terminal is wide enough to fit the
+    /// dialog box at this width. Default: `None`. 
+    pub width_columns: Option<u16>, 
+    /// Maximum height of the dialog box as a percentage (between `1` and `100`) of the total height of the
+    /// terminal. Values outside this range are clamped. A body too tall to fit is clipped to a window
+    /// starting [`scroll`](DrawInfo::scroll) lines into it, with `…` in place of the first and last lines of
+    /// the window where the body continues beyond it. Default: `80`. 
+    pub max_height_percentage: u8, 
+    /// Number of lines of the [body](DrawInfo::body), once wrapped, scrolled past when it is clipped. This is
+    /// capped so that the end of the body is at the bottom of the dialog box at most. Dialogs scrolling their
+    /// body this way should size their steps with [`DrawInfo::max_body_height`]. Default: `0`. 
+    pub scroll: u16, 
+    /// Height of the [body](DrawInfo::body) in lines. If set, the body is drawn at this height regardless of
+    /// its contents, cutting it off if too long, e.g. for dialogs that scroll their contents themselves. 
+    /// Default: `None`; the height fits the body. 
+    pub body_height: Option<u16>, 
     /// Settings used to wrap the body [`Paragraph`]. Set to `None` to disable wrapping. Default: uses
     /// wrapping with [`Wrap::trim`] set to false. 
     pub wrap: Option<Wrap>, 
-    /// Function constructing a [`Title`] from a string. Default: turns the title uppercase and inserts a
-    /// space on either side of it. 
+    /// Preset for how the title is displayed. If set, overrides [`create_title`](DrawInfo::create_title). 
+    /// Default: `None`. 
+    pub title_style: Option<TitleStyle>, 
+    /// Preset for how the border of the dialog box is drawn. If set, overrides
+    /// [`create_block`](DrawInfo::create_block). Default: `None`. 
+    pub border: Option<BorderPreset>, 
+    /// Horizontal alignment of the title, and of the [bottom title](DrawInfo::bottom_title), within the top
+    /// and bottom borders. Default: `Alignment::Left`. 
+    pub title_alignment: Alignment, 
+    /// Style of the title, and of the [bottom title](DrawInfo::bottom_title), patched over that of the
+    /// border, e.g. to draw the title bold or in another colour. Default: `None`; the title is drawn like the
+    /// border. 
+    pub title_text_style: Option<Style>, 
+    /// String displayed as given in the bottom border, e.g. to show the hint inside the border. Default: 
+    /// `""`. 
+    pub bottom_title: Cow<'a, str>, 
+    /// Function constructing the title [`Line`] from a string. The [alignment](DrawInfo::title_alignment)
+    /// and [style](DrawInfo::title_text_style) of the title are applied to the line returned, except that an
+    /// alignment set on the line takes precedence. Default: that of [`TitleStyle::UppercaseSpaced`], which
+    /// turns the title uppercase and inserts a space on either side of it. 
     pub create_title: fn(Cow<'a, str>) -> Line<'a>, 
     /// Function constructing the [`Block`], which represents the dialog box. Note that two properties are
     /// later overriden: 
     /// - `Block::fg()`, which is set to [`color`](DrawInfo::color). 
     /// - `Block::title()`, which is set to the output of [`create_title`](DrawInfo::create_title). 
     /// 
-    /// Default: uses `Borders::ALL` and `BorderType::Thick`. 
+    /// Default: that of [`BorderPreset::Thick`], which uses `Borders::ALL` and `BorderType::Thick`. 
     pub create_block: fn() -> Block<'a>, 
+    /// Whether the background state is [dimmed](dim_area) while the dialog is shown, setting the dialog box
+    /// apart from a busy background. Default: `false`. 
+    pub dim_background: bool, 
+    /// Whether a shadow is drawn one cell below and to the right of the dialog box. The shadow is left out
+    /// when the dialog box touches the right or bottom edge of the frame. Default: `false`. 
+    pub shadow: bool, 
+    /// Where the dialog box is placed in the frame. Default: [`Anchor::Center`]. 
+    pub anchor: Anchor, 
+    /// Offset `[columns, rows]` of the dialog box from where it is [anchored](DrawInfo::anchor), towards the
+    /// right and the bottom of the frame. The dialog box is kept inside the frame regardless of the offset. 
+    /// Default: `[0, 0]`. 
+    pub offset: [i16; 2], 
+    /// Animation shown before the dialog is first drawn, growing the dialog box to its final size. Default: 
+    /// `None`. 
+    pub animate_open: Option<AnimationSpec>, 
+    /// Animation shown after the dialog has closed, shrinking the dialog box from the size it was last drawn
+    /// with. Default: `None`. 
+    pub animate_close: Option<AnimationSpec>, 
 }
 
 impl<'a> Default for DrawInfo<'a> {
@@ -200,16 +499,197 @@ impl<'a> Default for DrawInfo<'a> {
             color: Color::Cyan, 
             body: "".into(), 
             hint: "".into(), 
+            hint_alignment: Alignment::Left, 
+            hint_style: Style::new().italic(), 
             inner_margin: [3, 1], 
             width_percentage: 50, 
+            min_width: 20, 
+            max_width: None, 
+            width_columns: None, 
+            max_height_percentage: 80, 
+            scroll: 0, 
+            body_height: None, 
             wrap: Some(Wrap{ trim: false }), 
-            create_title: |title| match title.is_empty() {
+            title_style: None, 
+            border: None, 
+            title_alignment: Alignment::Left, 
+            title_text_style: None, 
+            bottom_title: "".into(), 
+            create_title: TitleStyle::default().create_title(), 
+            create_block: BorderPreset::default().create_block(), 
+            dim_background: false, 
+            shadow: false, 
+            anchor: Anchor::default(), 
+            offset: [0, 0], 
+            animate_open: None, 
+            animate_close: None, 
+        }
+    }
+}
+
+/// Where a dialog box is placed in the frame. Used by [`DrawInfo::anchor`]. 
+/// 
+/// Placing a dialog box away from the centre keeps what the user was looking at in view, e.g. the selected
+/// row of a table. The dialog box is drawn against the edges of the frame it is anchored to, and is centred
+/// along the others. 
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum Anchor {
+    /// In the middle of the frame. 
+    #[default]
+    Center, 
+    /// Against the top edge of the frame. 
+    Top, 
+    /// Against the bottom edge of the frame. 
+    Bottom, 
+    /// Against the left edge of the frame. 
+    Left, 
+    /// Against the right edge of the frame. 
+    Right, 
+    /// In the top-left corner of the frame. 
+    TopLeft, 
+    /// In the top-right corner of the frame. 
+    TopRight, 
+    /// In the bottom-left corner of the frame. 
+    BottomLeft, 
+    /// In the bottom-right corner of the frame. 
+    BottomRight, 
+}
+
+impl Anchor {
+    /// The position `[horizontal, vertical]` along each axis in halves of the free space: `0` against the
+    /// start, `1` in the middle, and `2` against the end. 
+    fn halves(self) -> [u16; 2] {
+        match self {
+            Anchor::Center => [1, 1], 
+            Anchor::Top => [1, 0], 
+            Anchor::Bottom => [1, 2], 
+            Anchor::Left => [0, 1], 
+            Anchor::Right => [2, 1], 
+            Anchor::TopLeft => [0, 0], 
+            Anchor::TopRight => [2, 0], 
+            Anchor::BottomLeft => [0, 2], 
+            Anchor::BottomRight => [2, 2], 
+        }
+    }
+
+    /// Places a dialog box of given outer size in the frame per this anchor, moved by `offset`. The dialog
+    /// box is shrunk to the size of the frame if larger, and is kept inside it regardless of the offset. 
+    fn place(self, frame: Rect, [width, height]: [u16; 2], [offset_x, offset_y]: [i16; 2]) -> Rect {
+        let [width, height] = [width.min(frame.width), height.min(frame.height)];
+        let [delta_width, delta_height] = [frame.width - width, frame.height - height];
+        let [horizontal, vertical] = self.halves();
+
+        // if the delta is odd, the margins of a centred box would be 0.5 too small on either side. to
+        // account for this, the box is widened by 1 horizontally, and the top margin is rounded down
+        // vertically
+        let width = match horizontal {
+            1 => width + (delta_width & 1), 
+            _ => width, 
+        };
+        let x = (delta_width * horizontal / 2)
+            .saturating_add_signed(offset_x)
+            .min(frame.width - width);
+        let y = (delta_height * vertical / 2)
+            .saturating_add_signed(offset_y)
+            .min(frame.height - height);
+        Rect::new(frame.x + x, frame.y + y, width, height)
+    }
+}
+
+/// Preset for how the title of a dialog is displayed. Used by [`DrawInfo::title_style`]. 
+/// 
+/// Each preset corresponds to a function for [`DrawInfo::create_title`], which gives full control over the
+/// title. 
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum TitleStyle {
+    /// Turns the title uppercase and inserts a space on either side of it. 
+    #[default]
+    UppercaseSpaced, 
+    /// Displays the title as given. 
+    AsIs, 
+    /// Displays no title. 
+    Hidden, 
+}
+
+impl<'a> DrawInfo<'a> {
+    /// The width available to the [body](DrawInfo::body) when the dialog is drawn in a frame of given width, 
+    /// per [`width_columns`](DrawInfo::width_columns) if the dialog box fits, and
+    /// [`width_percentage`](DrawInfo::width_percentage) within [`min_width`](DrawInfo::min_width) and
+    /// [`max_width`](DrawInfo::max_width) otherwise. 
+    pub fn body_width(&self, frame_width: u16) -> u16 {
+        let [chrome, _] = outer_size(&self.block(), self.inner_margin[0] * 2, 0);
+        let widest = frame_width.saturating_sub(chrome);
+        match self.width_columns {
+            Some(columns) if columns <= widest => columns, 
+            _ => {
+                let width = (frame_width as u32 * self.width_percentage.clamp(1, 100) as u32 / 100) as u16;
+                let width = width.max(self.min_width.min(widest));
+                self.max_width.map_or(width, |max_width| width.min(max_width))
+            }
+        }
+    }
+
+    /// Creates the dialog box per [`border`](DrawInfo::border) or [`create_block`](DrawInfo::create_block). 
+    fn block(&self) -> Block<'a> {
+        let create_block = self.border.map_or(self.create_block, BorderPreset::create_block);
+        create_block()
+    }
+
+    /// The largest height the [body](DrawInfo::body) can have without the dialog box exceeding its
+    /// [maximum height](DrawInfo::max_height_percentage) in a frame of given area. This accounts for the
+    /// border, the [margin](DrawInfo::inner_margin), and the [hint](DrawInfo::hint). 
+    pub fn max_body_height(&self, frame_area: Rect) -> u16 {
+        let hint_height = Paragraph::new(self.hint.as_ref())
+            .wrap(Wrap{ trim: true })
+            .line_count(self.body_width(frame_area.width)) as u16;
+        let [_, chrome] = outer_size(&self.block(), 0, self.inner_margin[1] * 2 + 2 + hint_height);
+        let max_height = frame_area.height as u32 * self.max_height_percentage.clamp(1, 100) as u32 / 100;
+        (max_height as u16).saturating_sub(chrome)
+    }
+}
+
+impl TitleStyle {
+    /// The function creating the title line from a string per this style. 
+    pub fn create_title<'a>(self) -> fn(Cow<'a, str>) -> Line<'a> {
+        match self {
+            TitleStyle::UppercaseSpaced => |title| match title.is_empty() {
                 true => "".into(), 
                 false => format!(" {title} ").to_uppercase().into(), 
             }, 
-            create_block: || Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Thick), 
+            TitleStyle::AsIs => |title| title.into(), 
+            TitleStyle::Hidden => |_| "".into(), 
+        }
+    }
+}
+
+/// Preset for how the border of a dialog box is drawn. Used by [`DrawInfo::border`]. 
+/// 
+/// Each preset corresponds to a function for [`DrawInfo::create_block`], which gives full control over the
+/// dialog box. 
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum BorderPreset {
+    /// Thick lines on all sides. 
+    #[default]
+    Thick, 
+    /// Plain lines with rounded corners on all sides. 
+    Rounded, 
+    /// Plain lines on all sides. 
+    Plain, 
+    /// Double lines on all sides. 
+    Double, 
+    /// No border. The title is still drawn at the top of the dialog box. 
+    None, 
+}
+
+impl BorderPreset {
+    /// The function creating the dialog box per this preset. 
+    pub fn create_block<'a>(self) -> fn() -> Block<'a> {
+        match self {
+            BorderPreset::Thick => || Block::bordered().border_type(BorderType::Thick), 
+            BorderPreset::Rounded => || Block::bordered().border_type(BorderType::Rounded), 
+            BorderPreset::Plain => || Block::bordered().border_type(BorderType::Plain), 
+            BorderPreset::Double => || Block::bordered().border_type(BorderType::Double), 
+            BorderPreset::None => Block::default, 
         }
     }
 }
@@ -218,46 +698,282 @@ impl<'a> Default for DrawInfo<'a> {
 /// [dialogs](Dialog). 
 /// 
 /// It is responsible for rendering the dialog box, dialog contents, and background state. 
+/// 
+/// With the `draw-cache` feature, the composite frame is cached between draws and re-used for as long as the
+/// dialog has not changed and the frame has not been resized. This matters most in chains of nested dialogs, 
+/// where each layer would otherwise re-render every layer beneath it on each frame --- e.g. a form and its
+/// background while a validation error is shown over the form. The background cannot change while the
+/// dialog is shown, since it is borrowed immutably, so only changes to the dialog itself invalidate the
+/// cache. Note that this assumes the background does not draw differently between frames on its own (e.g. 
+/// through interior mutability). 
 struct Container<'a, T, U> {
     /// Dialog contents. 
     content: T, 
     /// Background state. 
     background: &'a U, 
+    /// Bumped each time the dialog contents may have changed; i.e. on input and when polled. 
+    generation: u64, 
+    /// The previous composite frame. 
+    cache: RefCell<DrawCache>, 
+    /// Size of the dialog box as a percentage of its final size; less than `100` while animating. 
+    scale: Cell<u8>, 
+    /// Recorded each time the dialog is drawn, if the dialog has a close animation. 
+    closing: RefCell<Option<animation::Closing>>, 
+    /// The step of the running [wizard](Wizard), added to the title. 
+    step: Option<String>, 
+}
+
+impl<'a, T, U> Container<'a, T, U> {
+    fn new(content: T, background: &'a U) -> Self {
+        Container {
+            content, 
+            background, 
+            generation: 0, 
+            cache: Default::default(), 
+            scale: Cell::new(100), 
+            closing: Default::default(), 
+            step: None, 
+        }
+    }
+
+    /// Updates the dialog contents, bumping the generation if the dialog stays open. 
+    fn update(self, f: impl FnOnce(T) -> Signal<T>) -> Signal<Self>
+    where
+        T: Dialog, 
+        U: State, 
+    {
+        match f(self.content) {
+            Signal::Return(out) => Signal::Return(out), 
+            Signal::Continue(content) => Signal::Continue(Container {
+                content, 
+                generation: self.generation + 1, 
+                ..self
+            }), 
+        }
+    }
 }
 
 impl<T: Dialog, U: State> State for Container<'_, T, U> {
     type Result<V> = V;
     type Out = T::Out;
-    type Global = ();
+    type Global = T::Global;
 
     fn draw(&self, frame: &mut Frame) {
+        let scale = self.scale.get();
+        let mut cache = self.cache.borrow_mut();
+        if scale == 100 && cache.restore(self.generation, frame) {
+            return
+        }
         self.background.draw(frame);
-        let draw_info = self.content.format();
+        let mut draw_info = self.content.format_in(frame.area());
+        if let Some(step) = &self.step {
+            draw_info.title = match draw_info.title.is_empty() {
+                true => step.clone().into(), 
+                false => format!("{} · {step}", draw_info.title).into(), 
+            };
+        }
+        if draw_info.dim_background {
+            let area = frame.area();
+            dim_area(frame.buffer_mut(), area);
+        }
+        let closing = draw_info.animate_close
+            .clone()
+            .map(|spec| (spec, draw_info.color, draw_info.border.unwrap_or_default()));
 
         // factored out non-generic code to reduce code generation
-        draw_dialog(draw_info, frame)
+        let area = draw_dialog(draw_info, frame, scale);
+        if scale == 100 {
+            cache.store(self.generation, frame);
+            *self.closing.borrow_mut() = closing.map(|(spec, color, border)| animation::Closing {
+                spec, 
+                area, 
+                color, 
+                border, 
+            });
+        }
+    }
+
+    fn input(self, key: KeyEvent, ctx: &mut Context<T::Global>) -> Signal<Self> {
+        self.update(|content| content.input(key, ctx))
+    }
+
+    fn paste(self, text: String, _ctx: &mut Context<T::Global>) -> Signal<Self> {
+        self.update(|content| Dialog::paste(content, &text))
+    }
+
+    fn resize(self, width: u16, height: u16, ctx: &mut Context<T::Global>) -> Signal<Self> {
+        self.update(|content| Dialog::resize(content, width, height, ctx))
+    }
+
+    fn focus_changed(self, focused: bool, ctx: &mut Context<T::Global>) -> Signal<Self> {
+        self.update(|content| Dialog::focus_changed(content, focused, ctx))
+    }
+
+    fn exit(self, _ctx: &mut Context<T::Global>) -> Signal<Self> {
+        match self.content.exit() {
+            Signal::Return(out) => Signal::Return(out), 
+            Signal::Continue(content) => Signal::Continue(Container{ content, ..self }), 
+        }
+    }
+
+    /// Same as the default implementation, but polls the dialog before blocking on input (and at its
+    /// [poll rate](Dialog::POLL_RATE) while waiting), and runs the open and close animations of the dialog. 
+    /// The close animation is skipped when the dialog closes since an exit is requested. 
+    fn run(mut self, ctx: &mut Context<T::Global>) -> Self::Out {
+        let animate = ctx.animations() && !ctx.exit_requested();
+        if let (Some(spec), true) = (self.content.format().animate_open, animate) {
+            animation::open(&self, &spec, ctx);
+        }
+        loop {
+            if ctx.exit_requested() {
+                match self.exit(ctx) {
+                    Signal::Return(out) => break out, 
+                    Signal::Continue(new_self) => self = new_self, 
+                }
+            }
+            // pending input takes precedence over polling, so the dialog is only polled before blocking
+            if !ctx.event_pending().unwrap() {
+                if let Some(out) = self.content.poll() {
+                    break out
+                }
+                self.generation += 1;
+            }
+            ctx.draw_state(&self).unwrap();
+            // with a poll rate, the dialog is polled and redrawn again if no input arrives in time
+            if !ctx.wait_event(&self, T::POLL_RATE).unwrap() {
+                continue
+            }
+            let event = ctx.read_event().unwrap();
+            let background = self.background;
+            let closing = self.closing.borrow().clone();
+
+            match self.event(event, ctx) {
+                Signal::Return(out) => {
+                    if let (Some(closing), true) = (closing, ctx.animations()) {
+                        closing.run(background, ctx);
+                    }
+                    break out
+                }
+                Signal::Continue(new_self) => self = new_self, 
+            }
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T: Dialog, U: State> Container<'_, T, U> {
+    /// Same as [`State::run`] for the container, but waits for input without blocking the thread. 
+    async fn run_async(mut self, ctx: &mut Context<T::Global>) -> T::Out {
+        let animate = ctx.animations() && !ctx.exit_requested();
+        if let (Some(spec), true) = (self.content.format().animate_open, animate) {
+            animation::open_async(&self, &spec, ctx).await;
+        }
+        loop {
+            if ctx.exit_requested() {
+                match self.exit(ctx) {
+                    Signal::Return(out) => break out, 
+                    Signal::Continue(new_self) => self = new_self, 
+                }
+            }
+            if !ctx.event_pending().unwrap() {
+                if let Some(out) = self.content.poll() {
+                    break out
+                }
+                self.generation += 1;
+            }
+            ctx.draw_state(&self).unwrap();
+            let Some(event) = ctx.next_event_async(&self, T::POLL_RATE).await.unwrap() else {
+                continue
+            };
+            let background = self.background;
+            let closing = self.closing.borrow().clone();
+
+            match self.event(event, ctx) {
+                Signal::Return(out) => {
+                    if let (Some(closing), true) = (closing, ctx.animations()) {
+                        closing.run_async(background, ctx).await;
+                    }
+                    break out
+                }
+                Signal::Continue(new_self) => self = new_self, 
+            }
+        }
+    }
+}
+
+/// Snapshot of the composite frame drawn by a [`Container`], re-used while the container is unchanged. 
+#[derive(Default)]
+struct DrawCache {
+    /// Generation of the container when the snapshot was taken. 
+    generation: u64, 
+    snapshot: Option<Buffer>, 
+}
+
+impl DrawCache {
+    /// Whether the cache is in use; the snapshot would hide the areas marked for debugging otherwise. 
+    const ENABLED: bool = cfg!(all(feature = "draw-cache", not(feature = "debug-draw")));
+
+    /// Copies the snapshot into the frame if it was taken at the same generation and with the same frame
+    /// size. Returns whether the frame was restored. 
+    fn restore(&self, generation: u64, frame: &mut Frame) -> bool {
+        match &self.snapshot {
+            Some(snapshot) if self.generation == generation && snapshot.area == frame.buffer_mut().area => {
+                frame.buffer_mut().clone_from(snapshot);
+                true
+            }
+            _ => false, 
+        }
     }
 
-    fn input(self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
-        match self.content.input(key) {
-            Signal::Return(out) => Signal::Return(out),
-            Signal::Continue(content) => Signal::Continue(Container{ content, ..self }),
+    /// Takes a snapshot of the frame. 
+    fn store(&mut self, generation: u64, frame: &mut Frame) {
+        if Self::ENABLED {
+            self.generation = generation;
+            self.snapshot = Some(frame.buffer_mut().clone());
         }
     }
 }
 
+/// Dims the cells of a buffer within given area, e.g. to set a dialog box apart from the background state
+/// drawn beneath it. The cells keep their contents and colours, but are drawn with [`Modifier::DIM`]. This is
+/// done by [dialogs](Dialog) when [`DrawInfo::dim_background`] is set, and may be used by custom
+/// [states](State) drawing their own overlays. 
+pub fn dim_area(buffer: &mut Buffer, area: Rect) {
+    let area = area.intersection(buffer.area);
+    for position in area.positions() {
+        buffer[position].modifier.insert(Modifier::DIM);
+    }
+}
+
+/// Draws a dialog box, returning its outer area. If `scale` is less than `100`, only the outline of the box is
+/// drawn, scaled to the given percentage of its size. 
 #[inline(never)]
-fn draw_dialog<'a>(info: DrawInfo<'a>, frame: &mut Frame) {
+fn draw_dialog<'a>(info: DrawInfo<'a>, frame: &mut Frame, scale: u8) -> Rect {
+    let frame_size = frame.area();
+    let inner_width = info.body_width(frame_size.width);
+    let max_body_height = info.max_body_height(frame_size);
+    let block = info.block();
     let DrawInfo {
         title, 
         body, 
         color, 
         hint, 
+        hint_alignment, 
+        hint_style, 
         inner_margin: [inner_margin_x, inner_margin_y], 
-        width_percentage, 
+        body_height: fixed_body_height, 
+        scroll, 
         wrap, 
+        title_style, 
+        border, 
+        title_alignment, 
+        title_text_style, 
+        bottom_title, 
         create_title, 
-        create_block, 
+        anchor, 
+        offset, 
+        shadow, 
+        ..
     } = info;
 
     // create body and hint paragraphs
@@ -265,49 +981,66 @@ fn draw_dialog<'a>(info: DrawInfo<'a>, frame: &mut Frame) {
         (Some(wrap), body) => body.wrap(wrap), 
         (None, body) => body, 
     };
-    let hint = Paragraph::new(hint)
+    let hint = Paragraph::new(hints::styled(&hint))
         .wrap(Wrap{ trim: true })
-        .italic();
+        .alignment(hint_alignment)
+        .style(hint_style);
 
     // compute the required inner dimensions
-    let frame_size = frame.area();
-    let inner_width = (frame_size.width * width_percentage as u16) / 100;
-    let [hint_height, body_height] = [&hint, &body].map(|x|
+    let [hint_height, body_lines] = [&hint, &body].map(|x|
         x.line_count(inner_width) as u16
     );
+    let body_height = fixed_body_height.unwrap_or(body_lines);
+
+    // a body too tall for the dialog box is clipped to a window, starting at the scroll offset
+    let clipped = body_height > max_body_height;
+    let body_height = body_height.min(max_body_height);
+    let scroll = scroll.min(body_lines.saturating_sub(body_height));
+    let body = body.scroll((scroll, 0));
     let inner_height = body_height + 2 + hint_height; // 2 spaces between body and hint
 
     // draw box and compute its actual inner area
-    let inner_area = {
+    let (outer_area, inner_area) = {
+        let create_title = title_style.map_or(create_title, TitleStyle::create_title);
         let title = create_title(title);
-        let block = create_block()
-            .title_top(title)
+        let style_title = |title: Line<'a>| {
+            let title = match title.alignment {
+                Some(_) => title, 
+                None => title.alignment(title_alignment), 
+            };
+            match title_text_style {
+                Some(style) => title.patch_style(style), 
+                None => title, 
+            }
+        };
+        let block = block
+            .title_top(style_title(title))
             .fg(color);
+        let block = match bottom_title.is_empty() {
+            true => block, 
+            false => block.title_bottom(style_title(bottom_title.into())), 
+        };
         let [outer_width, outer_height] = outer_size(
             &block, 
             inner_width + inner_margin_x * 2, 
             inner_height + inner_margin_y * 2, 
         );
-        let [delta_width, delta_height] = [
-            frame_size.width.saturating_sub(outer_width), 
-            frame_size.height.saturating_sub(outer_height), 
-        ];
-        let mut outer_area = frame_size.inner(Margin {
-            horizontal: delta_width / 2,
-            vertical: delta_height / 2,
-        });
-
-        // if the delta height is odd, the margin will be 0.5 too small on both the top and bottom. to
-        // account for this, we remove 1 from the dialog height -- basically rounding the top margin down and
-        // the bottom margin up
-        outer_area.height -= delta_height & 1;
+        let outer_area = anchor.place(frame_size, [outer_width, outer_height], offset);
 
+        if scale < 100 {
+            let outline = animation::interpolate(outer_area, scale);
+            animation::draw_outline(frame, outline, color, border.unwrap_or_default());
+            return outer_area
+        }
         let inner_area = block.inner(outer_area);
 
+        if shadow {
+            draw_shadow(frame.buffer_mut(), outer_area, frame_size);
+        }
         frame.render_widget(Clear, outer_area);
         frame.render_widget(block, outer_area);
 
-        inner_area
+        (outer_area, inner_area)
     };
 
     // draw body and hint inside the inner area
@@ -324,7 +1057,31 @@ fn draw_dialog<'a>(info: DrawInfo<'a>, frame: &mut Frame) {
     
         frame.render_widget(body, layout[0]);
         frame.render_widget(hint, layout[2]);
+
+        // indicate where the body continues beyond the window
+        if clipped {
+            let [top, bottom] = [layout[0].top(), layout[0].bottom().saturating_sub(1)];
+            let more = [(scroll > 0, top), (scroll + body_height < body_lines, bottom)];
+            for (_, y) in more.into_iter().filter(|&(more, _)| more) {
+                let row = Rect{ y, height: 1, ..layout[0] };
+                frame.render_widget(Clear, row);
+                frame.render_widget(Line::from("…"), row);
+            }
+        }
+    }
+    outer_area
+}
+
+/// Darkens the cells one column to the right and one row below a dialog box, unless the dialog box touches
+/// the right or bottom edge of the frame. 
+fn draw_shadow(buffer: &mut Buffer, outer_area: Rect, frame_area: Rect) {
+    if outer_area.right() >= frame_area.right() || outer_area.bottom() >= frame_area.bottom() {
+        return
     }
+    let right = Rect::new(outer_area.right(), outer_area.y + 1, 1, outer_area.height);
+    let bottom = Rect::new(outer_area.x + 1, outer_area.bottom(), outer_area.width, 1);
+    buffer.set_style(right, Style::new().fg(Color::DarkGray).bg(Color::Black));
+    buffer.set_style(bottom, Style::new().fg(Color::DarkGray).bg(Color::Black));
 }
 
 fn outer_size(block: &Block, inner_width: u16, inner_height: u16) -> [u16; 2] {
@@ -334,3 +1091,559 @@ fn outer_size(block: &Block, inner_width: u16, inner_height: u16) -> [u16; 2] {
     let dy = dummy.height - height;
     [inner_width + dx, inner_height + dy]
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
+    use std::iter;
+    use crate::{context::script, crossterm::event::Event};
+    use super::*;
+
+    /// Draws a dialog over an empty terminal of size 40x11 and returns the contents. 
+    fn render(info: DrawInfo) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(40, 11)).unwrap();
+        terminal
+            .draw(|frame| {
+                draw_dialog(info, frame, 100);
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    /// Collects the symbols of one row of a buffer. 
+    fn row(buffer: &Buffer, y: u16) -> String {
+        (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol())
+            .collect()
+    }
+
+    fn info<'a>() -> DrawInfo<'a> {
+        DrawInfo {
+            title: "Note".into(), 
+            body: "Body".into(), 
+            hint: "Hint".into(), 
+            width_percentage: 50, 
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn border_presets() {
+        let top_left = |info: DrawInfo| {
+            let buffer = render(info);
+            let top = row(&buffer, 1);
+            top.trim().chars().next().unwrap()
+        };
+        let preset = |border| top_left(DrawInfo{ border: Some(border), ..info() });
+        assert_eq!(top_left(info()), '┏');
+        assert_eq!(preset(BorderPreset::Thick), '┏');
+        assert_eq!(preset(BorderPreset::Rounded), '╭');
+        assert_eq!(preset(BorderPreset::Plain), '┌');
+        assert_eq!(preset(BorderPreset::Double), '╔');
+
+        // the preset overrides the closure
+        let created = |border| DrawInfo {
+            border, 
+            create_block: || Block::bordered().border_type(BorderType::Double), 
+            ..info()
+        };
+        assert_eq!(top_left(created(None)), '╔');
+        assert_eq!(top_left(created(Some(BorderPreset::Rounded))), '╭');
+
+        // without a border, the title is drawn on the first row of the box
+        let buffer = render(DrawInfo{ border: Some(BorderPreset::None), ..info() });
+        let rows: Vec<_> = (0..buffer.area.height).map(|y| row(&buffer, y)).collect();
+        assert!(rows.iter().all(|row| !row.contains(['┏', '━', '┃'])));
+        assert!(rows.iter().any(|row| row.trim() == "NOTE"));
+    }
+
+    #[test]
+    fn body_width() {
+        let width = |width_percentage, width_columns, frame_width| DrawInfo {
+            width_percentage, 
+            width_columns, 
+            min_width: 0, 
+            ..info()
+        }.body_width(frame_width);
+
+        // percentages are clamped
+        assert_eq!(width(50, None, 80), 40);
+        assert_eq!(width(0, None, 80), 0);
+        assert_eq!(width(0, None, 200), 2);
+        assert_eq!(width(150, None, 80), 80);
+
+        // columns win if the dialog box fits; that is, along with the margin and border
+        assert_eq!(width(50, Some(60), 80), 60);
+        assert_eq!(width(50, Some(72), 80), 72);
+        assert_eq!(width(50, Some(73), 80), 40);
+
+        // the percentage is kept within the minimum and maximum, as long as the dialog box fits
+        let width = |min_width, max_width, frame_width| DrawInfo {
+            min_width, 
+            max_width, 
+            ..info()
+        }.body_width(frame_width);
+        assert_eq!(width(20, None, 80), 40);
+        assert_eq!(width(20, None, 30), 20);
+        assert_eq!(width(30, None, 30), 22);
+        assert_eq!(width(20, Some(60), 250), 60);
+        assert_eq!(width(80, Some(60), 250), 60);
+        assert_eq!(width(20, Some(60), 80), 40);
+    }
+
+    #[test]
+    fn width_limits() {
+        // the width of the dialog box drawn in a frame of given width, including the margin and border
+        let box_width = |info: DrawInfo, frame_width| {
+            let mut terminal = Terminal::new(TestBackend::new(frame_width, 11)).unwrap();
+            terminal
+                .draw(|frame| {
+                    draw_dialog(info, frame, 100);
+                })
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            let top = (0..11)
+                .map(|y| row(&buffer, y))
+                .find(|row| row.contains('┏'))
+                .unwrap();
+            top.trim().chars().count() as u16
+        };
+        let limited = || DrawInfo{ body: "Failed".into(), max_width: Some(60), ..info() };
+        assert_eq!(box_width(limited(), 30), 20 + 8);
+        assert_eq!(box_width(limited(), 80), 40 + 8);
+        assert_eq!(box_width(limited(), 250), 60 + 8);
+
+        // short messages don't wrap on narrow terminals, unless the minimum is lifted
+        let one_line = |min_width| {
+            let mut terminal = Terminal::new(TestBackend::new(30, 11)).unwrap();
+            let info = DrawInfo{ body: "Connection failed".into(), min_width, ..info() };
+            terminal
+                .draw(|frame| {
+                    draw_dialog(info, frame, 100);
+                })
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (0..11).any(|y| row(&buffer, y).contains("Connection failed"))
+        };
+        assert!(one_line(20));
+        assert!(!one_line(0));
+    }
+
+    #[test]
+    fn title_styles() {
+        let title = |title_style| {
+            let buffer = render(DrawInfo{ title_style, ..info() });
+            row(&buffer, 1).trim().trim_matches(['┏', '┓', '━']).to_owned()
+        };
+        assert_eq!(title(None), " NOTE ");
+        assert_eq!(title(Some(TitleStyle::UppercaseSpaced)), " NOTE ");
+        assert_eq!(title(Some(TitleStyle::AsIs)), "Note");
+        assert_eq!(title(Some(TitleStyle::Hidden)), "");
+
+        // the preset overrides the closure
+        let created = |title_style| render(DrawInfo {
+            title_style, 
+            create_title: |title| format!("<{title}>").into(), 
+            ..info()
+        });
+        assert!(row(&created(None), 1).contains("<Note>"));
+        assert!(row(&created(Some(TitleStyle::AsIs)), 1).contains("┏Note━"));
+    }
+
+    #[test]
+    fn title_layout() {
+        // the box spans columns 6 to 33, with the top border on row 1 and the bottom border on row 8
+        let title_column = |info: DrawInfo, y, title: &str| {
+            let buffer = render(info);
+            let row = row(&buffer, y);
+            row.find(title).map(|i| row[..i].chars().count())
+        };
+        let aligned = |title_alignment| DrawInfo{ title_alignment, ..info() };
+        assert_eq!(title_column(info(), 1, " NOTE "), Some(7));
+        assert_eq!(title_column(aligned(Alignment::Center), 1, " NOTE "), Some(17));
+        assert_eq!(title_column(aligned(Alignment::Right), 1, " NOTE "), Some(27));
+
+        // the bottom title is aligned alike
+        let bottom = || DrawInfo{ bottom_title: "esc".into(), title_alignment: Alignment::Right, ..info() };
+        assert_eq!(title_column(bottom(), 8, "esc"), Some(30));
+        assert_eq!(title_column(info(), 8, "esc"), None);
+
+        // an alignment set by the closure takes precedence
+        let created = DrawInfo {
+            title_alignment: Alignment::Right, 
+            create_title: |title| Line::from(title).centered(), 
+            ..info()
+        };
+        assert_eq!(title_column(created, 1, "Note"), Some(18));
+
+        // the title is styled apart from the border
+        let style = Style::new().fg(Color::Red).bold();
+        let buffer = render(DrawInfo{ title_text_style: Some(style), bottom_title: "esc".into(), ..info() });
+        assert_eq!(buffer[(8, 1)].symbol(), "N");
+        assert_eq!(buffer[(8, 1)].fg, Color::Red);
+        assert!(buffer[(8, 1)].modifier.contains(Modifier::BOLD));
+        assert_eq!(buffer[(7, 8)].fg, Color::Red);
+        assert_eq!(buffer[(6, 1)].fg, Color::Cyan);
+    }
+
+    #[test]
+    fn hint_alignment() {
+        let hint_column = |hint_alignment| {
+            let buffer = render(DrawInfo{ hint_alignment, ..info() });
+            (0..buffer.area.height)
+                .find_map(|y| row(&buffer, y).find("Hint"))
+                .unwrap()
+        };
+        let left = hint_column(Alignment::Left);
+        let right = hint_column(Alignment::Right);
+
+        // the inner width is 20, of which 4 are taken by the hint
+        assert_eq!(right - left, 16);
+    }
+
+    #[test]
+    fn anchors() {
+        let frame = Rect::new(0, 0, 40, 11);
+        let place = |anchor: Anchor, offset| anchor.place(frame, [20, 6], offset);
+
+        // odd deltas widen centred boxes, and round their top margin down
+        assert_eq!(place(Anchor::Center, [0, 0]), Rect::new(10, 2, 20, 6));
+        assert_eq!(Anchor::Center.place(frame, [19, 6], [0, 0]), Rect::new(10, 2, 20, 6));
+        assert_eq!(place(Anchor::Top, [0, 0]), Rect::new(10, 0, 20, 6));
+        assert_eq!(place(Anchor::Bottom, [0, 0]), Rect::new(10, 5, 20, 6));
+        assert_eq!(place(Anchor::Left, [0, 0]), Rect::new(0, 2, 20, 6));
+        assert_eq!(place(Anchor::TopRight, [0, 0]), Rect::new(20, 0, 20, 6));
+        assert_eq!(place(Anchor::BottomLeft, [0, 0]), Rect::new(0, 5, 20, 6));
+        assert_eq!(Anchor::Right.place(frame, [19, 6], [0, 0]), Rect::new(21, 2, 19, 6));
+
+        // offsets move the box, which is kept inside the frame
+        assert_eq!(place(Anchor::TopRight, [-2, 1]), Rect::new(18, 1, 20, 6));
+        assert_eq!(place(Anchor::TopRight, [2, -1]), Rect::new(20, 0, 20, 6));
+        assert_eq!(place(Anchor::Center, [-100, 100]), Rect::new(0, 5, 20, 6));
+        assert_eq!(Anchor::BottomRight.place(frame, [50, 20], [0, 0]), frame);
+
+        // the frame need not start at the origin
+        let frame = Rect::new(5, 3, 40, 11);
+        assert_eq!(Anchor::BottomRight.place(frame, [20, 6], [0, 0]), Rect::new(25, 8, 20, 6));
+
+        let buffer = render(DrawInfo{ anchor: Anchor::Top, ..info() });
+        assert!(row(&buffer, 0).contains(" NOTE "));
+        let buffer = render(DrawInfo{ anchor: Anchor::Bottom, offset: [0, -1], ..info() });
+        assert!(row(&buffer, 9).contains('┛'));
+    }
+
+    #[test]
+    fn clipped_body() {
+        let body = (1..=50)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let render = |info: DrawInfo| {
+            let mut terminal = Terminal::new(TestBackend::new(80, 10)).unwrap();
+            terminal
+                .draw(|frame| {
+                    draw_dialog(info, frame, 100);
+                })
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (0..10).map(|y| row(&buffer, y)).collect::<Vec<_>>()
+        };
+        let info = || DrawInfo{ body: body.as_str().into(), max_height_percentage: 100, ..info() };
+
+        // the dialog box fits in the frame, with the rest of the body indicated below
+        let rows = render(info());
+        assert!(rows[0].contains('┏'));
+        assert!(rows[9].contains('┗'));
+        assert!(rows[2].contains("line 1 "));
+        assert!(rows[4].contains("…"));
+        assert!(rows[7].contains("Hint"));
+
+        // scrolling shows a window further into the body, stopping at its end
+        let rows = render(DrawInfo{ scroll: 10, ..info() });
+        assert!(rows[2].contains("…"));
+        assert!(rows[3].contains("line 12 "));
+        assert!(rows[4].contains("…"));
+        let rows = render(DrawInfo{ scroll: 100, ..info() });
+        assert!(rows[2].contains("…"));
+        assert!(rows[4].contains("line 50 "));
+
+        // the maximum height is a percentage of the frame
+        let rows = render(DrawInfo{ max_height_percentage: 80, ..info() });
+        assert!(rows[1].contains('┏'));
+        assert!(rows[8].contains('┗'));
+        let frame = Rect::new(0, 0, 80, 10);
+        assert_eq!(DrawInfo{ max_height_percentage: 80, ..info() }.max_body_height(frame), 1);
+    }
+
+    #[test]
+    fn dim() {
+        let mut buffer = Buffer::with_lines(["abcd", "efgh"]);
+        dim_area(&mut buffer, Rect::new(2, 1, 10, 10));
+        let dimmed: Vec<_> = buffer.area
+            .positions()
+            .filter(|&position| buffer[position].modifier.contains(Modifier::DIM))
+            .collect();
+        assert_eq!(dimmed, [(2, 1).into(), (3, 1).into()]);
+        assert_eq!(buffer[(3, 1)].symbol(), "h");
+    }
+
+    #[test]
+    fn shadow() {
+        let plain = render(info());
+        let shadowed = render(DrawInfo{ shadow: true, ..info() });
+
+        // the box is drawn at columns 6 to 33 and rows 1 to 8, and only the cells of the shadow differ
+        let shade = Style::new().fg(Color::DarkGray).bg(Color::Black);
+        let mut expected = plain.clone();
+        expected.set_style(Rect::new(34, 2, 1, 8), shade);
+        expected.set_style(Rect::new(7, 9, 28, 1), shade);
+        assert_eq!(shadowed, expected);
+        assert_ne!(shadowed, plain);
+
+        // no shadow is drawn when the dialog box touches the edge of the frame
+        let info = || DrawInfo{ anchor: Anchor::BottomRight, ..info() };
+        assert_eq!(render(DrawInfo{ shadow: true, ..info() }), render(info()));
+    }
+
+    /// Closes once it has received a given number of inputs. 
+    struct Countdown {
+        inputs: usize, 
+        close_after: usize, 
+    }
+
+    impl Dialog for Countdown {
+        type Out = usize;
+        type Global = ();
+
+        fn format(&self) -> DrawInfo<'_> {
+            DrawInfo::default()
+        }
+
+        fn input(mut self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+            self.inputs += 1;
+            Signal::Continue(self)
+        }
+
+        fn poll(&mut self) -> Option<usize> {
+            (self.inputs >= self.close_after).then_some(self.inputs)
+        }
+    }
+
+    #[test]
+    fn poll() {
+        // the condition is true from the start, so the dialog closes without blocking on input
+        let ctx = &mut Context::headless(40, 11, script(iter::repeat_n(KeyCode::Char('a'), 0)));
+        assert_eq!(Countdown{ inputs: 0, close_after: 0 }.run_over(&(), ctx), 0);
+
+        // the condition flips after the second input, and the dialog closes before blocking again
+        let ctx = &mut Context::headless(40, 11, script(iter::repeat_n(KeyCode::Char('a'), 2)));
+        assert_eq!(Countdown{ inputs: 0, close_after: 2 }.run_over(&(), ctx), 2);
+        assert!(ctx.script_finished());
+
+        // the condition flips after the second input, but pending input wins
+        let ctx = &mut Context::headless(40, 11, script(iter::repeat_n(KeyCode::Char('a'), 4)));
+        assert_eq!(Countdown{ inputs: 0, close_after: 2 }.run_over(&(), ctx), 4);
+        assert!(ctx.script_finished());
+    }
+
+    /// Dialog asking for confirmation in a nested dialog before closing, counting the times it asked. 
+    struct Closing {
+        asked: usize, 
+    }
+
+    impl Dialog for Closing {
+        type Out = usize;
+        type Global = ();
+
+        fn format(&self) -> DrawInfo<'_> {
+            DrawInfo::default()
+        }
+
+        fn input(mut self, _key: KeyEvent, ctx: &mut Context) -> Signal<Self> {
+            self.asked += 1;
+            match crate::dialog::confirm("Close?", &self, ctx) {
+                true => Signal::Return(self.asked), 
+                false => Signal::Continue(self), 
+            }
+        }
+    }
+
+    #[test]
+    fn nested() {
+        let key = |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        let ctx = &mut Context::headless(40, 11, [key('a'), key('n'), key('a'), key('y')]);
+        assert_eq!(Closing{ asked: 0 }.run_over(&(), ctx), 2);
+        assert!(ctx.script_finished());
+    }
+
+    /// Dialog showing the last size it was resized to, closing on any key. 
+    struct Resized {
+        size: Option<(u16, u16)>, 
+    }
+
+    impl Dialog for Resized {
+        type Out = Option<(u16, u16)>;
+        type Global = ();
+
+        fn format(&self) -> DrawInfo<'_> {
+            DrawInfo {
+                body: format!("{:?}", self.size).into(), 
+                ..Default::default()
+            }
+        }
+
+        fn input(self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+            Signal::Return(self.size)
+        }
+
+        fn resize(self, width: u16, height: u16, _ctx: &mut Context) -> Signal<Self> {
+            Signal::Continue(Resized{ size: Some((width, height)) })
+        }
+    }
+
+    #[test]
+    fn resize() {
+        // resizes reach the dialog through the container, and the dialog is redrawn at the new size
+        let key = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        let ctx = &mut Context::headless(40, 11, [Event::Resize(50, 12), key]);
+        assert_eq!(Resized{ size: None }.run_over(&(), ctx), Some((50, 12)));
+        let screen = ctx.screen();
+        assert_eq!(ctx.buffer().area.width, 50);
+        assert!(screen.contains("Some((50, 12))"));
+    }
+
+    /// Background counting the number of times it is drawn, optionally sleeping to simulate a slow state. 
+    #[derive(Default)]
+    struct Counting {
+        draws: std::cell::Cell<usize>, 
+        delay: std::time::Duration, 
+    }
+
+    impl State for Counting {
+        type Result<T> = T;
+        type Out = ();
+        type Global = ();
+
+        fn draw(&self, frame: &mut Frame) {
+            self.draws.set(self.draws.get() + 1);
+            std::thread::sleep(self.delay);
+            frame.render_widget(Line::from("SVALBARD"), frame.area());
+        }
+
+        fn input(self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+            Signal::Return(())
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "draw-cache", not(feature = "debug-draw")))]
+    fn draw_cache() {
+        let background = Counting::default();
+        let outer = Container::new(Countdown{ inputs: 0, close_after: 1 }, &background);
+        let mut inner = Container::new(Countdown{ inputs: 0, close_after: 1 }, &outer);
+        let mut terminal = Terminal::new(TestBackend::new(40, 11)).unwrap();
+        let draw = |inner: &Container<_, _>, terminal: &mut Terminal<_>| terminal
+            .draw(|frame| inner.draw(frame))
+            .unwrap()
+            .buffer
+            .clone();
+
+        // repeated draws are served from the cache, and are identical to the first
+        let first = draw(&inner, &mut terminal);
+        for _ in 0..3 {
+            assert_eq!(draw(&inner, &mut terminal), first);
+        }
+        assert_eq!(background.draws.get(), 1);
+
+        // input to the inner dialog redraws it, but not the unchanged layers beneath it
+        let ctx = &mut Context::headless(40, 11, []);
+        let key = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE);
+        inner = match inner.input(key, ctx) {
+            Signal::Continue(inner) => inner, 
+            Signal::Return(_) => unreachable!(), 
+        };
+        draw(&inner, &mut terminal);
+        assert_eq!(background.draws.get(), 1);
+
+        // resizing redraws everything
+        terminal.backend_mut().resize(50, 11);
+        terminal.resize(ratatui::layout::Rect::new(0, 0, 50, 11)).unwrap();
+        let resized = draw(&inner, &mut terminal);
+        assert_eq!(background.draws.get(), 2);
+        assert_eq!(resized.area.width, 50);
+        assert!(row(&resized, 0).starts_with("SVALBARD"));
+    }
+
+    /// Measures drawing a chain of two nested dialogs over a slow background. Run with
+    /// `cargo test --release -- --ignored --nocapture`, with and without `--no-default-features`, to compare
+    /// the timings with and without the `draw-cache` feature. 
+    #[test]
+    #[ignore = "benchmark"]
+    fn bench_nested_draw() {
+        const FRAMES: u32 = 100;
+
+        let background = Counting {
+            delay: std::time::Duration::from_millis(2), 
+            ..Default::default()
+        };
+        let outer = Container::new(Countdown{ inputs: 0, close_after: 1 }, &background);
+        let inner = Container::new(Countdown{ inputs: 0, close_after: 1 }, &outer);
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+
+        let start = std::time::Instant::now();
+        for _ in 0..FRAMES {
+            terminal
+                .draw(|frame| inner.draw(frame))
+                .unwrap();
+        }
+        let elapsed = start.elapsed();
+        println!(
+            "{FRAMES} frames in {elapsed:?} ({:?} per frame); background drawn {} times", 
+            elapsed / FRAMES, 
+            background.draws.get(), 
+        );
+    }
+
+    /// Closes on any key, with open and close animations of two steps each. 
+    struct Animated;
+
+    impl Dialog for Animated {
+        type Out = ();
+        type Global = ();
+
+        fn format(&self) -> DrawInfo<'_> {
+            let spec = AnimationSpec {
+                frame_time: std::time::Duration::ZERO, 
+                ..Default::default()
+            };
+            DrawInfo {
+                animate_open: Some(spec.clone()), 
+                animate_close: Some(spec), 
+                ..Default::default()
+            }
+        }
+
+        fn input(self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+            Signal::Return(())
+        }
+    }
+
+    #[test]
+    fn animations() {
+        let background_draws = |enabled: bool| {
+            let ctx = &mut Context::headless(40, 11, script(iter::repeat_n(KeyCode::Char('a'), 1)));
+            ctx.set_animations(enabled);
+            let background = Counting::default();
+            Animated.run_over(&background, ctx);
+            background.draws.get()
+        };
+        // two frames growing the dialog, the dialog itself, and two frames shrinking it
+        assert_eq!(background_draws(true), 5);
+        assert_eq!(background_draws(false), 1);
+
+        // the setting is shared by chained contexts
+        let ctx = Context::headless(40, 11, []);
+        ctx.chain_without_global().set_animations(false);
+        assert!(!ctx.animations());
+    }
+}
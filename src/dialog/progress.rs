@@ -0,0 +1,651 @@
+//! Defines dialogs shown while work runs in the background. 
+
+use std::{
+    sync::{atomic::{AtomicBool, Ordering}, mpsc::{self, Receiver, Sender}, Arc}, 
+    thread::{self, ScopedJoinHandle}, 
+    time::{Duration, Instant}, 
+};
+#[cfg(feature = "tokio")]
+use std::{cell::Cell, future::Future, rc::Rc};
+use super::*;
+
+/// Options of a progress dialog shown with [`dialog::progress_with`]. 
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct ProgressOptions {
+    /// Whether the user may cancel the work by pressing `esc`, which the work observes through
+    /// [`ProgressHandle::is_cancelled`]. `Esc` is ignored otherwise. Default: `false`. 
+    pub cancellable: bool, 
+}
+
+/// Displays a blue dialog showing the progress of some work, e.g. copying files, while the work runs on
+/// another thread. The dialog closes once the work returns, giving what it returned. 
+/// 
+/// The work is given a [`ProgressHandle`] through which it reports its progress. The dialog cannot be
+/// closed by the user; see [`dialog::progress_with`] to let the user cancel the work. 
+/// 
+/// 
+/// # Panics
+/// 
+/// If the work panics, the panic is resumed on the calling thread once the dialog has closed. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::prelude::*;
+/// 
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// # let files = ["a.txt", "b.txt"];
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// // let files: &[&str]
+/// let copied = dialog::progress("Copying", current_state, ctx, |progress| {
+///     for (i, file) in files.iter().enumerate() {
+///         progress.message(format!("Copying {file}..."));
+///         progress.set(i as f64 / files.len() as f64);
+///         // copy the file
+///     }
+///     progress.finish();
+///     files.len()
+/// });
+/// ```
+pub fn progress<T: Send, G>(
+    title: impl AsRef<str>, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+    work: impl FnOnce(ProgressHandle) -> T + Send, 
+) -> T {
+    progress_with(title, ProgressOptions::default(), over, ctx, work)
+}
+
+/// Same as [`dialog::progress`], but with custom [options](ProgressOptions). 
+/// 
+/// If the work is cancellable and the user presses `esc`, the dialog keeps showing until the work notices
+/// the cancellation and returns. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, dialog::ProgressOptions};
+/// 
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// # let chunks: Vec<Vec<u8>> = Vec::new();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// // let chunks: Vec<Vec<u8>>
+/// let options = ProgressOptions{ cancellable: true };
+/// let checksum = dialog::progress_with("Hashing", options, current_state, ctx, |progress| {
+///     let mut checksum = 0u8;
+///     for (i, chunk) in chunks.iter().enumerate() {
+///         if progress.is_cancelled() {
+///             return None
+///         }
+///         checksum = chunk.iter().fold(checksum, |sum, byte| sum.wrapping_add(*byte));
+///         progress.set((i + 1) as f64 / chunks.len() as f64);
+///     }
+///     Some(checksum)
+/// });
+/// ```
+pub fn progress_with<T: Send, G>(
+    title: impl AsRef<str>, 
+    options: ProgressOptions, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+    work: impl FnOnce(ProgressHandle) -> T + Send, 
+) -> T {
+    let (sender, receiver) = mpsc::channel();
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let handle = ProgressHandle {
+        sender, 
+        cancelled: Arc::clone(&cancelled), 
+    };
+    thread::scope(|scope| {
+        let dialog = Progress {
+            title: title.as_ref(), 
+            fraction: 0.0, 
+            message: String::new(), 
+            receiver, 
+            worker: Some(Worker::Thread(scope.spawn(move || work(handle)))), 
+            cancelled: options.cancellable.then_some(cancelled), 
+        };
+        let defaults = ctx.dialog_theme().all;
+        Themed{ dialog, defaults }.run_over(over, &mut ctx.chain_without_global())
+    })
+    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+}
+
+/// Displays a blue dialog with a spinner while some work without measurable progress, e.g. a network
+/// request, runs on another thread. The dialog closes once the work returns, giving what it returned. 
+/// 
+/// Keys pressed while the work runs are discarded, and the dialog cannot be closed by the user. See
+/// [`dialog::progress`] for work that can report its progress. 
+/// 
+/// 
+/// # Panics
+/// 
+/// If the work panics, the panic is resumed on the calling thread once the dialog has closed. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::prelude::*;
+/// 
+/// # fn fetch_weather() -> String { String::new() }
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// let weather = dialog::busy("Fetching the forecast...", current_state, ctx, fetch_weather);
+/// ```
+pub fn busy<T: Send, G>(
+    msg: impl AsRef<str>, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+    work: impl FnOnce() -> T + Send, 
+) -> T {
+    thread::scope(|scope| {
+        let dialog = Busy {
+            msg: msg.as_ref(), 
+            started: Instant::now(), 
+            worker: Some(Worker::Thread(scope.spawn(work))), 
+        };
+        let defaults = ctx.dialog_theme().all;
+        Themed{ dialog, defaults }.run_over(over, &mut ctx.chain_without_global())
+    })
+    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+}
+
+/// Same as [`dialog::progress_with`], but the work is a future, which is awaited alongside the dialog
+/// without blocking the thread. This is meant for [asynchronous states](crate::StateAsync), and requires the
+/// `tokio` feature. 
+/// 
+/// Since the work runs on the same task as the dialog, it must not block the thread itself. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use std::time::Duration;
+/// use tundra::{prelude::*, dialog::ProgressOptions};
+/// 
+/// # async fn upload(_chunk: usize) {}
+/// # async fn example() {
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// let options = ProgressOptions{ cancellable: true };
+/// let uploaded = dialog::progress_async("Uploading", options, current_state, ctx, |progress| async move {
+///     for chunk in 0..10 {
+///         if progress.is_cancelled() {
+///             return chunk
+///         }
+///         upload(chunk).await;
+///         progress.set(chunk as f64 / 10.0);
+///     }
+///     10
+/// })
+/// .await;
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn progress_async<T, F, G>(
+    title: impl AsRef<str>, 
+    options: ProgressOptions, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+    work: impl FnOnce(ProgressHandle) -> F, 
+) -> T
+where
+    F: Future<Output = T>, 
+{
+    let (sender, receiver) = mpsc::channel();
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let handle = ProgressHandle {
+        sender, 
+        cancelled: Arc::clone(&cancelled), 
+    };
+    let output = Rc::new(Cell::new(None));
+    let dialog = Progress {
+        title: title.as_ref(), 
+        fraction: 0.0, 
+        message: String::new(), 
+        receiver, 
+        worker: Some(Worker::Future(Rc::clone(&output))), 
+        cancelled: options.cancellable.then_some(cancelled), 
+    };
+    let defaults = ctx.dialog_theme().all;
+    let ctx = &mut ctx.chain_without_global();
+    let dialog = Themed{ dialog, defaults }.run_over_async(over, ctx);
+    let (out, ()) = tokio::join!(dialog, async { output.set(Some(work(handle).await)) });
+    out.unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+}
+
+/// Same as [`dialog::busy`], but the work is a future, which is awaited alongside the dialog without
+/// blocking the thread. This is meant for [asynchronous states](crate::StateAsync), and requires the `tokio`
+/// feature. 
+/// 
+/// Since the work runs on the same task as the dialog, it must not block the thread itself. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::prelude::*;
+/// 
+/// # async fn fetch_weather() -> String { String::new() }
+/// # async fn example() {
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// let weather = dialog::busy_async("Fetching the forecast...", current_state, ctx, fetch_weather()).await;
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn busy_async<T, G>(
+    msg: impl AsRef<str>, 
+    over: &impl State, 
+    ctx: &mut Context<G>, 
+    work: impl Future<Output = T>, 
+) -> T {
+    let output = Rc::new(Cell::new(None));
+    let dialog = Busy {
+        msg: msg.as_ref(), 
+        started: Instant::now(), 
+        worker: Some(Worker::Future(Rc::clone(&output))), 
+    };
+    let defaults = ctx.dialog_theme().all;
+    let ctx = &mut ctx.chain_without_global();
+    let dialog = Themed{ dialog, defaults }.run_over_async(over, ctx);
+    let (out, ()) = tokio::join!(dialog, async { output.set(Some(work.await)) });
+    out.unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+}
+
+/// The work shown by a dialog. 
+enum Worker<'scope, T> {
+    /// Work running on a scoped thread. 
+    Thread(ScopedJoinHandle<'scope, T>), 
+    /// Work running as a future alongside the dialog, which stores its output here once it completes. 
+    #[cfg(feature = "tokio")]
+    Future(Rc<Cell<Option<T>>>), 
+}
+
+/// Gives the result of the work once it has returned, taking the worker. The result is an error if the work
+/// panicked, which is resumed by the caller once the dialog has closed. 
+fn join_finished<T>(worker: &mut Option<Worker<T>>) -> Option<thread::Result<T>> {
+    match worker.take()? {
+        Worker::Thread(handle) if handle.is_finished() => Some(handle.join()), 
+        #[cfg(feature = "tokio")]
+        Worker::Future(output) => match output.take() {
+            Some(out) => Some(Ok(out)), 
+            None => {
+                *worker = Some(Worker::Future(output));
+                None
+            }
+        }, 
+        unfinished => {
+            *worker = Some(unfinished);
+            None
+        }
+    }
+}
+
+/// Handle given to the work of a [progress dialog](dialog::progress) for reporting its progress. 
+/// 
+/// The handle may be cloned to report progress from several threads. Reports sent after the dialog has
+/// closed are ignored. 
+#[derive(Clone, Debug)]
+pub struct ProgressHandle {
+    sender: Sender<Update>, 
+    cancelled: Arc<AtomicBool>, 
+}
+
+impl ProgressHandle {
+    /// Sets the fraction of the work that is done, from `0.0` to `1.0`, clamped to that range. 
+    pub fn set(&self, fraction: f64) {
+        let _ = self.sender.send(Update::Fraction(fraction));
+    }
+
+    /// Sets the message shown above the progress bar, e.g. what is currently being worked on. 
+    pub fn message(&self, message: impl Into<String>) {
+        let _ = self.sender.send(Update::Message(message.into()));
+    }
+
+    /// Marks the work as done, filling the progress bar, e.g. before some final cleanup. The dialog closes
+    /// once the work returns, whether or not this is called. 
+    pub fn finish(&self) {
+        self.set(1.0)
+    }
+
+    /// Whether the user has cancelled the work. The work should check this regularly and return early once
+    /// cancelled. Always `false` unless the dialog was shown with [`ProgressOptions::cancellable`]. 
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Report sent from a [`ProgressHandle`] to the dialog. 
+#[derive(Debug)]
+enum Update {
+    Fraction(f64), 
+    Message(String), 
+}
+
+/// Dialog showing the progress of some work. 
+struct Progress<'a, 'scope, T> {
+    title: &'a str, 
+    /// The fraction of the work that is done, from `0.0` to `1.0`. 
+    fraction: f64, 
+    message: String, 
+    receiver: Receiver<Update>, 
+    /// Taken once the work has returned. 
+    worker: Option<Worker<'scope, T>>, 
+    /// The flag shared with the handle if the work is cancellable. 
+    cancelled: Option<Arc<AtomicBool>>, 
+}
+
+impl<T> Progress<'_, '_, T> {
+    /// Whether the user has cancelled the work. 
+    fn is_cancelled(&self) -> bool {
+        self.cancelled
+            .as_ref()
+            .is_some_and(|cancelled| cancelled.load(Ordering::Relaxed))
+    }
+
+    /// Cancels the work if it is cancellable. 
+    fn cancel(&self) {
+        if let Some(cancelled) = &self.cancelled {
+            cancelled.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Formats a bar of given width filled to the current fraction, followed by the percentage. 
+    fn bar(&self, width: usize) -> Line<'_> {
+        let percentage = format!(" {:>3}%", (self.fraction * 100.0).floor() as u8);
+        let width = width.saturating_sub(percentage.len());
+        let filled = (self.fraction * width as f64).round() as usize;
+        Line::from(vec![
+            "█".repeat(filled).into(), 
+            "░".repeat(width - filled).dim(), 
+            percentage.into(), 
+        ])
+    }
+}
+
+impl<T> Dialog for Progress<'_, '_, T> {
+    type Out = thread::Result<T>;
+    type Global = ();
+    const POLL_RATE: Option<Duration> = Some(Duration::from_millis(50));
+
+    fn format(&self) -> DrawInfo<'_> {
+        self.format_in(Rect::new(0, 0, 80, 24))
+    }
+
+    fn format_in(&self, area: Rect) -> DrawInfo<'_> {
+        let hint = match (&self.cancelled, self.is_cancelled()) {
+            (None, _) => "Please wait...".into(), 
+            (Some(_), false) => Hints::new().esc("cancel").into(), 
+            (Some(_), true) => "Cancelling...".into(), 
+        };
+        let info = DrawInfo {
+            title: self.title.into(), 
+            color: Color::Cyan, 
+            hint, 
+            ..Default::default()
+        };
+        let bar = self.bar(info.body_width(area.width) as usize);
+        let body: Vec<Line> = match self.message.is_empty() {
+            true => vec![bar], 
+            false => vec![self.message.as_str().into(), Line::default(), bar], 
+        };
+        DrawInfo{ body: body.into(), ..info }
+    }
+
+    fn input(self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        if key.code == KeyCode::Esc {
+            self.cancel();
+        }
+        Signal::Continue(self)
+    }
+
+    fn poll(&mut self) -> Option<Self::Out> {
+        // joined before draining the reports, so that none sent just before the work returned are missed
+        let out = join_finished(&mut self.worker);
+        for update in self.receiver.try_iter() {
+            match update {
+                Update::Fraction(fraction) if !fraction.is_nan() => self.fraction = fraction.clamp(0.0, 1.0), 
+                Update::Fraction(_) => (), 
+                Update::Message(message) => self.message = message, 
+            }
+        }
+        out
+    }
+
+    /// The work cannot be abandoned, so it is cancelled if possible and the dialog stays open until it
+    /// returns. 
+    fn exit(self) -> Signal<Self> {
+        self.cancel();
+        Signal::Continue(self)
+    }
+}
+
+/// Dialog showing a spinner while some work runs. 
+struct Busy<'a, 'scope, T> {
+    msg: &'a str, 
+    /// When the work started, which the spinner is animated from. 
+    started: Instant, 
+    /// Taken once the work has returned. 
+    worker: Option<Worker<'scope, T>>, 
+}
+
+impl<T> Busy<'_, '_, T> {
+    /// The frames of the spinner, one of which is shown per [poll rate](Dialog::POLL_RATE). 
+    const FRAMES: [&'static str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+    /// The frame of the spinner shown once given time has elapsed. 
+    fn frame(elapsed: Duration) -> &'static str {
+        let rate = Self::POLL_RATE.expect("The dialog has a poll rate");
+        let index = elapsed.as_millis() / rate.as_millis();
+        Self::FRAMES[index as usize % Self::FRAMES.len()]
+    }
+}
+
+impl<T> Dialog for Busy<'_, '_, T> {
+    type Out = thread::Result<T>;
+    type Global = ();
+    const POLL_RATE: Option<Duration> = Some(Duration::from_millis(100));
+
+    fn format(&self) -> DrawInfo<'_> {
+        let frame = Self::frame(self.started.elapsed());
+        DrawInfo {
+            title: "Busy".into(), 
+            color: Color::Cyan, 
+            body: format!("{frame} {}", self.msg).into(), 
+            hint: "Please wait...".into(), 
+            ..Default::default()
+        }
+    }
+
+    fn input(self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        Signal::Continue(self)
+    }
+
+    fn poll(&mut self) -> Option<Self::Out> {
+        join_finished(&mut self.worker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::{atomic::AtomicBool, mpsc, Arc}, thread};
+    use crate::{prelude::*, context::script, crossterm::event::Event, dialog::{Dialog, DrawInfo}};
+    use super::{Busy, Progress, ProgressHandle, ProgressOptions, Worker};
+
+    fn text(info: &DrawInfo) -> String {
+        info.body.lines
+            .iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn updates() {
+        let (sender, receiver) = mpsc::channel();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let handle = ProgressHandle{ sender, cancelled: Arc::clone(&cancelled) };
+        let (proceed, wait) = mpsc::channel();
+        let (reported, report) = mpsc::channel();
+        // moved into the scope, so that the work isn't left waiting if an assertion fails
+        thread::scope(move |scope| {
+            let mut dialog = Progress {
+                title: "Copying", 
+                fraction: 0.0, 
+                message: String::new(), 
+                receiver, 
+                worker: Some(Worker::Thread(scope.spawn(move || {
+                    handle.message("Copying a.txt...");
+                    handle.set(0.5);
+                    reported.send(()).unwrap();
+                    let _ = wait.recv();
+                    handle.set(f64::NAN);
+                    handle.set(2.0);
+                    42
+                }))), 
+                cancelled: None, 
+            };
+            let info = dialog.format();
+            assert_eq!(text(&info), format!("{}   0%", "░".repeat(35)));
+            assert_eq!(info.hint, "Please wait...");
+
+            // the reports are shown once the dialog is polled
+            report.recv().unwrap();
+            assert!(dialog.poll().is_none());
+            let bar = format!("{}{}  50%", "█".repeat(18), "░".repeat(17));
+            assert_eq!(text(&dialog.format()), format!("Copying a.txt...\n\n{bar}"));
+
+            // esc is ignored since the work is not cancellable
+            let ctx = &mut Context::headless(80, 24, []);
+            let Signal::Continue(mut dialog) = Dialog::input(dialog, KeyCode::Esc.into(), ctx) else {
+                panic!()
+            };
+            assert!(!cancelled.load(std::sync::atomic::Ordering::Relaxed));
+
+            // the fraction is clamped, and the dialog gives what the work returned once it has returned
+            proceed.send(()).unwrap();
+            let out = loop {
+                if let Some(out) = dialog.poll() {
+                    break out
+                }
+            };
+            assert_eq!(out.unwrap(), 42);
+            assert_eq!(dialog.fraction, 1.0);
+        });
+    }
+
+    #[test]
+    fn progress() {
+        let ctx = &mut Context::headless(80, 24, []);
+        let out = dialog::progress("Hashing", &(), ctx, |progress| {
+            progress.set(0.25);
+            assert!(!progress.is_cancelled());
+            "done"
+        });
+        assert_eq!(out, "done");
+    }
+
+    #[test]
+    fn cancel() {
+        // the dialog stays open until the work notices the cancellation
+        let esc = Event::Key(KeyCode::Esc.into());
+        let ctx = &mut Context::headless(80, 24, [esc]);
+        let options = ProgressOptions{ cancellable: true };
+        let out = dialog::progress_with("Hashing", options, &(), ctx, |progress| {
+            while !progress.is_cancelled() {
+                thread::yield_now();
+            }
+            None::<u8>
+        });
+        assert_eq!(out, None);
+        assert!(ctx.script_finished());
+        let screen = ctx.screen();
+        assert!(screen.contains("Cancelling..."));
+    }
+
+    #[test]
+    #[should_panic(expected = "Disk on fire")]
+    fn panic() {
+        let ctx = &mut Context::headless(80, 24, []);
+        dialog::progress("Copying", &(), ctx, |_| panic!("Disk on fire"));
+    }
+
+    #[test]
+    fn busy() {
+        // keys pressed while the work runs are discarded
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc, KeyCode::Enter]));
+        let out = dialog::busy("Fetching...", &(), ctx, || {
+            thread::sleep(std::time::Duration::from_millis(10));
+            42
+        });
+        assert_eq!(out, 42);
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    #[should_panic(expected = "Connection on fire")]
+    fn busy_panic() {
+        let ctx = &mut Context::headless(80, 24, []);
+        dialog::busy("Fetching...", &(), ctx, || panic!("Connection on fire"));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn busy_async() {
+        // keys pressed while the work is awaited are discarded
+        let ctx = &mut Context::headless(80, 24, script([KeyCode::Esc, KeyCode::Enter]));
+        let work = async {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            42
+        };
+        let out = dialog::busy_async("Fetching...", &(), ctx, work).await;
+        assert_eq!(out, 42);
+        assert!(ctx.script_finished());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn cancel_async() {
+        // the dialog stays open until the work notices the cancellation
+        let esc = Event::Key(KeyCode::Esc.into());
+        let ctx = &mut Context::headless(80, 24, [esc]);
+        let options = ProgressOptions{ cancellable: true };
+        let out = dialog::progress_async("Hashing", options, &(), ctx, |progress| async move {
+            progress.set(0.25);
+            while !progress.is_cancelled() {
+                tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+            }
+            None::<u8>
+        })
+        .await;
+        assert_eq!(out, None);
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn spinner() {
+        use std::time::Duration;
+
+        // the frame advances once per poll rate, wrapping around
+        let frame = |millis| Busy::<()>::frame(Duration::from_millis(millis));
+        assert_eq!(frame(0), "⠋");
+        assert_eq!(frame(99), "⠋");
+        assert_eq!(frame(100), "⠙");
+        assert_eq!(frame(950), "⠏");
+        assert_eq!(frame(1000), "⠋");
+    }
+}
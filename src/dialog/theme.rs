@@ -0,0 +1,385 @@
+use std::borrow::Cow;
+use ratatui::{layout::Rect, style::{Color, Style, Stylize}};
+use crate::prelude::*;
+use super::{Anchor, BorderPreset, Dialog, DrawInfo, TitleStyle};
+
+/// Overrides of how a kind of dialog is drawn, as part of a [`Theme`]. Overrides that are `None` keep what
+/// the dialog gives in its [`DrawInfo`]. 
+/// 
+/// 
+/// # Examples
+/// 
+/// To draw forms green with rounded borders: 
+/// ```no_run
+/// use tundra::{prelude::*, dialog::{BorderPreset, DrawInfoDefaults, Theme}};
+/// use tundra::ratatui::style::Color;
+/// 
+/// # let ctx = &mut Context::new().unwrap();
+/// // let ctx: &mut Context<_>
+/// ctx.set_dialog_theme(Theme {
+///     form: DrawInfoDefaults {
+///         color: Some(Color::Green),
+///         border: Some(BorderPreset::Rounded),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct DrawInfoDefaults {
+    /// Overrides [`DrawInfo::color`]. Default: `None`. 
+    pub color: Option<Color>, 
+    /// Overrides [`DrawInfo::hint`]. Default: `None`. 
+    pub hint: Option<Cow<'static, str>>, 
+    /// Overrides [`DrawInfo::hint_style`]. Default: `None`. 
+    pub hint_style: Option<Style>, 
+    /// Overrides [`DrawInfo::border`]. Default: `None`. 
+    pub border: Option<BorderPreset>, 
+    /// Overrides [`DrawInfo::title_style`]. Default: `None`. 
+    pub title_style: Option<TitleStyle>, 
+    /// Overrides [`DrawInfo::width_percentage`]. Default: `None`. 
+    pub width_percentage: Option<u8>, 
+    /// Overrides [`DrawInfo::width_columns`]. Default: `None`. 
+    pub width_columns: Option<u16>, 
+    /// Overrides [`DrawInfo::anchor`]. Default: `None`. 
+    pub anchor: Option<Anchor>, 
+    /// Overrides [`DrawInfo::offset`]. Default: `None`. 
+    pub offset: Option<[i16; 2]>, 
+    /// Overrides [`DrawInfo::dim_background`]. Default: `None`. 
+    pub dim_background: Option<bool>, 
+    /// Overrides [`DrawInfo::shadow`]. Default: `None`. 
+    pub shadow: Option<bool>, 
+}
+
+impl DrawInfoDefaults {
+    /// Applies the overrides to given draw info. 
+    pub fn apply<'a>(&self, info: DrawInfo<'a>) -> DrawInfo<'a> {
+        DrawInfo {
+            color: self.color.unwrap_or(info.color), 
+            hint: self.hint.clone().unwrap_or(info.hint), 
+            hint_style: self.hint_style.unwrap_or(info.hint_style), 
+            border: self.border.or(info.border), 
+            title_style: self.title_style.or(info.title_style), 
+            width_percentage: self.width_percentage.unwrap_or(info.width_percentage), 
+            width_columns: self.width_columns.or(info.width_columns), 
+            anchor: self.anchor.unwrap_or(info.anchor), 
+            offset: self.offset.unwrap_or(info.offset), 
+            dim_background: self.dim_background.unwrap_or(info.dim_background), 
+            shadow: self.shadow.unwrap_or(info.shadow), 
+            ..info
+        }
+    }
+
+    /// Combines two sets of overrides, taking each override from `self` if given, and from `other`
+    /// otherwise. 
+    pub fn or(self, other: DrawInfoDefaults) -> DrawInfoDefaults {
+        DrawInfoDefaults {
+            color: self.color.or(other.color), 
+            hint: self.hint.or(other.hint), 
+            hint_style: self.hint_style.or(other.hint_style), 
+            border: self.border.or(other.border), 
+            title_style: self.title_style.or(other.title_style), 
+            width_percentage: self.width_percentage.or(other.width_percentage), 
+            width_columns: self.width_columns.or(other.width_columns), 
+            anchor: self.anchor.or(other.anchor), 
+            offset: self.offset.or(other.offset), 
+            dim_background: self.dim_background.or(other.dim_background), 
+            shadow: self.shadow.or(other.shadow), 
+        }
+    }
+}
+
+/// Application-wide overrides of how the library provided dialogs are drawn, e.g. to match the colours and
+/// wording of the application without repeating them at each call site. The theme is installed with
+/// [`Context::set_dialog_theme`], and is shared between [chained](Context#chaining-with-new-globals)
+/// contexts. The default theme overrides nothing, and draws the dialogs as if no theme was installed. 
+/// 
+/// The overrides for a kind of dialog, e.g. [`Theme::info`], take precedence over [`Theme::all`]. Custom
+/// dialogs are not affected by the theme. 
+/// 
+/// 
+/// # Examples
+/// 
+/// To draw all dialogs with rounded borders, info dialogs magenta, and the name of the focused field of forms
+/// in magenta: 
+/// ```no_run
+/// use tundra::{prelude::*, dialog::{BorderPreset, DrawInfoDefaults, FieldStyles, Theme}};
+/// use tundra::ratatui::style::{Color, Style};
+/// 
+/// # let ctx = &mut Context::new().unwrap();
+/// // let ctx: &mut Context<_>
+/// ctx.set_dialog_theme(Theme {
+///     all: DrawInfoDefaults {
+///         border: Some(BorderPreset::Rounded), 
+///         ..Default::default()
+///     }, 
+///     info: DrawInfoDefaults {
+///         color: Some(Color::Magenta), 
+///         ..Default::default()
+///     }, 
+///     fields: FieldStyles {
+///         focused: Style::new().fg(Color::Magenta), 
+///         ..Default::default()
+///     }, 
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Theme {
+    /// Applies to all library provided dialogs, unless overridden for their kind below. 
+    pub all: DrawInfoDefaults, 
+    /// Applies to [`dialog::info`]. 
+    pub info: DrawInfoDefaults, 
+    /// Applies to [`dialog::warning`]. 
+    pub warning: DrawInfoDefaults, 
+    /// Applies to [`dialog::error`], [`dialog::fatal`], [`dialog::fatal_over`], and the error messages shown
+    /// by forms when validation fails. The first three [dim the background](DrawInfo::dim_background) unless
+    /// overridden here. 
+    pub error: DrawInfoDefaults, 
+    /// Applies to [`dialog::confirm`], [`dialog::confirm_with`], and [`dialog::confirm3`]. 
+    pub confirm: DrawInfoDefaults, 
+    /// Applies to forms, both those shown by [`dialog::form!`] and by [`dialog::FormBuilder`]. Individual
+    /// forms may override the theme further with the `theme` metadatum. 
+    pub form: DrawInfoDefaults, 
+    /// How the fields of forms are drawn. 
+    pub fields: FieldStyles, 
+}
+
+impl Theme {
+    /// Folds the overrides for [all](Theme::all) dialogs into those of each kind of dialog, giving the
+    /// overrides that apply to each kind. An override given for a kind takes precedence over that for all
+    /// dialogs. 
+    pub fn resolve(self) -> Theme {
+        let Theme{ all, info, warning, error, confirm, form, fields } = self;
+        Theme {
+            info: info.or(all.clone()), 
+            warning: warning.or(all.clone()), 
+            error: error.or(all.clone()), 
+            confirm: confirm.or(all.clone()), 
+            form: form.or(all.clone()), 
+            all, 
+            fields, 
+        }
+    }
+}
+
+/// Styles of the fields of forms, as part of a [`Theme`]. The styles are patched onto the name of the field. 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct FieldStyles {
+    /// Style of the name of the focused field. Default: bold. 
+    pub focused: Style, 
+    /// Style of the name of an invalid field, and of its error message. Default: red. 
+    pub error: Style, 
+}
+
+impl Default for FieldStyles {
+    fn default() -> Self {
+        Self {
+            focused: Style::new().bold(), 
+            error: Style::new().red(), 
+        }
+    }
+}
+
+/// Wraps a dialog, applying a set of overrides to how it is drawn. 
+pub(crate) struct Themed<T> {
+    pub dialog: T, 
+    pub defaults: DrawInfoDefaults, 
+}
+
+impl<T: Dialog> Themed<T> {
+    /// Updates the wrapped dialog, keeping the overrides if it stays open. 
+    fn update(self, f: impl FnOnce(T) -> Signal<T>) -> Signal<Self> {
+        let Themed{ dialog, defaults } = self;
+        match f(dialog) {
+            Signal::Return(out) => Signal::Return(out), 
+            Signal::Continue(dialog) => Signal::Continue(Themed{ dialog, defaults }), 
+        }
+    }
+}
+
+impl<T: Dialog> Dialog for Themed<T> {
+    type Out = T::Out;
+    type Global = T::Global;
+    const POLL_RATE: Option<std::time::Duration> = T::POLL_RATE;
+
+    fn format(&self) -> DrawInfo<'_> {
+        self.defaults.apply(self.dialog.format())
+    }
+
+    fn format_in(&self, area: Rect) -> DrawInfo<'_> {
+        self.defaults.apply(self.dialog.format_in(area))
+    }
+
+    fn input(self, key: KeyEvent, ctx: &mut Context<T::Global>) -> Signal<Self> {
+        self.update(|dialog| dialog.input(key, ctx))
+    }
+
+    fn paste(self, text: &str) -> Signal<Self> {
+        self.update(|dialog| dialog.paste(text))
+    }
+
+    fn resize(self, width: u16, height: u16, ctx: &mut Context<T::Global>) -> Signal<Self> {
+        self.update(|dialog| dialog.resize(width, height, ctx))
+    }
+
+    fn focus_changed(self, focused: bool, ctx: &mut Context<T::Global>) -> Signal<Self> {
+        self.update(|dialog| dialog.focus_changed(focused, ctx))
+    }
+
+    fn poll(&mut self) -> Option<Self::Out> {
+        self.dialog.poll()
+    }
+
+    fn exit(self) -> Signal<Self> {
+        self.update(Dialog::exit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Style, Stylize};
+    use crate::{prelude::*, crossterm::event::Event, field::Textbox};
+    use crate::dialog::{Anchor, BorderPreset, DrawInfo, TitleStyle};
+    use super::{DrawInfoDefaults, FieldStyles, Theme};
+
+    #[test]
+    fn apply() {
+        let info = DrawInfo {
+            title: "Info".into(), 
+            color: Color::Cyan, 
+            body: "Hello".into(), 
+            hint: "Press any key to close...".into(), 
+            ..Default::default()
+        };
+
+        // no overrides leave the draw info untouched
+        assert_eq!(DrawInfoDefaults::default().apply(info.clone()), info);
+
+        let defaults = DrawInfoDefaults {
+            color: Some(Color::Green), 
+            hint: Some("Any key closes".into()), 
+            hint_style: Some(Style::new().dim()), 
+            border: Some(BorderPreset::Rounded), 
+            title_style: Some(TitleStyle::AsIs), 
+            width_percentage: Some(80), 
+            width_columns: Some(72), 
+            anchor: Some(Anchor::Top), 
+            offset: Some([0, 1]), 
+            dim_background: Some(true), 
+            shadow: Some(true), 
+        };
+        let expected = DrawInfo {
+            color: Color::Green, 
+            hint: "Any key closes".into(), 
+            hint_style: Style::new().dim(), 
+            border: Some(BorderPreset::Rounded), 
+            title_style: Some(TitleStyle::AsIs), 
+            width_percentage: 80, 
+            width_columns: Some(72), 
+            anchor: Anchor::Top, 
+            offset: [0, 1], 
+            dim_background: true, 
+            shadow: true, 
+            ..info.clone()
+        };
+        assert_eq!(defaults.apply(info), expected);
+    }
+
+    #[test]
+    fn or() {
+        let color = DrawInfoDefaults{ color: Some(Color::Green), ..Default::default() };
+        let both = DrawInfoDefaults {
+            color: Some(Color::Red), 
+            border: Some(BorderPreset::Plain), 
+            ..Default::default()
+        };
+        let combined = color.or(both);
+        assert_eq!(combined.color, Some(Color::Green));
+        assert_eq!(combined.border, Some(BorderPreset::Plain));
+        assert_eq!(combined.hint, None);
+    }
+
+    #[test]
+    fn context() {
+        let esc = || Event::Key(KeyCode::Esc.into());
+        let ctx = &mut Context::headless(80, 24, [esc(), esc()]);
+        ctx.set_dialog_theme(Theme {
+            info: DrawInfoDefaults {
+                color: Some(Color::Green), 
+                hint: Some("Any key closes".into()), 
+                border: Some(BorderPreset::Double), 
+                ..Default::default()
+            }, 
+            ..Default::default()
+        });
+
+        // the theme is shared with chained contexts, and applies only to the kind of dialog it is given for
+        assert_eq!(ctx.chain_with_global(1).dialog_theme(), ctx.dialog_theme());
+        dialog::info("Hello", &(), ctx);
+        let corner = ctx.buffer().content
+            .iter()
+            .find(|cell| cell.symbol() == "╔")
+            .map(|cell| cell.fg);
+        assert_eq!(corner, Some(Color::Green));
+        assert!(ctx.screen().contains("Any key closes"));
+
+        dialog::warning("Hello", &(), ctx);
+        assert!(ctx.screen().contains("Press any key to close..."));
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn all() {
+        let esc = || Event::Key(KeyCode::Esc.into());
+        let ctx = &mut Context::headless(80, 24, [esc(), esc(), esc()]);
+        ctx.set_dialog_theme(Theme {
+            all: DrawInfoDefaults {
+                border: Some(BorderPreset::Rounded), 
+                ..Default::default()
+            }, 
+            info: DrawInfoDefaults {
+                border: Some(BorderPreset::Double), 
+                ..Default::default()
+            }, 
+            ..Default::default()
+        });
+        let corner = |ctx: &Context| ctx.screen()
+            .chars()
+            .find(|symbol| ['╭', '╔', '┌'].contains(symbol))
+            .map(String::from);
+
+        // the theme for all dialogs applies to those without a kind, and to kinds not overriding it
+        dialog::select_index("Pick", ["a", "b"], &(), ctx);
+        assert_eq!(corner(ctx).as_deref(), Some("╭"));
+        dialog::warning("Hello", &(), ctx);
+        assert_eq!(corner(ctx).as_deref(), Some("╭"));
+        dialog::info("Hello", &(), ctx);
+        assert_eq!(corner(ctx).as_deref(), Some("╔"));
+        assert!(ctx.script_finished());
+    }
+
+    #[test]
+    fn fields() {
+        let ctx = &mut Context::headless(80, 24, [Event::Key(KeyCode::Esc.into())]);
+        ctx.set_dialog_theme(Theme {
+            fields: FieldStyles {
+                focused: Style::new().fg(Color::Magenta), 
+                ..Default::default()
+            }, 
+            ..Default::default()
+        });
+        dialog::form!{
+            name: Textbox{ name: "Name" }, 
+            [title]: "Register", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        let name = ctx.buffer().content
+            .iter()
+            .find(|cell| cell.symbol() == "N")
+            .map(|cell| cell.fg);
+        assert_eq!(name, Some(Color::Magenta));
+        assert!(ctx.script_finished());
+    }
+}
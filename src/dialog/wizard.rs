@@ -0,0 +1,289 @@
+use std::borrow::Cow;
+use crate::prelude::*;
+
+/// Guides the user through a sequence of dependent steps, e.g. choosing the type of an item, then entering
+/// options specific to that type, and finally confirming. 
+/// 
+/// Each step is a closure that typically shows a [form](crate::dialog::form!) or another dialog, stores what
+/// the user entered in the [accumulated output](WizardState::data), and returns a [`StepResult`] deciding
+/// which step comes next. The wizard takes care of the navigation between the steps, and adds the progress
+/// of the wizard, e.g. "Step 2 of 4", to the title of the dialogs shown directly by the steps. 
+/// 
+/// Steps may be run several times as the user navigates back and forth, and should therefore start from the
+/// output entered previously where applicable, e.g. as the initial values of a form. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, field::*, dialog::{StepResult, Wizard}};
+/// 
+/// #[derive(Default)]
+/// struct Account {
+///     kind: usize, 
+///     name: String, 
+/// }
+/// 
+/// # let current_state = &();
+/// # let ctx = &mut Context::new().unwrap();
+/// // let current_state: &impl State
+/// // let ctx: &mut Context<_>
+/// let account = Wizard::new(Account::default())
+///     .step("Type", |ctx, state| {
+///         let kinds = ["Personal", "Business"];
+///         let default = state.data.kind;
+///         let kind = dialog::select_index_with_default("Account type?", kinds, default, current_state, ctx);
+///         let Some(kind) = kind else { return StepResult::Cancelled };
+///         state.data.kind = kind;
+///         StepResult::Next
+///     })
+///     .step("Details", |ctx, state| {
+///         let values = dialog::form!{
+///             name: Textbox{ name: "Name", value: state.data.name.clone() }, 
+///             [title]: "Details", 
+///             [context]: ctx, 
+///             [background]: current_state, 
+///         };
+///         let Some(values) = values else { return StepResult::Cancelled };
+///         state.data.name = values.name;
+///         StepResult::Next
+///     })
+///     .run(ctx);
+/// ```
+pub struct Wizard<'a, T, G = ()> {
+    data: T, 
+    steps: Vec<Step<'a, T, G>>, 
+    back_on_cancel: bool, 
+}
+
+/// A step of a [`Wizard`]: its user-visible name and the closure running it. 
+type Step<'a, T, G> = (
+    Cow<'a, str>, 
+    Box<dyn FnMut(&mut Context<G>, &mut WizardState<T>) -> StepResult + 'a>, 
+);
+
+/// What a step of a [`Wizard`] decides is shown next. 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum StepResult {
+    /// Proceeds to the next step, or finishes the wizard after the last step. 
+    Next, 
+    /// Returns to the previous step, or aborts the wizard from the first step. 
+    Back, 
+    /// The user cancelled the step, e.g. by closing a form. This returns to the previous step, unless
+    /// [`Wizard::back_on_cancel`] is disabled, in which case it aborts the wizard. 
+    Cancelled, 
+    /// Finishes the wizard without running the remaining steps. 
+    Finish, 
+    /// Aborts the wizard, discarding the accumulated output. 
+    Abort, 
+}
+
+/// The state of a running [`Wizard`], given to each of its steps. 
+#[derive(Clone, Debug)]
+pub struct WizardState<T> {
+    /// The output accumulated by the steps, returned once the wizard finishes. 
+    pub data: T, 
+    /// Index of the current step. 
+    step: usize, 
+    /// The user-visible names of the steps. 
+    names: Vec<String>, 
+}
+
+impl<T> WizardState<T> {
+    /// Index of the current step, starting from `0`. 
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// The number of steps of the wizard. 
+    pub fn count(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Whether the current step is the first one. 
+    pub fn is_first(&self) -> bool {
+        self.step == 0
+    }
+
+    /// Whether the current step is the last one. 
+    pub fn is_last(&self) -> bool {
+        self.step + 1 == self.count()
+    }
+
+    /// The progress of the wizard, e.g. "Step 2 of 4". This is added to the title of the dialogs shown by
+    /// the current step. 
+    pub fn progress(&self) -> String {
+        format!("Step {} of {}", self.step + 1, self.count())
+    }
+
+    /// The names of the steps up to and including the current one, e.g. "Type › Options". This may be
+    /// shown in the message of a dialog to remind the user of how they got there. 
+    pub fn breadcrumbs(&self) -> String {
+        self.names[..=self.step].join(" › ")
+    }
+}
+
+impl<'a, T, G> Wizard<'a, T, G> {
+    /// Creates a wizard without any steps, starting from given accumulated output. 
+    pub fn new(data: T) -> Self {
+        Self {
+            data, 
+            steps: Vec::new(), 
+            back_on_cancel: true, 
+        }
+    }
+
+    /// Adds a step after those previously added. The name of the step is shown in the
+    /// [breadcrumbs](WizardState::breadcrumbs). 
+    pub fn step(
+        mut self, 
+        name: impl Into<Cow<'a, str>>, 
+        step: impl FnMut(&mut Context<G>, &mut WizardState<T>) -> StepResult + 'a, 
+    ) -> Self {
+        self.steps.push((name.into(), Box::new(step)));
+        self
+    }
+
+    /// Whether a [cancelled](StepResult::Cancelled) step returns to the previous step rather than aborting
+    /// the wizard. Default: `true`. 
+    pub fn back_on_cancel(self, enabled: bool) -> Self {
+        Self{ back_on_cancel: enabled, ..self }
+    }
+
+    /// Runs the steps of the wizard, starting from the first one. 
+    /// 
+    /// If an [exit](crate::State#exiting) is requested, the wizard is aborted once the current step
+    /// returns. 
+    /// 
+    /// 
+    /// # Returns
+    /// 
+    /// - `Some` with the accumulated output if the user got past the last step, or a step finished the
+    /// wizard early. 
+    /// - `None` if the wizard was aborted, or has no steps. 
+    pub fn run(self, ctx: &mut Context<G>) -> Option<T> {
+        let Wizard{ data, mut steps, back_on_cancel } = self;
+        if steps.is_empty() {
+            return None
+        }
+        let names = steps
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect();
+        let mut state = WizardState{ data, step: 0, names };
+
+        let previous_step = ctx.dialog_step.take();
+        let finished = loop {
+            *ctx.dialog_step.borrow_mut() = Some(state.progress());
+            let result = (steps[state.step].1)(ctx, &mut state);
+            if ctx.exit_requested() {
+                break false
+            }
+            let result = match result {
+                StepResult::Cancelled if back_on_cancel => StepResult::Back, 
+                StepResult::Cancelled => StepResult::Abort, 
+                result => result, 
+            };
+            match result {
+                StepResult::Next if state.is_last() => break true, 
+                StepResult::Next => state.step += 1, 
+                StepResult::Back if state.is_first() => break false, 
+                StepResult::Back => state.step -= 1, 
+                StepResult::Finish => break true, 
+                _ => break false, 
+            }
+        };
+        *ctx.dialog_step.borrow_mut() = previous_step;
+        finished.then_some(state.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use crate::{prelude::*, crossterm::event::Event};
+    use super::{StepResult, Wizard};
+
+    /// A wizard whose steps return the scripted results, recording the visited steps. 
+    fn scripted(results: &[StepResult], count: usize, back_on_cancel: bool)
+        -> (Option<Vec<usize>>, Vec<usize>)
+    {
+        let (results, visited) = (&RefCell::new(results.iter().copied()), &RefCell::new(Vec::new()));
+        let mut wizard = Wizard::new(Vec::new()).back_on_cancel(back_on_cancel);
+        for i in 0..count {
+            wizard = wizard.step(format!("{i}"), move |_, state| {
+                visited.borrow_mut().push(i);
+                state.data.push(i);
+                results.borrow_mut().next().unwrap()
+            });
+        }
+        let out = wizard.run(&mut Context::headless(80, 24, []));
+        (out, visited.take())
+    }
+
+    #[test]
+    fn navigation() {
+        use StepResult::*;
+
+        assert_eq!(scripted(&[Next, Next, Next], 3, true), (Some(vec![0, 1, 2]), vec![0, 1, 2]));
+        assert_eq!(scripted(&[Next, Back, Next, Next, Next], 3, true).1, vec![0, 1, 0, 1, 2]);
+        assert_eq!(scripted(&[Next, Finish], 3, true), (Some(vec![0, 1]), vec![0, 1]));
+        assert_eq!(scripted(&[Next, Abort], 3, true), (None, vec![0, 1]));
+        assert_eq!(scripted(&[Back], 3, true), (None, vec![0]));
+        assert_eq!(scripted(&[], 0, true), (None, vec![]));
+
+        // cancelling goes back unless configured otherwise
+        assert_eq!(scripted(&[Next, Cancelled, Next, Next, Next], 3, true).1, vec![0, 1, 0, 1, 2]);
+        assert_eq!(scripted(&[Next, Cancelled], 3, false), (None, vec![0, 1]));
+        assert_eq!(scripted(&[Cancelled], 3, true), (None, vec![0]));
+    }
+
+    #[test]
+    fn state() {
+        let ctx = &mut Context::headless(80, 24, []);
+        let out = Wizard::new(Vec::new())
+            .step("Type", |_, state| {
+                assert!(state.is_first());
+                state.data.push(state.breadcrumbs());
+                StepResult::Next
+            })
+            .step("Options", |_, state| {
+                assert!(state.is_last());
+                assert_eq!((state.step(), state.count()), (1, 2));
+                state.data.push(state.progress());
+                state.data.push(state.breadcrumbs());
+                StepResult::Next
+            })
+            .run(ctx);
+        assert_eq!(out.unwrap(), ["Type", "Step 2 of 2", "Type › Options"]);
+    }
+
+    #[test]
+    fn title() {
+        let esc = || Event::Key(KeyCode::Esc.into());
+        let ctx = &mut Context::headless(80, 24, [esc(), esc(), esc()]);
+
+        // the progress is added to the title of the dialogs shown by the steps
+        let screens = RefCell::new(Vec::new());
+        Wizard::new(())
+            .step("Type", |ctx, _| {
+                dialog::info("Hello", &(), ctx);
+                screens.borrow_mut().push(ctx.screen());
+                StepResult::Next
+            })
+            .step("Options", |ctx, _| {
+                dialog::warning("Hello", &(), ctx);
+                screens.borrow_mut().push(ctx.screen());
+                StepResult::Next
+            })
+            .run(ctx);
+        let screens = screens.into_inner();
+        assert!(screens[0].contains(" INFO · STEP 1 OF 2 "));
+        assert!(screens[1].contains(" WARNING · STEP 2 OF 2 "));
+
+        // and is removed once the wizard finishes
+        dialog::info("Hello", &(), ctx);
+        assert!(!ctx.screen().contains("STEP"));
+        assert!(ctx.script_finished());
+    }
+}
@@ -0,0 +1,93 @@
+//! Object-safe wrapper around [`Field`], for storing fields of different types together. 
+
+use std::{any::Any, borrow::Cow};
+use ratatui::text::Text;
+use crate::KeyEvent;
+use super::{Field, InputResult};
+
+/// Object-safe counterpart of [`Field`], allowing fields of different types to be stored together as
+/// `Box<dyn AnyField>`, e.g. in a [`FormBuilder`](crate::dialog::FormBuilder). 
+/// 
+/// This is implemented for every [`Field`] whose value is `'static`, and mirrors its methods, except that
+/// the value is accessed as [`Any`]. 
+/// 
+/// Since the methods share their names with those of [`Field`], calling them on a field with both traits
+/// in scope is ambiguous. The trait is therefore not re-exported by the [`field`](super) module, and rarely
+/// needs to be imported at all; fields are simply boxed and coerced, e.g.
+/// `Box::new(checkbox) as Box<dyn AnyField>`. 
+pub trait AnyField {
+    /// See [`Field::name`]. 
+    fn name(&self) -> &str;
+    /// See [`Field::input`]. 
+    fn input(&mut self, key: KeyEvent) -> InputResult;
+    /// See [`Field::format`]. 
+    fn format(&self, focused: bool) -> Text<'_>;
+    /// See [`Field::format_width`]. 
+    fn format_width(&self, focused: bool, width: u16) -> Text<'_>;
+    /// Borrows the current user-entered value. See [`Field::value`]. 
+    fn value_any(&self) -> &dyn Any;
+    /// Consumes the field and returns the current user-entered value. See [`Field::into_value`]. 
+    fn into_value_any(self: Box<Self>) -> Box<dyn Any>;
+    /// See [`Field::focused_line`]. 
+    fn focused_line(&self) -> Option<usize>;
+    /// See [`Field::error`]. 
+    fn error(&self) -> Option<Cow<'static, str>>;
+    /// See [`Field::captures`]. 
+    fn captures(&self, key: &KeyEvent) -> bool;
+    /// See [`Field::paste`]. 
+    fn paste(&mut self, text: &str) -> InputResult;
+    /// See [`Field::focus_lost`]. 
+    fn focus_lost(&mut self);
+    /// See [`Field::enabled`]. 
+    fn enabled(&self) -> bool;
+}
+
+impl<T: Field + 'static> AnyField for T {
+    fn name(&self) -> &str {
+        Field::name(self)
+    }
+
+    fn input(&mut self, key: KeyEvent) -> InputResult {
+        Field::input(self, key)
+    }
+
+    fn format(&self, focused: bool) -> Text<'_> {
+        Field::format(self, focused)
+    }
+
+    fn format_width(&self, focused: bool, width: u16) -> Text<'_> {
+        Field::format_width(self, focused, width)
+    }
+
+    fn value_any(&self) -> &dyn Any {
+        Field::value(self)
+    }
+
+    fn into_value_any(self: Box<Self>) -> Box<dyn Any> {
+        Box::new(Field::into_value(*self))
+    }
+
+    fn focused_line(&self) -> Option<usize> {
+        Field::focused_line(self)
+    }
+
+    fn error(&self) -> Option<Cow<'static, str>> {
+        Field::error(self)
+    }
+
+    fn captures(&self, key: &KeyEvent) -> bool {
+        Field::captures(self, key)
+    }
+
+    fn paste(&mut self, text: &str) -> InputResult {
+        Field::paste(self, text)
+    }
+
+    fn focus_lost(&mut self) {
+        Field::focus_lost(self)
+    }
+
+    fn enabled(&self) -> bool {
+        Field::enabled(self)
+    }
+}
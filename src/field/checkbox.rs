@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use ratatui::text::Text;
+use unicode_width::UnicodeWidthStr;
 use crate::prelude::*;
 use super::*;
 
@@ -8,6 +9,14 @@ use super::*;
 /// See [`checkbox::Builder`] for the methods available when constructing the field. 
 /// 
 /// 
+/// # Symbols
+/// 
+/// The value is displayed as one of two symbols, by default [`DEFAULT_SYMBOLS`]. Since some terminals and
+/// fonts render these poorly, they can be changed with [`Builder::symbols`], or to [`ASCII_SYMBOLS`] with
+/// [`Builder::ascii`]. The narrower symbol is padded to the display width of the wider one, so that toggling
+/// the value doesn't shift the layout. 
+/// 
+/// 
 /// # Key bindings
 /// 
 /// Any key toggles the value. 
@@ -15,10 +24,21 @@ use super::*;
 pub struct Checkbox {
     /// The user-visible name displayed by the input field. 
     pub name: Cow<'static, str>, 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
     /// The current user-entered value. 
     pub value: bool, 
+    /// The symbols displayed when checked and unchecked, respectively. 
+    symbols: (&'static str, &'static str), 
 }
 
+/// The default symbols of a [`Checkbox`]: `✓` when checked and `𐄂` when unchecked. 
+pub const DEFAULT_SYMBOLS: (&str, &str) = ("✓", "𐄂");
+
+/// ASCII symbols for a [`Checkbox`], for terminals and fonts that render the [default](DEFAULT_SYMBOLS)
+/// poorly: `[x]` when checked and `[ ]` when unchecked. 
+pub const ASCII_SYMBOLS: (&str, &str) = ("[x]", "[ ]");
+
 impl Field for Checkbox {
     type Value = bool;
     type Builder = Builder;
@@ -27,6 +47,10 @@ impl Field for Checkbox {
         &self.name
     }
 
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
     fn input(&mut self, key: KeyEvent) -> InputResult {
         if let KeyCode::Up | KeyCode::Down = key.code {
             InputResult::Ignored
@@ -36,11 +60,14 @@ impl Field for Checkbox {
         }
     }
 
-    fn format(&self, _focused: bool) -> Text {
-        match self.value {
-            true => "✓", 
-            false => "𐄂", 
-        }.into()
+    fn format(&self, _focused: bool) -> Text<'_> {
+        let (checked, unchecked) = self.symbols;
+        let symbol = match self.value {
+            true => checked, 
+            false => unchecked, 
+        };
+        let width = usize::max(checked.width(), unchecked.width());
+        format!("{symbol}{}", " ".repeat(width - symbol.width())).into()
     }
 
     fn value(&self) -> &Self::Value {
@@ -65,7 +92,9 @@ impl Default for Builder {
     fn default() -> Self {
         Self(Checkbox {
             name: Default::default(), 
+            disabled: false, 
             value: false, 
+            symbols: DEFAULT_SYMBOLS, 
         })
     }
 }
@@ -77,10 +106,26 @@ impl<const NAME: bool> Builder<NAME> {
         Builder(Checkbox{ name, ..self.0 })
     }
 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder(Checkbox{ disabled, ..self.0 })
+    }
+
     /// The initial value. 
     pub fn value(self, value: bool) -> Self {
         Builder(Checkbox{ value, ..self.0 })
     }
+
+    /// The symbols displayed when checked and unchecked, respectively. See the
+    /// [type-level](Checkbox#symbols) documentation for more information. Default: [`DEFAULT_SYMBOLS`]. 
+    pub fn symbols(self, symbols: (&'static str, &'static str)) -> Self {
+        Builder(Checkbox{ symbols, ..self.0 })
+    }
+
+    /// Uses [`ASCII_SYMBOLS`]. 
+    pub fn ascii(self) -> Self {
+        self.symbols(ASCII_SYMBOLS)
+    }
 }
 
 impl Build for Builder<true> {
@@ -115,4 +160,26 @@ mod tests {
         test(KeyCode::Up, InputResult::Ignored);
         test(KeyCode::Down, InputResult::Ignored);
     }
+
+    #[test]
+    fn symbol_width() {
+        use unicode_width::UnicodeWidthStr;
+
+        let width = |checkbox: &Checkbox| checkbox.format(false).to_string().width();
+        let check = |mut checkbox: Checkbox, expected: usize| {
+            assert_eq!(width(&checkbox), expected);
+            checkbox.input(KeyCode::Char(' ').into());
+            assert_eq!(width(&checkbox), expected);
+        };
+        check(Checkbox::builder().name("").build(), 1);
+        check(Checkbox::builder().name("").ascii().build(), 3);
+
+        // a double-width emoji pads the single-width symbol
+        let checkbox = Checkbox::builder()
+            .name("")
+            .symbols(("✅", "-"))
+            .build();
+        assert_eq!(checkbox.format(false).to_string(), "- ");
+        check(checkbox, 2);
+    }
 }
@@ -0,0 +1,254 @@
+use std::borrow::Cow;
+use ratatui::text::Text;
+use crate::prelude::*;
+use super::*;
+
+/// An [input field](super) for selecting one value among a set, each displayed with a label. 
+/// 
+/// This behaves exactly like a [`Radio`], except that the [value](Field::value) is the value associated with
+/// the selected item rather than its index. This saves indexing back into a separate array of values, which
+/// is error-prone when the items are built dynamically. See [`choice::Builder`] for the methods
+/// available when constructing the field. 
+/// 
+/// 
+/// # Key bindings
+/// 
+/// The same as for [`Radio`]. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Choice<T> {
+    /// Selects among the labels of the items. 
+    radio: Radio, 
+    /// The value associated with each item. 
+    values: Vec<T>, 
+}
+
+impl<T> Choice<T> {
+    /// Index of the currently selected item. 
+    pub fn selected_index(&self) -> usize {
+        *self.radio.value()
+    }
+
+    /// The user-visible labels of the items that can be chosen between. 
+    pub fn labels(&self) -> &[Cow<'static, str>] {
+        &self.radio.items
+    }
+}
+
+impl<T> Field for Choice<T> {
+    type Value = T;
+    type Builder = Builder<T>;
+
+    fn name(&self) -> &str {
+        self.radio.name()
+    }
+
+    fn enabled(&self) -> bool {
+        self.radio.enabled()
+    }
+
+    fn input(&mut self, key: KeyEvent) -> InputResult {
+        self.radio.input(key)
+    }
+
+    fn format(&self, focused: bool) -> Text<'_> {
+        self.radio.format(focused)
+    }
+
+    fn format_width(&self, focused: bool, width: u16) -> Text<'_> {
+        self.radio.format_width(focused, width)
+    }
+
+    fn focused_line(&self) -> Option<usize> {
+        self.radio.focused_line()
+    }
+
+    fn error(&self) -> Option<Cow<'static, str>> {
+        self.radio.error()
+    }
+
+    fn captures(&self, key: &KeyEvent) -> bool {
+        self.radio.captures(key)
+    }
+
+    fn paste(&mut self, text: &str) -> InputResult {
+        self.radio.paste(text)
+    }
+
+    fn focus_lost(&mut self) {
+        self.radio.focus_lost()
+    }
+
+    fn value(&self) -> &T {
+        &self.values[self.selected_index()]
+    }
+
+    fn into_value(mut self) -> T {
+        self.values.swap_remove(self.selected_index())
+    }
+}
+
+/// Constructs a [`Choice`]. 
+/// 
+/// This is mainly used by the [form macro](crate::dialog::form!) when instantiating choice fields, but may
+/// also be used in application code for creating a stand-alone field. 
+/// 
+/// Requires that both [`Builder::name`] and [`Builder::items`] are called before the field can be built. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Builder<T, const NAME: bool = false, const ITEMS: bool = false> {
+    radio: radio::Builder<NAME, ITEMS>, 
+    values: Vec<T>, 
+}
+
+impl<T> Default for Builder<T> {
+    fn default() -> Self {
+        Builder {
+            radio: Default::default(), 
+            values: Vec::new(), 
+        }
+    }
+}
+
+impl<T, const NAME: bool, const ITEMS: bool> Builder<T, NAME, ITEMS> {
+    /// The user-visible name displayed by the input field. 
+    pub fn name(self, name: impl Into<Cow<'static, str>>) -> Builder<T, true, ITEMS> {
+        let Builder{ radio, values } = self;
+        Builder{ radio: radio.name(name), values }
+    }
+
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        let Builder{ radio, values } = self;
+        Builder{ radio: radio.disabled(disabled), values }
+    }
+
+    /// The user-visible labels of all items that can be chosen between, and their associated values. 
+    /// 
+    /// 
+    /// # Panics
+    /// 
+    /// When the number of items is zero. 
+    pub fn items<L>(self, items: impl IntoIterator<Item = (L, T)>) -> Builder<T, NAME, true>
+    where
+        L: Into<Cow<'static, str>>, 
+    {
+        let (labels, values): (Vec<L>, _) = items
+            .into_iter()
+            .unzip();
+        let radio = self.radio.items(labels);
+        Builder{ radio, values }
+    }
+
+    /// Displays the items as a vertical list showing at most `max_visible` items at a time. See the
+    /// [`Radio` documentation](Radio#vertical-layout) for more information. 
+    pub fn vertical(self, max_visible: usize) -> Self {
+        let Builder{ radio, values } = self;
+        Builder{ radio: radio.vertical(max_visible), values }
+    }
+}
+
+impl<T, const NAME: bool> Builder<T, NAME, true> {
+    /// The index of the currently selected item. An index beyond the last item is clamped to the last item. 
+    /// 
+    /// 
+    /// # Panics
+    /// 
+    /// In debug builds, when the index is beyond the last item. 
+    pub fn selected(self, index: usize) -> Self {
+        let Builder{ radio, values } = self;
+        Builder{ radio: radio.selected(index), values }
+    }
+
+    /// Selects the first item associated with given value. The selection is left unchanged if no item is
+    /// associated with the value. 
+    pub fn value(self, value: T) -> Self
+    where
+        T: PartialEq, 
+    {
+        match self.values.iter().position(|item| *item == value) {
+            Some(index) => self.selected(index), 
+            None => self, 
+        }
+    }
+}
+
+impl<T> Build for Builder<T, true, true> {
+    type Field = Choice<T>;
+
+    fn build(self) -> Choice<T> {
+        let Builder{ radio, values } = self;
+        Choice{ radio: radio.build(), values }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, field::*};
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Units {
+        Metric, 
+        Imperial, 
+    }
+
+    #[test]
+    fn values() {
+        let mut choice = Choice::builder()
+            .name("Units")
+            .items([("Metric", Units::Metric), ("Imperial", Units::Imperial)])
+            .build();
+        assert_eq!(*choice.value(), Units::Metric);
+        assert_eq!(choice.format(false).to_string(), "<Metric>");
+
+        assert_eq!(choice.input(KeyCode::Right.into()), InputResult::Updated);
+        assert_eq!(choice.selected_index(), 1);
+        assert_eq!(choice.into_value(), Units::Imperial);
+    }
+
+    #[test]
+    fn owned_values() {
+        let names = ["Alice", "Bob", "Carol"].map(String::from);
+        let choice = Choice::builder()
+            .name("User")
+            .items(names.iter().map(|name| (name.clone(), vec![name.len()])))
+            .selected(2)
+            .build();
+        assert_eq!(choice.labels()[2], "Carol");
+        assert_eq!(choice.into_value(), [5]);
+    }
+
+    #[test]
+    fn value() {
+        let choice = |value| Choice::builder()
+            .name("Units")
+            .items([("Metric", Units::Metric), ("Imperial", Units::Imperial)])
+            .selected(1)
+            .value(value)
+            .build();
+        assert_eq!(choice(Units::Metric).selected_index(), 0);
+        assert_eq!(choice(Units::Imperial).selected_index(), 1);
+    }
+
+    #[test]
+    fn forwarded() {
+        let items = ["Metric", "Imperial", "Nautical"];
+        let mut radio = Radio::builder()
+            .name("Units")
+            .items(items)
+            .vertical(2)
+            .build();
+        let mut choice = Choice::builder()
+            .name("Units")
+            .items(items.map(|item| (item, item.len())))
+            .vertical(2)
+            .build();
+
+        // every method behaves just like it does for the underlying radio
+        for key in [KeyCode::Up, KeyCode::Down, KeyCode::Enter, KeyCode::Char('a')] {
+            assert_eq!(choice.captures(&key.into()), radio.captures(&key.into()));
+        }
+        assert_eq!(choice.paste("Imperial"), radio.paste("Imperial"));
+        assert_eq!(choice.error(), radio.error());
+        assert_eq!(choice.format_width(true, 12), radio.format_width(true, 12));
+        assert_eq!(choice.focused_line(), radio.focused_line());
+    }
+}
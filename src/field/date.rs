@@ -0,0 +1,534 @@
+use std::{
+    borrow::Cow, 
+    fmt::{self, Display}, 
+    time::{SystemTime, UNIX_EPOCH}, 
+};
+use ratatui::{
+    style::{Style, Stylize}, 
+    text::{Line, Span, Text}, 
+};
+use crate::prelude::*;
+use super::*;
+
+/// A calendar date, as entered with a [`DateField`]. 
+/// 
+/// Dates are ordered chronologically. The fields are public for convenience, but a date constructed by hand
+/// may be invalid; see [`Date::new`] for a checked constructor. 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    /// The year, between `1` and `9999`. 
+    pub year: u16, 
+    /// The month, between `1` and `12`. 
+    pub month: u8, 
+    /// The day of the month, between `1` and the number of days in the month. 
+    pub day: u8, 
+}
+
+impl Date {
+    /// The earliest date that can be represented; `0001-01-01`. 
+    pub const MIN: Date = Date{ year: 1, month: 1, day: 1 };
+    /// The latest date that can be represented; `9999-12-31`. 
+    pub const MAX: Date = Date{ year: 9999, month: 12, day: 31 };
+
+    /// Constructs a date, returning `None` if it does not exist (e.g. February 30). 
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Date> {
+        let valid = (1..=9999).contains(&year)
+            && (1..=12).contains(&month)
+            && (1..=Date::days_in_month(year, month)).contains(&day);
+        valid.then_some(Date{ year, month, day })
+    }
+
+    /// The current date in UTC. 
+    pub fn today() -> Date {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_secs())
+            .unwrap_or(0);
+        Date::from_days((seconds / 86_400) as i64)
+    }
+
+    /// Whether a year is a leap year in the Gregorian calendar. 
+    pub fn is_leap_year(year: u16) -> bool {
+        year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+    }
+
+    /// The number of days in a month of a year. 
+    pub fn days_in_month(year: u16, month: u8) -> u8 {
+        match month {
+            2 if Date::is_leap_year(year) => 29, 
+            2 => 28, 
+            4 | 6 | 9 | 11 => 30, 
+            _ => 31, 
+        }
+    }
+
+    /// Constructs the date a number of days after `1970-01-01`. Based on Howard Hinnant's
+    /// [`civil_from_days`](http://howardhinnant.github.io/date_algorithms.html#civil_from_days). 
+    fn from_days(days: i64) -> Date {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + (month <= 2) as i64;
+        Date {
+            year: year.clamp(1, 9999) as u16, 
+            month: month as u8, 
+            day: day as u8, 
+        }
+    }
+
+    /// Makes the date valid by clamping each component, after which it is clamped between `min` and `max`. 
+    fn normalise(self, min: Date, max: Date) -> Date {
+        let year = self.year.clamp(1, 9999);
+        let month = self.month.clamp(1, 12);
+        let day = self.day.clamp(1, Date::days_in_month(year, month));
+        Date{ year, month, day }.clamp(min, max)
+    }
+}
+
+/// Formats the date as `YYYY-MM-DD`. 
+impl Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// The order and separator in which a [`DateField`] displays the components of a date. 
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum DateFormat {
+    /// `2024-06-15`. 
+    #[default]
+    Iso, 
+    /// `15/06/2024`. 
+    Dmy, 
+    /// `06/15/2024`. 
+    Mdy, 
+}
+
+impl DateFormat {
+    /// The components in the order they are displayed. 
+    fn segments(self) -> [Segment; 3] {
+        match self {
+            DateFormat::Iso => [Segment::Year, Segment::Month, Segment::Day], 
+            DateFormat::Dmy => [Segment::Day, Segment::Month, Segment::Year], 
+            DateFormat::Mdy => [Segment::Month, Segment::Day, Segment::Year], 
+        }
+    }
+
+    /// The string displayed between the components. 
+    fn separator(self) -> &'static str {
+        match self {
+            DateFormat::Iso => "-", 
+            DateFormat::Dmy | DateFormat::Mdy => "/", 
+        }
+    }
+}
+
+/// A component of a date, edited as a unit. 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+enum Segment {
+    Year, 
+    Month, 
+    Day, 
+}
+
+impl Segment {
+    /// The number of digits displayed. 
+    fn width(self) -> usize {
+        match self {
+            Segment::Year => 4, 
+            Segment::Month | Segment::Day => 2, 
+        }
+    }
+
+    fn get(self, date: Date) -> u16 {
+        match self {
+            Segment::Year => date.year, 
+            Segment::Month => date.month as u16, 
+            Segment::Day => date.day as u16, 
+        }
+    }
+
+    /// Replaces the component in a date. The result may be invalid. 
+    fn set(self, date: Date, value: u16) -> Date {
+        let narrow = value.min(u8::MAX as u16) as u8;
+        match self {
+            Segment::Year => Date{ year: value, ..date }, 
+            Segment::Month => Date{ month: narrow, ..date }, 
+            Segment::Day => Date{ day: narrow, ..date }, 
+        }
+    }
+
+    /// Steps the component of a date by one. The month and day wrap around, while the year does not. 
+    fn step(self, date: Date, up: bool) -> Date {
+        let value = self.get(date);
+        let count = match self {
+            Segment::Year => return self.set(date, match up {
+                true => value.saturating_add(1), 
+                false => value.saturating_sub(1), 
+            }), 
+            Segment::Month => 12, 
+            Segment::Day => Date::days_in_month(date.year, date.month) as u16, 
+        };
+        let value = match up {
+            true => value % count + 1, 
+            false => (value + count - 2) % count + 1, 
+        };
+        self.set(date, value)
+    }
+}
+
+/// An [input field](super) for entering a calendar [`Date`]. 
+/// 
+/// The date is edited one component at a time, and is displayed in a configurable [`DateFormat`] with the
+/// focused component highlighted, e.g. `2024-›06‹-15`. Only valid dates between the minimum and maximum of
+/// the field can be entered; if changing the year or month leaves the day past the end of the month, the
+/// day is clamped to the last day of the month. 
+/// 
+/// See [`date::Builder`] for the methods available when constructing the field. 
+/// 
+/// 
+/// # Key bindings
+/// 
+/// [`KeyCode::Left`] and [`KeyCode::Right`] move the focus to the previous and next component, respectively. 
+/// 
+/// [`KeyCode::Up`] and [`KeyCode::Down`] increase and decrease the focused component by one, respectively. 
+/// The month and day wrap around. 
+/// 
+/// Digits replace the focused component. Once as many digits as the component is wide have been typed (four
+/// for the year, two for the month and day), the component is set and the focus moves to the next one. A
+/// partially typed component is set when the focus moves away from it. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct DateField {
+    /// The user-visible name displayed by the input field. 
+    pub name: Cow<'static, str>, 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
+    /// The current user-entered value. 
+    value: Date, 
+    /// The earliest date that can be entered. 
+    min: Date, 
+    /// The latest date that can be entered. 
+    max: Date, 
+    /// How the date is displayed. 
+    format: DateFormat, 
+    /// Index of the focused component, in display order. 
+    focus: usize, 
+    /// Digits typed into the focused component that have not yet been set. 
+    typed: String, 
+}
+
+impl DateField {
+    /// Sets the current value, which is clamped between the minimum and maximum. 
+    pub fn set_value(&mut self, value: Date) {
+        self.value = value.normalise(self.min, self.max);
+        self.typed.clear();
+    }
+
+    /// The focused component. 
+    fn segment(&self) -> Segment {
+        self.format.segments()[self.focus]
+    }
+
+    /// Sets the focused component from the typed digits, if any. Returns whether the value changed. 
+    fn commit(&mut self) -> bool {
+        let Ok(typed) = self.typed.parse() else {
+            return false
+        };
+        self.typed.clear();
+        self.update(self.segment().set(self.value, typed))
+    }
+
+    /// Sets the value, returning whether it changed. 
+    fn update(&mut self, value: Date) -> bool {
+        let old = self.value;
+        self.value = value.normalise(self.min, self.max);
+        old != self.value
+    }
+
+    /// Moves the focus to the component at given index, setting the partially typed component. 
+    fn move_focus(&mut self, focus: usize) -> InputResult {
+        let updated = self.commit();
+        self.focus = focus;
+        match updated {
+            true => InputResult::Updated, 
+            false => InputResult::Consumed, 
+        }
+    }
+}
+
+impl Field for DateField {
+    type Value = Date;
+    type Builder = Builder;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
+    fn input(&mut self, key: KeyEvent) -> InputResult {
+        match key.code {
+            KeyCode::Left if self.focus > 0 => self.move_focus(self.focus - 1), 
+            KeyCode::Right if self.focus < 2 => self.move_focus(self.focus + 1), 
+            KeyCode::Up | KeyCode::Down => {
+                let committed = self.commit();
+                let stepped = self.segment().step(self.value, key.code == KeyCode::Up);
+                match self.update(stepped) || committed {
+                    true => InputResult::Updated, 
+                    false => InputResult::Consumed, 
+                }
+            }
+            KeyCode::Char(digit @ '0'..='9') => {
+                self.typed.push(digit);
+                if self.typed.len() < self.segment().width() {
+                    return InputResult::Consumed
+                }
+                let updated = self.commit();
+                self.focus = usize::min(self.focus + 1, 2);
+                match updated {
+                    true => InputResult::Updated, 
+                    false => InputResult::Consumed, 
+                }
+            }
+            _ => InputResult::Ignored, 
+        }
+    }
+
+    fn paste(&mut self, text: &str) -> InputResult {
+        // the text is typed one character at a time, skipping separators such as `-` and `:`
+        text.chars()
+            .map(|c| self.input(KeyCode::Char(c).into()))
+            .max()
+            .unwrap_or(InputResult::Ignored)
+    }
+
+    fn format(&self, focused: bool) -> Text<'_> {
+        let separator = self.format.separator();
+        let mut spans = Vec::with_capacity(7);
+        for (i, segment) in self.format.segments().into_iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw(separator));
+            }
+            let width = segment.width();
+            let text = match (focused && i == self.focus, self.typed.is_empty()) {
+                (false, _) => format!("{:0width$}", segment.get(self.value)), 
+                (true, true) => format!("›{:0width$}‹", segment.get(self.value)), 
+                (true, false) => format!("›{:_<width$}‹", self.typed), 
+            };
+            let style = match focused && i == self.focus {
+                true => Style::new().bold(), 
+                false => Style::new(), 
+            };
+            spans.push(Span::styled(text, style));
+        }
+        Line::from(spans).into()
+    }
+
+    fn value(&self) -> &Date {
+        &self.value
+    }
+
+    fn into_value(mut self) -> Date {
+        self.commit();
+        self.value
+    }
+}
+
+/// Constructs a [`DateField`]. 
+/// 
+/// This is mainly used by the [form macro](crate::dialog::form!) when instantiating date fields, but may
+/// also be used in application code for creating a stand-alone field. 
+/// 
+/// Requires that [`Builder::name`] is called before the field can be built. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Builder<const NAME: bool = false> {
+    name: Cow<'static, str>, 
+    disabled: bool, 
+    value: Option<Date>, 
+    min: Date, 
+    max: Date, 
+    format: DateFormat, 
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Builder {
+            name: Default::default(), 
+            disabled: false, 
+            value: None, 
+            min: Date::MIN, 
+            max: Date::MAX, 
+            format: DateFormat::default(), 
+        }
+    }
+}
+
+impl<const NAME: bool> Builder<NAME> {
+    /// The user-visible name displayed by the input field. 
+    pub fn name(self, name: impl Into<Cow<'static, str>>) -> Builder<true> {
+        let name = name.into();
+        let Builder{ disabled, value, min, max, format, .. } = self;
+        Builder{ name, disabled, value, min, max, format }
+    }
+
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder{ disabled, ..self }
+    }
+
+    /// The initial value. Clamped between the minimum and maximum when the field is built. Default: 
+    /// [today](Date::today). 
+    pub fn value(self, value: Date) -> Self {
+        let value = Some(value);
+        Builder{ value, ..self }
+    }
+
+    /// The earliest date that can be entered. Default: [`Date::MIN`]. 
+    pub fn min(self, min: Date) -> Self {
+        Builder{ min, ..self }
+    }
+
+    /// The latest date that can be entered. Default: [`Date::MAX`]. 
+    pub fn max(self, max: Date) -> Self {
+        Builder{ max, ..self }
+    }
+
+    /// How the date is displayed. Default: [`DateFormat::Iso`]. 
+    pub fn format(self, format: DateFormat) -> Self {
+        Builder{ format, ..self }
+    }
+}
+
+impl Build for Builder<true> {
+    type Field = DateField;
+
+    /// If the name has been defined with [`Builder::name`], consumes the builder and returns the constructed
+    /// [`DateField`]. 
+    fn build(self) -> DateField {
+        let Builder{ name, disabled, value, min, max, format } = self;
+        let min = min.normalise(Date::MIN, Date::MAX);
+        let max = max.normalise(min, Date::MAX);
+        let mut field = DateField {
+            name, 
+            disabled, 
+            value: min, 
+            min, 
+            max, 
+            format, 
+            focus: 0, 
+            typed: String::new(), 
+        };
+        field.set_value(value.unwrap_or_else(Date::today));
+        field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, field::*};
+    use super::{Date, DateFormat};
+
+    fn date(year: u16, month: u8, day: u8) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    fn with_value(value: Date) -> DateField {
+        DateField::builder()
+            .name("")
+            .value(value)
+            .build()
+    }
+
+    fn typed(field: &mut DateField, keys: &str) {
+        for c in keys.chars() {
+            field.input(KeyCode::Char(c).into());
+        }
+    }
+
+    #[test]
+    fn validity() {
+        assert_eq!(Date::new(2023, 2, 29), None);
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert_eq!(Date::new(1900, 2, 29), None);
+        assert!(Date::new(2000, 2, 29).is_some());
+        assert_eq!(Date::new(2024, 13, 1), None);
+        assert_eq!(super::Date::from_days(0), date(1970, 1, 1));
+        assert_eq!(super::Date::from_days(19_889), date(2024, 6, 15));
+    }
+
+    #[test]
+    fn clamps_day() {
+        // stepping the month past January clamps the day to the end of February
+        let mut field = with_value(date(2024, 1, 31));
+        field.input(KeyCode::Right.into());
+        field.input(KeyCode::Up.into());
+        assert_eq!(*field.value(), date(2024, 2, 29));
+
+        // typing a day past the end of the month clamps it
+        field.input(KeyCode::Right.into());
+        typed(&mut field, "30");
+        assert_eq!(*field.value(), date(2024, 2, 29));
+
+        // as does changing the year to a non-leap year
+        field.input(KeyCode::Left.into());
+        field.input(KeyCode::Left.into());
+        field.input(KeyCode::Down.into());
+        assert_eq!(*field.value(), date(2023, 2, 28));
+    }
+
+    #[test]
+    fn segments() {
+        let mut field = DateField::builder()
+            .name("")
+            .value(date(2024, 6, 15))
+            .format(DateFormat::Dmy)
+            .build();
+        assert_eq!(field.format(false).to_string(), "15/06/2024");
+        assert_eq!(field.format(true).to_string(), "›15‹/06/2024");
+
+        // typing a whole component moves on to the next
+        typed(&mut field, "0107");
+        assert_eq!(field.format(true).to_string(), "01/07/›2024‹");
+        typed(&mut field, "19");
+        assert_eq!(field.format(true).to_string(), "01/07/›19__‹");
+        assert_eq!(field.into_value(), date(19, 7, 1));
+
+        // the month and day wrap around
+        let mut field = with_value(date(2024, 12, 31));
+        field.input(KeyCode::Right.into());
+        field.input(KeyCode::Up.into());
+        assert_eq!(*field.value(), date(2024, 1, 31));
+        assert_eq!(field.input(KeyCode::Left.into()), InputResult::Consumed);
+        assert_eq!(field.input(KeyCode::Left.into()), InputResult::Ignored);
+    }
+
+    #[test]
+    fn range() {
+        let mut field = DateField::builder()
+            .name("")
+            .min(date(2000, 1, 1))
+            .max(date(2030, 12, 31))
+            .value(date(1999, 5, 5))
+            .build();
+        assert_eq!(*field.value(), date(2000, 1, 1));
+
+        typed(&mut field, "2099");
+        assert_eq!(*field.value(), date(2030, 12, 31));
+    }
+
+    #[test]
+    fn paste() {
+        // the digits are typed into each component in turn, skipping the separators
+        let mut field = with_value(date(2024, 6, 15));
+        assert_eq!(field.paste("2025-03-09"), InputResult::Updated);
+        assert_eq!(*field.value(), date(2025, 3, 9));
+        assert_eq!(field.paste("--"), InputResult::Ignored);
+    }
+}
@@ -0,0 +1,127 @@
+use std::borrow::Cow;
+use ratatui::text::Text;
+use crate::prelude::*;
+use super::*;
+
+/// A read-only row of text, for showing context alongside the fields of a [form](crate::dialog::form!), such
+/// as the account being edited. 
+/// 
+/// The text may be styled, and is shown aligned with the other fields under [`Display::name`]. The row is
+/// never [enabled](Field::enabled), so it is shown dimmed, skipped when moving the focus, and never
+/// validated. It has no value to speak of, so its [`Field::Value`] is `()`. 
+/// 
+/// See [`display::Builder`] for the methods available when constructing the field. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, field::*};
+/// 
+/// # let ctx = &mut Context::new().unwrap();
+/// let email = "alice@example.com";
+/// // let ctx: &mut Context<_>
+/// let values = dialog::form!{
+///     account: Display{ name: "Account", text: email },
+///     password: Textbox{ name: "New password", hidden },
+///     [title]: "Change Password",
+///     [context]: ctx,
+///     [background]: &(),
+/// };
+/// ```
+/// 
+/// 
+/// # Key bindings
+/// 
+/// All keys are [ignored](InputResult::Ignored). 
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Display {
+    /// The user-visible name displayed by the field. 
+    pub name: Cow<'static, str>, 
+    /// The text shown by the field. 
+    pub text: Text<'static>, 
+}
+
+impl Field for Display {
+    type Value = ();
+    type Builder = Builder;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn input(&mut self, _key: KeyEvent) -> InputResult {
+        InputResult::Ignored
+    }
+
+    fn format(&self, _focused: bool) -> Text<'_> {
+        self.text.clone()
+    }
+
+    fn value(&self) -> &Self::Value {
+        &()
+    }
+
+    fn into_value(self) -> Self::Value {}
+}
+
+/// Constructs a [`Display`]. 
+/// 
+/// This is mainly used by the [form macro](crate::dialog::form!) when instantiating display rows, but may
+/// also be used in application code for creating a stand-alone field. 
+/// 
+/// Requires that [`Builder::name`] is called before the field can be built. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Builder<const NAME: bool = false>(Display);
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self(Display::default())
+    }
+}
+
+impl<const NAME: bool> Builder<NAME> {
+    /// The user-visible name displayed by the field. 
+    pub fn name(self, name: impl Into<Cow<'static, str>>) -> Builder<true> {
+        let name = name.into();
+        Builder(Display{ name, ..self.0 })
+    }
+
+    /// The text shown by the field, which may be styled. Default: empty. 
+    pub fn text(self, text: impl Into<Text<'static>>) -> Self {
+        let text = text.into();
+        Builder(Display{ text, ..self.0 })
+    }
+}
+
+impl Build for Builder<true> {
+    type Field = Display;
+
+    fn build(self) -> Display {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Stylize;
+    use crate::{prelude::*, field::*};
+
+    #[test]
+    fn display() {
+        let mut display = Display::builder()
+            .name("Account")
+            .text("alice".bold())
+            .build();
+        assert!(!display.enabled());
+        for code in [KeyCode::Char('a'), KeyCode::Enter, KeyCode::Backspace, KeyCode::Up, KeyCode::Down] {
+            assert_eq!(display.input(code.into()), InputResult::Ignored);
+        }
+        assert_eq!(display.paste("bob"), InputResult::Ignored);
+        assert_eq!(display.format(true), Text::from("alice".bold()));
+    }
+}
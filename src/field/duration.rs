@@ -0,0 +1,435 @@
+use std::{
+    borrow::Cow, 
+    time::Duration, 
+};
+use ratatui::{
+    text::{Span, Text}, 
+    style::Stylize, 
+};
+use crate::prelude::*;
+use super::*;
+
+/// The smallest unit of time that can be entered in a [`DurationField`]. 
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum Granularity {
+    /// Whole seconds. Bare numbers are read as seconds. 
+    #[default]
+    Seconds, 
+    /// Whole milliseconds. Bare numbers are read as milliseconds, and the unit `ms` may be used. 
+    Millis, 
+}
+
+impl Granularity {
+    /// The duration of one unit. 
+    fn unit(self) -> Duration {
+        match self {
+            Granularity::Seconds => Duration::from_secs(1), 
+            Granularity::Millis => Duration::from_millis(1), 
+        }
+    }
+
+    /// Rounds a duration down to a whole number of units. 
+    fn truncate(self, duration: Duration) -> Duration {
+        match self {
+            Granularity::Seconds => Duration::from_secs(duration.as_secs()), 
+            Granularity::Millis => Duration::from_millis(duration.as_millis() as u64), 
+        }
+    }
+}
+
+/// Parses a duration written as a sequence of numbers with unit suffixes, e.g. `1h 30m` or `90s`. The
+/// supported units are `h`, `m`, `s`, and `ms` (only with [`Granularity::Millis`]). Numbers without a unit
+/// are read in the unit of the granularity. Whitespace between the components is optional. 
+/// 
+/// Returns `None` if the text is malformed, and [`Duration::MAX`] if the duration is too long to be
+/// represented. 
+fn parse(text: &str, granularity: Granularity) -> Option<Duration> {
+    let mut rest = text.trim_start();
+    if rest.is_empty() {
+        return None
+    }
+    let mut total = Duration::ZERO;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if digits == 0 {
+            return None
+        }
+        let (number, tail) = rest.split_at(digits);
+        let unit_len = tail
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_len);
+        let unit = match (unit, granularity) {
+            ("", _) => granularity.unit(), 
+            ("h", _) => Duration::from_secs(3600), 
+            ("m", _) => Duration::from_secs(60), 
+            ("s", _) => Duration::from_secs(1), 
+            ("ms", Granularity::Millis) => Duration::from_millis(1), 
+            _ => return None, 
+        };
+        let component = number
+            .parse::<u32>()
+            .ok()
+            .and_then(|number| unit.checked_mul(number))
+            .and_then(|component| total.checked_add(component));
+        total = match component {
+            Some(total) => total, 
+            None => return Some(Duration::MAX), 
+        };
+        rest = tail.trim_start();
+    }
+    Some(total)
+}
+
+/// Formats a duration in the syntax read by [`parse`], e.g. `1h 30m`. 
+fn format(duration: Duration, granularity: Granularity) -> String {
+    let seconds = duration.as_secs();
+    let components = [
+        (seconds / 3600, "h"), 
+        (seconds / 60 % 60, "m"), 
+        (seconds % 60, "s"), 
+        (duration.subsec_millis() as u64 * (granularity == Granularity::Millis) as u64, "ms"), 
+    ];
+    let formatted: Vec<String> = components
+        .into_iter()
+        .filter(|&(count, _)| count > 0)
+        .map(|(count, unit)| format!("{count}{unit}"))
+        .collect();
+    match formatted.is_empty() {
+        true => "0s".to_owned(), 
+        false => formatted.join(" "), 
+    }
+}
+
+/// An [input field](super) for entering a [`Duration`], such as a timeout. 
+/// 
+/// The duration is typed as a sequence of numbers with unit suffixes, e.g. `1h 30m` or `90s`. The units `h`,
+/// `m`, and `s` are supported, as well as `ms` if the [granularity](Granularity) is milliseconds. Numbers
+/// without a unit are read in the unit of the granularity. Durations longer than the maximum are clamped to
+/// it, which is shown after the text. 
+/// 
+/// While the text does not parse, the field reports itself as [invalid](Field::error), which is highlighted
+/// by [forms](crate::dialog::form!) in the same way as failed field validation. The
+/// [value](Field::value) of the field is then the last duration that did parse. 
+/// 
+/// See [`duration::Builder`] for the methods available when constructing the field. 
+/// 
+/// 
+/// # Key bindings
+/// 
+/// The same as for [`Textbox`], except that only digits, spaces, and the letters of the units are accepted. 
+/// 
+/// [`KeyCode::Up`] and [`KeyCode::Down`] increase and decrease the duration by the
+/// [step](Builder::step), respectively, after which the text is rewritten to match. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct DurationField {
+    /// The user-visible name displayed by the input field. 
+    pub name: Cow<'static, str>, 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
+    /// The entered text. 
+    text: Textbox, 
+    /// The last valid duration parsed from the text, clamped to the maximum. 
+    value: Duration, 
+    /// The longest duration that can be entered. 
+    max: Duration, 
+    /// The amount added or subtracted by `Up` and `Down`. 
+    step: Duration, 
+    /// The smallest unit of time that can be entered. 
+    granularity: Granularity, 
+    /// Whether the current text parses. 
+    valid: bool, 
+    /// Whether the duration parsed from the current text was clamped. 
+    clamped: bool, 
+}
+
+impl DurationField {
+    /// Sets the current value, which is clamped to the maximum and rounded down to the granularity. 
+    pub fn set_value(&mut self, value: Duration) {
+        let value = self.granularity.truncate(value.min(self.max));
+        self.text.set_value(format(value, self.granularity));
+        self.parse();
+    }
+
+    /// The entered text, which may not be a valid duration. 
+    pub fn text(&self) -> &str {
+        self.text.value()
+    }
+
+    /// Whether a character can occur in a duration. 
+    fn accepts(c: char) -> bool {
+        matches!(c, '0'..='9' | ' ' | 'h' | 'm' | 's')
+    }
+
+    /// Parses the entered text, updating the value if it is valid. 
+    fn parse(&mut self) {
+        let parsed = parse(self.text.value(), self.granularity);
+        self.valid = parsed.is_some();
+        if let Some(duration) = parsed {
+            self.clamped = duration > self.max;
+            self.value = duration.min(self.max);
+        }
+    }
+}
+
+impl Field for DurationField {
+    type Value = Duration;
+    type Builder = Builder;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
+    fn input(&mut self, key: KeyEvent) -> InputResult {
+        let stepped = match key.code {
+            KeyCode::Up => self.value.saturating_add(self.step), 
+            KeyCode::Down => self.value.saturating_sub(self.step), 
+            // the textbox inserts any character typed without control, including shifted ones
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) && !Self::accepts(c) => {
+                return InputResult::Ignored
+            }
+            _ => {
+                let result = self.text.input(key);
+                if let InputResult::Updated = result {
+                    self.parse();
+                }
+                return result
+            }
+        };
+        let old = (self.value, self.text.value().to_owned());
+        self.set_value(stepped);
+        match old == (self.value, self.text.value().to_owned()) {
+            true => InputResult::Consumed, 
+            false => InputResult::Updated, 
+        }
+    }
+
+    fn paste(&mut self, text: &str) -> InputResult {
+        let text: String = text
+            .chars()
+            .filter(|&c| Self::accepts(c))
+            .collect();
+        let result = self.text.paste(&text);
+        if let InputResult::Updated = result {
+            self.parse();
+        }
+        result
+    }
+
+    fn format(&self, focused: bool) -> Text<'_> {
+        let mut text = self.text.format(focused);
+        if self.valid && self.clamped {
+            let clamped = Span::raw(format!(" → {}", format(self.value, self.granularity))).dim();
+            if let Some(line) = text.lines.first_mut() {
+                line.spans.push(clamped);
+            }
+        }
+        text
+    }
+
+    fn value(&self) -> &Duration {
+        &self.value
+    }
+
+    fn into_value(self) -> Duration {
+        self.value
+    }
+
+    fn error(&self) -> Option<Cow<'static, str>> {
+        match self.valid {
+            true => None, 
+            false => Some("Not a valid duration, e.g. 1h 30m".into()), 
+        }
+    }
+}
+
+/// Constructs a [`DurationField`]. 
+/// 
+/// This is mainly used by the [form macro](crate::dialog::form!) when instantiating duration fields, but
+/// may also be used in application code for creating a stand-alone field. 
+/// 
+/// Requires that [`Builder::name`] is called before the field can be built. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Builder<const NAME: bool = false> {
+    name: Cow<'static, str>, 
+    disabled: bool, 
+    value: Duration, 
+    max: Duration, 
+    step: Duration, 
+    granularity: Granularity, 
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Builder {
+            name: Default::default(), 
+            disabled: false, 
+            value: Duration::ZERO, 
+            max: Duration::MAX, 
+            step: Duration::from_secs(1), 
+            granularity: Granularity::default(), 
+        }
+    }
+}
+
+impl<const NAME: bool> Builder<NAME> {
+    /// The user-visible name displayed by the input field. 
+    pub fn name(self, name: impl Into<Cow<'static, str>>) -> Builder<true> {
+        let name = name.into();
+        let Builder{ disabled, value, max, step, granularity, .. } = self;
+        Builder{ name, disabled, value, max, step, granularity }
+    }
+
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder{ disabled, ..self }
+    }
+
+    /// The initial value. Clamped to the maximum and rounded down to the granularity when the field is
+    /// built. Default: zero. 
+    pub fn value(self, value: Duration) -> Self {
+        Builder{ value, ..self }
+    }
+
+    /// The longest duration that can be entered. Default: unlimited. 
+    pub fn max(self, max: Duration) -> Self {
+        Builder{ max, ..self }
+    }
+
+    /// The amount added or subtracted by `Up` and `Down`. Default: one second. 
+    pub fn step(self, step: Duration) -> Self {
+        Builder{ step, ..self }
+    }
+
+    /// The smallest unit of time that can be entered. Default: [`Granularity::Seconds`]. 
+    pub fn granularity(self, granularity: Granularity) -> Self {
+        Builder{ granularity, ..self }
+    }
+}
+
+impl Build for Builder<true> {
+    type Field = DurationField;
+
+    /// If the name has been defined with [`Builder::name`], consumes the builder and returns the constructed
+    /// [`DurationField`]. 
+    fn build(self) -> DurationField {
+        let Builder{ name, disabled, value, max, step, granularity } = self;
+        let mut field = DurationField {
+            name, 
+            disabled, 
+            text: Textbox::builder().name("").build(), 
+            value: Duration::ZERO, 
+            max, 
+            step, 
+            granularity, 
+            valid: true, 
+            clamped: false, 
+        };
+        field.set_value(value);
+        field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::{prelude::*, field::*};
+    use super::{format, parse, Granularity};
+
+    #[test]
+    fn parsing() {
+        let secs = |text| parse(text, Granularity::Seconds);
+        let millis = |text| parse(text, Granularity::Millis);
+
+        // combined units, with or without whitespace
+        assert_eq!(secs("1h 30m"), Some(Duration::from_secs(5400)));
+        assert_eq!(secs("1h30m15s"), Some(Duration::from_secs(5415)));
+        assert_eq!(millis("2s 500ms"), Some(Duration::from_millis(2500)));
+
+        // bare numbers are read in the unit of the granularity
+        assert_eq!(secs("90"), Some(Duration::from_secs(90)));
+        assert_eq!(millis("90"), Some(Duration::from_millis(90)));
+        assert_eq!(secs("1m 5"), Some(Duration::from_secs(65)));
+
+        assert_eq!(secs("0"), Some(Duration::ZERO));
+        assert_eq!(secs("0h 0m"), Some(Duration::ZERO));
+
+        // malformed input
+        assert_eq!(secs(""), None);
+        assert_eq!(secs("h"), None);
+        assert_eq!(secs("1x"), None);
+        assert_eq!(secs("500ms"), None);
+
+        // too long to be represented
+        assert_eq!(secs("99999999999999h 4000000000h"), Some(Duration::MAX));
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(format(Duration::ZERO, Granularity::Seconds), "0s");
+        assert_eq!(format(Duration::from_secs(5415), Granularity::Seconds), "1h 30m 15s");
+        assert_eq!(format(Duration::from_millis(2500), Granularity::Millis), "2s 500ms");
+        assert_eq!(format(Duration::from_millis(2500), Granularity::Seconds), "2s");
+    }
+
+    #[test]
+    fn field() {
+        let mut field = DurationField::builder()
+            .name("Timeout")
+            .value(Duration::from_millis(90_500))
+            .max(Duration::from_secs(120))
+            .step(Duration::from_secs(30))
+            .build();
+        assert_eq!(field.text(), "1m 30s");
+
+        field.input(KeyCode::Up.into());
+        assert_eq!(*field.value(), Duration::from_secs(120));
+        assert_eq!(field.input(KeyCode::Up.into()), InputResult::Consumed);
+        assert_eq!(field.text(), "2m");
+
+        // typing a unit without a number makes the field invalid, keeping the last valid value
+        field.input(KeyCode::Char(' ').into());
+        field.input(KeyCode::Char('h').into());
+        assert!(field.error().is_some());
+        assert_eq!(*field.value(), Duration::from_secs(120));
+
+        // the value is clamped to the maximum
+        field.input(KeyCode::Backspace.into());
+        field.input(KeyCode::Char('5').into());
+        assert!(field.error().is_none());
+        assert_eq!(field.format(false).to_string(), "2m 5 → 2m");
+    }
+
+    #[test]
+    fn paste() {
+        let mut field = DurationField::builder()
+            .name("Timeout")
+            .build();
+        field.input(KeyEvent::new(KeyCode::Backspace, KeyModifiers::CONTROL));
+        assert_eq!(field.paste("1h, 30m!"), InputResult::Updated);
+        assert_eq!(field.text(), "1h 30m");
+        assert_eq!(*field.value(), Duration::from_secs(5400));
+        assert_eq!(field.paste("?"), InputResult::Ignored);
+    }
+
+    #[test]
+    fn modifiers() {
+        let mut field = DurationField::builder()
+            .name("")
+            .build();
+
+        // shifted and alt-modified characters are filtered just like plain ones
+        let shift = KeyEvent::new(KeyCode::Char('X'), KeyModifiers::SHIFT);
+        let alt = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::ALT);
+        assert_eq!(field.input(shift), InputResult::Ignored);
+        assert_eq!(field.input(alt), InputResult::Ignored);
+        assert_eq!(field.text(), "0s");
+        assert_eq!(field.error(), None);
+    }
+}
@@ -0,0 +1,228 @@
+use std::borrow::Cow;
+use ratatui::{style::{Style, Stylize}, text::{Line, Text}};
+use crate::prelude::*;
+use super::*;
+
+/// Formats a key press as its modifiers followed by its key code, separated by `+`, e.g. `ctrl+shift+F5`. 
+pub fn format_keyevent(key: KeyEvent) -> String {
+    const MODIFIERS: [(KeyModifiers, &str); 6] = [
+        (KeyModifiers::CONTROL, "ctrl"), 
+        (KeyModifiers::ALT, "alt"), 
+        (KeyModifiers::SHIFT, "shift"), 
+        (KeyModifiers::SUPER, "super"), 
+        (KeyModifiers::HYPER, "hyper"), 
+        (KeyModifiers::META, "meta"), 
+    ];
+    let code = match key.code {
+        KeyCode::Char(' ') => "space".to_owned(), 
+        KeyCode::Char(c) => c.to_string(), 
+        KeyCode::F(n) => format!("F{n}"), 
+        KeyCode::PageUp => "pageup".to_owned(), 
+        KeyCode::PageDown => "pagedown".to_owned(), 
+        KeyCode::BackTab => "backtab".to_owned(), 
+        code => format!("{code:?}").to_lowercase(), 
+    };
+    MODIFIERS
+        .into_iter()
+        .filter(|&(modifier, _)| key.modifiers.contains(modifier))
+        .map(|(_, name)| name)
+        .chain([code.as_str()])
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+/// An [input field](super) for entering a key binding, such as in a settings form where the user rebinds
+/// keys. 
+/// 
+/// The value is the key code and modifiers of the captured key press, displayed as formatted by
+/// [`format_keyevent`]. See [`keybind::Builder`] for the methods available when constructing the
+/// field. 
+/// 
+/// 
+/// # Capture mode
+/// 
+/// Since any key may be bound, including those used to navigate forms, keys are only captured in an
+/// explicit capture mode. Outside of it, all keys except `Enter` are [ignored](InputResult::Ignored). 
+/// 
+/// The field [captures](Field::captures) `Enter` and, in capture mode, every key from
+/// [forms](crate::dialog::form!). Note that this means a form can't be submitted while a key capture field
+/// is focused; the focus must first be moved to another field. 
+/// 
+/// 
+/// # Key bindings
+/// 
+/// [`KeyCode::Enter`] enters capture mode. In capture mode, the next key press is stored as the value and
+/// capture mode is left, except for [`KeyCode::Esc`], which leaves capture mode without changing the value. 
+/// Capture mode is also left when the focus moves away from the field, e.g. through a form's quick jump. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct KeyCapture {
+    /// The user-visible name displayed by the input field. 
+    pub name: Cow<'static, str>, 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
+    /// The captured key code and modifiers. 
+    value: (KeyCode, KeyModifiers), 
+    /// Whether the next key press is captured. 
+    capturing: bool, 
+}
+
+impl KeyCapture {
+    /// Whether the field is in [capture mode](KeyCapture#capture-mode). 
+    pub fn is_capturing(&self) -> bool {
+        self.capturing
+    }
+}
+
+impl Field for KeyCapture {
+    type Value = (KeyCode, KeyModifiers);
+    type Builder = Builder;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
+    fn input(&mut self, key: KeyEvent) -> InputResult {
+        match (self.capturing, key.code) {
+            (false, KeyCode::Enter) => {
+                self.capturing = true;
+                InputResult::Consumed
+            }
+            (false, _) => InputResult::Ignored, 
+            (true, KeyCode::Esc) => {
+                self.capturing = false;
+                InputResult::Consumed
+            }
+            (true, code) => {
+                self.value = (code, key.modifiers);
+                self.capturing = false;
+                InputResult::Updated
+            }
+        }
+    }
+
+    fn format(&self, focused: bool) -> Text<'_> {
+        let (code, modifiers) = self.value;
+        let line = match (focused && self.capturing, code) {
+            (true, _) => Line::styled("Press a key...", Style::new().reversed()), 
+            (false, KeyCode::Null) => Line::from("None").dim(), 
+            (false, _) => Line::from(format_keyevent(KeyEvent::new(code, modifiers))), 
+        };
+        match focused {
+            true => line.bold().into(), 
+            false => line.into(), 
+        }
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.value
+    }
+
+    fn into_value(self) -> Self::Value {
+        self.value
+    }
+
+    fn captures(&self, key: &KeyEvent) -> bool {
+        self.capturing || key.code == KeyCode::Enter
+    }
+
+    fn focus_lost(&mut self) {
+        self.capturing = false;
+    }
+}
+
+/// Constructs a [`KeyCapture`]. 
+/// 
+/// This is mainly used by the [form macro](crate::dialog::form!) when instantiating key capture fields, but
+/// may also be used in application code for creating a stand-alone field. 
+/// 
+/// Requires that [`Builder::name`] is called before the field can be built. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Builder<const NAME: bool = false>(KeyCapture);
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self(KeyCapture {
+            name: Default::default(), 
+            disabled: false, 
+            value: (KeyCode::Null, KeyModifiers::NONE), 
+            capturing: false, 
+        })
+    }
+}
+
+impl<const NAME: bool> Builder<NAME> {
+    /// The user-visible name displayed by the input field. 
+    pub fn name(self, name: impl Into<Cow<'static, str>>) -> Builder<true> {
+        let name = name.into();
+        Builder(KeyCapture{ name, ..self.0 })
+    }
+
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder(KeyCapture{ disabled, ..self.0 })
+    }
+
+    /// The initial key code and modifiers. Default: [`KeyCode::Null`], which is displayed as `None`. 
+    pub fn value(self, value: (KeyCode, KeyModifiers)) -> Self {
+        Builder(KeyCapture{ value, ..self.0 })
+    }
+}
+
+impl Build for Builder<true> {
+    type Field = KeyCapture;
+
+    fn build(self) -> Self::Field {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, field::*};
+    use super::format_keyevent;
+
+    #[test]
+    fn formatting() {
+        let format = |code, modifiers| format_keyevent(KeyEvent::new(code, modifiers));
+        assert_eq!(format(KeyCode::F(5), KeyModifiers::CONTROL | KeyModifiers::SHIFT), "ctrl+shift+F5");
+        assert_eq!(format(KeyCode::Char('s'), KeyModifiers::CONTROL), "ctrl+s");
+        assert_eq!(format(KeyCode::Char(' '), KeyModifiers::NONE), "space");
+        assert_eq!(format(KeyCode::PageDown, KeyModifiers::ALT), "alt+pagedown");
+        assert_eq!(format(KeyCode::Esc, KeyModifiers::NONE), "esc");
+    }
+
+    #[test]
+    fn capture() {
+        let mut field = KeyCapture::builder()
+            .name("Save")
+            .value((KeyCode::Char('s'), KeyModifiers::CONTROL))
+            .build();
+
+        // keys are ignored outside of capture mode
+        assert_eq!(field.input(KeyCode::Down.into()), InputResult::Ignored);
+        assert!(!field.captures(&KeyCode::Esc.into()));
+
+        // escape cancels capture
+        assert_eq!(field.input(KeyCode::Enter.into()), InputResult::Consumed);
+        assert!(field.captures(&KeyCode::Esc.into()));
+        assert_eq!(field.input(KeyCode::Esc.into()), InputResult::Consumed);
+        assert_eq!(*field.value(), (KeyCode::Char('s'), KeyModifiers::CONTROL));
+
+        field.input(KeyCode::Enter.into());
+        assert_eq!(field.format(true).to_string(), "Press a key...");
+        let key = KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT);
+        assert_eq!(field.input(key), InputResult::Updated);
+        assert!(!field.is_capturing());
+        assert_eq!(field.format(true).to_string(), "shift+up");
+
+        // losing the focus cancels capture
+        field.input(KeyCode::Enter.into());
+        field.focus_lost();
+        assert!(!field.is_capturing());
+        assert_eq!(*field.value(), (KeyCode::Up, KeyModifiers::SHIFT));
+    }
+}
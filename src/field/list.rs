@@ -0,0 +1,380 @@
+use std::borrow::Cow;
+use ratatui::{style::Stylize, text::{Line, Span, Text}};
+use crate::prelude::*;
+use super::*;
+
+/// Checks a single entry of a [`ListEditor`], returning an error message if it is invalid. 
+pub type Validator = fn(&str) -> Result<(), Cow<'static, str>>;
+
+/// An [input field](super) for entering a list of strings, such as tags, hosts, or email addresses. 
+/// 
+/// Each entry is displayed on its own line, and the focused entry is edited as in a [`Textbox`]. See
+/// [`list::Builder`] for the methods available when constructing the field. 
+/// 
+/// If a [validator](Builder::validate) is given, the field reports itself as [invalid](Field::error) while
+/// any entry fails it, which is highlighted by [forms](crate::dialog::form!) in the same way as failed field
+/// validation. 
+/// 
+/// 
+/// # Key bindings
+/// 
+/// The same as for [`Textbox`] when editing the focused entry, with the addition of: 
+/// 
+/// [`KeyCode::Up`] and [`KeyCode::Down`] move the focus to the previous and next entry, respectively. At the
+/// first and last entry, they are [ignored](InputResult::Ignored), so that [forms](crate::dialog::form!) move
+/// their focus as usual. 
+/// 
+/// `ctrl+n` adds an empty entry after the focused one, unless the [maximum](Builder::max_items) number of
+/// entries has been reached. `ctrl+d` deletes the focused entry; deleting one word to the right is still
+/// possible with `ctrl+delete`. Typing into an empty list adds its first entry. 
+#[derive(Clone, Debug)]
+pub struct ListEditor {
+    /// The user-visible name displayed by the input field. 
+    pub name: Cow<'static, str>, 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
+    /// The entries of the list. 
+    values: Vec<String>, 
+    /// The focused entry being edited; kept in sync with `values[focus]`. 
+    text: Textbox, 
+    /// Index of the focused entry. 
+    focus: usize, 
+    /// The maximum number of entries. 
+    max_items: usize, 
+    /// Checks each entry. 
+    validator: Option<Validator>, 
+}
+
+impl ListEditor {
+    /// Sets the entries of the list. Entries beyond the maximum number are discarded. 
+    pub fn set_values<T>(&mut self, values: impl IntoIterator<Item = T>)
+    where
+        T: Into<String>, 
+    {
+        self.values = values
+            .into_iter()
+            .take(self.max_items)
+            .map(Into::into)
+            .collect();
+        self.focus(0);
+    }
+
+    /// Moves the focus to an entry, loading it for editing. 
+    fn focus(&mut self, index: usize) {
+        self.focus = index;
+        let value = self.values
+            .get(index)
+            .map(String::as_str)
+            .unwrap_or_default();
+        self.text.set_value(value);
+    }
+
+    /// Inserts an empty entry after the focused one and focuses it. 
+    fn add(&mut self) -> InputResult {
+        if self.values.len() >= self.max_items {
+            return InputResult::Ignored
+        }
+        let index = match self.values.is_empty() {
+            true => 0, 
+            false => self.focus + 1, 
+        };
+        self.values.insert(index, String::new());
+        self.focus(index);
+        InputResult::Updated
+    }
+}
+
+impl Field for ListEditor {
+    type Value = Vec<String>;
+    type Builder = Builder;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
+    fn input(&mut self, key: KeyEvent) -> InputResult {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match (key.code, ctrl) {
+            (KeyCode::Up, _) => match self.focus {
+                0 => InputResult::Ignored, 
+                focus => {
+                    self.focus(focus - 1);
+                    InputResult::Consumed
+                }
+            }
+            (KeyCode::Down, _) if self.focus + 1 < self.values.len() => {
+                self.focus(self.focus + 1);
+                InputResult::Consumed
+            }
+            (KeyCode::Down, _) => InputResult::Ignored, 
+            (KeyCode::Char('n'), true) => self.add(), 
+            (KeyCode::Char('d'), true) if !self.values.is_empty() => {
+                self.values.remove(self.focus);
+                self.focus(self.focus.min(self.values.len().saturating_sub(1)));
+                InputResult::Updated
+            }
+            (KeyCode::Char('d'), true) => InputResult::Ignored, 
+            (KeyCode::Char(_), false) if self.values.is_empty() => {
+                if let InputResult::Ignored = self.add() {
+                    return InputResult::Ignored
+                }
+                self.input(key)
+            }
+            _ if self.values.is_empty() => InputResult::Ignored, 
+            _ => {
+                let result = self.text.input(key);
+                if let InputResult::Updated = result {
+                    self.values[self.focus] = self.text.value().to_owned();
+                }
+                result
+            }
+        }
+    }
+
+    fn paste(&mut self, text: &str) -> InputResult {
+        // just like typing, pasting into an empty list adds an entry
+        let added = self.values.is_empty();
+        if added && self.add() == InputResult::Ignored {
+            return InputResult::Ignored
+        }
+        let result = self.text.paste(text);
+        match result {
+            InputResult::Updated => self.values[self.focus] = self.text.value().to_owned(), 
+            _ if added => self.values.clear(), 
+            _ => (), 
+        }
+        result
+    }
+
+    fn format(&self, focused: bool) -> Text<'_> {
+        if self.values.is_empty() {
+            let hint = match focused {
+                true => "None (ctrl+n to add)", 
+                false => "None", 
+            };
+            return Line::from(hint).dim().into()
+        }
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let mut line = match focused && i == self.focus {
+                    true => self.text
+                        .format(true)
+                        .lines
+                        .swap_remove(0), 
+                    false => Line::from(value.as_str()), 
+                };
+                line.spans.insert(0, Span::raw("• "));
+                line
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.values
+    }
+
+    fn into_value(self) -> Self::Value {
+        self.values
+    }
+
+    fn focused_line(&self) -> Option<usize> {
+        Some(self.focus)
+    }
+
+    fn error(&self) -> Option<Cow<'static, str>> {
+        let validator = self.validator?;
+        self.values
+            .iter()
+            .find_map(|value| validator(value).err())
+    }
+}
+
+/// Constructs a [`ListEditor`]. 
+/// 
+/// This is mainly used by the [form macro](crate::dialog::form!) when instantiating list editors, but may
+/// also be used in application code for creating a stand-alone field. 
+/// 
+/// Requires that [`Builder::name`] is called before the field can be built. 
+#[derive(Clone, Debug)]
+pub struct Builder<const NAME: bool = false> {
+    name: Cow<'static, str>, 
+    disabled: bool, 
+    values: Vec<String>, 
+    max_items: usize, 
+    validator: Option<Validator>, 
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Builder {
+            name: Default::default(), 
+            disabled: false, 
+            values: Vec::new(), 
+            max_items: usize::MAX, 
+            validator: None, 
+        }
+    }
+}
+
+impl<const NAME: bool> Builder<NAME> {
+    /// The user-visible name displayed by the input field. 
+    pub fn name(self, name: impl Into<Cow<'static, str>>) -> Builder<true> {
+        let name = name.into();
+        let Builder{ disabled, values, max_items, validator, .. } = self;
+        Builder{ name, disabled, values, max_items, validator }
+    }
+
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder{ disabled, ..self }
+    }
+
+    /// The initial entries. Entries beyond the maximum number are discarded when the field is built.
+    /// Default: none. 
+    pub fn values<T>(self, values: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<String>, 
+    {
+        let values = values
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Builder{ values, ..self }
+    }
+
+    /// Alias of [`Builder::values`], for consistency with the other fields. 
+    pub fn value<T>(self, value: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<String>, 
+    {
+        self.values(value)
+    }
+
+    /// The maximum number of entries. Default: unlimited. 
+    pub fn max_items(self, max_items: usize) -> Self {
+        Builder{ max_items, ..self }
+    }
+
+    /// Checks each entry, marking the field as [invalid](Field::error) with the returned message while any
+    /// entry fails. Closures that don't capture anything may be given. 
+    pub fn validate(self, validator: Validator) -> Self {
+        let validator = Some(validator);
+        Builder{ validator, ..self }
+    }
+}
+
+impl Build for Builder<true> {
+    type Field = ListEditor;
+
+    /// If the name has been defined with [`Builder::name`], consumes the builder and returns the constructed
+    /// [`ListEditor`]. 
+    fn build(self) -> ListEditor {
+        let Builder{ name, disabled, values, max_items, validator } = self;
+        let mut field = ListEditor {
+            name, 
+            disabled, 
+            values: Vec::new(), 
+            text: Textbox::builder().name("").build(), 
+            focus: 0, 
+            max_items, 
+            validator, 
+        };
+        field.set_values(values);
+        field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, field::*};
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    #[test]
+    fn editing() {
+        let mut list = ListEditor::builder()
+            .name("Hosts")
+            .max_items(3)
+            .build();
+        assert_eq!(list.format(false).to_string(), "None");
+
+        // typing into the empty list adds an entry
+        list.input(KeyCode::Char('a').into());
+        assert_eq!(list.input(ctrl('n')), InputResult::Updated);
+        list.input(KeyCode::Char('c').into());
+        list.input(KeyCode::Up.into());
+        list.input(ctrl('n'));
+        list.input(KeyCode::Char('b').into());
+        assert_eq!(*list.value(), ["a", "b", "c"]);
+
+        // the maximum has been reached
+        assert_eq!(list.input(ctrl('n')), InputResult::Ignored);
+
+        assert_eq!(list.input(ctrl('d')), InputResult::Updated);
+        assert_eq!(*list.value(), ["a", "c"]);
+        assert_eq!(list.focused_line(), Some(1));
+        assert_eq!(list.format(false).to_string(), "• a\n• c");
+    }
+
+    #[test]
+    fn navigation() {
+        let mut list = ListEditor::builder()
+            .name("Tags")
+            .values(["one", "two"])
+            .build();
+
+        // the focus can only leave the field at the edges
+        assert_eq!(list.input(KeyCode::Up.into()), InputResult::Ignored);
+        assert_eq!(list.input(KeyCode::Down.into()), InputResult::Consumed);
+        assert_eq!(list.input(KeyCode::Down.into()), InputResult::Ignored);
+
+        // the focused entry is edited
+        list.input(KeyCode::Backspace.into());
+        assert_eq!(*list.value(), ["one", "tw"]);
+
+        list.input(ctrl('d'));
+        list.input(ctrl('d'));
+        assert!(list.value().is_empty());
+        assert_eq!(list.input(ctrl('d')), InputResult::Ignored);
+        assert_eq!(list.input(KeyCode::Backspace.into()), InputResult::Ignored);
+    }
+
+    #[test]
+    fn validation() {
+        let list = ListEditor::builder()
+            .name("Emails")
+            .values(["a@example.com", "invalid"])
+            .validate(|value| match value.contains('@') {
+                true => Ok(()), 
+                false => Err(format!("\"{value}\" is not an email address").into()), 
+            })
+            .build();
+        assert_eq!(list.error().as_deref(), Some("\"invalid\" is not an email address"));
+    }
+
+    #[test]
+    fn paste() {
+        let mut list = ListEditor::builder()
+            .name("Hosts")
+            .build();
+
+        // pasting into the empty list adds an entry, unless nothing is pasted
+        assert_eq!(list.paste("\n"), InputResult::Ignored);
+        assert!(list.value().is_empty());
+        assert_eq!(list.paste("example.com\n"), InputResult::Updated);
+        assert_eq!(*list.value(), ["example.com"]);
+
+        list.input(KeyCode::Char('/').into());
+        list.paste("index");
+        assert_eq!(*list.value(), ["example.com/index"]);
+    }
+}
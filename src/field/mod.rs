@@ -2,11 +2,24 @@
 //! 
 //! The following input fields are defined in this module: 
 //! - [`Checkbox`] for entering booleans. 
+//! - [`Choice`] for selecting one value among a set. 
+//! - [`DateField`] for entering calendar dates. 
+//! - [`Display`] for showing read-only text alongside other fields. 
+//! - [`Dropdown`] for selecting one item among a large set by searching for it. 
+//! - [`DurationField`] for entering durations of time. 
+//! - [`KeyCapture`] for entering key bindings. 
+//! - [`ListEditor`] for entering a list of strings. 
+//! - [`NumberBox`] for typing numbers. 
+//! - [`PathField`] for entering filesystem paths. 
 //! - [`Radio`] for selecting one item among a set. 
+//! - [`Rating`] for selecting a rating, displayed as a row of stars. 
 //! - [`Slider`] for entering a number in a range. 
 //! - [`Textbox`] for entering single-line strings. 
+//! - [`TimeField`] for entering times of day. 
 //! - [`Toggle`] for toggling a set of items on/off. 
 //! 
+//! Fields of different types can be stored together as [`Box<dyn AnyField>`](any::AnyField). 
+//! 
 //! Fields are mainly designed to be used in [forms](crate::dialog::form!), but can be used on their own by
 //! feeding key-presses with [`Field::input`] and drawing them using the [`Text`] returned from
 //! [`Field::format`]. 
@@ -17,21 +30,45 @@
 //! Custom fields may be created by implementing the [`Field`] trait. See its documentation for more
 //! information. 
 
+pub mod any;
 pub mod checkbox;
+pub mod choice;
+pub mod date;
+pub mod display;
+pub mod duration;
+pub mod keybind;
+pub mod list;
+pub mod number;
+pub mod path;
 pub mod radio;
+pub mod rating;
+pub mod select;
 pub mod slider;
 pub mod textbox;
+pub mod time;
 pub mod toggle;
 
+use std::borrow::Cow;
 use ratatui::text::Text;
 use crate::KeyEvent;
 
 #[doc(inline)]
 pub use {
     checkbox::Checkbox, 
+    choice::Choice, 
+    date::DateField, 
+    display::Display, 
+    duration::DurationField, 
+    keybind::KeyCapture, 
+    list::ListEditor, 
+    number::NumberBox, 
+    path::PathField, 
     radio::Radio, 
+    rating::Rating, 
+    select::Dropdown, 
     slider::Slider, 
     textbox::Textbox, 
+    time::TimeField, 
     toggle::Toggle, 
 };
 
@@ -177,11 +214,55 @@ pub trait Field: Sized {
     /// Passes a key input event. 
     fn input(&mut self, key: KeyEvent) -> InputResult;
     /// Renders the field. 
-    fn format(&self, focused: bool) -> Text;
+    fn format(&self, focused: bool) -> Text<'_>;
+    /// Renders the field within given width, in columns. This is called by [forms](crate::dialog::form!)
+    /// instead of [`Field::format`] when the width available to the field is known. Fields whose contents
+    /// may grow wide, such as a [`Textbox`], may override this to clip or scroll their contents instead of
+    /// having them wrapped. Default: delegates to [`Field::format`]. 
+    #[allow(unused_variables)]
+    fn format_width(&self, focused: bool, width: u16) -> Text<'_> {
+        self.format(focused)
+    }
     /// Borrows the current user-entered value.
     fn value(&self) -> &Self::Value;
     /// Consumes the field and returns the current user-entered value. 
     fn into_value(self) -> Self::Value;
+    /// Index of the line in the output of [`Field::format`] that holds the internal focus of the field, if
+    /// any. This is used by [forms](crate::dialog::form!) to keep the internal focus visible when the
+    /// height of the field is capped. Default: `None`. 
+    fn focused_line(&self) -> Option<usize> {
+        None
+    }
+    /// An error in what the user has entered that keeps the field from holding a valid value, e.g. text that
+    /// does not parse. This is treated by [forms](crate::dialog::form!) as failed
+    /// [field validation](crate::dialog::form!#field-validation), and is checked before any control
+    /// statements. Default: `None`. 
+    fn error(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+    /// Whether the field captures a key press that [forms](crate::dialog::form!) otherwise handle
+    /// themselves: `Enter` (or the configured submit key) for submitting the form, `Esc` for cancelling it, 
+    /// and `Tab`/`BackTab` for moving the focus. Captured key presses are passed to [`Field::input`]
+    /// instead. Default: `false`. 
+    fn captures(&self, _key: &KeyEvent) -> bool {
+        false
+    }
+    /// Passes text pasted by the user, if [bracketed paste](crate::Context::set_bracketed_paste) is enabled. 
+    /// The text is passed as a whole, so that it is treated as a single edit; [forms](crate::dialog::form!)
+    /// validate the field once after the paste rather than once per character. Default:
+    /// [ignored](InputResult::Ignored). 
+    fn paste(&mut self, _text: &str) -> InputResult {
+        InputResult::Ignored
+    }
+    /// Called by [forms](crate::dialog::form!) when the focus moves away from the field, e.g. to leave a
+    /// mode entered while the field was focused. Default: does nothing. 
+    fn focus_lost(&mut self) {}
+    /// Whether the field can be edited. [Forms](crate::dialog::form!) show disabled fields dimmed, skip them
+    /// when moving the focus, and do not [validate](crate::dialog::form!#field-validation) them. The library
+    /// provided fields are disabled through the `disabled` method of their builders. Default: `true`. 
+    fn enabled(&self) -> bool {
+        true
+    }
     /// Constructs the [field builder](Build) using [`Default`]. 
     fn builder() -> Self::Builder {
         Default::default()
@@ -194,8 +275,9 @@ pub trait Field: Sized {
 /// # Custom fields
 /// 
 /// Note that care should be taken when and when not to return [`Consumed`](InputResult::Consumed), since it
-/// blocks [forms](crate::dialog::form!) from responding to [`KeyCode::Up`](crate::prelude::KeyCode::Up) and
-/// [`KeyCode::Down`](crate::prelude::KeyCode::Down) inputs. 
+/// blocks [forms](crate::dialog::form!) from responding to [`KeyCode::Up`](crate::prelude::KeyCode::Up), 
+/// [`KeyCode::Down`](crate::prelude::KeyCode::Down), [`KeyCode::Tab`](crate::prelude::KeyCode::Tab), and
+/// [`KeyCode::BackTab`](crate::prelude::KeyCode::BackTab) inputs. 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum InputResult {
     /// The key press was ignored. 
@@ -206,3 +288,8 @@ pub enum InputResult {
     /// The key press was used to update the [`value`](Field::value) of the field. 
     Updated, 
 }
+
+/// Treats an empty item description as no description. 
+fn non_empty(description: Cow<'static, str>) -> Option<Cow<'static, str>> {
+    (!description.is_empty()).then_some(description)
+}
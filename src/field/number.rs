@@ -0,0 +1,395 @@
+use std::{
+    borrow::Cow, 
+    fmt::Display, 
+    str::FromStr, 
+};
+use num_traits::{Bounded, Zero};
+use ratatui::{
+    text::{Span, Text}, 
+    style::Stylize, 
+};
+use crate::prelude::*;
+use super::*;
+
+/// An [input field](super) for typing a number. 
+/// 
+/// The type parameter `T` is the type of the number being entered. The following bounds are placed on `T`: 
+/// ```text
+/// T: Clone + Display + FromStr + PartialOrd,
+/// ```
+/// Additional bounds are placed on `T` when building the field. See the [builder documentation](Builder)
+/// for more information. 
+/// 
+/// Unlike a [`Slider`], the number is typed digit by digit, which is better suited for arbitrary large
+/// numbers such as ports or amounts of money. 
+/// 
+/// See [`number::Builder`] for the methods available when constructing the field. 
+/// 
+/// 
+/// # Parsing
+/// 
+/// The entered text is parsed with [`FromStr`] each time it changes. Numbers outside of the allowed range
+/// --- including those too large to be represented by `T` at all --- are clamped to the range, which is
+/// shown after the text. 
+/// 
+/// While the text does not parse (e.g. while it is empty or just `-`), the field reports itself as
+/// [invalid](Field::error), which is highlighted by [forms](crate::dialog::form!) in the same way as failed
+/// field validation. The [value](Field::value) of the field is then the last number that did parse. 
+/// 
+/// 
+/// # Key bindings
+/// 
+/// The same as for [`Textbox`], except that only the characters that can occur in a number of type `T` are
+/// accepted: digits, `-` if `T` is signed, and `.` if `T` has a fractional part. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct NumberBox<T> {
+    /// The user-visible name displayed by the input field. 
+    pub name: Cow<'static, str>, 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
+    /// The entered text. 
+    text: Textbox, 
+    /// The last valid number parsed from the text, clamped to the range. 
+    value: T, 
+    /// The smallest number that can be entered. 
+    min: T, 
+    /// The largest number that can be entered. 
+    max: T, 
+    /// Whether the current text parses. 
+    valid: bool, 
+    /// Whether the number parsed from the current text was clamped. 
+    clamped: bool, 
+}
+
+impl<T> NumberBox<T>
+where
+    T: Clone + Display + FromStr + PartialOrd, 
+{
+    /// Sets the current value, which is clamped to the range. 
+    pub fn set_value(&mut self, value: T) {
+        let (value, _) = self.clamp(value);
+        self.text.set_value(value.to_string());
+        self.parse();
+    }
+
+    /// The entered text, which may not be a valid number. 
+    pub fn text(&self) -> &str {
+        self.text.value()
+    }
+
+    /// Whether a character can occur in a number of type `T`. 
+    fn accepts(c: char) -> bool {
+        match c {
+            '0'..='9' => true, 
+            '-' => "-1".parse::<T>().is_ok(), 
+            '.' => "0.5".parse::<T>().is_ok(), 
+            _ => false, 
+        }
+    }
+
+    /// Parses the entered text, updating the value if it is valid. 
+    fn parse(&mut self) {
+        let text = self.text.value();
+        let parsed = match (text.parse::<T>(), overflow(text)) {
+            (Ok(number), _) => Some((number, false)), 
+            (Err(_), Some(true)) => Some((self.min.clone(), true)), 
+            (Err(_), Some(false)) => Some((self.max.clone(), true)), 
+            (Err(_), None) => None, 
+        };
+        self.valid = parsed.is_some();
+        if let Some((number, overflowed)) = parsed {
+            let (number, clamped) = self.clamp(number);
+            self.value = number;
+            self.clamped = clamped || overflowed;
+        }
+    }
+
+    /// Clamps a number to the range, returning whether it was outside of it. 
+    fn clamp(&self, number: T) -> (T, bool) {
+        match (number < self.min, number > self.max) {
+            (true, _) => (self.min.clone(), true), 
+            (_, true) => (self.max.clone(), true), 
+            (_, _) => (number, false), 
+        }
+    }
+}
+
+/// Whether a string that failed to parse is still a well-formed number, in which case it was too large to be
+/// represented. Returns whether the number is negative, or `None` if it is not well-formed. 
+fn overflow(text: &str) -> Option<bool> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits), 
+        None => (false, text), 
+    };
+    let (int, fraction) = digits
+        .split_once('.')
+        .unwrap_or((digits, ""));
+    let well_formed = !int.is_empty()
+        && int.bytes().all(|b| b.is_ascii_digit())
+        && fraction.bytes().all(|b| b.is_ascii_digit());
+    well_formed.then_some(negative)
+}
+
+impl<T> Field for NumberBox<T>
+where
+    T: Clone + Display + FromStr + PartialOrd, 
+{
+    type Value = T;
+    type Builder = Builder<T>;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
+    fn input(&mut self, key: KeyEvent) -> InputResult {
+        // the textbox inserts any character typed without control, including shifted ones
+        if let KeyCode::Char(c) = key.code {
+            if !key.modifiers.contains(KeyModifiers::CONTROL) && !Self::accepts(c) {
+                return InputResult::Ignored
+            }
+        }
+        let result = self.text.input(key);
+        if let InputResult::Updated = result {
+            self.parse();
+        }
+        result
+    }
+
+    fn paste(&mut self, text: &str) -> InputResult {
+        // characters that could not have been typed are dropped, e.g. the separators in "1,500"
+        let text: String = text
+            .chars()
+            .filter(|&c| Self::accepts(c))
+            .collect();
+        let result = self.text.paste(&text);
+        if let InputResult::Updated = result {
+            self.parse();
+        }
+        result
+    }
+
+    fn format(&self, focused: bool) -> Text<'_> {
+        let mut text = self.text.format(focused);
+        if self.valid && self.clamped {
+            let clamped = Span::raw(format!(" → {}", self.value)).dim();
+            if let Some(line) = text.lines.first_mut() {
+                line.spans.push(clamped);
+            }
+        }
+        text
+    }
+
+    fn value(&self) -> &T {
+        &self.value
+    }
+
+    fn into_value(self) -> T {
+        self.value
+    }
+
+    fn error(&self) -> Option<Cow<'static, str>> {
+        match self.valid {
+            true => None, 
+            false => Some("Not a valid number".into()), 
+        }
+    }
+}
+
+/// Constructs a [`NumberBox`]. 
+/// 
+/// This is mainly used by the [form macro](crate::dialog::form!) when instantiating number boxes, but may
+/// also be used in application code for creating a stand-alone field. 
+/// 
+/// Requires that [`Builder::name`] is called before the field can be built. 
+/// 
+/// 
+/// # Bounds
+/// 
+/// The value, minimum, and maximum are all optional. Building the field places the bounds `T: Zero + Bounded`
+/// on `T` for defaulting them; the value defaults to zero (clamped to the range), and the range to
+/// `T::min_value()..=T::max_value()`. These hold for all primitive numerical types. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Builder<T, const NAME: bool = false> {
+    name: Cow<'static, str>, 
+    disabled: bool, 
+    value: Option<T>, 
+    min: Option<T>, 
+    max: Option<T>, 
+}
+
+impl<T> Default for Builder<T> {
+    fn default() -> Self {
+        Builder {
+            name: Default::default(), 
+            disabled: false, 
+            value: None, 
+            min: None, 
+            max: None, 
+        }
+    }
+}
+
+impl<T, const NAME: bool> Builder<T, NAME> {
+    /// The user-visible name displayed by the input field. 
+    pub fn name(self, name: impl Into<Cow<'static, str>>) -> Builder<T, true> {
+        let Builder{ disabled, value, min, max, .. } = self;
+        Builder{ name: name.into(), disabled, value, min, max }
+    }
+
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder{ disabled, ..self }
+    }
+
+    /// The initial value. This is clamped to the range when the field is built. 
+    pub fn value(self, value: T) -> Self {
+        let value = Some(value);
+        Builder{ value, ..self }
+    }
+
+    /// The smallest number that can be entered. 
+    pub fn min(self, min: T) -> Self {
+        let min = Some(min);
+        Builder{ min, ..self }
+    }
+
+    /// The largest number that can be entered. 
+    pub fn max(self, max: T) -> Self {
+        let max = Some(max);
+        Builder{ max, ..self }
+    }
+}
+
+impl<T> Build for Builder<T, true>
+where
+    T: Clone + Display + FromStr + PartialOrd + Zero + Bounded, 
+{
+    type Field = NumberBox<T>;
+
+    /// If the name has been defined with [`Builder::name`], consumes the builder and returns the constructed
+    /// [`NumberBox`]. 
+    fn build(self) -> NumberBox<T> {
+        let min = self.min.unwrap_or_else(T::min_value);
+        let max = self.max.unwrap_or_else(T::max_value);
+        let mut number_box = NumberBox {
+            name: self.name, 
+            disabled: self.disabled, 
+            text: Textbox::builder().name("").build(), 
+            value: min.clone(), 
+            min, 
+            max, 
+            valid: true, 
+            clamped: false, 
+        };
+        number_box.set_value(self.value.unwrap_or_else(T::zero));
+        number_box
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, field::*};
+
+    fn typed<T>(number_box: &mut NumberBox<T>, text: &str)
+    where
+        NumberBox<T>: Field, 
+    {
+        number_box.input(KeyEvent::new(KeyCode::Backspace, KeyModifiers::CONTROL));
+        for c in text.chars() {
+            number_box.input(KeyCode::Char(c).into());
+        }
+    }
+
+    #[test]
+    fn parsing() {
+        let mut port = NumberBox::<u16>::builder()
+            .name("Port")
+            .value(8080)
+            .build();
+        assert_eq!(port.text(), "8080");
+
+        // leading zeros are accepted
+        typed(&mut port, "0443");
+        assert_eq!(*port.value(), 443);
+        assert_eq!(port.error(), None);
+
+        // characters that cannot occur in an unsigned integer are ignored
+        typed(&mut port, "-1.5x");
+        assert_eq!(port.text(), "15");
+
+        // overflow is clamped to the largest value
+        typed(&mut port, "99999");
+        assert_eq!(*port.value(), u16::MAX);
+        assert_eq!(port.format(false).to_string(), "99999 → 65535");
+    }
+
+    #[test]
+    fn range() {
+        let mut number_box = NumberBox::<i64>::builder()
+            .name("")
+            .min(-10)
+            .max(10)
+            .value(100)
+            .build();
+        assert_eq!(*number_box.value(), 10);
+        assert_eq!(number_box.text(), "10");
+
+        typed(&mut number_box, "-11");
+        assert_eq!(*number_box.value(), -10);
+
+        // overflowing `i64` clamps to the minimum
+        typed(&mut number_box, "-99999999999999999999");
+        assert_eq!(*number_box.value(), -10);
+    }
+
+    #[test]
+    fn invalid() {
+        let mut number_box = NumberBox::<f64>::builder()
+            .name("")
+            .value(1.5)
+            .build();
+
+        // the value falls back to the last valid parse
+        typed(&mut number_box, "-");
+        assert!(number_box.error().is_some());
+        assert_eq!(number_box.clone().into_value(), 1.5);
+
+        typed(&mut number_box, "-2.25");
+        assert!(number_box.error().is_none());
+        assert_eq!(number_box.into_value(), -2.25);
+    }
+
+    #[test]
+    fn paste() {
+        let mut rent = NumberBox::<u32>::builder()
+            .name("Rent")
+            .build();
+        typed(&mut rent, "");
+
+        // characters that cannot be typed are dropped from the pasted text
+        assert_eq!(rent.paste("$1,500.00\n"), InputResult::Updated);
+        assert_eq!(rent.text(), "150000");
+        assert_eq!(*rent.value(), 150_000);
+        assert_eq!(rent.paste("n/a"), InputResult::Ignored);
+    }
+
+    #[test]
+    fn modifiers() {
+        let mut number_box = NumberBox::<u32>::builder()
+            .name("")
+            .value(5)
+            .build();
+
+        // shifted and alt-modified characters are filtered just like plain ones
+        let shift = KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT);
+        let alt = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::ALT);
+        assert_eq!(number_box.input(shift), InputResult::Ignored);
+        assert_eq!(number_box.input(alt), InputResult::Ignored);
+        assert_eq!(number_box.text(), "5");
+        assert_eq!(number_box.error(), None);
+    }
+}
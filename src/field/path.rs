@@ -0,0 +1,347 @@
+use std::{
+    borrow::Cow, 
+    fs, 
+    path::{self, PathBuf}, 
+};
+use ratatui::{
+    text::{Span, Text}, 
+    style::Stylize, 
+};
+use crate::prelude::*;
+use super::*;
+
+/// An entry of a directory, as listed when completing a path. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+struct Entry {
+    name: String, 
+    is_dir: bool, 
+}
+
+/// Finds the completions of the last component of a path. 
+/// 
+/// The text up to and including the last separator is the directory whose entries are listed with `list`,
+/// and the remainder is the prefix that the names of the entries must start with. Hidden entries are only
+/// included if the prefix starts with `.`. Directories are completed with a trailing separator. 
+/// 
+/// Returns the completed texts in alphabetical order, excluding the text itself. 
+fn complete(text: &str, directories_only: bool, list: impl FnOnce(&str) -> Vec<Entry>) -> Vec<String> {
+    let split = text
+        .rfind(path::is_separator)
+        .map(|index| index + 1)
+        .unwrap_or(0);
+    let (parent, prefix) = text.split_at(split);
+    let mut completions: Vec<String> = list(parent)
+        .into_iter()
+        .filter(|entry| entry.name.starts_with(prefix))
+        .filter(|entry| prefix.starts_with('.') || !entry.name.starts_with('.'))
+        .filter(|entry| entry.is_dir || !directories_only)
+        .map(|entry| match entry.is_dir {
+            true => format!("{parent}{}{}", entry.name, path::MAIN_SEPARATOR), 
+            false => format!("{parent}{}", entry.name), 
+        })
+        .filter(|completion| completion != text)
+        .collect();
+    completions.sort();
+    completions
+}
+
+/// Lists the entries of a directory on the filesystem. Errors are ignored. 
+fn list(dir: PathBuf) -> Vec<Entry> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new()
+    };
+    entries
+        .flatten()
+        .map(|entry| Entry {
+            name: entry.file_name().to_string_lossy().into_owned(), 
+            is_dir: entry.path().is_dir(), 
+        })
+        .collect()
+}
+
+/// An in-progress cycle through the completions of a path, started by pressing `Tab`. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+struct Cycle {
+    completions: Vec<String>, 
+    index: usize, 
+}
+
+/// An [input field](super) for entering a filesystem path. 
+/// 
+/// The path is entered as in a [`Textbox`], with the addition of tab completion: the last component of the
+/// path is completed from the entries of its parent directory, and the best completion is shown dimmed after
+/// the caret. Relative paths are resolved against the [base directory](Builder::base), which defaults to the
+/// current working directory. 
+/// 
+/// The filesystem is read synchronously whenever the text changes. Errors, such as a directory that cannot
+/// be read, are ignored and simply yield no completions. 
+/// 
+/// If [`Builder::must_exist`] is given, the field reports itself as [invalid](Field::error) while the path
+/// doesn't exist, which is highlighted by [forms](crate::dialog::form!) in the same way as failed field
+/// validation. 
+/// 
+/// See [`path::Builder`](Builder) for the methods available when constructing the field. 
+/// 
+/// 
+/// # Key bindings
+/// 
+/// The same as for [`Textbox`], with the addition of: 
+/// 
+/// [`KeyCode::Tab`] completes the last component of the path. If there are several completions, pressing it
+/// again cycles through them, and [`KeyCode::BackTab`] cycles through them in reverse. 
+/// 
+/// When there is nothing to complete, these keys are [ignored](InputResult::Ignored), so that
+/// [forms](crate::dialog::form!) move their focus as usual. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct PathField {
+    /// The user-visible name displayed by the input field. 
+    pub name: Cow<'static, str>, 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
+    /// The entered text. 
+    text: Textbox, 
+    /// The entered path, resolved against the base directory. 
+    value: PathBuf, 
+    /// The directory that relative paths are resolved against. 
+    base: PathBuf, 
+    /// Whether only directories are completed. 
+    directories_only: bool, 
+    /// Whether the path must exist for the field to be valid. 
+    must_exist: bool, 
+    /// The completions of the current text. 
+    completions: Vec<String>, 
+    /// The cycle through completions started by the last key press, if it was `Tab`. 
+    cycle: Option<Cycle>, 
+}
+
+impl PathField {
+    /// Sets the current value. 
+    pub fn set_value(&mut self, value: impl Into<PathBuf>) {
+        self.text.set_value(value.into().to_string_lossy());
+        self.update();
+        self.cycle = None;
+    }
+
+    /// The entered text, before being resolved against the base directory. 
+    pub fn text(&self) -> &str {
+        self.text.value()
+    }
+
+    /// Resolves the entered text and finds its completions. 
+    fn update(&mut self) {
+        let text = self.text.value();
+        self.value = self.base.join(text);
+        self.completions = complete(text, self.directories_only, |parent| list(self.base.join(parent)));
+    }
+
+    /// Moves through the completions in the given direction, starting a new cycle if none is in progress. 
+    fn cycle(&mut self, forward: bool) -> InputResult {
+        let cycle = match (self.cycle.take(), forward) {
+            (Some(Cycle{ completions, index }), _) => {
+                let len = completions.len();
+                let index = match forward {
+                    true => (index + 1) % len, 
+                    false => (index + len - 1) % len, 
+                };
+                Cycle{ completions, index }
+            }
+            (None, true) if !self.completions.is_empty() => {
+                Cycle{ completions: self.completions.clone(), index: 0 }
+            }
+            (None, _) => return InputResult::Ignored, 
+        };
+        self.text.set_value(&cycle.completions[cycle.index]);
+        self.update();
+
+        // a lone completion needs no cycle; the next tab completes the path further
+        if cycle.completions.len() > 1 {
+            self.cycle = Some(cycle);
+        }
+        InputResult::Updated
+    }
+}
+
+impl Field for PathField {
+    type Value = PathBuf;
+    type Builder = Builder;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
+    fn input(&mut self, key: KeyEvent) -> InputResult {
+        match key.code {
+            KeyCode::Tab => self.cycle(true), 
+            KeyCode::BackTab => self.cycle(false), 
+            _ => {
+                self.cycle = None;
+                let result = self.text.input(key);
+                if let InputResult::Updated = result {
+                    self.update();
+                }
+                result
+            }
+        }
+    }
+
+    fn paste(&mut self, text: &str) -> InputResult {
+        self.cycle = None;
+        let result = self.text.paste(text);
+        if let InputResult::Updated = result {
+            self.update();
+        }
+        result
+    }
+
+    fn format(&self, focused: bool) -> Text<'_> {
+        let mut text = self.text.format(focused);
+        let hint = self.completions
+            .first()
+            .filter(|_| focused && self.cycle.is_none() && self.text.caret_at_end())
+            .and_then(|completion| completion.strip_prefix(self.text.value()))
+            .filter(|hint| !hint.is_empty());
+        if let (Some(hint), Some(line)) = (hint, text.lines.first_mut()) {
+            line.spans.push(Span::raw(hint.to_owned()).dim());
+        }
+        text
+    }
+
+    fn value(&self) -> &PathBuf {
+        &self.value
+    }
+
+    fn into_value(self) -> PathBuf {
+        self.value
+    }
+
+    fn error(&self) -> Option<Cow<'static, str>> {
+        match (self.must_exist, self.directories_only) {
+            (false, _) => None, 
+            (true, _) if !self.value.exists() => Some("No such file or directory".into()), 
+            (true, true) if !self.value.is_dir() => Some("Not a directory".into()), 
+            (true, _) => None, 
+        }
+    }
+}
+
+/// Constructs a [`PathField`]. 
+/// 
+/// This is mainly used by the [form macro](crate::dialog::form!) when instantiating path fields, but may
+/// also be used in application code for creating a stand-alone field. 
+/// 
+/// Requires that [`Builder::name`] is called before the field can be built. 
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Builder<const NAME: bool = false> {
+    name: Cow<'static, str>, 
+    disabled: bool, 
+    value: PathBuf, 
+    base: Option<PathBuf>, 
+    directories_only: bool, 
+    must_exist: bool, 
+}
+
+impl<const NAME: bool> Builder<NAME> {
+    /// The user-visible name displayed by the input field. 
+    pub fn name(self, name: impl Into<Cow<'static, str>>) -> Builder<true> {
+        let name = name.into();
+        let Builder{ disabled, value, base, directories_only, must_exist, .. } = self;
+        Builder{ name, disabled, value, base, directories_only, must_exist }
+    }
+
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder{ disabled, ..self }
+    }
+
+    /// The initial value. Default: empty. 
+    pub fn value(self, value: impl Into<PathBuf>) -> Self {
+        let value = value.into();
+        Builder{ value, ..self }
+    }
+
+    /// The directory that relative paths are resolved against. Default: the current working directory. 
+    pub fn base(self, base: impl Into<PathBuf>) -> Self {
+        let base = Some(base.into());
+        Builder{ base, ..self }
+    }
+
+    /// Only complete directories, and with [`Builder::must_exist`], require the path to be a directory. 
+    pub fn directories_only(self) -> Self {
+        Builder{ directories_only: true, ..self }
+    }
+
+    /// Mark the field as [invalid](Field::error) while the path doesn't exist. 
+    pub fn must_exist(self) -> Self {
+        Builder{ must_exist: true, ..self }
+    }
+}
+
+impl Build for Builder<true> {
+    type Field = PathField;
+
+    /// If the name has been defined with [`Builder::name`], consumes the builder and returns the constructed
+    /// [`PathField`]. 
+    fn build(self) -> PathField {
+        let Builder{ name, disabled, value, base, directories_only, must_exist } = self;
+        let base = base
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default();
+        let mut field = PathField {
+            name, 
+            disabled, 
+            text: Textbox::builder().name("").build(), 
+            value: PathBuf::new(), 
+            base, 
+            directories_only, 
+            must_exist, 
+            completions: Vec::new(), 
+            cycle: None, 
+        };
+        field.set_value(value);
+        field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::MAIN_SEPARATOR as SEP;
+    use super::{complete, Entry};
+
+    fn list(parent: &str) -> Vec<Entry> {
+        let entries: &[(&str, bool)] = match parent {
+            "" => &[("src", true), ("Cargo.toml", false), ("Cargo.lock", false), (".git", true)], 
+            "src/" => &[("lib.rs", false), ("field", true)], 
+            _ => &[], 
+        };
+        entries
+            .iter()
+            .map(|&(name, is_dir)| Entry{ name: name.to_owned(), is_dir })
+            .collect()
+    }
+
+    #[test]
+    fn completion() {
+        assert_eq!(complete("Car", false, list), ["Cargo.lock", "Cargo.toml"]);
+        assert_eq!(complete("s", false, list), [format!("src{SEP}")]);
+        assert_eq!(complete("src/f", false, list), [format!("src/field{SEP}")]);
+        assert_eq!(complete("src/", false, list), [format!("src/field{SEP}"), "src/lib.rs".to_owned()]);
+        assert!(complete("missing/", false, list).is_empty());
+
+        // a complete name has nothing left to complete
+        assert!(complete("Cargo.toml", false, list).is_empty());
+    }
+
+    #[test]
+    fn filtering() {
+        // hidden entries are only completed when asked for
+        assert_eq!(complete("", false, list).len(), 3);
+        assert_eq!(complete(".", false, list), [format!(".git{SEP}")]);
+
+        assert_eq!(complete("", true, list), [format!("src{SEP}")]);
+        assert!(complete("Car", true, list).is_empty());
+    }
+}
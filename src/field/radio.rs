@@ -6,21 +6,46 @@ use super::*;
 /// An [input field](super) for selecting one item among a set. 
 /// 
 /// The value is the index of the selected item. See [`radio::Builder`] for the methods available when
-/// constructing the field. 
+/// constructing the field. To associate a value with each item instead, use a [`Choice`]. 
+/// 
+/// 
+/// # Vertical layout
+/// 
+/// By default, only the selected item is displayed, on a single line as `<Item>`. With [`Builder::vertical`],
+/// the items are instead displayed as a vertical list with the selected item marked by `→`, scrolling to
+/// keep it in view when there are more items than fit. 
 /// 
 /// 
 /// # Key bindings
 /// 
-/// [`KeyCode::Up`] and [`KeyCode::Down`] move the focused item up and down, respectively. Any other key sets
-/// the focused item to the selected one. 
+/// [`KeyCode::Left`] and [`KeyCode::Right`] select the previous and next item, respectively, wrapping around
+/// at the ends. 
+/// 
+/// In the vertical layout, [`KeyCode::Up`] and [`KeyCode::Down`] are used instead. These don't wrap around;
+/// at the first and last item they are [ignored](InputResult::Ignored), so that
+/// [forms](crate::dialog::form!) move their focus as usual. 
+/// 
+/// 
+/// # Descriptions
+/// 
+/// Items may be given a description with [`Builder::items_with_descriptions`]. Since only the selected item
+/// is displayed, only its description is shown, as a dim span after the item. 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Radio {
     /// The user-visible name displayed by the input field. 
     pub name: Cow<'static, str>, 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
     /// The user-visible names of the items that can chosen between. 
     pub items: Vec<Cow<'static, str>>, 
+    /// The description of each item, if any. 
+    descriptions: Vec<Option<Cow<'static, str>>>, 
     /// Index of the currently selected item. 
     selected: usize, 
+    /// The maximum number of items shown at a time in the vertical layout, if enabled. 
+    vertical: Option<usize>, 
+    /// Index of the first item shown in the vertical layout. 
+    scroll: usize, 
 }
 
 impl Radio {
@@ -28,6 +53,22 @@ impl Radio {
     fn max_selected(&self) -> usize {
        self.items.len() - 1 
     }
+
+    /// Selects an item in the vertical layout, scrolling to keep it visible. 
+    fn select_vertical(&mut self, index: usize, max_visible: usize) -> InputResult {
+        self.selected = index;
+        self.scroll = self.scroll
+            .min(index)
+            .max((index + 1).saturating_sub(max_visible));
+        InputResult::Updated
+    }
+
+    /// Gets the description of the item at given index, if it has one. 
+    pub fn description(&self, index: usize) -> Option<&str> {
+        self.descriptions
+            .get(index)?
+            .as_deref()
+    }
 }
 
 impl Field for Radio {
@@ -38,7 +79,20 @@ impl Field for Radio {
         &self.name
     }
 
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
     fn input(&mut self, key: KeyEvent) -> InputResult {
+        if let Some(max_visible) = self.vertical {
+            return match key.code {
+                KeyCode::Up if self.selected > 0 => self.select_vertical(self.selected - 1, max_visible), 
+                KeyCode::Down if self.selected < self.max_selected() => {
+                    self.select_vertical(self.selected + 1, max_visible)
+                }
+                _ => InputResult::Ignored, 
+            }
+        }
         match key.code {
             // move selected item left/right
             KeyCode::Left => {
@@ -59,17 +113,42 @@ impl Field for Radio {
         }
     }
 
-    fn format(&self, focused: bool) -> Text {
-        let value = self.items[self.selected].to_string();
+    fn format(&self, focused: bool) -> Text<'_> {
         let style = match focused {
             true => Style::new().bold(), 
             false => Style::new(), 
         };
-        Line::from(vec![
+        if let Some(max_visible) = self.vertical {
+            let format_item = |(i, item)| {
+                let mut line = match i == self.selected {
+                    true => Line::styled(format!("→ {item}"), style), 
+                    false => Line::from(format!("· {item}")), 
+                };
+                if let Some(description) = self.description(i) {
+                    line.push_span(Span::from(format!(" {description}")).dim());
+                }
+                line
+            };
+            return self.items
+                .iter()
+                .enumerate()
+                .skip(self.scroll)
+                .take(max_visible)
+                .map(format_item)
+                .collect::<Vec<_>>()
+                .into()
+        }
+
+        let value = self.items[self.selected].to_string();
+        let mut line = Line::from(vec![
             Span::from("<"), 
             Span::styled(value, style), 
             Span::from(">"), 
-        ]).into()
+        ]);
+        if let Some(description) = self.description(self.selected) {
+            line.push_span(Span::from(format!(" {description}")).dim());
+        }
+        line.into()
     }
 
     fn value(&self) -> &Self::Value {
@@ -79,6 +158,10 @@ impl Field for Radio {
     fn into_value(self) -> Self::Value {
         self.selected
     }
+
+    fn focused_line(&self) -> Option<usize> {
+        self.vertical.map(|_| self.selected - self.scroll)
+    }
 }
 
 /// Constructs a [`Radio`]. 
@@ -94,8 +177,12 @@ impl Default for Builder {
     fn default() -> Self {
         Self(Radio {
             name: Default::default(), 
+            disabled: false, 
             items: Default::default(), 
+            descriptions: Default::default(), 
             selected: 0, 
+            vertical: None, 
+            scroll: 0, 
         })
     }
 }
@@ -107,6 +194,11 @@ impl<const NAME: bool, const ITEMS: bool> Builder<NAME, ITEMS> {
         Builder(Radio{ name, ..self.0 })
     }
 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder(Radio{ disabled, ..self.0 })
+    }
+
     /// The user-visible names of all items that can be chosen between. 
     /// 
     /// 
@@ -123,15 +215,65 @@ impl<const NAME: bool, const ITEMS: bool> Builder<NAME, ITEMS> {
             .collect();
         debug_assert!(!items.is_empty());
 
-        Builder(Radio{ items, ..self.0 })
+        Builder(Radio{ items, descriptions: Vec::new(), ..self.0 })
+    }
+
+    /// The user-visible names and descriptions of all items that can be chosen between. The description of
+    /// the selected item is displayed after it; items with an empty description are displayed without one. 
+    /// 
+    /// 
+    /// # Panics
+    /// 
+    /// When the number of items is zero. 
+    pub fn items_with_descriptions<T, U>(self, items: impl IntoIterator<Item = (T, U)>) -> Builder<NAME, true>
+    where
+        T: Into<Cow<'static, str>>, 
+        U: Into<Cow<'static, str>>, 
+    {
+        let (items, descriptions): (Vec<_>, _) = items
+            .into_iter()
+            .map(|(item, description)| (item.into(), non_empty(description.into())))
+            .unzip();
+        debug_assert!(!items.is_empty());
+
+        Builder(Radio{ items, descriptions, ..self.0 })
+    }
+
+    /// Displays the items as a vertical list showing at most `max_visible` items at a time. See the
+    /// [type-level](Radio#vertical-layout) documentation for more information. 
+    pub fn vertical(self, max_visible: usize) -> Self {
+        let max_visible = max_visible.max(1);
+        let mut radio = Radio{ vertical: Some(max_visible), scroll: 0, ..self.0 };
+        radio.select_vertical(radio.selected, max_visible);
+        Builder(radio)
     }
 }
 
 impl<const NAME: bool> Builder<NAME, true> {
-    /// The index of the currently selected item. 
+    /// The index of the currently selected item. An index beyond the last item is clamped to the last item. 
+    /// 
+    /// 
+    /// # Panics
+    /// 
+    /// In debug builds, when the index is beyond the last item. 
     pub fn selected(self, index: usize) -> Self {
-        let selected = index;
-        Builder(Radio{ selected, ..self.0 })
+        let Radio{ name, items, .. } = &self.0;
+        debug_assert!(
+            index < items.len(), 
+            "Selected index {index} of radio \"{name}\" is out of range for {} items", 
+            items.len(), 
+        );
+        let selected = index.min(self.0.max_selected());
+        let mut radio = Radio{ selected, scroll: 0, ..self.0 };
+        if let Some(max_visible) = radio.vertical {
+            radio.select_vertical(selected, max_visible);
+        }
+        Builder(radio)
+    }
+
+    /// Alias of [`Builder::selected`], for consistency with the other fields. 
+    pub fn value(self, index: usize) -> Self {
+        self.selected(index)
     }
 }
 
@@ -145,6 +287,7 @@ impl Build for Builder<true, true> {
 
 #[cfg(test)]
 mod tests {
+    use ratatui::style::Modifier;
     use crate::{prelude::*, field::*};
 
     #[test]
@@ -178,4 +321,102 @@ mod tests {
         input(KeyCode::Right, radio, InputResult::Updated);
         assert_eq!(radio.selected, 0);
     }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions, 
+        should_panic(expected = "Selected index 7 of radio \"Units\" is out of range for 2 items"), 
+    )]
+    fn selected_out_of_range() {
+        let radio = Radio::builder()
+            .name("Units")
+            .items(["Metric", "Imperial"])
+            .selected(7)
+            .build();
+        assert_eq!(*radio.value(), 1);
+    }
+
+    #[test]
+    fn vertical() {
+        let format = |radio: &Radio| radio
+            .format(false)
+            .lines
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        let mut radio = Radio::builder()
+            .name("")
+            .items(["One", "Two", "Three", "Four", "Five"])
+            .vertical(3)
+            .build();
+        assert_eq!(format(&radio), ["→ One", "· Two", "· Three"]);
+
+        // the focus can only leave the field at the edges
+        assert_eq!(radio.input(KeyCode::Up.into()), InputResult::Ignored);
+        assert_eq!(radio.input(KeyCode::Left.into()), InputResult::Ignored);
+
+        // the window only scrolls once the selection would leave it
+        radio.input(KeyCode::Down.into());
+        radio.input(KeyCode::Down.into());
+        assert_eq!(format(&radio), ["· One", "· Two", "→ Three"]);
+        radio.input(KeyCode::Down.into());
+        assert_eq!(format(&radio), ["· Two", "· Three", "→ Four"]);
+        radio.input(KeyCode::Down.into());
+        assert_eq!(format(&radio), ["· Three", "· Four", "→ Five"]);
+        assert_eq!(radio.focused_line(), Some(2));
+        assert_eq!(radio.input(KeyCode::Down.into()), InputResult::Ignored);
+
+        radio.input(KeyCode::Up.into());
+        radio.input(KeyCode::Up.into());
+        assert_eq!(format(&radio), ["→ Three", "· Four", "· Five"]);
+        radio.input(KeyCode::Up.into());
+        assert_eq!(format(&radio), ["→ Two", "· Three", "· Four"]);
+        assert_eq!(*radio.value(), 1);
+
+        // the initial selection is scrolled into view
+        let radio = Radio::builder()
+            .name("")
+            .items(["One", "Two", "Three", "Four", "Five"])
+            .vertical(2)
+            .selected(3)
+            .build();
+        assert_eq!(format(&radio), ["· Three", "→ Four"]);
+        let radio = Radio::builder()
+            .name("")
+            .items(["One", "Two", "Three", "Four", "Five"])
+            .selected(4)
+            .vertical(2)
+            .build();
+        assert_eq!(format(&radio), ["· Four", "→ Five"]);
+    }
+
+    #[test]
+    fn descriptions() {
+        let format = |radio: &Radio| radio
+            .format(true)
+            .lines
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        let radio = Radio::builder()
+            .name("")
+            .items(["Metric", "Imperial"])
+            .build();
+        assert_eq!(format(&radio), ["<Metric>"]);
+
+        // only some items have descriptions; only that of the selected item is shown
+        let mut radio = Radio::builder()
+            .name("")
+            .items_with_descriptions([("Metric", "Kilometres"), ("Imperial", "")])
+            .build();
+        assert_eq!(format(&radio), ["<Metric> Kilometres"]);
+        let text = radio.format(true);
+        assert!(text.lines[0].spans[3].style.add_modifier.contains(Modifier::DIM));
+
+        radio.input(KeyCode::Right.into());
+        assert_eq!(format(&radio), ["<Imperial>"]);
+        assert_eq!(*radio.value(), 1);
+    }
 }
@@ -0,0 +1,293 @@
+use std::borrow::Cow;
+use ratatui::{
+    style::{Style, Stylize}, 
+    text::{Line, Span, Text}, 
+};
+use crate::prelude::*;
+use super::*;
+
+/// An [input field](super) for selecting a rating between zero or one and some maximum, displayed as a row
+/// of stars. 
+/// 
+/// See [`rating::Builder`] for the methods available when constructing the field. 
+/// 
+/// 
+/// # Key bindings
+/// 
+/// [`KeyCode::Left`] and [`KeyCode::Right`] decrease and increase the rating by one, respectively. The digit
+/// keys `1` through `9` set the rating directly, clamped to the maximum. If zero is allowed, `0` clears the
+/// rating. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Rating {
+    /// The user-visible name displayed by the input field. 
+    pub name: Cow<'static, str>, 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
+    /// The current user-entered value. 
+    value: u8, 
+    /// The maximum rating. 
+    max: u8, 
+    /// Whether the rating can be zero. Otherwise, the minimum rating is one. 
+    allow_zero: bool, 
+    /// Symbols used to display the rating. 
+    glyphs: Glyphs, 
+}
+
+impl Rating {
+    /// The minimum rating. 
+    pub fn min(&self) -> u8 {
+        match self.allow_zero {
+            true => 0, 
+            false => 1, 
+        }
+    }
+
+    /// The maximum rating. 
+    pub fn max(&self) -> u8 {
+        self.max
+    }
+
+    /// Sets the rating, clamped to the allowed range. Returns whether the rating changed. 
+    pub fn set_value(&mut self, value: u8) -> bool {
+        let value = value.clamp(self.min(), self.max);
+        let changed = value != self.value;
+        self.value = value;
+        changed
+    }
+}
+
+impl Field for Rating {
+    type Value = u8;
+    type Builder = Builder;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
+    fn input(&mut self, key: KeyEvent) -> InputResult {
+        let value = match key.code {
+            KeyCode::Left => self.value.saturating_sub(1), 
+            KeyCode::Right => self.value.saturating_add(1), 
+            KeyCode::Char('0') if self.allow_zero => 0, 
+            KeyCode::Char(digit @ '1'..='9') => digit as u8 - b'0', 
+            _ => return InputResult::Ignored, 
+        };
+        match self.set_value(value) {
+            true => InputResult::Updated, 
+            false => InputResult::Consumed, 
+        }
+    }
+
+    fn format(&self, focused: bool) -> Text<'_> {
+        let Glyphs{ filled, empty } = self.glyphs;
+        let filled_style = match focused {
+            true => Style::new().bold(), 
+            false => Style::new(), 
+        };
+        let filled = Span::styled(filled.to_string().repeat(self.value as usize), filled_style);
+        let empty = Span::raw(empty.to_string().repeat((self.max - self.value) as usize));
+        Line::from(vec![filled, empty]).into()
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.value
+    }
+
+    fn into_value(self) -> Self::Value {
+        self.value
+    }
+}
+
+/// The symbols used to display a [`Rating`]. 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Glyphs {
+    /// Symbol of each point up to and including the rating. 
+    pub filled: char, 
+    /// Symbol of each point above the rating. 
+    pub empty: char, 
+}
+
+impl Glyphs {
+    /// Stars; `★` filled and `☆` empty. This is the default. 
+    pub const UNICODE: Glyphs = Glyphs{ filled: '★', empty: '☆' };
+    /// Fallback for terminals without Unicode support; `*` filled and `.` empty. 
+    pub const ASCII: Glyphs = Glyphs{ filled: '*', empty: '.' };
+}
+
+impl Default for Glyphs {
+    fn default() -> Self {
+        Glyphs::UNICODE
+    }
+}
+
+/// Constructs a [`Rating`]. 
+/// 
+/// This is mainly used by the [form macro](crate::dialog::form!) when instantiating ratings, but may also be
+/// used in application code for creating a stand-alone field. 
+/// 
+/// Requires that [`Builder::name`] is called before the field can be built. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Builder<const NAME: bool = false> {
+    name: Cow<'static, str>, 
+    disabled: bool, 
+    value: u8, 
+    max: u8, 
+    allow_zero: bool, 
+    glyphs: Glyphs, 
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Builder {
+            name: Default::default(), 
+            disabled: false, 
+            value: 0, 
+            max: 5, 
+            allow_zero: false, 
+            glyphs: Glyphs::default(), 
+        }
+    }
+}
+
+impl<const NAME: bool> Builder<NAME> {
+    /// The user-visible name displayed by the input field. 
+    pub fn name(self, name: impl Into<Cow<'static, str>>) -> Builder<true> {
+        let name = name.into();
+        let Builder{ disabled, value, max, allow_zero, glyphs, .. } = self;
+        Builder{ name, disabled, value, max, allow_zero, glyphs }
+    }
+
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder{ disabled, ..self }
+    }
+
+    /// The maximum rating. Clamped to be at least one. Default: `5`. 
+    pub fn max(self, max: u8) -> Self {
+        let max = max.max(1);
+        Builder{ max, ..self }
+    }
+
+    /// The initial value. Clamped to the allowed range when the field is built. Default: the minimum rating. 
+    pub fn value(self, value: u8) -> Self {
+        Builder{ value, ..self }
+    }
+
+    /// Allows the rating to be zero. Otherwise, the minimum rating is one. 
+    pub fn allow_zero(self) -> Self {
+        Builder{ allow_zero: true, ..self }
+    }
+
+    /// The symbols used to display the rating. Default: [`Glyphs::UNICODE`]. 
+    pub fn glyphs(self, glyphs: Glyphs) -> Self {
+        Builder{ glyphs, ..self }
+    }
+}
+
+impl Build for Builder<true> {
+    type Field = Rating;
+
+    fn build(self) -> Rating {
+        let Builder{ name, disabled, value, max, allow_zero, glyphs } = self;
+        let mut rating = Rating{ name, disabled, value: 0, max, allow_zero, glyphs };
+        rating.set_value(value);
+        rating
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Modifier;
+    use crate::{prelude::*, field::*};
+    use super::Glyphs;
+
+    #[test]
+    fn clamping() {
+        let rating = Rating::builder()
+            .name("")
+            .value(9)
+            .build();
+        assert_eq!(rating.value, 5);
+
+        // the minimum is one unless zero is allowed
+        let rating = Rating::builder()
+            .name("")
+            .build();
+        assert_eq!(rating.value, 1);
+        let rating = Rating::builder()
+            .name("")
+            .allow_zero()
+            .build();
+        assert_eq!(rating.value, 0);
+
+        // the initial value is clamped to the maximum regardless of the order of the builder methods
+        let rating = Rating::builder()
+            .name("")
+            .value(4)
+            .max(3)
+            .build();
+        assert_eq!(rating.value, 3);
+    }
+
+    #[test]
+    fn input() {
+        let mut rating = Rating::builder()
+            .name("")
+            .max(3)
+            .build();
+        let mut input = |code: KeyCode| (rating.input(code.into()), *rating.value());
+
+        assert_eq!(input(KeyCode::Left), (InputResult::Consumed, 1));
+        assert_eq!(input(KeyCode::Right), (InputResult::Updated, 2));
+        assert_eq!(input(KeyCode::Right), (InputResult::Updated, 3));
+        assert_eq!(input(KeyCode::Right), (InputResult::Consumed, 3));
+
+        // digits beyond the maximum jump to the maximum
+        assert_eq!(input(KeyCode::Char('1')), (InputResult::Updated, 1));
+        assert_eq!(input(KeyCode::Char('9')), (InputResult::Updated, 3));
+        assert_eq!(input(KeyCode::Char('7')), (InputResult::Consumed, 3));
+
+        // zero is not allowed
+        assert_eq!(input(KeyCode::Char('0')), (InputResult::Ignored, 3));
+
+        // these two must be ignored for form navigation to work properly
+        assert_eq!(input(KeyCode::Up), (InputResult::Ignored, 3));
+        assert_eq!(input(KeyCode::Down), (InputResult::Ignored, 3));
+
+        let mut rating = Rating::builder()
+            .name("")
+            .allow_zero()
+            .value(2)
+            .build();
+        assert_eq!(rating.input(KeyCode::Char('0').into()), InputResult::Updated);
+        assert_eq!(rating.value, 0);
+    }
+
+    #[test]
+    fn format() {
+        let format = |glyphs: Glyphs| Rating::builder()
+            .name("")
+            .value(2)
+            .max(4)
+            .glyphs(glyphs)
+            .build()
+            .format(false)
+            .to_string();
+        assert_eq!(format(Glyphs::UNICODE), "★★☆☆");
+        assert_eq!(format(Glyphs::ASCII), "**..");
+
+        // the filled region is bold when focused
+        let rating = Rating::builder()
+            .name("")
+            .value(1)
+            .build();
+        let text = rating.format(true);
+        let spans = &text.lines[0].spans;
+        assert!(spans[0].style.add_modifier.contains(Modifier::BOLD));
+        assert!(!spans[1].style.add_modifier.contains(Modifier::BOLD));
+    }
+}
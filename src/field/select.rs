@@ -0,0 +1,385 @@
+use std::borrow::Cow;
+use ratatui::{style::{Style, Stylize}, text::{Line, Span, Text}};
+use crate::prelude::*;
+use super::*;
+
+/// Finds the items matching a search query, ignoring case. Items containing the query are ranked first,
+/// followed by items containing the characters of the query in order, but not necessarily adjacent (e.g. 
+/// `"uk"` matches `"United Kingdom"`). 
+/// 
+/// Returns the indices of the matching items, ordered by rank and then by index. 
+fn filter(items: &[Cow<'static, str>], query: &str) -> Vec<usize> {
+    let query = query.to_lowercase();
+    let (mut substrings, mut subsequences) = (Vec::new(), Vec::new());
+    for (index, item) in items.iter().enumerate() {
+        let item = item.to_lowercase();
+        if item.contains(&query) {
+            substrings.push(index);
+        } else {
+            let mut chars = item.chars();
+            if query.chars().all(|q| chars.any(|c| c == q)) {
+                subsequences.push(index);
+            }
+        }
+    }
+    substrings.append(&mut subsequences);
+    substrings
+}
+
+/// An [input field](super) for selecting one item among a large set, by searching for it. 
+/// 
+/// The value is the index of the selected item, whose label is displayed on one line. Typing while the field
+/// is focused opens a list of the items matching what has been typed on the following lines, at most
+/// [`max_visible`](Builder::max_visible) at a time. The highlighted item of the list is selected as it
+/// changes. 
+/// 
+/// For only a handful of items, consider a [`Radio`] instead. 
+/// 
+/// See [`select::Builder`] for the methods available when constructing the field. 
+/// 
+/// 
+/// # Key bindings
+/// 
+/// [`KeyCode::Char`] inputs are added to the search query, opening the list if it is closed. 
+/// [`KeyCode::Backspace`] removes the last character of the query, or closes the list if the query is
+/// empty. 
+/// 
+/// While the list is open, [`KeyCode::Up`] and [`KeyCode::Down`] move the highlighted item, and
+/// [`KeyCode::Right`] confirms the highlighted item and closes the list. While the list is closed, the
+/// arrow keys are [ignored](InputResult::Ignored), so that [forms](crate::dialog::form!) move their focus as
+/// usual. 
+/// 
+/// Since the highlighted item is already selected, pressing `Enter` in a form submits it with the
+/// highlighted item. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Dropdown {
+    /// The user-visible name displayed by the input field. 
+    pub name: Cow<'static, str>, 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
+    /// The user-visible names of the items that can be chosen between. 
+    items: Vec<Cow<'static, str>>, 
+    /// Index of the currently selected item. 
+    selected: usize, 
+    /// The maximum number of items shown in the list at a time. 
+    max_visible: usize, 
+    /// The search query, if the list is open. 
+    query: Option<String>, 
+    /// Indices of the items matching the query. 
+    matches: Vec<usize>, 
+    /// Index into `matches` of the highlighted item. 
+    highlighted: usize, 
+    /// Index into `matches` of the first item shown in the list. 
+    scroll: usize, 
+}
+
+impl Dropdown {
+    /// The user-visible names of the items that can be chosen between. 
+    pub fn items(&self) -> &[Cow<'static, str>] {
+        &self.items
+    }
+
+    /// Whether the list of matching items is open. 
+    pub fn is_open(&self) -> bool {
+        self.query.is_some()
+    }
+
+    /// Moves the highlight to an index into `matches`, scrolling the list to keep it visible and selecting
+    /// the highlighted item. 
+    fn highlight(&mut self, index: usize) -> InputResult {
+        self.highlighted = index;
+        self.scroll = self.scroll
+            .min(index)
+            .max((index + 1).saturating_sub(self.max_visible));
+
+        let old = self.selected;
+        if let Some(&selected) = self.matches.get(index) {
+            self.selected = selected;
+        }
+        match old == self.selected {
+            true => InputResult::Consumed, 
+            false => InputResult::Updated, 
+        }
+    }
+
+    /// Refilters the items after the query has changed, highlighting the best match. 
+    fn search(&mut self) -> InputResult {
+        let query = self.query.as_deref().unwrap_or_default();
+        self.matches = filter(&self.items, query);
+        self.scroll = 0;
+        self.highlight(0)
+    }
+}
+
+impl Field for Dropdown {
+    type Value = usize;
+    type Builder = Builder;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
+    fn input(&mut self, key: KeyEvent) -> InputResult {
+        let Some(query) = &mut self.query else {
+            return match key.code {
+                KeyCode::Char(c) => {
+                    self.query = Some(c.to_string());
+                    self.search()
+                }
+                _ => InputResult::Ignored, 
+            }
+        };
+        match key.code {
+            KeyCode::Char(c) => {
+                query.push(c);
+                self.search()
+            }
+            KeyCode::Backspace => match query.pop() {
+                Some(_) => self.search(), 
+                None => {
+                    self.query = None;
+                    InputResult::Consumed
+                }
+            }
+            KeyCode::Up => self.highlight(self.highlighted.saturating_sub(1)), 
+            KeyCode::Down => {
+                let last = self.matches.len().saturating_sub(1);
+                self.highlight(usize::min(self.highlighted + 1, last))
+            }
+            KeyCode::Right => {
+                self.query = None;
+                InputResult::Consumed
+            }
+            _ => InputResult::Ignored, 
+        }
+    }
+
+    fn format(&self, focused: bool) -> Text<'_> {
+        let label = self.items[self.selected].to_string();
+        let query = self.query
+            .as_deref()
+            .filter(|_| focused);
+        let Some(query) = query else {
+            let style = match focused {
+                true => Style::new().bold(), 
+                false => Style::new(), 
+            };
+            return Line::styled(label, style).into()
+        };
+
+        let header = Line::from(vec![
+            Span::raw(query.to_owned()), 
+            Span::styled(" ", Style::new().reversed()), 
+            Span::raw(format!(" {label}")).dim(), 
+        ]);
+        let list = self.matches
+            .iter()
+            .enumerate()
+            .skip(self.scroll)
+            .take(self.max_visible)
+            .map(|(i, &item)| match i == self.highlighted {
+                true => Line::from(format!("> {}", self.items[item])).bold(), 
+                false => Line::from(format!("  {}", self.items[item])), 
+            });
+        let mut lines: Vec<Line> = std::iter::once(header)
+            .chain(list)
+            .collect();
+        if self.matches.is_empty() {
+            lines.push(Line::from("  No matches").dim());
+        }
+        lines.into()
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.selected
+    }
+
+    fn into_value(self) -> Self::Value {
+        self.selected
+    }
+
+    fn focused_line(&self) -> Option<usize> {
+        self.is_open().then(|| 1 + self.highlighted - self.scroll)
+    }
+}
+
+/// Constructs a [`Dropdown`]. 
+/// 
+/// This is mainly used by the [form macro](crate::dialog::form!) when instantiating dropdowns, but may also
+/// be used in application code for creating a stand-alone field. 
+/// 
+/// Requires that both [`Builder::name`] and [`Builder::items`] are called before the field can be built. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Builder<const NAME: bool = false, const ITEMS: bool = false>(Dropdown);
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self(Dropdown {
+            name: Default::default(), 
+            disabled: false, 
+            items: Default::default(), 
+            selected: 0, 
+            max_visible: 5, 
+            query: None, 
+            matches: Vec::new(), 
+            highlighted: 0, 
+            scroll: 0, 
+        })
+    }
+}
+
+impl<const NAME: bool, const ITEMS: bool> Builder<NAME, ITEMS> {
+    /// The user-visible name displayed by the input field. 
+    pub fn name(self, name: impl Into<Cow<'static, str>>) -> Builder<true, ITEMS> {
+        let name = name.into();
+        Builder(Dropdown{ name, ..self.0 })
+    }
+
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder(Dropdown{ disabled, ..self.0 })
+    }
+
+    /// The user-visible names of all items that can be chosen between. 
+    /// 
+    /// 
+    /// # Panics
+    /// 
+    /// When the number of items is zero. 
+    pub fn items<T>(self, items: impl IntoIterator<Item = T>) -> Builder<NAME, true>
+    where
+        T: Into<Cow<'static, str>>, 
+    {
+        let items: Vec<_> = items
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        debug_assert!(!items.is_empty());
+
+        Builder(Dropdown{ items, ..self.0 })
+    }
+
+    /// The maximum number of items shown in the list at a time. Default: 5. 
+    pub fn max_visible(self, max_visible: usize) -> Self {
+        let max_visible = max_visible.max(1);
+        Builder(Dropdown{ max_visible, ..self.0 })
+    }
+}
+
+impl<const NAME: bool> Builder<NAME, true> {
+    /// The index of the currently selected item. An index beyond the last item is clamped to the last item. 
+    /// 
+    /// 
+    /// # Panics
+    /// 
+    /// In debug builds, when the index is beyond the last item. 
+    pub fn selected(self, index: usize) -> Self {
+        let Dropdown{ name, items, .. } = &self.0;
+        debug_assert!(
+            index < items.len(), 
+            "Selected index {index} of dropdown \"{name}\" is out of range for {} items", 
+            items.len(), 
+        );
+        let selected = index.min(items.len() - 1);
+        Builder(Dropdown{ selected, ..self.0 })
+    }
+
+    /// Alias of [`Builder::selected`], for consistency with the other fields. 
+    pub fn value(self, index: usize) -> Self {
+        self.selected(index)
+    }
+}
+
+impl Build for Builder<true, true> {
+    type Field = Dropdown;
+
+    fn build(self) -> Self::Field {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, field::*};
+    use super::filter;
+
+    const COUNTRIES: [&str; 6] = ["Germany", "United Kingdom", "Algeria", "Nigeria", "Norway", "Uganda"];
+
+    fn dropdown() -> Dropdown {
+        Dropdown::builder()
+            .name("Country")
+            .items(COUNTRIES)
+            .max_visible(2)
+            .build()
+    }
+
+    #[test]
+    fn filtering() {
+        let items = COUNTRIES.map(Into::into);
+
+        // substring matches are ranked before subsequence matches
+        assert_eq!(filter(&items, "ger"), [0, 2, 3]);
+        assert_eq!(filter(&items, "UK"), [1]);
+        assert_eq!(filter(&items, "nor"), [4]);
+        assert_eq!(filter(&items, ""), [0, 1, 2, 3, 4, 5]);
+        assert!(filter(&items, "xyz").is_empty());
+    }
+
+    #[test]
+    fn input() {
+        let mut dropdown = dropdown();
+
+        // arrow keys are left to the form while the list is closed
+        assert_eq!(dropdown.input(KeyCode::Down.into()), InputResult::Ignored);
+
+        // the best match is selected while typing
+        assert_eq!(dropdown.input(KeyCode::Char('n').into()), InputResult::Consumed);
+        assert_eq!(dropdown.input(KeyCode::Char('i').into()), InputResult::Updated);
+        assert_eq!(*dropdown.value(), 1);
+        assert!(dropdown.is_open());
+
+        // "United Kingdom", "Nigeria"
+        assert_eq!(dropdown.input(KeyCode::Down.into()), InputResult::Updated);
+        assert_eq!(dropdown.input(KeyCode::Down.into()), InputResult::Consumed);
+        assert_eq!(*dropdown.value(), 3);
+        assert_eq!(dropdown.focused_line(), Some(2));
+        assert_eq!(dropdown.input(KeyCode::Up.into()), InputResult::Updated);
+        assert_eq!(dropdown.focused_line(), Some(1));
+
+        assert_eq!(dropdown.input(KeyCode::Right.into()), InputResult::Consumed);
+        assert!(!dropdown.is_open());
+        assert_eq!(dropdown.input(KeyCode::Up.into()), InputResult::Ignored);
+        assert_eq!(*dropdown.value(), 1);
+    }
+
+    #[test]
+    fn format() {
+        let format = |dropdown: &Dropdown, focused| dropdown
+            .format(focused)
+            .lines
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        let mut dropdown = dropdown();
+        assert_eq!(format(&dropdown, true), ["Germany"]);
+
+        dropdown.input(KeyCode::Char('a').into());
+        assert_eq!(format(&dropdown, true), ["a  Germany", "> Germany", "  Algeria"]);
+        assert_eq!(format(&dropdown, false), ["Germany"]);
+
+        dropdown.input(KeyCode::Char('x').into());
+        assert_eq!(format(&dropdown, true), ["ax  Germany", "  No matches"]);
+
+        // removing the last character of an empty query closes the list
+        dropdown.input(KeyCode::Backspace.into());
+        dropdown.input(KeyCode::Backspace.into());
+        assert!(dropdown.is_open());
+        dropdown.input(KeyCode::Backspace.into());
+        assert!(!dropdown.is_open());
+    }
+}
@@ -1,9 +1,11 @@
 use std::{
     borrow::Cow, 
-    fmt::Display, 
+    fmt::{self, Debug, Display}, 
+    hash::{Hash, Hasher}, 
     ops::{Sub, Add, RangeInclusive}, 
+    str::FromStr, 
 };
-use num_traits::{Bounded, One, Zero};
+use num_traits::{Bounded, Float, One, Zero};
 use ratatui::{
     text::{Line, Span, Text}, 
     style::{Style, Stylize}, 
@@ -15,24 +17,66 @@ use super::*;
 /// 
 /// The type parameter `T` is the type of the value being entered. The following bounds are placed on `T`: 
 /// ```text
-///  T: Clone + Display + PartialOrd + num_traits::Zero + num_traits::One + num_traits::Bounded, 
+///  T: Clone + Display + PartialOrd, 
 /// &T: Add<Output = T> + Sub<Output = T>, 
 /// ```
+/// Additional bounds are placed on `T` when building the field, depending on which parameters were given. 
+/// See the [builder documentation](Builder#bounds) for more information. 
+/// 
 /// Those bounds hold for all primitive numerical types (e.g., `i8`, `usize`, `f64`), but the design allows
-/// for other types as well. 
+/// for other types as well. Newtypes over numerical types can be supported using
+/// [`impl_slider_ops!`](crate::impl_slider_ops!). 
 /// 
 /// See [`slider::Builder`] for the methods available when constructing the field. 
 /// 
 /// 
+/// # Display
+/// 
+/// The value is displayed with [`Display`] by default. With [`Builder::precision`], floating-point values are
+/// displayed with a fixed number of decimals, e.g. `0.30` instead of `0.30000000000000004`. With
+/// [`Builder::display_with`], any function may be used, e.g. for displaying labels instead of numbers. This
+/// only affects how the value is displayed; the [value](Field::value) itself is left untouched. 
+/// 
+/// 
+/// # Bar
+/// 
+/// With [`Builder::show_bar`], a bar such as `[█████░░░░░]` is drawn before the value, filled in proportion to
+/// where the value lies in the range. The proportion is computed from the [displayed](Display) value and
+/// bounds parsed as [`f64`], which avoids overflow for ranges spanning all values of `T`. If they don't
+/// parse, such as for newtypes displayed with units, the bar is omitted. 
+/// 
+/// 
+/// # Direct entry
+/// 
+/// With [`Builder::editable`], the value may also be typed. Typing a digit or pressing `e` enters an edit
+/// mode, where the typed text is shown in place of the value. While editing: 
+/// - The value follows the typed text, clamped to the range. Characters that can't occur in a number are
+/// ignored, and text that doesn't parse leaves the value unchanged. 
+/// - [`KeyCode::Enter`] leaves the edit mode, keeping the value. So does moving the focus away with `Tab` or
+/// `BackTab`. 
+/// - [`KeyCode::Esc`] leaves the edit mode, restoring the value from before it was entered. 
+/// - All other keys are consumed, including the arrow keys. 
+/// 
+/// The field [captures](Field::captures) `Enter` and `Esc` while editing, so that
+/// [forms](crate::dialog::form!) are neither submitted nor cancelled. 
+/// 
+/// 
 /// # Key bindings
 /// 
 /// [`KeyCode::Left`] and [`KeyCode::Right`] move the value one step to the left and right, respectively. If
 /// a modifier key is held, the value is "snapped" to the nearest anchor in the given direction, where the
 /// anchors are `self.range.start()`, `self.default`, and `self.range.end()` (in order). 
+/// 
+/// If [wrapping](Builder::wrap) is enabled, moving past one end of the range wraps around to the other. A
+/// step that would overshoot the end lands on it first, so that the ends are always reachable. When snapping,
+/// the ends are considered adjacent, e.g. snapping left from `self.range.start()` lands on
+/// `self.range.end()`. 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Slider<T> {
     /// The user-visible name displayed by the input field. 
     pub name: Cow<'static, str>,
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
     /// The current user-entered value. 
     pub value: T, 
     /// The allowed range of the value that can be entered. 
@@ -45,12 +89,164 @@ pub struct Slider<T> {
     pub prefix: Option<Cow<'static, str>>, 
     /// Suffix visually inserted after the entered number. 
     pub suffix: Option<Cow<'static, str>>, 
+    /// Width of the bar drawn before the value, if any. See the [type-level](Slider#bar) documentation for
+    /// more information. 
+    pub bar: Option<u16>, 
+    /// How the value is displayed. 
+    format: Format<T>, 
+    /// Whether moving past one end of the range wraps around to the other. 
+    pub wrap: bool, 
+    /// Parses typed text, if direct entry is enabled. 
+    parse: Option<Parse<T>>, 
+    /// The typed text and the value from before it was typed, while editing. 
+    edit: Option<(String, T)>, 
+}
+
+/// Formats the value for display. See the [type-level](Slider#display) documentation for more information. 
+#[derive(Clone, Debug)]
+enum Format<T> {
+    Display, 
+    Precision(usize), 
+    Custom(fn(&T) -> String), 
+}
+
+impl<T> PartialEq for Format<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Format::Display, Format::Display) => true, 
+            (Format::Precision(a), Format::Precision(b)) => a == b, 
+            (Format::Custom(a), Format::Custom(b)) => std::ptr::fn_addr_eq(*a, *b), 
+            _ => false, 
+        }
+    }
+}
+
+impl<T> Eq for Format<T> {}
+
+impl<T> Hash for Format<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Format::Display => 0.hash(state), 
+            Format::Precision(precision) => (1, precision).hash(state), 
+            Format::Custom(format) => (2, *format as usize).hash(state), 
+        }
+    }
+}
+
+/// Parses typed text into a value. Implements the standard traits regardless of `T`, and compares by
+/// address. 
+struct Parse<T>(fn(&str) -> Option<T>);
+
+impl<T: FromStr> Default for Parse<T> {
+    fn default() -> Self {
+        Parse(|text| text.parse().ok())
+    }
+}
+
+impl<T> Clone for Parse<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Parse<T> {}
+
+impl<T> Debug for Parse<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Parse")
+    }
+}
+
+impl<T> PartialEq for Parse<T> {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::fn_addr_eq(self.0, other.0)
+    }
+}
+
+impl<T> Eq for Parse<T> {}
+
+impl<T> Hash for Parse<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.0 as usize).hash(state);
+    }
+}
+
+impl<T: Clone + PartialOrd> Slider<T> {
+    /// Whether the value is being typed. See the [type-level](Slider#direct-entry) documentation for more
+    /// information. 
+    pub fn is_editing(&self) -> bool {
+        self.edit.is_some()
+    }
+
+    /// Handles a key press while editing. 
+    fn input_edit(&mut self, key: KeyEvent, parse: Parse<T>) -> InputResult {
+        let Some((text, original)) = &mut self.edit else {
+            return InputResult::Ignored
+        };
+        let old = self.value.clone();
+        match key.code {
+            KeyCode::Char(c @ ('0'..='9' | '-' | '+' | '.')) => text.push(c), 
+            KeyCode::Backspace => _ = text.pop(), 
+            KeyCode::Esc => {
+                self.value = original.clone();
+                self.edit = None;
+            }
+            KeyCode::Enter => self.edit = None, 
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.edit = None;
+                return InputResult::Ignored
+            }
+            _ => return InputResult::Consumed, 
+        }
+        if let Some(value) = self.edit.as_ref().and_then(|(text, _)| parse.0(text)) {
+            self.value = match (&value < self.range.start(), &value > self.range.end()) {
+                (true, _) => self.range.start().clone(), 
+                (_, true) => self.range.end().clone(), 
+                (_, _) => value, 
+            };
+        }
+        match self.value == old {
+            true => InputResult::Consumed, 
+            false => InputResult::Updated, 
+        }
+    }
+}
+
+impl<T: Display> Slider<T> {
+    /// Formats the value for display. 
+    fn format_value(&self) -> String {
+        match self.format {
+            Format::Display => self.value.to_string(), 
+            Format::Precision(precision) => format!("{:.*}", precision, self.value), 
+            Format::Custom(format) => format(&self.value), 
+        }
+    }
+
+    /// Formats the bar, if enabled and the value and range can be parsed. 
+    fn format_bar(&self) -> Option<String> {
+        let width = self.bar? as usize;
+        let parse = |x: &T| x.to_string().parse::<f64>().ok();
+        let [value, start, end] = [&self.value, self.range.start(), self.range.end()].map(parse);
+        let (value, start, end) = (value?, start?, end?);
+
+        // the bounds are checked exactly, so that the bar is never full or empty elsewhere due to rounding
+        let filled = match (value <= start, value >= end) {
+            (true, _) => 0, 
+            (_, true) => width, 
+            (_, _) => {
+                let ratio = (value - start) / (end - start);
+                ((ratio * width as f64) as usize)
+                    .max(1)
+                    .min(width.saturating_sub(1))
+            }
+        };
+        Some(format!("[{}{}] ", "█".repeat(filled), "░".repeat(width - filled)))
+    }
 }
 
 impl<T> Field for Slider<T>
 where
     T: Clone + Display + PartialOrd, 
-    Builder<T>: Default, 
     for<'a> &'a T: Add<Output = T> + Sub<Output = T>, 
 {
     type Value = T;
@@ -60,7 +256,24 @@ where
         &self.name
     }
 
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
     fn input(&mut self, key: KeyEvent) -> InputResult {
+        if let Some(parse) = self.parse {
+            match (&self.edit, key.code) {
+                (Some(_), _) => return self.input_edit(key, parse), 
+                (None, KeyCode::Char(c @ ('0'..='9' | 'e'))) if key.modifiers.is_empty() => {
+                    self.edit = Some((String::new(), self.value.clone()));
+                    return match c {
+                        'e' => InputResult::Consumed, 
+                        _ => self.input_edit(key, parse), 
+                    }
+                }
+                (None, _) => (), 
+            }
+        }
         let modifier = !key.modifiers.is_empty();
         self.value = match (key.code, modifier) {
             // move slider one step
@@ -94,13 +307,28 @@ where
                     self.range.end().clone()
                 }
             }
+
+            // wrap around to the other end
+            (KeyCode::Left, _) if self.wrap && self.range.start() < self.range.end() => {
+                self.range.end().clone()
+            }
+            (KeyCode::Right, _) if self.wrap && self.range.start() < self.range.end() => {
+                self.range.start().clone()
+            }
             _ => return InputResult::Ignored, 
         };
         InputResult::Updated
     }
 
-    fn format(&self, focused: bool) -> Text {
-        let val = format!("{}", self.value);
+    fn format(&self, focused: bool) -> Text<'_> {
+        let val = match &self.edit {
+            Some((text, _)) => text.clone(), 
+            None => self.format_value(), 
+        };
+        let caret = match focused && self.edit.is_some() {
+            true => Span::styled(" ", Style::new().reversed()), 
+            false => Span::default(), 
+        };
         let style = |cond| match focused && cond {
             true => Style::new().bold(), 
             false => Style::new(), 
@@ -109,10 +337,13 @@ where
             .map(Option::as_ref)
             .map(|x| x.map(AsRef::as_ref).map(Span::from))
             .map(Option::unwrap_or_default);
+        let bar = self.format_bar().map(Span::from).unwrap_or_default();
         Line::from(vec![
+            bar, 
             Span::styled("<", style(&self.value != self.range.start())), 
             prefix, 
             Span::styled(val, style(focused)), 
+            caret, 
             suffix, 
             Span::styled(">", style(&self.value != self.range.end())), 
         ]).into()
@@ -125,6 +356,10 @@ where
     fn into_value(self) -> T {
         self.value
     }
+
+    fn captures(&self, key: &KeyEvent) -> bool {
+        self.is_editing() && matches!(key.code, KeyCode::Enter | KeyCode::Esc)
+    }
 }
 
 /// Constructs a [`Slider`]. 
@@ -133,84 +368,639 @@ where
 /// be used in application code for creating a stand-alone field. 
 /// 
 /// Requires that [`Builder::name`] is called before the field can be built. 
+/// 
+/// 
+/// # Bounds
+/// 
+/// The value, range, and step are all optional. Values that are not given are defaulted when the field is
+/// built, which places the following bounds on `T`: 
+/// - If no [range](Builder::range) was given: `T: Bounded`. The range defaults to
+/// `T::min_value()..=T::max_value()`. 
+/// - If no [step](Builder::step) was given: `T: One`. The step defaults to one. 
+/// - If no [value](Builder::value) was given: `T: Zero`. The value defaults to zero, clamped to the range. 
+/// 
+/// Whether each of these was given is tracked by the type parameters of the builder, so a slider with all
+/// three given (such as one constructed with [`Builder::with_range`]) places no further bounds on `T`. 
+/// 
+/// These hold for all primitive numerical types. For newtypes over numerical types, they may be implemented
+/// with [`impl_slider_ops!`](crate::impl_slider_ops!). 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct Builder<T, const NAME: bool = false>(Slider<T>);
+pub struct Builder<
+    T, 
+    const NAME: bool = false, 
+    const RANGE: bool = false, 
+    const STEP: bool = false, 
+    const VALUE: bool = false, 
+> {
+    name: Cow<'static, str>, 
+    value: Option<T>, 
+    range: Option<RangeInclusive<T>>, 
+    step: Option<T>, 
+    prefix: Option<Cow<'static, str>>, 
+    suffix: Option<Cow<'static, str>>, 
+    bar: Option<u16>, 
+    format: Format<T>, 
+    wrap: bool, 
+    parse: Option<Parse<T>>, 
+    disabled: bool, 
+}
 
-impl<T> Default for Builder<T>
-where
-    T: Zero + One + Bounded, 
-{
+impl<T> Default for Builder<T> {
     fn default() -> Self {
-        Self(Slider {
+        Builder {
             name: Default::default(), 
-            value: T::zero(), 
-            range: T::min_value()..=T::max_value(), 
-            step: T::one(), 
-            default: T::zero(), 
+            value: None, 
+            range: None, 
+            step: None, 
             prefix: None, 
             suffix: None, 
-        })
+            bar: None, 
+            format: Format::Display, 
+            wrap: false, 
+            parse: None, 
+            disabled: false, 
+        }
+    }
+}
+
+impl<T> Builder<T> {
+    /// Constructs the builder with the range, step, and initial value given up-front. 
+    pub fn with_range(range: RangeInclusive<T>, step: T, value: T) -> Builder<T, false, true, true, true> {
+        Builder::default()
+            .range(range)
+            .step(step)
+            .value(value)
     }
 }
 
-impl<T, const NAME: bool> Builder<T, NAME> {
+impl<T, const NAME: bool, const RANGE: bool, const STEP: bool, const VALUE: bool>
+    Builder<T, NAME, RANGE, STEP, VALUE>
+{
     /// The user-visible name displayed by the input field. 
-    pub fn name(self, name: impl Into<Cow<'static, str>>) -> Builder<T, true> {
+    pub fn name(self, name: impl Into<Cow<'static, str>>) -> Builder<T, true, RANGE, STEP, VALUE> {
         let name = name.into();
-        Builder(Slider{ name, ..self.0 })
+        Builder{ name, ..self.cast() }
     }
 
-    /// The initial and default value. 
-    pub fn value(self, value: T) -> Self
-    where
-        T: Clone, 
-    {
-        let default = value.clone();
-        Builder(Slider{ value, default, ..self.0 })
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder{ disabled, ..self }
     }
 
-    /// The allowed range of the value that can be entered. Clamps the value to the range. 
-    pub fn range(self, range: RangeInclusive<T>) -> Self
-    where
-        T: Clone + PartialOrd, 
-    {
-        let (min, max) = range.clone().into_inner();
-        let value = self.0.value.clone();
-        let value = match (value < min, value > max) {
-            (true, _) => min, 
-            (_, true) => max, 
-            (_, _) => value, 
-        };
-        Builder(Slider{ range, ..self.0 }).value(value)
+    /// The initial and default value. This is clamped to the range when the field is built. 
+    pub fn value(self, value: T) -> Builder<T, NAME, RANGE, STEP, true> {
+        let value = Some(value);
+        Builder{ value, ..self.cast() }
+    }
+
+    /// The allowed range of the value that can be entered. 
+    pub fn range(self, range: RangeInclusive<T>) -> Builder<T, NAME, true, STEP, VALUE> {
+        let range = Some(range);
+        Builder{ range, ..self.cast() }
     }
 
     /// The amount that is added to or subtracted from the value. 
-    pub fn step(self, step: T) -> Self {
-        Builder(Slider{ step, ..self.0 })
+    pub fn step(self, step: T) -> Builder<T, NAME, RANGE, true, VALUE> {
+        let step = Some(step);
+        Builder{ step, ..self.cast() }
     }
 
     /// Prefix visually inserted before the entered number. 
     pub fn prefix(self, prefix: impl Into<Cow<'static, str>>) -> Self {
         let prefix = Some(prefix.into());
-        Builder(Slider{ prefix, ..self.0 })
+        Builder{ prefix, ..self }
     }
 
     /// Suffix visually inserted after the entered number. 
     pub fn suffix(self, suffix: impl Into<Cow<'static, str>>) -> Self {
         let suffix = Some(suffix.into());
-        Builder(Slider{ suffix, ..self.0 })
+        Builder{ suffix, ..self }
+    }
+
+    /// Draws a bar of given width before the value, filled in proportion to where the value lies in the
+    /// range. See the [type-level](Slider#bar) documentation for more information. 
+    pub fn show_bar(self, width: u16) -> Self {
+        let bar = Some(width);
+        Builder{ bar, ..self }
+    }
+
+    /// Displays floating-point values with a fixed number of decimals. See the
+    /// [type-level](Slider#display) documentation for more information. 
+    pub fn precision(self, precision: usize) -> Self
+    where
+        T: Float, 
+    {
+        let format = Format::Precision(precision);
+        Builder{ format, ..self }
+    }
+
+    /// Displays the value using a function instead of [`Display`]. Closures that don't capture anything may
+    /// be given. See the [type-level](Slider#display) documentation for more information. 
+    pub fn display_with(self, format: fn(&T) -> String) -> Self {
+        let format = Format::Custom(format);
+        Builder{ format, ..self }
+    }
+
+    /// Wraps around to the other end of the range when moving past one end, instead of stopping. See the
+    /// [type-level](Slider#key-bindings) documentation for more information. 
+    pub fn wrap(self) -> Self {
+        Builder{ wrap: true, ..self }
+    }
+
+    /// Allows typing the value directly, parsed with [`FromStr`]. See the [type-level](Slider#direct-entry)
+    /// documentation for more information. 
+    pub fn editable(self) -> Self
+    where
+        T: FromStr, 
+    {
+        let parse = Some(Parse::default());
+        Builder{ parse, ..self }
+    }
+
+    /// Changes the type state of the builder. 
+    fn cast<const A: bool, const B: bool, const C: bool, const D: bool>(self) -> Builder<T, A, B, C, D> {
+        let Builder{ name, value, range, step, prefix, suffix, bar, format, wrap, parse, disabled } = self;
+        Builder{ name, value, range, step, prefix, suffix, bar, format, wrap, parse, disabled }
+    }
+
+    /// Constructs the slider from the given or defaulted range, step, and value. 
+    fn finish(self, range: RangeInclusive<T>, step: T, value: T) -> Slider<T>
+    where
+        T: Clone + PartialOrd, 
+    {
+        let (min, max) = (range.start(), range.end());
+        let value = match (&value < min, &value > max) {
+            (true, _) => min.clone(), 
+            (_, true) => max.clone(), 
+            (_, _) => value, 
+        };
+        Slider {
+            name: self.name, 
+            disabled: self.disabled, 
+            default: value.clone(), 
+            value, 
+            range, 
+            step, 
+            prefix: self.prefix, 
+            suffix: self.suffix, 
+            bar: self.bar, 
+            format: self.format, 
+            wrap: self.wrap, 
+            parse: self.parse, 
+            edit: None, 
+        }
     }
 }
 
-impl<T> Build for Builder<T, true>
-where
-    Slider<T>: Field
-{
-    type Field = Slider<T>;
+/// Takes a builder value, which must be present if its type state says it was given. Otherwise, it may
+/// be defaulted. 
+macro_rules! given_or {
+    (true, $given:expr, $default:expr) => { $given.expect("Value is given") };
+    (false, $given:expr, $default:expr) => { $given.unwrap_or_else(|| $default) };
+}
+
+/// Implements [`Build`] for one combination of given range, step, and value, with the bounds needed to
+/// default the rest. 
+macro_rules! impl_build {
+    ($range:tt, $step:tt, $value:tt $(, $bound:ident)*) => {
+        impl<T> Build for Builder<T, true, $range, $step, $value>
+        where
+            T: Clone + PartialOrd $(+ $bound)*, 
+            Slider<T>: Field, 
+        {
+            type Field = Slider<T>;
+
+            /// If the name has been defined with [`Builder::name`], consumes the builder and returns the
+            /// constructed [`Slider`]. See the [bounds](Builder#bounds) for how missing values are defaulted. 
+            fn build(mut self) -> Slider<T> {
+                let range = given_or!($range, self.range.take(), T::min_value()..=T::max_value());
+                let step = given_or!($step, self.step.take(), T::one());
+                let value = given_or!($value, self.value.take(), T::zero());
+                self.finish(range, step, value)
+            }
+        }
+    };
+}
+
+impl_build!(false, false, false, Bounded, One, Zero);
+impl_build!(false, false, true, Bounded, One);
+impl_build!(false, true, false, Bounded, Zero);
+impl_build!(false, true, true, Bounded);
+impl_build!(true, false, false, One, Zero);
+impl_build!(true, false, true, One);
+impl_build!(true, true, false, Zero);
+impl_build!(true, true, true);
+
+#[doc(hidden)]
+pub use num_traits as __num_traits;
+
+/// Implements the traits required by [`Slider`] for a newtype over a numerical type. 
+/// 
+/// Given a tuple struct with a single field, this forwards [`Add`] and [`Sub`] (both by value and by
+/// reference), [`Mul`](std::ops::Mul), [`Zero`], and [`One`] to the wrapped type. Note that [`Display`] and
+/// [`PartialOrd`] are not implemented, and should be implemented or derived separately. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, field::Slider};
+/// 
+/// /// An amount of money, in cents. 
+/// #[derive(Clone, PartialEq, PartialOrd)]
+/// struct Cents(i64);
+/// 
+/// impl std::fmt::Display for Cents {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{}.{:02}", self.0 / 100, self.0 % 100)
+///     }
+/// }
+/// 
+/// tundra::impl_slider_ops!(Cents);
+/// 
+/// # let ctx = &mut Context::new().unwrap();
+/// let values = dialog::form!{
+///     price: Slider<Cents>{ name: "Price", range: Cents(0)..=Cents(10_000), step: Cents(50) }, 
+///     [title]: "Set price", 
+///     [context]: ctx, 
+///     [background]: &(), 
+/// };
+/// ```
+#[macro_export]
+macro_rules! impl_slider_ops {
+    ($type:ident) => {
+        impl ::std::ops::Add for $type {
+            type Output = $type;
+
+            fn add(self, rhs: $type) -> $type {
+                $type(self.0 + rhs.0)
+            }
+        }
+
+        impl ::std::ops::Sub for $type {
+            type Output = $type;
+
+            fn sub(self, rhs: $type) -> $type {
+                $type(self.0 - rhs.0)
+            }
+        }
+
+        impl ::std::ops::Mul for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: $type) -> $type {
+                $type(self.0 * rhs.0)
+            }
+        }
+
+        impl ::std::ops::Add for &$type {
+            type Output = $type;
+
+            fn add(self, rhs: &$type) -> $type {
+                $type(&self.0 + &rhs.0)
+            }
+        }
+
+        impl ::std::ops::Sub for &$type {
+            type Output = $type;
+
+            fn sub(self, rhs: &$type) -> $type {
+                $type(&self.0 - &rhs.0)
+            }
+        }
+
+        impl $crate::field::slider::__num_traits::Zero for $type {
+            fn zero() -> $type {
+                $type($crate::field::slider::__num_traits::Zero::zero())
+            }
+
+            fn is_zero(&self) -> bool {
+                $crate::field::slider::__num_traits::Zero::is_zero(&self.0)
+            }
+        }
+
+        impl $crate::field::slider::__num_traits::One for $type {
+            fn one() -> $type {
+                $type($crate::field::slider::__num_traits::One::one())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fmt, ops::{Add, Sub}};
+    use crate::{prelude::*, field::*};
+
+    /// Newtype over a numerical type without [`num_traits::Bounded`]. 
+    #[derive(Clone, Debug, PartialEq, PartialOrd)]
+    struct Cents(i64);
+
+    impl fmt::Display for Cents {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    crate::impl_slider_ops!(Cents);
+
+    #[test]
+    fn newtype() {
+        let mut slider = Slider::builder()
+            .name("")
+            .range(Cents(0)..=Cents(100))
+            .step(Cents(30))
+            .build();
+        assert_eq!(slider.value, Cents(0));
+
+        slider.input(KeyCode::Right.into());
+        assert_eq!(slider.value, Cents(30));
+
+        // stepping past the end clamps to the end
+        for _ in 0..4 {
+            slider.input(KeyCode::Right.into());
+        }
+        assert_eq!(slider.value, Cents(100));
+    }
+
+    #[test]
+    fn with_range() {
+        let slider = slider::Builder::with_range(Cents(10)..=Cents(20), Cents(1), Cents(50))
+            .name("")
+            .build();
+        // value is clamped to the range
+        assert_eq!(slider.value, Cents(20));
+        assert_eq!(slider.default, Cents(20));
+    }
+
+    /// Newtype implementing only the operations required by the field itself. 
+    #[derive(Clone, Debug, PartialEq, PartialOrd)]
+    struct Percent(u8);
+
+    impl fmt::Display for Percent {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}%", self.0)
+        }
+    }
+
+    impl Add for &Percent {
+        type Output = Percent;
+
+        fn add(self, rhs: &Percent) -> Percent {
+            Percent(self.0 + rhs.0)
+        }
+    }
+
+    impl Sub for &Percent {
+        type Output = Percent;
+
+        fn sub(self, rhs: &Percent) -> Percent {
+            Percent(self.0 - rhs.0)
+        }
+    }
+
+    #[test]
+    fn no_defaults() {
+        let mut slider = slider::Builder::with_range(Percent(0)..=Percent(100), Percent(25), Percent(50))
+            .name("")
+            .build();
+        slider.input(KeyCode::Right.into());
+        assert_eq!(slider.value, Percent(75));
+
+        // the order of the builder methods doesn't matter
+        let slider = Slider::builder()
+            .value(Percent(10))
+            .name("")
+            .step(Percent(5))
+            .range(Percent(20)..=Percent(40))
+            .build();
+        assert_eq!(slider.value, Percent(20));
+    }
+
+    #[test]
+    fn decimal() {
+        use rust_decimal::Decimal;
+
+        let step = Decimal::new(25, 2);
+        let mut slider = slider::Builder::with_range(Decimal::ZERO..=Decimal::TEN, step, Decimal::ONE)
+            .name("")
+            .build();
+        slider.input(KeyCode::Right.into());
+        assert_eq!(slider.value, Decimal::new(125, 2));
+        assert_eq!(slider.format(false).to_string(), "<1.25>");
+
+        // defaults the step and value
+        let mut slider = Slider::builder()
+            .name("")
+            .range(Decimal::ONE..=Decimal::TEN)
+            .build();
+        assert_eq!(slider.value, Decimal::ONE);
+        slider.input(KeyCode::Right.into());
+        assert_eq!(slider.value, Decimal::TWO);
+    }
+
+    #[test]
+    fn defaults() {
+        let slider = Slider::<i8>::builder()
+            .name("")
+            .build();
+        assert_eq!(slider.range, i8::MIN..=i8::MAX);
+        assert_eq!(slider.value, 0);
+        assert_eq!(slider.step, 1);
+
+        // value is clamped regardless of the order the builder methods were called in
+        let slider = Slider::<i8>::builder()
+            .name("")
+            .range(5..=10)
+            .build();
+        assert_eq!(slider.value, 5);
+        let slider = Slider::<i8>::builder()
+            .name("")
+            .range(5..=10)
+            .value(100)
+            .build();
+        assert_eq!(slider.value, 10);
+    }
+
+    #[test]
+    fn prefix_suffix() {
+        let slider = Slider::builder()
+            .name("")
+            .range(0..=5000)
+            .value(1500)
+            .prefix("$")
+            .build();
+        assert_eq!(slider.format(false).to_string(), "<$1500>");
+        assert_eq!(*slider.value(), 1500);
+
+        let slider = Slider::builder()
+            .name("")
+            .range(0..=100)
+            .value(85)
+            .suffix("%")
+            .build();
+        assert_eq!(slider.format(true).to_string(), "<85%>");
+        assert_eq!(slider.into_value(), 85);
+    }
+
+    #[test]
+    fn bar() {
+        let format = |value| Slider::builder()
+            .name("")
+            .range(1000..=2000)
+            .value(value)
+            .show_bar(10)
+            .build()
+            .format(false)
+            .to_string();
+        assert_eq!(format(1000), "[░░░░░░░░░░] <1000>");
+        assert_eq!(format(1500), "[█████░░░░░] <1500>");
+        assert_eq!(format(2000), "[██████████] <2000>");
+
+        // values near the bounds are neither empty nor full
+        assert_eq!(format(1001), "[█░░░░░░░░░] <1001>");
+        assert_eq!(format(1999), "[█████████░] <1999>");
+
+        // the full range of a type doesn't overflow
+        let slider = Slider::<i8>::builder()
+            .name("")
+            .show_bar(4)
+            .build();
+        assert_eq!(slider.format(false).to_string(), "[██░░] <0>");
+    }
+
+    #[test]
+    fn precision() {
+        let mut slider = Slider::builder()
+            .name("")
+            .range(0.0..=1.0)
+            .value(0.2)
+            .step(0.1)
+            .precision(2)
+            .build();
+        slider.input(KeyCode::Right.into());
+        assert_eq!(slider.format(false).to_string(), "<0.30>");
+        assert_ne!(*slider.value(), 0.3);
+    }
+
+    #[test]
+    fn display_with() {
+        let mut slider = Slider::builder()
+            .name("Priority")
+            .range(0..=2)
+            .display_with(|value| match value {
+                0 => "Low".into(), 
+                1 => "Medium".into(), 
+                _ => "High".into(), 
+            })
+            .build();
+        assert_eq!(slider.format(false).to_string(), "<Low>");
+        slider.input(KeyCode::Right.into());
+        assert_eq!(slider.format(false).to_string(), "<Medium>");
+        assert_eq!(*slider.value(), 1);
+    }
+
+    #[test]
+    fn wrap() {
+        let mut slider = Slider::builder()
+            .name("Hour")
+            .range(0..=23)
+            .value(2)
+            .step(5)
+            .wrap()
+            .build();
+
+        // an overshooting step lands on the end first
+        assert_eq!(slider.input(KeyCode::Left.into()), InputResult::Updated);
+        assert_eq!(*slider.value(), 0);
+        slider.input(KeyCode::Left.into());
+        assert_eq!(*slider.value(), 23);
+        slider.input(KeyCode::Right.into());
+        assert_eq!(*slider.value(), 0);
+        slider.input(KeyCode::Right.into());
+        assert_eq!(*slider.value(), 5);
+
+        // the ends are adjacent when snapping to anchors
+        let ctrl = |code| KeyEvent::new(code, KeyModifiers::CONTROL);
+        slider.input(ctrl(KeyCode::Left));
+        assert_eq!(*slider.value(), 2);
+        slider.input(ctrl(KeyCode::Left));
+        assert_eq!(*slider.value(), 0);
+        slider.input(ctrl(KeyCode::Left));
+        assert_eq!(*slider.value(), 23);
+        slider.input(ctrl(KeyCode::Right));
+        assert_eq!(*slider.value(), 0);
+    }
+
+    fn editable() -> Slider<u32> {
+        Slider::builder()
+            .name("")
+            .range(10..=4000)
+            .value(100)
+            .editable()
+            .build()
+    }
+
+    fn typed(slider: &mut Slider<u32>, text: &str) {
+        for c in text.chars() {
+            slider.input(KeyCode::Char(c).into());
+        }
+    }
+
+    #[test]
+    fn direct_entry() {
+        let mut slider = editable();
+        typed(&mut slider, "25");
+        assert!(slider.is_editing());
+        assert_eq!(slider.format(true).to_string(), "<25 >");
+        assert_eq!(*slider.value(), 25);
+
+        // out-of-range entries are clamped
+        typed(&mut slider, "000");
+        assert_eq!(*slider.value(), 4000);
+        assert_eq!(slider.input(KeyCode::Backspace.into()), InputResult::Updated);
+        assert_eq!(*slider.value(), 2500);
+        for _ in 0..4 {
+            slider.input(KeyCode::Backspace.into());
+        }
+        typed(&mut slider, "5");
+        assert_eq!(*slider.value(), 10);
+
+        assert!(slider.captures(&KeyCode::Enter.into()));
+        assert_eq!(slider.input(KeyCode::Enter.into()), InputResult::Consumed);
+        assert!(!slider.is_editing());
+        assert!(!slider.captures(&KeyCode::Enter.into()));
+        assert_eq!(slider.format(true).to_string(), "<10>");
+    }
+
+    #[test]
+    fn direct_entry_invalid() {
+        let mut slider = editable();
+
+        // `e` enters edit mode without typing, and other keys are consumed without effect
+        assert_eq!(slider.input(KeyCode::Char('e').into()), InputResult::Consumed);
+        assert_eq!(slider.input(KeyCode::Char('x').into()), InputResult::Consumed);
+        assert_eq!(slider.input(KeyCode::Right.into()), InputResult::Consumed);
+        assert_eq!(slider.format(false).to_string(), "<>");
+
+        // text that doesn't parse leaves the value unchanged
+        typed(&mut slider, "20-");
+        assert_eq!(*slider.value(), 20);
+
+        // escape restores the value from before editing
+        assert_eq!(slider.input(KeyCode::Esc.into()), InputResult::Updated);
+        assert_eq!(*slider.value(), 100);
+
+        // moving the focus away keeps the value
+        typed(&mut slider, "300");
+        assert_eq!(slider.input(KeyCode::Tab.into()), InputResult::Ignored);
+        assert!(!slider.is_editing());
+        assert_eq!(*slider.value(), 300);
 
-    /// If the name has been defined with [`Builder::name`], consumes the builder and returns the constructed
-    /// [`Slider`]. 
-    fn build(self) -> Slider<T> {
-        self.0
+        // digits are ignored unless direct entry is enabled
+        let mut slider = Slider::<u32>::builder()
+            .name("")
+            .build();
+        assert_eq!(slider.input(KeyCode::Char('5').into()), InputResult::Ignored);
     }
 }
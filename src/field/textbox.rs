@@ -1,5 +1,11 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow, 
+    hash::{Hash, Hasher}, 
+    ops::Range, 
+};
 use ratatui::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 use crate::prelude::*;
 use super::*;
 
@@ -13,9 +19,23 @@ use super::*;
 /// The entered value can be hidden with [`Textbox::hidden`] or [`Builder::hidden`]. When this is toggled,
 /// all entered characters are replaced with `•` when the textbox is drawn. 
 /// 
+/// A hidden textbox may also show the strength of the entered password with [`Builder::strength_meter`]. 
+/// While focused, the strength is displayed as a colored bar after the input, e.g. `▮▮▮▮▯ strong`. The meter
+/// is only drawn and has no effect on the [value](Field::value). 
+/// 
+/// 
+/// # Width
+/// 
+/// Values wider than the space available in a [form](crate::dialog::form!) are clipped rather than wrapped, 
+/// with `…` marking the clipped ends. While focused, the value is scrolled horizontally to keep the caret
+/// visible. See [`Field::format_width`]. 
+/// 
 /// 
 /// # Key bindings
 /// 
+/// A character here means a grapheme cluster; what is perceived as a single character, such as an accented
+/// letter or an emoji with modifiers, even if it consists of several unicode chars. 
+/// 
 /// [`KeyCode::Left`] and [`KeyCode::Right`] move the caret one character to the left and right, 
 /// respectively. If [`KeyModifiers::CONTROL`] is held, the caret moves one word in the given direction. 
 /// 
@@ -26,19 +46,116 @@ use super::*;
 /// respectively. If [`KeyModifiers::CONTROL`] is held, one whole word is removed in the given direction. 
 /// 
 /// [`KeyCode::Char`] inputs are inserted into the input string directly after the caret. 
+/// 
+/// Unless disabled with [`Builder::emacs_bindings`], the following readline-style shortcuts are also
+/// available: 
+/// - `ctrl+a` and `ctrl+e` move the caret to the beginning and end of the input string, respectively. 
+/// - `ctrl+u` and `ctrl+k` remove everything before and after the caret, respectively. 
+/// - `ctrl+w` and `ctrl+d` remove one whole word to the left and right of the caret, respectively. 
+/// 
+/// 
+/// # Undo and redo
+/// 
+/// `ctrl+z` undoes the last edit, and `ctrl+y` or `ctrl+shift+z` redoes the last undone edit. Consecutive
+/// typed characters are undone together. Up to 100 edits are remembered, and the history is cleared by
+/// [`Textbox::set_value`]. Both return [`InputResult::Updated`] if there was anything to undo or redo, so
+/// that [forms](crate::dialog::form!) validate the field again. 
+/// 
+/// 
+/// # Selection
+/// 
+/// Holding [`KeyModifiers::SHIFT`] while moving the caret extends a selection from where the caret was, 
+/// which is drawn reversed. Moving the caret without holding shift clears the selection. While text is
+/// selected: 
+/// - [`KeyCode::Backspace`] and [`KeyCode::Delete`] remove it, and [`KeyCode::Char`] inputs replace it. 
+/// - `ctrl+c` copies it into a register internal to the textbox, and `ctrl+x` cuts it. This is disabled
+/// while the input is [hidden](Textbox#hidden-input). 
+/// 
+/// `ctrl+v` inserts the contents of the register after the caret, replacing the selection if any. 
+/// 
+/// 
+/// # Pasting
+/// 
+/// Text [pasted](Field::paste) by the user is inserted after the caret in the same way, replacing the
+/// selection if any. Since the textbox holds a single line, line breaks in the pasted text are replaced with
+/// spaces, except at the end of the text where they are removed. 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Textbox {
     /// The user-visible name displayed by the input field. 
     pub name: Cow<'static, str>, 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
     /// Whether the input should be hidden. See the [type-level](Textbox#hidden-input) documentation for more
     /// information.
     pub hidden: bool, 
     /// The current user-entered value. 
     value: String, 
-    /// The *byte* index of the currently highlighted char. This may differ from the *char* index due to
-    /// UTF-8. To maintain this invariance, `caret` and `value` are not directly modifiable by application
-    /// code. 
+    /// The *byte* index of the currently highlighted grapheme. This may differ from the *grapheme* index due
+    /// to UTF-8 and multi-char graphemes. To maintain this invariance, `caret` and `value` are not directly
+    /// modifiable by application code. 
     caret: usize, 
+    /// The byte index where the selection started, if any. The selection spans from here to the caret. 
+    anchor: Option<usize>, 
+    /// The last copied or cut text. 
+    register: String, 
+    /// Whether the readline-style shortcuts are enabled. 
+    emacs_bindings: bool, 
+    /// Rates the strength of the entered value, if the strength meter is enabled. 
+    strength: Option<StrengthMeter>, 
+    /// The edit history, for undoing and redoing edits. 
+    history: History, 
+}
+
+/// Rates the strength of the value of a [`Textbox`], caching the last rating. 
+#[derive(Clone, Copy, Debug)]
+struct StrengthMeter {
+    rate: fn(&str) -> u8, 
+    strength: u8, 
+}
+
+impl PartialEq for StrengthMeter {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::fn_addr_eq(self.rate, other.rate) && self.strength == other.strength
+    }
+}
+
+impl Eq for StrengthMeter {}
+
+impl Hash for StrengthMeter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.rate as usize).hash(state);
+        self.strength.hash(state);
+    }
+}
+
+/// A simple heuristic for rating the strength of a password from 0 (very weak) to 4 (very strong), based on
+/// its length and the number of character classes (lowercase, uppercase, digits, and others) it contains. 
+/// 
+/// This is the intended default for [`Builder::strength_meter`], but it is no substitute for a proper
+/// password policy. 
+pub fn default_strength(password: &str) -> u8 {
+    let classes: [fn(&char) -> bool; 4] = [
+        char::is_ascii_lowercase, 
+        char::is_ascii_uppercase, 
+        char::is_ascii_digit, 
+        |c| !c.is_ascii_alphanumeric(), 
+    ];
+    let classes = classes
+        .into_iter()
+        .filter(|class| password.chars().any(|c| class(&c)))
+        .count();
+    let length = match password.chars().count() {
+        0..=7 => return u8::from(classes >= 3), 
+        8..=11 => 1, 
+        12..=15 => 2, 
+        _ => 3, 
+    };
+    let variety = match classes {
+        0 | 1 => 0, 
+        2 | 3 => 1, 
+        _ => 2, 
+    };
+    u8::min(length + variety, 4)
 }
 
 impl Textbox {
@@ -46,6 +163,69 @@ impl Textbox {
     pub fn set_value(&mut self, value: impl Into<String>) {
         self.value = value.into();
         self.caret = self.max_caret();
+        self.anchor = None;
+        self.history = History::default();
+        self.rate();
+    }
+
+    /// The byte range of the selected text, if any. See the [type-level](Textbox#selection) documentation
+    /// for more information. 
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor?;
+        match anchor.cmp(&self.caret) {
+            std::cmp::Ordering::Less => Some(anchor..self.caret), 
+            std::cmp::Ordering::Equal => None, 
+            std::cmp::Ordering::Greater => Some(self.caret..anchor), 
+        }
+    }
+
+    /// Removes a range of the value, moving the caret to where it was and clearing the selection. 
+    fn remove(&mut self, range: Range<usize>) {
+        self.caret = range.start;
+        self.anchor = None;
+        self.value.drain(range);
+    }
+
+    /// Handles a key press acting on the selection, if any. Returns [`None`] if the key press is to be
+    /// handled as usual, which may follow removing the selection (e.g. when replacing it). 
+    fn input_selection(&mut self, key: KeyEvent) -> Option<InputResult> {
+        let range = self.selection()?;
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match (key.code, ctrl) {
+            (KeyCode::Char('c'), true) if !self.hidden => {
+                self.register = self.value[range].to_owned();
+                Some(InputResult::Consumed)
+            }
+            (KeyCode::Char('x'), true) if !self.hidden => {
+                self.register = self.value[range.clone()].to_owned();
+                self.remove(range);
+                Some(InputResult::Updated)
+            }
+            (KeyCode::Backspace | KeyCode::Delete, _) => {
+                self.remove(range);
+                Some(InputResult::Updated)
+            }
+            (KeyCode::Char(_), false) => {
+                self.remove(range);
+                None
+            }
+            (KeyCode::Char('v'), true) if !self.register.is_empty() => {
+                self.remove(range);
+                None
+            }
+            _ => None, 
+        }
+    }
+
+    /// Inserts text after the caret, replacing the selection if any. 
+    fn insert(&mut self, text: &str) -> InputResult {
+        if let Some(range) = self.selection() {
+            self.remove(range);
+        }
+        self.value.insert_str(self.caret, text);
+        self.caret = self.snap(self.caret + text.len());
+        self.rate();
+        InputResult::Updated
     }
 
     /// Gets the current value. 
@@ -53,29 +233,53 @@ impl Textbox {
         &self.value
     }
 
+    /// Whether the caret is after the last character. 
+    pub(crate) fn caret_at_end(&self) -> bool {
+        self.caret == self.max_caret()
+    }
+
+    /// Rerates the strength of the value, if the strength meter is enabled. 
+    fn rate(&mut self) {
+        if let Some(meter) = &mut self.strength {
+            meter.strength = (meter.rate)(&self.value).min(4);
+        }
+    }
+
     /// Splits the current value into three slices: before the caret, the caret itself, and after the caret. 
     fn split_caret(&self) -> [&str; 3] {
         let (a, b) = self.value.split_at(self.caret);
-        let (b, c) = b.chars()
-            .nth(0)
-            .map(|first| b.split_at(first.len_utf8()))
+        let (b, c) = b.graphemes(true)
+            .next()
+            .map(|first| b.split_at(first.len()))
             .unwrap_or(("", ""));
         [a, b, c]
     }
 
     /// The maximum possible index for the caret, given the current value. Defined for explicitness. Note
-    /// that the caret can go one char out of bounds to the right where the next symbol is to be inserted. 
+    /// that the caret can go one grapheme out of bounds to the right where the next symbol is to be
+    /// inserted. 
     fn max_caret(&self) -> usize {
         self.value.len()
     }
 
-    /// Finds the byte index of the unicode char one step from the caret in the given direction. 
+    /// Finds the first grapheme boundary at or after a byte index. Inserting a char may merge it with the
+    /// grapheme following it (e.g. a letter before a combining accent), leaving the index after the inserted
+    /// char inside a grapheme. 
+    fn snap(&self, index: usize) -> usize {
+        self.value
+            .grapheme_indices(true)
+            .map(|(start, _)| start)
+            .find(|&start| start >= index)
+            .unwrap_or(self.max_caret())
+    }
+
+    /// Finds the byte index of the grapheme one step from the caret in the given direction. 
     fn step(&self, direction: Direction) -> usize {
         let [pre, caret, _] = self.split_caret();
         match direction {
-            Direction::Left => pre.chars()
-                .nth_back(0)
-                .map(|last| self.caret - last.len_utf8())
+            Direction::Left => pre.graphemes(true)
+                .next_back()
+                .map(|last| self.caret - last.len())
                 .unwrap_or(0),
             Direction::Right => self.caret + caret.len(),
         }
@@ -95,40 +299,44 @@ impl Textbox {
             return fallback
         }
 
-        // finds the next word-boundary in an iterator of char indices (which may be reversed for
+        // whether a grapheme is whitespace; combining marks cannot turn a whitespace char into a word
+        fn is_whitespace(grapheme: &str) -> bool {
+            grapheme
+                .chars()
+                .next()
+                .is_some_and(char::is_whitespace)
+        }
+
+        // finds the next word-boundary in an iterator of grapheme indices (which may be reversed for
         // Direction::Left) 
-        fn iter(mut it: impl Iterator<Item = (usize, char)>, mut prev_ws: bool) -> Option<usize> {
+        fn iter<'a>(mut it: impl Iterator<Item = (usize, &'a str)>, mut prev_ws: bool) -> Option<usize> {
             it.find_map(|(index, curr)| {
-                let curr_ws = curr.is_whitespace();
+                let curr_ws = is_whitespace(curr);
                 let valid = !prev_ws && curr_ws;
                 prev_ws = curr_ws;
                 valid.then_some(index)
             })
         }
-        let chars = string.char_indices();
+        let graphemes = string.grapheme_indices(true);
         let index = match direction {
-            Direction::Left => iter(chars.rev(), true), 
-            Direction::Right => iter(chars, caret
-                    .chars()
-                    .nth_back(0)
-                    .map_or(false, char::is_whitespace)
-                )
+            Direction::Left => iter(graphemes.rev(), true), 
+            Direction::Right => iter(graphemes, is_whitespace(caret))
                 .map(|index| index + self.caret + caret.len()), 
         };
         index.unwrap_or(fallback)
     }
-}
 
-impl Field for Textbox {
-    type Value = String;
-    type Builder = Builder<false>;
-
-    fn name(&self) -> &str {
-        &self.name
-    }
-
-    fn input(&mut self, key: KeyEvent) -> InputResult {
+    /// Handles a key press editing the value or moving the caret. 
+    fn edit(&mut self, key: KeyEvent) -> InputResult {
+        if let Some(result) = self.input_selection(key) {
+            if let InputResult::Updated = result {
+                self.rate();
+            }
+            return result
+        }
         let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        let anchor = self.anchor.take();
         let (new_caret, result) = match (key.code, ctrl) {
             // move caret one char
             (KeyCode::Left,  false) => (self.step(Direction::Left), InputResult::Consumed), 
@@ -142,66 +350,240 @@ impl Field for Textbox {
             (KeyCode::Home, _) => (0, InputResult::Consumed), 
             (KeyCode::End,  _) => (self.max_caret(), InputResult::Consumed), 
 
-            // remove char
+            // remove grapheme
             (KeyCode::Backspace, false) if self.caret > 0 => {
                 let new = self.step(Direction::Left);
-                self.value.remove(new);
+                self.value.drain(new..self.caret);
                 (new, InputResult::Updated)
             }
             (KeyCode::Delete, false) if self.caret < self.max_caret() => {
-                self.value.remove(self.caret);
+                let end = self.step(Direction::Right);
+                self.value.drain(self.caret..end);
                 (self.caret, InputResult::Updated)
             }
 
             // remove word
-            (KeyCode::Backspace | KeyCode::Char('w'), true) if self.caret > 0 => {
+            (KeyCode::Backspace, true) if self.caret > 0 => {
                 let end = self.scan(Direction::Left);
                 self.value.drain(end..self.caret);
                 (end, InputResult::Updated)
             }
-            (KeyCode::Delete | KeyCode::Char('d'), true) if self.caret < self.max_caret() => {
+            (KeyCode::Delete, true) if self.caret < self.max_caret() => {
                 let end = self.scan(Direction::Right);
                 self.value.drain(self.caret..end);
                 (self.caret, InputResult::Updated)
             }
 
+            // readline-style shortcuts
+            (KeyCode::Char(c @ ('a' | 'e' | 'w' | 'd' | 'u' | 'k')), true) if self.emacs_bindings => match c {
+                'a' => (0, InputResult::Consumed), 
+                'e' => (self.max_caret(), InputResult::Consumed), 
+                'w' => return self.edit(KeyEvent::new(KeyCode::Backspace, KeyModifiers::CONTROL)), 
+                'd' => return self.edit(KeyEvent::new(KeyCode::Delete, KeyModifiers::CONTROL)), 
+                'u' if self.caret > 0 => {
+                    self.value.drain(..self.caret);
+                    (0, InputResult::Updated)
+                }
+                'k' if self.caret < self.max_caret() => {
+                    self.value.truncate(self.caret);
+                    (self.caret, InputResult::Updated)
+                }
+                _ => (self.caret, InputResult::Ignored), 
+            }
+
             // insert char
             (KeyCode::Char(c), false) => {
                 self.value.insert(self.caret, c);
-                (self.caret + c.len_utf8(), InputResult::Updated)
+                (self.snap(self.caret + c.len_utf8()), InputResult::Updated)
+            }
+
+            // paste register
+            (KeyCode::Char('v'), true) if !self.register.is_empty() => {
+                self.value.insert_str(self.caret, &self.register);
+                (self.snap(self.caret + self.register.len()), InputResult::Updated)
             }
             _ => (self.caret, InputResult::Ignored), 
         };
+
+        // only caret movement is consumed; holding shift extends the selection
+        if let (InputResult::Consumed, true) = (result, shift) {
+            self.anchor = Some(anchor.unwrap_or(self.caret));
+        }
         self.caret = new_caret;
+        if let InputResult::Updated = result {
+            self.rate();
+        }
         result
     }
 
-    fn format(&self, focused: bool) -> Text {
+    /// Formats the textbox within given width. See the [type-level](Textbox#width) documentation for more
+    /// information. 
+    fn format_clipped(&self, focused: bool, width: usize) -> Text<'_> {
         // hides the contents if `self.hidden == true`; clones them otherwise
         let visibility = match self.hidden {
-            true => |s: &str| s.chars()
+            true => |s: &str| s.graphemes(true)
                 .map(|_| '•')
-                .collect(),
+                .collect(), 
             false => ToOwned::to_owned, 
         };
 
         match focused {
             true => {
-                let [pre, caret, post] = self.split_caret().map(visibility);
+                let [pre, caret, post] = match self.selection() {
+                    Some(range) => [
+                        &self.value[..range.start], 
+                        &self.value[range.clone()], 
+                        &self.value[range.end..], 
+                    ], 
+                    None => self.split_caret(), 
+                }.map(visibility);
                 let caret = match caret.is_empty() {
-                    true => " ".to_owned(),
-                    false => caret,
+                    true => " ".to_owned(), 
+                    false => caret, 
                 };
-                Line::from(vec![
+                let meter = self.strength.filter(|_| self.hidden && !self.value.is_empty());
+                let meter = meter.map(|meter| format_strength(meter.strength));
+                let meter_width = meter
+                    .iter()
+                    .flatten()
+                    .map(Span::width)
+                    .sum::<usize>();
+
+                // keep the caret visible, preferring to show the text before it
+                let width = width.saturating_sub(caret.width() + meter_width);
+                let (pre, post) = match pre.width() + post.width() > width {
+                    true => {
+                        let pre = clip(&pre, width.saturating_sub(usize::from(!post.is_empty())), true);
+                        let post = clip(&post, width.saturating_sub(pre.width()), false);
+                        (pre, post)
+                    }
+                    false => (pre, post), 
+                };
+                let mut line = Line::from(vec![
                     Span::raw(pre), 
                     Span::styled(caret, Style::new().reversed()), 
                     Span::raw(post), 
-                ]).into()
+                ]);
+                line.spans.extend(meter.into_iter().flatten());
+                line.into()
             }
             false => {
-                visibility(&self.value).into()
+                clip(&visibility(&self.value), width, false).into()
+            }
+        }
+    }
+
+    /// Takes a snapshot of the value and caret. 
+    fn snapshot(&self) -> Snapshot {
+        Snapshot{ value: self.value.clone(), caret: self.caret }
+    }
+
+    /// Restores a snapshot, clearing the selection. Returns the snapshot replaced by it. 
+    fn restore(&mut self, snapshot: Snapshot) -> Snapshot {
+        let replaced = self.snapshot();
+        self.value = snapshot.value;
+        self.caret = snapshot.caret;
+        self.anchor = None;
+        self.rate();
+        replaced
+    }
+
+    /// Undoes the last edit. See the [type-level](Textbox#undo-and-redo) documentation for more information. 
+    fn undo(&mut self) -> InputResult {
+        let Some(snapshot) = self.history.undo.pop() else {
+            return InputResult::Ignored
+        };
+        let replaced = self.restore(snapshot);
+        self.history.redo.push(replaced);
+        self.history.typing = false;
+        InputResult::Updated
+    }
+
+    /// Redoes the last undone edit. See the [type-level](Textbox#undo-and-redo) documentation for more
+    /// information. 
+    fn redo(&mut self) -> InputResult {
+        let Some(snapshot) = self.history.redo.pop() else {
+            return InputResult::Ignored
+        };
+        let replaced = self.restore(snapshot);
+        self.history.undo.push(replaced);
+        self.history.typing = false;
+        InputResult::Updated
+    }
+}
+
+/// The value and caret of a [`Textbox`] at some point in its edit history. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+struct Snapshot {
+    value: String, 
+    caret: usize, 
+}
+
+/// The edit history of a [`Textbox`], used for undoing and redoing edits. 
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+struct History {
+    /// Snapshots from before each edit, most recent last. 
+    undo: Vec<Snapshot>, 
+    /// Snapshots from before each undo, most recent last. Cleared by new edits. 
+    redo: Vec<Snapshot>, 
+    /// Whether the last edit was a typed character, which following typed characters are coalesced with. 
+    typing: bool, 
+}
+
+impl History {
+    /// The maximum number of edits that can be undone. 
+    const MAX: usize = 100;
+
+    /// Records the snapshot from before an edit, unless the edit is typing that continues the last edit. 
+    fn record(&mut self, before: Snapshot, typing: bool) {
+        self.redo.clear();
+        if !(typing && self.typing) {
+            if self.undo.len() == Self::MAX {
+                self.undo.remove(0);
             }
+            self.undo.push(before);
         }
+        self.typing = typing;
+    }
+}
+
+impl Field for Textbox {
+    type Value = String;
+    type Builder = Builder<false>;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
+    fn input(&mut self, key: KeyEvent) -> InputResult {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        match (key.code, ctrl, shift) {
+            (KeyCode::Char('z'), true, false) => return self.undo(), 
+            (KeyCode::Char('y'), true, _) | (KeyCode::Char('z' | 'Z'), true, true) => return self.redo(), 
+            _ => (), 
+        }
+        // typed characters are coalesced into one edit, unless they replace a selection
+        let typing = !ctrl && matches!(key.code, KeyCode::Char(_)) && self.selection().is_none();
+        let before = self.snapshot();
+        let result = self.edit(key);
+        match result {
+            InputResult::Updated => self.history.record(before, typing), 
+            _ => self.history.typing = false, 
+        }
+        result
+    }
+
+    fn format(&self, focused: bool) -> Text<'_> {
+        self.format_clipped(focused, usize::MAX)
+    }
+
+    fn format_width(&self, focused: bool, width: u16) -> Text<'_> {
+        self.format_clipped(focused, width as usize)
     }
 
     fn value(&self) -> &String {
@@ -211,6 +593,66 @@ impl Field for Textbox {
     fn into_value(self) -> String {
         self.value
     }
+
+    fn paste(&mut self, text: &str) -> InputResult {
+        let text = text
+            .trim_end_matches(['\r', '\n'])
+            .replace("\r\n", " ")
+            .replace(['\r', '\n'], " ");
+        if text.is_empty() {
+            return InputResult::Ignored
+        }
+        let before = self.snapshot();
+        self.history.record(before, false);
+        self.insert(&text)
+    }
+}
+
+/// Clips a string to given display width, marking the clipped part with `…`. The start of the string is
+/// clipped if `start == true`, and the end otherwise. 
+fn clip(string: &str, width: usize, start: bool) -> String {
+    if string.width() <= width {
+        return string.to_owned()
+    }
+    if width == 0 {
+        return String::new()
+    }
+    let mut remaining = width - 1;
+    let mut fits = |grapheme: &&str| match remaining.checked_sub(grapheme.width()) {
+        Some(left) => {
+            remaining = left;
+            true
+        }
+        None => false, 
+    };
+    let graphemes = string.graphemes(true);
+    match start {
+        true => {
+            let mut kept: Vec<&str> = graphemes.rev().take_while(&mut fits).collect();
+            kept.push("…");
+            kept.into_iter().rev().collect()
+        }
+        false => graphemes
+            .take_while(fits)
+            .chain(["…"])
+            .collect(), 
+    }
+}
+
+/// Formats a strength from 0 to 4 as a colored bar followed by a label. 
+fn format_strength(strength: u8) -> [Span<'static>; 2] {
+    const LABELS: [&str; 5] = ["very weak", "weak", "fair", "strong", "very strong"];
+    let filled = strength as usize + 1;
+    let color = match strength {
+        0 | 1 => Color::Red, 
+        2 => Color::Yellow, 
+        _ => Color::Green, 
+    };
+    let bar = format!(" {}{}", "▮".repeat(filled), "▯".repeat(LABELS.len() - filled));
+    [
+        Span::styled(bar, Style::new().fg(color)), 
+        Span::raw(format!(" {}", LABELS[strength as usize])).dim(), 
+    ]
 }
 
 /// Constructs a [`Textbox`]. 
@@ -226,9 +668,15 @@ impl Default for Builder<false> {
     fn default() -> Self {
         Self(Textbox {
             name: Default::default(),
+            disabled: false, 
             value: Default::default(),
             hidden: false,
             caret: 0,
+            anchor: None, 
+            register: String::new(), 
+            emacs_bindings: true, 
+            strength: None, 
+            history: History::default(), 
         })
     }
 }
@@ -240,6 +688,11 @@ impl<const NAME: bool> Builder<NAME> {
         Builder(Textbox{ name, ..self.0 })
     }
 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder(Textbox{ disabled, ..self.0 })
+    }
+
     /// The initial value. 
     pub fn value(mut self, value: impl Into<String>) -> Self {
         self.0.set_value(value);
@@ -250,6 +703,25 @@ impl<const NAME: bool> Builder<NAME> {
     pub fn hidden(self) -> Self {
         Builder(Textbox{ hidden: true, ..self.0 })
     }
+
+    /// Whether the readline-style shortcuts listed under [key bindings](Textbox#key-bindings) are enabled. 
+    /// Disabling them leaves the keys to the application, e.g. for using `ctrl+a` to select all. Default: 
+    /// `true`. 
+    pub fn emacs_bindings(self, emacs_bindings: bool) -> Self {
+        Builder(Textbox{ emacs_bindings, ..self.0 })
+    }
+
+    /// Shows the strength of the entered value as rated by a function from 0 (very weak) to 4 (very strong), 
+    /// when the input is also [hidden](Builder::hidden). See the [type-level](Textbox#hidden-input)
+    /// documentation for more information. 
+    /// 
+    /// [`default_strength`] may be used for a simple heuristic. 
+    pub fn strength_meter(self, rate: fn(&str) -> u8) -> Self {
+        let strength = Some(StrengthMeter{ rate, strength: 0 });
+        let mut textbox = Textbox{ strength, ..self.0 };
+        textbox.rate();
+        Builder(textbox)
+    }
 }
 
 impl Build for Builder<true> {
@@ -267,3 +739,311 @@ enum Direction {
     Left, 
     Right, 
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, field::*};
+
+    /// Decomposed `é`, the Swedish flag, and a family emoji joined with ZWJs. 
+    const GRAPHEMES: [&str; 3] = [
+        "e\u{301}", 
+        "\u{1F1F8}\u{1F1EA}", 
+        "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", 
+    ];
+
+    fn with_value(value: &str) -> Textbox {
+        Textbox::builder()
+            .name("")
+            .value(value)
+            .build()
+    }
+
+    fn caret(textbox: &Textbox) -> String {
+        let line = &textbox.format(true).lines[0];
+        line.spans[1].content.to_string()
+    }
+
+    #[test]
+    fn caret_movement() {
+        let value = GRAPHEMES.concat();
+        let mut textbox = with_value(&value);
+        assert_eq!(caret(&textbox), " ");
+
+        // stepping left lands on each whole grapheme in turn
+        for expected in GRAPHEMES.iter().rev() {
+            textbox.input(KeyCode::Left.into());
+            assert_eq!(caret(&textbox), *expected);
+        }
+        textbox.input(KeyCode::Left.into());
+        assert_eq!(caret(&textbox), GRAPHEMES[0]);
+
+        for expected in &GRAPHEMES[1..] {
+            textbox.input(KeyCode::Right.into());
+            assert_eq!(caret(&textbox), *expected);
+        }
+    }
+
+    #[test]
+    fn removal() {
+        for grapheme in GRAPHEMES {
+            let mut textbox = with_value(&format!("a{grapheme}b"));
+            textbox.input(KeyCode::Left.into());
+            textbox.input(KeyCode::Backspace.into());
+            assert_eq!(textbox.value(), "ab");
+
+            let mut textbox = with_value(&format!("a{grapheme}b"));
+            textbox.input(KeyCode::Home.into());
+            textbox.input(KeyCode::Right.into());
+            textbox.input(KeyCode::Delete.into());
+            assert_eq!(textbox.value(), "ab");
+        }
+    }
+
+    #[test]
+    fn insertion_joins_grapheme() {
+        // typing a letter before a combining accent keeps the caret on a grapheme boundary
+        let mut textbox = with_value("\u{301}x");
+        textbox.input(KeyCode::Home.into());
+        textbox.input(KeyCode::Char('e').into());
+        assert_eq!(caret(&textbox), "x");
+        textbox.input(KeyCode::Backspace.into());
+        assert_eq!(textbox.value(), "x");
+    }
+
+    #[test]
+    fn word_scan() {
+        let value = GRAPHEMES.join(" ");
+        let mut textbox = with_value(&value);
+        let ctrl = |code| KeyEvent::new(code, KeyModifiers::CONTROL);
+        textbox.input(ctrl(KeyCode::Backspace));
+        assert_eq!(textbox.value(), GRAPHEMES[..2].join(" "));
+        textbox.input(KeyCode::Home.into());
+        textbox.input(ctrl(KeyCode::Right));
+        assert_eq!(caret(&textbox), " ");
+        textbox.input(KeyCode::Home.into());
+        textbox.input(ctrl(KeyCode::Delete));
+        assert_eq!(textbox.value(), format!(" {}", GRAPHEMES[1]));
+    }
+
+    #[test]
+    fn selection() {
+        let shift = |code| KeyEvent::new(code, KeyModifiers::SHIFT);
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        let value = format!("a{}b", GRAPHEMES[2]);
+        let mut textbox = with_value(&value);
+
+        // the selection extends over whole graphemes
+        textbox.input(shift(KeyCode::Left));
+        textbox.input(shift(KeyCode::Left));
+        assert_eq!(textbox.selection(), Some(1..value.len()));
+        assert_eq!(caret(&textbox), format!("{}b", GRAPHEMES[2]));
+
+        // typing replaces the selection
+        textbox.input(KeyCode::Char('x').into());
+        assert_eq!(textbox.value(), "ax");
+        assert_eq!(textbox.selection(), None);
+
+        // moving without shift clears the selection
+        textbox.input(shift(KeyCode::Home));
+        assert_eq!(textbox.selection(), Some(0..2));
+        textbox.input(KeyCode::End.into());
+        assert_eq!(textbox.selection(), None);
+
+        // cut and paste
+        textbox.input(shift(KeyCode::Left));
+        assert_eq!(textbox.input(ctrl('x')), InputResult::Updated);
+        assert_eq!(textbox.value(), "a");
+        textbox.input(KeyCode::Home.into());
+        assert_eq!(textbox.input(ctrl('v')), InputResult::Updated);
+        textbox.input(ctrl('v'));
+        assert_eq!(textbox.value(), "xxa");
+    }
+
+    #[test]
+    fn selection_words() {
+        let shift_ctrl = |code| KeyEvent::new(code, KeyModifiers::SHIFT | KeyModifiers::CONTROL);
+        let mut textbox = with_value("one two three");
+        textbox.input(shift_ctrl(KeyCode::Left));
+        assert_eq!(textbox.selection(), Some(7..13));
+        textbox.input(shift_ctrl(KeyCode::Left));
+        assert_eq!(textbox.selection(), Some(3..13));
+
+        // copying keeps the selection, and deleting removes it
+        textbox.input(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert_eq!(textbox.selection(), Some(3..13));
+        textbox.input(KeyCode::Backspace.into());
+        assert_eq!(textbox.value(), "one");
+        textbox.input(KeyEvent::new(KeyCode::Char('v'), KeyModifiers::CONTROL));
+        assert_eq!(textbox.value(), "one two three");
+    }
+
+    #[test]
+    fn paste() {
+        let mut textbox = with_value("ab");
+        textbox.input(KeyCode::Left.into());
+        assert_eq!(textbox.paste("one\r\ntwo\nthree\n"), InputResult::Updated);
+        assert_eq!(textbox.value(), "aone two threeb");
+        assert_eq!(caret(&textbox), "b");
+
+        // the selection is replaced
+        textbox.input(KeyEvent::new(KeyCode::Home, KeyModifiers::SHIFT));
+        textbox.paste(GRAPHEMES[0]);
+        assert_eq!(textbox.value(), format!("{}b", GRAPHEMES[0]));
+        assert_eq!(textbox.paste("\n"), InputResult::Ignored);
+    }
+
+    fn typed(textbox: &mut Textbox, text: &str) {
+        for c in text.chars() {
+            textbox.input(KeyCode::Char(c).into());
+        }
+    }
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    #[test]
+    fn undo_coalescing() {
+        let mut textbox = with_value("");
+        typed(&mut textbox, "hello");
+        textbox.input(KeyCode::Left.into());
+        typed(&mut textbox, "ww");
+        assert_eq!(textbox.value(), "hellwwo");
+
+        // each run of typed characters is undone as a whole
+        assert_eq!(textbox.input(ctrl('z')), InputResult::Updated);
+        assert_eq!(textbox.value(), "hello");
+        assert_eq!(caret(&textbox), "o");
+        textbox.input(ctrl('z'));
+        assert_eq!(textbox.value(), "");
+        assert_eq!(textbox.input(ctrl('z')), InputResult::Ignored);
+
+        textbox.input(ctrl('y'));
+        textbox.input(KeyEvent::new(KeyCode::Char('Z'), KeyModifiers::CONTROL | KeyModifiers::SHIFT));
+        assert_eq!(textbox.value(), "hellwwo");
+        assert_eq!(textbox.input(ctrl('y')), InputResult::Ignored);
+    }
+
+    #[test]
+    fn undo_word_removal() {
+        let mut textbox = with_value("one two");
+        textbox.input(ctrl('w'));
+        assert_eq!(textbox.value(), "one");
+        textbox.input(ctrl('z'));
+        assert_eq!(textbox.value(), "one two");
+
+        // a new edit discards what could be redone
+        textbox.input(ctrl('z'));
+        textbox.input(ctrl('z'));
+        typed(&mut textbox, "!");
+        assert_eq!(textbox.input(ctrl('y')), InputResult::Ignored);
+        assert_eq!(textbox.value(), "one two!");
+
+        // setting the value clears the history
+        textbox.set_value("three");
+        assert_eq!(textbox.input(ctrl('z')), InputResult::Ignored);
+    }
+
+    #[test]
+    fn emacs_bindings() {
+        let mut textbox = with_value("one two three");
+        textbox.input(KeyCode::Left.into());
+        textbox.input(KeyCode::Left.into());
+
+        assert_eq!(textbox.input(ctrl('a')), InputResult::Consumed);
+        assert_eq!(caret(&textbox), "o");
+        assert_eq!(textbox.input(ctrl('e')), InputResult::Consumed);
+        assert_eq!(caret(&textbox), " ");
+
+        // removes everything before and after the caret
+        textbox.input(ctrl('w'));
+        textbox.input(ctrl('a'));
+        textbox.input(KeyCode::Right.into());
+        assert_eq!(textbox.input(ctrl('k')), InputResult::Updated);
+        assert_eq!(textbox.value(), "o");
+        assert_eq!(caret(&textbox), " ");
+        assert_eq!(textbox.input(ctrl('k')), InputResult::Ignored);
+        assert_eq!(textbox.input(ctrl('u')), InputResult::Updated);
+        assert_eq!(textbox.value(), "");
+        assert_eq!(caret(&textbox), " ");
+
+        // all of them can be disabled
+        let mut textbox = Textbox::builder()
+            .name("")
+            .value("one two")
+            .emacs_bindings(false)
+            .build();
+        for c in ['a', 'e', 'u', 'k', 'w', 'd'] {
+            assert_eq!(textbox.input(ctrl(c)), InputResult::Ignored);
+        }
+        assert_eq!(textbox.value(), "one two");
+    }
+
+    #[test]
+    fn clipping() {
+        let format = |textbox: &Textbox, focused| textbox
+            .format_width(focused, 10)
+            .to_string();
+        let mut textbox = with_value("abcdefghijklmnopqrstuvwxyz");
+        assert_eq!(format(&textbox, false), "abcdefghi…");
+        assert_eq!(format(&textbox, true), "…stuvwxyz ");
+
+        // the view follows the caret, clipping both ends
+        for _ in 0..11 {
+            textbox.input(KeyCode::Left.into());
+        }
+        assert_eq!(caret(&textbox), "p");
+        assert_eq!(format(&textbox, true), "…ijklmnop…");
+        textbox.input(KeyCode::Home.into());
+        assert_eq!(format(&textbox, true), "abcdefghi…");
+
+        // wide graphemes are never split
+        let textbox = with_value(&"\u{4E2D}".repeat(8));
+        assert_eq!(format(&textbox, false), "\u{4E2D}\u{4E2D}\u{4E2D}\u{4E2D}…");
+
+        // values that fit are left as is
+        let textbox = with_value("short");
+        assert_eq!(format(&textbox, true), "short ");
+    }
+
+    #[test]
+    fn hidden_bullets() {
+        let mut textbox = with_value(&GRAPHEMES.concat());
+        textbox.hidden = true;
+        assert_eq!(textbox.format(false).to_string(), "•••");
+    }
+
+    #[test]
+    fn strength_meter() {
+        let mut textbox = Textbox::builder()
+            .name("Password")
+            .value("hunter2")
+            .hidden()
+            .strength_meter(textbox::default_strength)
+            .build();
+        assert_eq!(textbox.format(true).to_string(), "•••••••  ▮▯▯▯▯ very weak");
+        assert_eq!(textbox.format(false).to_string(), "•••••••");
+
+        // the meter follows inserts and deletes, but doesn't affect the value
+        for c in "Tr0ub4dor&3".chars() {
+            textbox.input(KeyCode::Char(c).into());
+        }
+        assert_eq!(textbox.value(), "hunter2Tr0ub4dor&3");
+        assert!(textbox.format(true).to_string().ends_with(" ▮▮▮▮▮ very strong"));
+        textbox.input(KeyEvent::new(KeyCode::Backspace, KeyModifiers::CONTROL));
+        assert_eq!(textbox.format(true).to_string(), " ");
+
+        // absent unless requested
+        let mut textbox = with_value("hunter2");
+        textbox.hidden = true;
+        assert_eq!(textbox.format(true).to_string(), "••••••• ");
+    }
+
+    #[test]
+    fn default_strength() {
+        assert_eq!(textbox::default_strength(""), 0);
+        assert_eq!(textbox::default_strength("password"), 1);
+        assert_eq!(textbox::default_strength("Passw0rd"), 2);
+        assert_eq!(textbox::default_strength("correct horse battery"), 4);
+    }
+}
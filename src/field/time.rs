@@ -0,0 +1,477 @@
+use std::{
+    borrow::Cow, 
+    fmt::{self, Display}, 
+};
+use ratatui::{
+    style::{Style, Stylize}, 
+    text::{Line, Span, Text}, 
+};
+use crate::prelude::*;
+use super::*;
+
+/// A time of day, as entered with a [`TimeField`]. 
+/// 
+/// Times are ordered chronologically. The fields are public for convenience, but a time constructed by hand
+/// may be invalid; see [`Time::new`] for a checked constructor. 
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Time {
+    /// The hour, between `0` and `23`. 
+    pub hour: u8, 
+    /// The minute, between `0` and `59`. 
+    pub minute: u8, 
+    /// The second, between `0` and `59`. 
+    pub second: u8, 
+}
+
+impl Time {
+    /// The start of the day; `00:00:00`. 
+    pub const MIDNIGHT: Time = Time{ hour: 0, minute: 0, second: 0 };
+
+    /// Constructs a time, returning `None` if any component is out of range. 
+    pub fn new(hour: u8, minute: u8, second: u8) -> Option<Time> {
+        let valid = hour < 24 && minute < 60 && second < 60;
+        valid.then_some(Time{ hour, minute, second })
+    }
+
+    /// Whether the time is after noon. 
+    pub fn is_pm(&self) -> bool {
+        self.hour >= 12
+    }
+
+    /// Makes the time valid by clamping each component. 
+    fn normalise(self) -> Time {
+        Time {
+            hour: self.hour.min(23), 
+            minute: self.minute.min(59), 
+            second: self.second.min(59), 
+        }
+    }
+}
+
+/// Formats the time as `HH:MM:SS`. 
+impl Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+/// A component of a time, edited as a unit. 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+enum Segment {
+    Hour, 
+    Minute, 
+    Second, 
+    /// `AM` or `PM` in 12-hour mode. 
+    Meridiem, 
+}
+
+/// An [input field](super) for entering a [`Time`] of day. 
+/// 
+/// The time is edited one component at a time, with the focused component highlighted, e.g. `09:›30‹`. The
+/// seconds are only shown (and can only be entered) if enabled with [`Builder::seconds`]; otherwise they are
+/// always zero. With [`Builder::twelve_hour`], the hour is displayed between `1` and `12` and followed by
+/// `AM` or `PM`. 
+/// 
+/// See [`time::Builder`] for the methods available when constructing the field. 
+/// 
+/// 
+/// # Key bindings
+/// 
+/// [`KeyCode::Left`] and [`KeyCode::Right`] move the focus to the previous and next component, respectively. 
+/// 
+/// [`KeyCode::Up`] and [`KeyCode::Down`] increase and decrease the focused component by one, respectively,
+/// wrapping around without carrying over into the other components; e.g. the hour wraps from `23` to `0`. On
+/// `AM`/`PM`, they toggle between the two. 
+/// 
+/// Digits replace the focused component. Once two digits have been typed, the component is set and the focus
+/// moves to the next one. A partially typed component is set when the focus moves away from it. In 12-hour
+/// mode, `a` and `p` switch to `AM` and `PM`, respectively. 
+/// 
+/// Note that all of these keys are always [consumed](InputResult::Consumed), even when the focus cannot move
+/// any further. In particular, [forms](crate::dialog::form!) do not move their focus on `Up` and `Down` while
+/// a time field is focused; use `Tab` and `BackTab` instead. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct TimeField {
+    /// The user-visible name displayed by the input field. 
+    pub name: Cow<'static, str>, 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
+    /// The current user-entered value. 
+    value: Time, 
+    /// Whether the seconds are shown. 
+    seconds: bool, 
+    /// Whether the hour is shown between `1` and `12`, followed by `AM` or `PM`. 
+    twelve_hour: bool, 
+    /// Index of the focused component, in display order. 
+    focus: usize, 
+    /// Digits typed into the focused component that have not yet been set. 
+    typed: String, 
+}
+
+impl TimeField {
+    /// Sets the current value. The seconds are discarded unless they are shown. 
+    pub fn set_value(&mut self, value: Time) {
+        self.value = self.normalise(value);
+        self.typed.clear();
+    }
+
+    /// The components in the order they are displayed. 
+    fn segments(&self) -> Vec<Segment> {
+        let mut segments = vec![Segment::Hour, Segment::Minute];
+        if self.seconds {
+            segments.push(Segment::Second);
+        }
+        if self.twelve_hour {
+            segments.push(Segment::Meridiem);
+        }
+        segments
+    }
+
+    /// The focused component. 
+    fn segment(&self) -> Segment {
+        self.segments()[self.focus]
+    }
+
+    /// Makes a time valid, discarding the seconds unless they are shown. 
+    fn normalise(&self, time: Time) -> Time {
+        let time = time.normalise();
+        match self.seconds {
+            true => time, 
+            false => Time{ second: 0, ..time }, 
+        }
+    }
+
+    /// The displayed value of a component. 
+    fn display(&self, segment: Segment) -> String {
+        let Time{ hour, minute, second } = self.value;
+        match segment {
+            Segment::Hour if self.twelve_hour => format!("{:02}", (hour + 11) % 12 + 1), 
+            Segment::Hour => format!("{hour:02}"), 
+            Segment::Minute => format!("{minute:02}"), 
+            Segment::Second => format!("{second:02}"), 
+            Segment::Meridiem if self.value.is_pm() => "PM".to_owned(), 
+            Segment::Meridiem => "AM".to_owned(), 
+        }
+    }
+
+    /// Replaces a component of the value with a typed number, clamped to the range of the component. 
+    fn set(&self, segment: Segment, number: u8) -> Time {
+        let time = self.value;
+        match segment {
+            Segment::Hour if self.twelve_hour => {
+                let hour = number.clamp(1, 12) % 12;
+                Time{ hour: hour + time.hour / 12 * 12, ..time }
+            }
+            Segment::Hour => Time{ hour: number, ..time }, 
+            Segment::Minute => Time{ minute: number, ..time }, 
+            Segment::Second => Time{ second: number, ..time }, 
+            Segment::Meridiem => time, 
+        }
+    }
+
+    /// Steps a component of the value by one, wrapping around. 
+    fn step(&self, segment: Segment, up: bool) -> Time {
+        let time = self.value;
+        let wrap = |value: u8, count: u8| match up {
+            true => (value + 1) % count, 
+            false => (value + count - 1) % count, 
+        };
+        match segment {
+            // in 12-hour mode, the hour wraps around within the same half of the day
+            Segment::Hour if self.twelve_hour => {
+                Time{ hour: wrap(time.hour % 12, 12) + time.hour / 12 * 12, ..time }
+            }
+            Segment::Hour => Time{ hour: wrap(time.hour, 24), ..time }, 
+            Segment::Minute => Time{ minute: wrap(time.minute, 60), ..time }, 
+            Segment::Second => Time{ second: wrap(time.second, 60), ..time }, 
+            Segment::Meridiem => Time{ hour: (time.hour + 12) % 24, ..time }, 
+        }
+    }
+
+    /// Sets the focused component from the typed digits, if any. Returns whether the value changed. 
+    fn commit(&mut self) -> bool {
+        let Ok(typed) = self.typed.parse() else {
+            return false
+        };
+        self.typed.clear();
+        self.update(self.set(self.segment(), typed))
+    }
+
+    /// Sets the value, returning whether it changed. 
+    fn update(&mut self, value: Time) -> bool {
+        let old = self.value;
+        self.value = self.normalise(value);
+        old != self.value
+    }
+}
+
+impl Field for TimeField {
+    type Value = Time;
+    type Builder = Builder;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
+    fn input(&mut self, key: KeyEvent) -> InputResult {
+        let last = self.segments().len() - 1;
+        let updated = match key.code {
+            KeyCode::Left => {
+                let updated = self.commit();
+                self.focus = self.focus.saturating_sub(1);
+                updated
+            }
+            KeyCode::Right => {
+                let updated = self.commit();
+                self.focus = usize::min(self.focus + 1, last);
+                updated
+            }
+            KeyCode::Up | KeyCode::Down => {
+                let committed = self.commit();
+                let stepped = self.step(self.segment(), key.code == KeyCode::Up);
+                self.update(stepped) || committed
+            }
+            KeyCode::Char(c @ ('a' | 'A' | 'p' | 'P')) if self.twelve_hour => {
+                let pm = c.eq_ignore_ascii_case(&'p');
+                let committed = self.commit();
+                let toggled = match pm == self.value.is_pm() {
+                    true => self.value, 
+                    false => self.step(Segment::Meridiem, true), 
+                };
+                self.update(toggled) || committed
+            }
+            KeyCode::Char(digit @ '0'..='9') if self.segment() != Segment::Meridiem => {
+                self.typed.push(digit);
+                if self.typed.len() < 2 {
+                    return InputResult::Consumed
+                }
+                let updated = self.commit();
+                self.focus = usize::min(self.focus + 1, last);
+                updated
+            }
+            _ => return InputResult::Ignored, 
+        };
+        match updated {
+            true => InputResult::Updated, 
+            false => InputResult::Consumed, 
+        }
+    }
+
+    fn paste(&mut self, text: &str) -> InputResult {
+        // the text is typed one character at a time, skipping separators such as `-` and `:`
+        text.chars()
+            .map(|c| self.input(KeyCode::Char(c).into()))
+            .max()
+            .unwrap_or(InputResult::Ignored)
+    }
+
+    fn format(&self, focused: bool) -> Text<'_> {
+        let mut spans = Vec::with_capacity(7);
+        for (i, segment) in self.segments().into_iter().enumerate() {
+            let separator = match (i, segment) {
+                (0, _) => "", 
+                (_, Segment::Meridiem) => " ", 
+                (_, _) => ":", 
+            };
+            spans.push(Span::raw(separator));
+
+            let focus = focused && i == self.focus;
+            let text = match (focus, self.typed.is_empty()) {
+                (false, _) => self.display(segment), 
+                (true, true) => format!("›{}‹", self.display(segment)), 
+                (true, false) => format!("›{:_<2}‹", self.typed), 
+            };
+            let style = match focus {
+                true => Style::new().bold(), 
+                false => Style::new(), 
+            };
+            spans.push(Span::styled(text, style));
+        }
+        Line::from(spans).into()
+    }
+
+    fn value(&self) -> &Time {
+        &self.value
+    }
+
+    fn into_value(mut self) -> Time {
+        self.commit();
+        self.value
+    }
+}
+
+/// Constructs a [`TimeField`]. 
+/// 
+/// This is mainly used by the [form macro](crate::dialog::form!) when instantiating time fields, but may
+/// also be used in application code for creating a stand-alone field. 
+/// 
+/// Requires that [`Builder::name`] is called before the field can be built. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Builder<const NAME: bool = false> {
+    name: Cow<'static, str>, 
+    disabled: bool, 
+    value: Time, 
+    seconds: bool, 
+    twelve_hour: bool, 
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Builder {
+            name: Default::default(), 
+            disabled: false, 
+            value: Time::MIDNIGHT, 
+            seconds: false, 
+            twelve_hour: false, 
+        }
+    }
+}
+
+impl<const NAME: bool> Builder<NAME> {
+    /// The user-visible name displayed by the input field. 
+    pub fn name(self, name: impl Into<Cow<'static, str>>) -> Builder<true> {
+        let name = name.into();
+        let Builder{ disabled, value, seconds, twelve_hour, .. } = self;
+        Builder{ name, disabled, value, seconds, twelve_hour }
+    }
+
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder{ disabled, ..self }
+    }
+
+    /// The initial value. The seconds are discarded unless [shown](Builder::seconds). Default: 
+    /// [`Time::MIDNIGHT`]. 
+    pub fn value(self, value: Time) -> Self {
+        Builder{ value, ..self }
+    }
+
+    /// Shows the seconds, allowing them to be entered. 
+    pub fn seconds(self) -> Self {
+        Builder{ seconds: true, ..self }
+    }
+
+    /// Displays the hour between `1` and `12`, followed by `AM` or `PM`. 
+    pub fn twelve_hour(self) -> Self {
+        Builder{ twelve_hour: true, ..self }
+    }
+}
+
+impl Build for Builder<true> {
+    type Field = TimeField;
+
+    /// If the name has been defined with [`Builder::name`], consumes the builder and returns the constructed
+    /// [`TimeField`]. 
+    fn build(self) -> TimeField {
+        let Builder{ name, disabled, value, seconds, twelve_hour } = self;
+        let mut field = TimeField {
+            name, 
+            disabled, 
+            value: Time::MIDNIGHT, 
+            seconds, 
+            twelve_hour, 
+            focus: 0, 
+            typed: String::new(), 
+        };
+        field.set_value(value);
+        field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, field::*};
+    use super::Time;
+
+    fn time(hour: u8, minute: u8, second: u8) -> Time {
+        Time::new(hour, minute, second).unwrap()
+    }
+
+    #[test]
+    fn wrap_around() {
+        let mut field = TimeField::builder()
+            .name("")
+            .value(time(23, 59, 30))
+            .build();
+        assert_eq!(*field.value(), time(23, 59, 0));
+        assert_eq!(field.input(KeyCode::Up.into()), InputResult::Updated);
+        assert_eq!(*field.value(), time(0, 59, 0));
+        field.input(KeyCode::Down.into());
+        assert_eq!(*field.value(), time(23, 59, 0));
+
+        // the minute wraps around without carrying over into the hour
+        field.input(KeyCode::Right.into());
+        field.input(KeyCode::Up.into());
+        assert_eq!(*field.value(), time(23, 0, 0));
+
+        // moving past the last component is still consumed
+        assert_eq!(field.input(KeyCode::Right.into()), InputResult::Consumed);
+        assert_eq!(field.format(true).to_string(), "23:›00‹");
+    }
+
+    #[test]
+    fn twelve_hour() {
+        let mut field = TimeField::builder()
+            .name("")
+            .value(time(9, 30, 0))
+            .twelve_hour()
+            .build();
+        assert_eq!(field.format(false).to_string(), "09:30 AM");
+
+        field.input(KeyCode::Char('p').into());
+        assert_eq!(*field.value(), time(21, 30, 0));
+        assert_eq!(field.input(KeyCode::Char('p').into()), InputResult::Consumed);
+
+        // the hour wraps around within the same half of the day
+        field.input(KeyCode::Up.into());
+        field.input(KeyCode::Up.into());
+        field.input(KeyCode::Up.into());
+        assert_eq!(field.format(false).to_string(), "12:30 PM");
+        assert_eq!(*field.value(), time(12, 30, 0));
+
+        // toggling on the last component
+        field.input(KeyCode::Right.into());
+        field.input(KeyCode::Right.into());
+        field.input(KeyCode::Down.into());
+        assert_eq!(*field.value(), time(0, 30, 0));
+        assert_eq!(field.format(false).to_string(), "12:30 AM");
+    }
+
+    #[test]
+    fn typing() {
+        let mut field = TimeField::builder()
+            .name("")
+            .seconds()
+            .build();
+        for c in "0745".chars() {
+            field.input(KeyCode::Char(c).into());
+        }
+        assert_eq!(field.format(true).to_string(), "07:45:›00‹");
+
+        // partially typed components are set when the field is consumed, and clamped to their range
+        field.input(KeyCode::Char('9').into());
+        assert_eq!(field.format(true).to_string(), "07:45:›9_‹");
+        field.input(KeyCode::Left.into());
+        field.input(KeyCode::Char('9').into());
+        field.input(KeyCode::Char('9').into());
+        assert_eq!(field.into_value(), time(7, 59, 9));
+    }
+
+    #[test]
+    fn paste() {
+        let mut field = TimeField::builder()
+            .name("")
+            .value(time(9, 30, 0))
+            .twelve_hour()
+            .build();
+
+        // the digits are typed into each component in turn, skipping the separators
+        assert_eq!(field.paste("11:45 PM"), InputResult::Updated);
+        assert_eq!(*field.value(), time(23, 45, 0));
+        assert_eq!(field.paste(":"), InputResult::Ignored);
+    }
+}
@@ -22,18 +22,81 @@ use super::*;
 /// 
 /// # Key bindings
 /// 
-/// [`KeyCode::Up`] and [`KeyCode::Down`] move the focused item up and down, respectively. Any other key
-/// toggles the focused item. 
+/// [`KeyCode::Up`] and [`KeyCode::Down`] move the focused item up and down, respectively. 
+/// 
+/// The [bulk keys](BulkKeys) change all items at once: by default, `a` toggles all items on, `n` toggles all
+/// items off, and `i` inverts all items. These can be changed or disabled with [`Builder::bulk_keys`], e.g. 
+/// when the items need those characters for something else. 
+/// 
+/// Any other key toggles the focused item. 
+/// 
+/// 
+/// # Descriptions
+/// 
+/// Items may be given a description with [`Builder::items_with_descriptions`], which is displayed as a dim
+/// line under the item. Description lines cannot be focused. 
+/// 
+/// 
+/// # Many items
+/// 
+/// The number of items displayed at a time can be limited with [`Builder::max_visible`]. The toggle then
+/// shows a window of items that follows the focused item, with dim `… n more above` and `… n more below`
+/// lines indicating the hidden items. 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Toggle {
     /// The user-visible name displayed by the input field. 
     pub name: Cow<'static, str>, 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. 
+    pub disabled: bool, 
     /// Index of the currently focused item. 
     focus: usize, 
     /// The user-visible names of the items that can be toggled. 
     items: Vec<Cow<'static, str>>, 
+    /// The description of each item, if any. 
+    descriptions: Vec<Option<Cow<'static, str>>>, 
     /// Whether the item corresponding to each index is toggled. 
     values: BitBox, 
+    /// The keys changing all items at once. See the [type-level](Toggle#key-bindings) documentation for
+    /// more information. 
+    pub bulk_keys: BulkKeys, 
+    /// The maximum number of items displayed at a time, if limited. 
+    max_visible: Option<usize>, 
+    /// Index of the first displayed item. 
+    scroll: usize, 
+}
+
+/// Keys changing all items of a [`Toggle`] at once. Set a key to `None` to disable it. 
+/// 
+/// Only the [`KeyCode`] is compared, so the keys are also triggered when a modifier is held (e.g.
+/// `ctrl + a`). 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct BulkKeys {
+    /// Toggles all items on. Default: `a`. 
+    pub select_all: Option<KeyCode>, 
+    /// Toggles all items off. Default: `n`. 
+    pub clear_all: Option<KeyCode>, 
+    /// Toggles each item to the opposite of its current value. Default: `i`. 
+    pub invert: Option<KeyCode>, 
+}
+
+impl BulkKeys {
+    /// Disables all bulk keys, so that every key other than [`KeyCode::Up`] and [`KeyCode::Down`] toggles the
+    /// focused item. 
+    pub const NONE: BulkKeys = BulkKeys {
+        select_all: None, 
+        clear_all: None, 
+        invert: None, 
+    };
+}
+
+impl Default for BulkKeys {
+    fn default() -> Self {
+        BulkKeys {
+            select_all: Some(KeyCode::Char('a')), 
+            clear_all: Some(KeyCode::Char('n')), 
+            invert: Some(KeyCode::Char('i')), 
+        }
+    }
 }
 
 impl Toggle {
@@ -46,16 +109,36 @@ impl Toggle {
     pub fn set_items<T>(&mut self, items: impl IntoIterator<Item = T>)
     where
         T: Into<Cow<'static, str>>, 
+    {
+        self.set_items_with_descriptions(items
+            .into_iter()
+            .map(|item| (item, ""))
+        )
+    }
+
+    /// Sets the user-visible names and descriptions of all items that can be toggled. Items with an empty
+    /// description are displayed without one. All existing values are discarded. 
+    /// 
+    /// 
+    /// # Panics
+    /// 
+    /// When the number of items is zero. 
+    pub fn set_items_with_descriptions<T, U>(&mut self, items: impl IntoIterator<Item = (T, U)>)
+    where
+        T: Into<Cow<'static, str>>, 
+        U: Into<Cow<'static, str>>, 
     {
         // set items
-        self.items = items
+        (self.items, self.descriptions) = items
             .into_iter()
-            .map(Into::into)
-            .collect();
+            .map(|(item, description)| (item.into(), non_empty(description.into())))
+            .unzip();
         assert!(!self.items.is_empty());
 
-        // set all values to 0
+        // set all values to 0, and focus the first item
         self.values = bitbox![0; self.items.len()];
+        self.focus = 0;
+        self.scroll = 0;
     }
 
     /// Sets the values at given indices. 
@@ -65,15 +148,43 @@ impl Toggle {
     /// 
     /// When any given index is out of bounds. 
     pub fn set_indices(&mut self, indices: impl IntoIterator<Item = usize>) {
+        let len = self.items.len();
         for i in indices {
+            assert!(i < len, "Index {i} of toggle \"{}\" is out of range for {len} items", self.name);
             self.values.set(i, true);
         }
     }
 
+    /// The range of indices of the displayed items. 
+    fn window(&self) -> std::ops::Range<usize> {
+        let len = self.items.len();
+        match self.max_visible {
+            Some(max_visible) => self.scroll..usize::min(self.scroll + max_visible, len), 
+            None => 0..len, 
+        }
+    }
+
+    /// Moves the focus to an item, scrolling the window to keep it visible. 
+    fn focus(&mut self, index: usize) {
+        self.focus = index;
+        if let Some(max_visible) = self.max_visible {
+            self.scroll = self.scroll
+                .min(index)
+                .max((index + 1).saturating_sub(max_visible));
+        }
+    }
+
     /// Gets the names of the items that can be toggled. 
     pub fn items(&self) -> &[Cow<'static, str>] {
         &self.items
     }
+
+    /// Gets the description of the item at given index, if it has one. 
+    pub fn description(&self, index: usize) -> Option<&str> {
+        self.descriptions
+            .get(index)?
+            .as_deref()
+    }
 }
 
 impl Field for Toggle {
@@ -84,21 +195,40 @@ impl Field for Toggle {
         &self.name
     }
 
+    fn enabled(&self) -> bool {
+        !self.disabled
+    }
+
     fn input(&mut self, key: KeyEvent) -> InputResult {
         match key.code {
             // move focused item up/down
             KeyCode::Up if self.focus > 0 => {
-                self.focus -= 1;
+                self.focus(self.focus - 1);
                 InputResult::Consumed
             }
             KeyCode::Down if self.focus < (self.items.len() - 1) => {
-                self.focus += 1;
+                self.focus(self.focus + 1);
                 InputResult::Consumed
             }
 
             // we are the top/bottom of the items, no change
             KeyCode::Up | KeyCode::Down => InputResult::Ignored, 
 
+            // change all items at once
+            code if Some(code) == self.bulk_keys.select_all => {
+                self.values.fill(true);
+                InputResult::Updated
+            }
+            code if Some(code) == self.bulk_keys.clear_all => {
+                self.values.fill(false);
+                InputResult::Updated
+            }
+            code if Some(code) == self.bulk_keys.invert => {
+                let values = std::mem::take(&mut self.values);
+                self.values = !values;
+                InputResult::Updated
+            }
+
             // toggle focused item on/off
             _ => {
                 let mut bit = self.values
@@ -110,17 +240,25 @@ impl Field for Toggle {
         }
     }
 
-    fn format(&self, focused: bool) -> Text {
-        std::iter::zip(self.items.iter(), self.values.iter())
+    fn format(&self, focused: bool) -> Text<'_> {
+        let window = self.window();
+        let more = |count: usize, direction: &str| (count > 0)
+            .then(|| Line::from(format!("… {count} more {direction}")).dim());
+        let above = more(window.start, "above");
+        let below = more(self.items.len() - window.end, "below");
+
+        let items = std::iter::zip(self.items.iter(), self.values.iter())
             .enumerate()
-            .map(|(i, (item, value))| {
+            .skip(window.start)
+            .take(window.len())
+            .flat_map(|(i, (item, value))| {
                 let value = *value;
                 let symbol = match value {
                     true => "✓", 
                     false => " ", 
                 };
                 let style = Style::new().bold();
-                match focused && i == self.focus {
+                let line = match focused && i == self.focus {
                     true => Line::from(vec![
                         Span::styled("<", style), 
                         Span::from(symbol), 
@@ -128,8 +266,17 @@ impl Field for Toggle {
                         Span::from(item.as_ref()), 
                     ]), 
                     false => Line::from(format!("({symbol}) {item}")), 
-                }
-            })
+                };
+                // indented to align with the item name
+                let description = self
+                    .description(i)
+                    .map(|description| Line::from(format!("    {description}")).dim());
+                std::iter::once(line).chain(description)
+            });
+        above
+            .into_iter()
+            .chain(items)
+            .chain(below)
             .collect::<Vec<_>>()
             .into()
     }
@@ -141,6 +288,16 @@ impl Field for Toggle {
     fn into_value(self) -> Self::Value {
         self.values
     }
+
+    fn focused_line(&self) -> Option<usize> {
+        let window = self.window();
+        let above = usize::from(window.start > 0);
+        let descriptions = self.descriptions[window.start..self.focus]
+            .iter()
+            .filter(|description| description.is_some())
+            .count();
+        Some(above + self.focus - window.start + descriptions)
+    }
 }
 
 /// Check whether number of toggled items is exactly `N`. 
@@ -190,9 +347,14 @@ impl Default for Builder {
     fn default() -> Self {
         Self(Toggle {
             name: Cow::default(), 
+            disabled: false, 
             focus: 0, 
             items: Vec::default(), 
+            descriptions: Vec::default(), 
             values: BitBox::default(), 
+            bulk_keys: BulkKeys::default(), 
+            max_visible: None, 
+            scroll: 0, 
         })
     }
 }
@@ -204,6 +366,11 @@ impl<const NAME: bool, const ITEMS: bool> Builder<NAME, ITEMS> {
         Builder(Toggle{ name, ..self.0 })
     }
 
+    /// Whether the field is shown but cannot be edited. See [`Field::enabled`]. Default: `false`. 
+    pub fn disabled(self, disabled: bool) -> Self {
+        Builder(Toggle{ disabled, ..self.0 })
+    }
+
     /// The user-visible names of all items that can be toggled. 
     /// 
     /// 
@@ -217,6 +384,37 @@ impl<const NAME: bool, const ITEMS: bool> Builder<NAME, ITEMS> {
         self.0.set_items(items);
         Builder(self.0)
     }
+
+    /// The user-visible names and descriptions of all items that can be toggled. Each description is
+    /// displayed as a dim line under its item; items with an empty description are displayed without one. 
+    /// 
+    /// 
+    /// # Panics
+    /// 
+    /// When the number of items is zero. 
+    pub fn items_with_descriptions<T, U>(mut self, items: impl IntoIterator<Item = (T, U)>)
+        -> Builder<NAME, true>
+    where
+        T: Into<Cow<'static, str>>, 
+        U: Into<Cow<'static, str>>, 
+    {
+        self.0.set_items_with_descriptions(items);
+        Builder(self.0)
+    }
+
+    /// The keys changing all items at once. Default: see [`BulkKeys`]. Use [`BulkKeys::NONE`] to disable
+    /// them. 
+    pub fn bulk_keys(mut self, bulk_keys: BulkKeys) -> Self {
+        self.0.bulk_keys = bulk_keys;
+        self
+    }
+
+    /// The maximum number of items displayed at a time. See the [type-level](Toggle#many-items)
+    /// documentation for more information. Default: unlimited. 
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.0.max_visible = Some(max_visible.max(1));
+        self
+    }
 }
 
 impl<const NAME: bool> Builder<NAME, true> {
@@ -230,6 +428,26 @@ impl<const NAME: bool> Builder<NAME, true> {
         self.0.set_indices(indices);
         Builder(self.0)
     }
+
+    /// The values of all items, e.g. as previously entered into a toggle with the same items. Unlike
+    /// [`Builder::set`], this also clears the values of the items not set. 
+    /// 
+    /// 
+    /// # Panics
+    /// 
+    /// In debug builds, when the number of values differs from the number of items. 
+    pub fn value(mut self, values: &BitSlice) -> Self {
+        let Toggle{ name, items, .. } = &self.0;
+        debug_assert_eq!(
+            values.len(), 
+            items.len(), 
+            "The number of values of toggle \"{name}\" differs from the number of items", 
+        );
+        let len = usize::min(values.len(), self.0.values.len());
+        self.0.values.fill(false);
+        self.0.values[..len].copy_from_bitslice(&values[..len]);
+        Builder(self.0)
+    }
 }
 
 impl Build for Builder<true, true> {
@@ -241,3 +459,165 @@ impl Build for Builder<true, true> {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Modifier;
+    use crate::{prelude::*, field::{*, toggle::BulkKeys}};
+
+    fn lines(toggle: &Toggle) -> Vec<String> {
+        toggle
+            .format(true)
+            .lines
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 3 of toggle \"Extras\" is out of range for 3 items")]
+    fn set_out_of_range() {
+        Toggle::builder()
+            .name("Extras")
+            .items(["Balcony", "Garage", "Garden"])
+            .set([0, 3]);
+    }
+
+    fn ones(toggle: &Toggle) -> Vec<usize> {
+        toggle
+            .value()
+            .iter_ones()
+            .collect()
+    }
+
+    #[test]
+    fn bulk_keys() {
+        let mut toggle = Toggle::builder()
+            .name("")
+            .items(["Balcony", "Garage", "Garden", "Sauna"])
+            .set([1])
+            .build();
+        assert_eq!(toggle.input(KeyCode::Char('a').into()), InputResult::Updated);
+        assert_eq!(ones(&toggle), [0, 1, 2, 3]);
+        assert_eq!(toggle.input(KeyCode::Char('n').into()), InputResult::Updated);
+        assert_eq!(ones(&toggle), [] as [usize; 0]);
+
+        toggle.set_indices([0, 2]);
+        assert_eq!(toggle.input(KeyCode::Char('i').into()), InputResult::Updated);
+        assert_eq!(ones(&toggle), [1, 3]);
+
+        // triggered with modifiers too
+        let key = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL);
+        assert_eq!(toggle.input(key), InputResult::Updated);
+        assert_eq!(ones(&toggle), [0, 1, 2, 3]);
+
+        // custom keys, with the defaults toggling the focused item instead
+        let mut toggle = Toggle::builder()
+            .name("")
+            .items(["Balcony", "Garage"])
+            .bulk_keys(BulkKeys{ select_all: Some(KeyCode::Char('*')), ..BulkKeys::NONE })
+            .build();
+        assert_eq!(toggle.input(KeyCode::Char('n').into()), InputResult::Updated);
+        assert_eq!(ones(&toggle), [0]);
+        assert_eq!(toggle.input(KeyCode::Char('*').into()), InputResult::Updated);
+        assert_eq!(ones(&toggle), [0, 1]);
+    }
+
+    #[test]
+    fn max_visible() {
+        let mut toggle = Toggle::builder()
+            .name("")
+            .items((0..50).map(|i| format!("Item {i}")))
+            .max_visible(3)
+            .set([1, 25])
+            .build();
+        let down = |toggle: &mut Toggle, n| for _ in 0..n {
+            toggle.input(KeyCode::Down.into());
+        };
+
+        // top
+        assert_eq!(lines(&toggle), ["< > Item 0", "(✓) Item 1", "( ) Item 2", "… 47 more below"]);
+        assert_eq!(toggle.focused_line(), Some(0));
+
+        // middle; the window only scrolls once the focus would leave it
+        down(&mut toggle, 25);
+        assert_eq!(lines(&toggle), [
+            "… 23 more above", 
+            "( ) Item 23", 
+            "( ) Item 24", 
+            "<✓> Item 25", 
+            "… 24 more below", 
+        ]);
+        assert_eq!(toggle.focused_line(), Some(3));
+        toggle.input(KeyCode::Up.into());
+        toggle.input(KeyCode::Up.into());
+        assert_eq!(lines(&toggle)[1..4], ["< > Item 23", "( ) Item 24", "(✓) Item 25"]);
+        assert_eq!(toggle.focused_line(), Some(1));
+
+        // bottom
+        down(&mut toggle, 30);
+        assert_eq!(lines(&toggle), ["… 47 more above", "( ) Item 47", "( ) Item 48", "< > Item 49"]);
+        assert_eq!(toggle.input(KeyCode::Down.into()), InputResult::Ignored);
+
+        // the value covers all items
+        assert_eq!(ones(&toggle), [1, 25]);
+    }
+
+    #[test]
+    fn descriptions() {
+        let toggle = Toggle::builder()
+            .name("")
+            .items(["Telemetry", "Updates"])
+            .build();
+        assert_eq!(lines(&toggle), ["< > Telemetry", "( ) Updates"]);
+        assert_eq!(toggle.focused_line(), Some(0));
+
+        // only some items have descriptions
+        let mut toggle = Toggle::builder()
+            .name("")
+            .items_with_descriptions([
+                ("Telemetry", "Anonymous usage statistics"), 
+                ("Updates", ""), 
+                ("Backups", "Nightly, kept for a week"), 
+            ])
+            .set([2])
+            .build();
+        assert_eq!(lines(&toggle), [
+            "< > Telemetry", 
+            "    Anonymous usage statistics", 
+            "( ) Updates", 
+            "(✓) Backups", 
+            "    Nightly, kept for a week", 
+        ]);
+        let text = toggle.format(true);
+        assert!(text.lines[1].style.add_modifier.contains(Modifier::DIM));
+        assert_eq!(toggle.description(1), None);
+
+        // description lines are skipped when moving the focus, but counted for the focused line
+        assert_eq!(toggle.input(KeyCode::Down.into()), InputResult::Consumed);
+        assert_eq!(toggle.focused_line(), Some(2));
+        assert_eq!(toggle.input(KeyCode::Down.into()), InputResult::Consumed);
+        assert_eq!(toggle.focused_line(), Some(3));
+        assert_eq!(toggle.input(KeyCode::Down.into()), InputResult::Ignored);
+
+        // values are unaffected
+        assert_eq!(toggle.input(KeyCode::Enter.into()), InputResult::Updated);
+        assert_eq!(toggle.value().iter_ones().collect::<Vec<_>>(), [] as [usize; 0]);
+    }
+
+    #[test]
+    fn value() {
+        let previous = Toggle::builder()
+            .name("Toppings")
+            .items(["Cheese", "Ham", "Olives"])
+            .set([0, 2])
+            .build();
+        let toggle = Toggle::builder()
+            .name("Toppings")
+            .items(["Cheese", "Ham", "Olives"])
+            .set([1])
+            .value(previous.value())
+            .build();
+        assert_eq!(toggle.value(), previous.value());
+    }
+}
@@ -248,10 +248,28 @@
 //! 
 //! See the [examples folder](https://github.com/user-simon/tundra/tree/main/examples) on GitHub. 
 
+// doc list items are continued without indentation throughout the crate
+#![allow(clippy::doc_lazy_continuation)]
+
+pub mod backgrounds;
+pub mod color;
 mod context;
+pub mod cookbook;
+#[cfg(feature = "debug-draw")]
+pub mod debug;
 pub mod dialog;
 pub mod field;
+#[cfg(feature = "log")]
+pub mod log_capture;
+pub mod remember;
 mod state;
+#[cfg(feature = "tokio")]
+mod state_async;
+pub mod toast;
+
+// lets the code generated by `tundra-derive` refer to this crate by name in its tests
+#[cfg(all(test, feature = "derive"))]
+extern crate self as tundra;
 
 // Re-export Ratatui and Crossterm to avoid dependency hell. 
 pub use ratatui;
@@ -267,6 +285,8 @@ pub use crate::{
     state::*, 
     context::*, 
 };
+#[cfg(feature = "tokio")]
+pub use crate::state_async::StateAsync;
 
 /// Exposes symbols required in virtually all applications. 
 pub mod prelude {
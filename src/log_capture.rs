@@ -0,0 +1,270 @@
+//! Capturing of [`log`] records while the terminal environment is active. 
+//! 
+//! Any write to stdout or stderr while the [context](crate::Context) manages the terminal environment
+//! corrupts the display until the next full redraw. This is a problem when libraries used by the application
+//! report diagnostics through the [`log`] crate with a logger writing to the terminal. 
+//! 
+//! Instead, install the logger provided by this module with [`log_capture::install`](install). While a
+//! context is alive, records are buffered in a bounded ring kept by the context instead of being written to
+//! the terminal. This holds for both managed and [unmanaged](crate::Context#unmanaged-terminal-environment)
+//! contexts. Records logged while no context is alive (e.g. before the first context is created) fall
+//! through to stderr. 
+//! 
+//! The buffered records are shared between [chained](crate::Context#chaining-with-new-globals) contexts, and
+//! can be: 
+//! - Displayed to the user with [`dialog::logs`](crate::dialog::logs). 
+//! - Drained with [`Context::drain_logs`](crate::Context::drain_logs), e.g. to persist them on exit. 
+//! 
+//! Since the [`log`] logger is process-wide, records logged from any thread are captured. If several
+//! separately created contexts are alive at once, the most recently created one captures them. 
+//! 
+//! This module requires the `log` feature. 
+//! 
+//! 
+//! # Interplay with `tracing`
+//! 
+//! Applications instrumented with `tracing` can route their events to the captured logger by enabling the
+//! `log` feature of `tracing`, which emits each event as a [`log`] record when no `tracing` subscriber is
+//! installed. Note that `tracing_log::LogTracer` does the opposite --- it forwards [`log`] records to
+//! `tracing` --- and cannot be used together with this module, since both register themselves as the global
+//! [`log`] logger. 
+//! 
+//! 
+//! # Examples
+//! 
+//! ```no_run
+//! use tundra::{prelude::*, log_capture};
+//! 
+//! // keep the 1000 most recent records
+//! log_capture::install(1000).expect("No other logger has been installed");
+//! 
+//! let mut ctx = Context::new()?;
+//! log::info!("Captured instead of corrupting the display");
+//! 
+//! // show the captured records
+//! dialog::logs(&(), &mut ctx);
+//! 
+//! // persist the captured records on exit
+//! for record in ctx.drain_logs() {
+//!     eprintln!("{record}");
+//! }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::{
+    collections::VecDeque, 
+    fmt, 
+    sync::{atomic::{AtomicUsize, Ordering}, Arc, Mutex}, 
+};
+use log::{Level, LevelFilter, Log, Metadata, SetLoggerError};
+
+/// A captured [`log::Record`]. 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Record {
+    /// Verbosity level of the record. 
+    pub level: Level, 
+    /// Target of the record; typically the module path of the call site. 
+    pub target: String, 
+    /// The formatted message. 
+    pub message: String, 
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Record{ level, target, message } = self;
+        write!(f, "[{level:<5} {target}] {message}")
+    }
+}
+
+/// Installs the capturing logger as the global [`log`] logger, keeping at most `max_lines` records. See
+/// the [module-level](self) documentation for more information. 
+/// 
+/// The maximum log level is set to [`LevelFilter::Trace`]; use [`log::set_max_level`] afterward to lower
+/// it. 
+/// 
+/// 
+/// # Errors
+/// 
+/// When a global logger has already been installed. 
+pub fn install(max_lines: usize) -> Result<(), SetLoggerError> {
+    LOGGER.max_lines.store(max_lines, Ordering::SeqCst);
+    log::set_logger(&LOGGER)?;
+    log::set_max_level(LevelFilter::Trace);
+    Ok(())
+}
+
+/// The records captured for a context, shared between chained contexts. Records are only captured once
+/// [attached](Capture::attach), until this is dropped. 
+#[derive(Debug, Default)]
+pub(crate) struct Capture {
+    /// The captured records, oldest first. 
+    records: Arc<Mutex<VecDeque<Record>>>, 
+}
+
+impl Capture {
+    /// Starts capturing records logged from any thread, taking over from previously attached captures. 
+    pub fn attach(&self) {
+        LOGGER.attached.lock().unwrap().push(Arc::clone(&self.records));
+    }
+
+    /// Copies the captured records, oldest first. 
+    pub fn records(&self) -> Vec<Record> {
+        self.records.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Removes and returns the captured records, oldest first. 
+    pub fn drain(&self) -> Vec<Record> {
+        self.records.lock().unwrap().drain(..).collect()
+    }
+}
+
+impl Drop for Capture {
+    fn drop(&mut self) {
+        LOGGER.attached
+            .lock()
+            .unwrap()
+            .retain(|records| !Arc::ptr_eq(records, &self.records));
+    }
+}
+
+static LOGGER: Logger = Logger {
+    max_lines: AtomicUsize::new(0), 
+    attached: Mutex::new(Vec::new()), 
+};
+
+/// The capturing [`log`] logger. 
+struct Logger {
+    /// The maximum number of records kept per capture; the oldest are discarded first. 
+    max_lines: AtomicUsize, 
+    /// The records of the attached captures, the most recently attached last. 
+    attached: Mutex<Vec<Arc<Mutex<VecDeque<Record>>>>>, 
+}
+
+impl Logger {
+    /// Buffers a record in the most recently attached capture, or returns it if none is attached. 
+    fn capture(&self, record: Record) -> Option<Record> {
+        let attached = self.attached.lock().unwrap();
+        let Some(records) = attached.last() else {
+            return Some(record)
+        };
+        let max_lines = self.max_lines.load(Ordering::SeqCst);
+        let mut records = records.lock().unwrap();
+        if max_lines > 0 {
+            if records.len() == max_lines {
+                records.pop_front();
+            }
+            records.push_back(record);
+        }
+        None
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        let record = Record {
+            level: record.level(), 
+            target: record.target().to_owned(), 
+            message: record.args().to_string(), 
+        };
+        // the locks are released before writing to stderr
+        if let Some(record) = self.capture(record) {
+            eprintln!("{record}");
+        }
+    }
+
+    fn flush(&self) {}
+}
+#[cfg(test)]
+mod tests {
+    use std::{env, io, process::Command};
+    use log::Level;
+    use ratatui::{layout::Rect, TerminalOptions, Viewport};
+    use crate::{Context, Terminal, context::Backend};
+    use super::*;
+
+    /// Creates an unmanaged context drawing to stdout, without querying the terminal. 
+    fn unmanaged() -> Context {
+        let viewport = Viewport::Fixed(Rect::new(0, 0, 20, 1));
+        let terminal = Terminal::with_options(Backend::new(io::stdout()), TerminalOptions{ viewport });
+        Context::new_unmanaged(terminal.unwrap())
+    }
+
+    #[test]
+    fn capture() {
+        install(3).unwrap();
+        assert!(install(3).is_err());
+
+        // without an attached capture, records fall through
+        let capture = Capture::default();
+        log::info!("before");
+        assert!(capture.records().is_empty());
+
+        capture.attach();
+        for i in 0..5 {
+            log::warn!(target: "test", "record {i}");
+        }
+        // only the three most recent records are kept
+        let records = capture.records();
+        let messages: Vec<_> = records
+            .iter()
+            .map(|record| record.message.as_str())
+            .collect();
+        assert_eq!(messages, ["record 2", "record 3", "record 4"]);
+        assert_eq!(records[0].level, Level::Warn);
+        assert_eq!(records[0].to_string(), "[WARN  test] record 2");
+
+        // draining empties the buffer
+        assert_eq!(capture.drain().len(), 3);
+        assert!(capture.records().is_empty());
+
+        // the most recently created context captures the records, shared with the contexts chained from it
+        let mut ctx = unmanaged();
+        log::error!("chained");
+        assert!(capture.records().is_empty());
+        assert_eq!(ctx.chain_with_global(1).drain_logs().len(), 1);
+        assert!(ctx.drain_logs().is_empty());
+
+        // the previous capture takes over once the context is dropped
+        drop(ctx);
+        log::error!("after");
+        assert_eq!(capture.records().len(), 1);
+    }
+
+    /// Whether this process is the child spawned by the [`silent`] test. 
+    const CHILD: &str = "TUNDRA_LOG_CAPTURE_CHILD";
+
+    #[test]
+    fn silent() {
+        if env::var_os(CHILD).is_some() {
+            install(10).unwrap();
+            let mut ctx = unmanaged();
+            log::error!("during");
+            std::thread::spawn(|| log::warn!("during, from another thread")).join().unwrap();
+            assert_eq!(ctx.drain_logs().len(), 2);
+
+            // dropping a chained context doesn't stop the capture
+            drop(ctx.chain_without_global());
+            log::error!("during, after chaining");
+            assert_eq!(ctx.chain_without_global().drain_logs().len(), 1);
+            drop(ctx);
+            log::error!("after");
+            return;
+        }
+
+        // nothing is written to stdout or stderr while the context is alive, and records fall through after
+        let output = Command::new(env::current_exe().unwrap())
+            .args(["--exact", "log_capture::tests::silent", "--nocapture", "--test-threads=1"])
+            .env(CHILD, "1")
+            .output()
+            .unwrap();
+        let [stdout, stderr] = [output.stdout, output.stderr].map(|x| String::from_utf8(x).unwrap());
+        assert!(output.status.success(), "{stdout}{stderr}");
+        assert!(stdout.contains("1 passed"));
+        assert!(!stdout.contains("during") && !stderr.contains("during"));
+        assert!(stderr.contains("[ERROR tundra::log_capture::tests] after"));
+    }
+}
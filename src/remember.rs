@@ -0,0 +1,203 @@
+//! Utilities for persisting view state across the destruction and re-creation of [states](crate::State). 
+//! 
+//! States are consumed when they finish running. When transitioning away from a state and later back to it
+//! by constructing it anew, any view state --- such as the selected row of a table, a scroll offset, or the
+//! text of a filter --- is therefore lost. Storing such values in the
+//! [application-defined global](crate::Context#application-defined-global) works, but pollutes the global
+//! with data that is only relevant to a single state. 
+//! 
+//! Instead, states may stash their view state inside the [context](crate::Context) using
+//! [`Remembered::store`] before they return, and retrieve it using [`Remembered::take`] when they are
+//! constructed again. 
+//! 
+//! Values are keyed by a caller-supplied `&'static str`, and each key should only ever be used with one type.
+//! Retrieving a value with a different type than it was stored with is a bug, and fails a debug assertion
+//! naming both types; in release builds, it yields [`Default::default`] and leaves the stored value
+//! untouched. Stored values are shared between [chained](crate::Context#chaining-with-new-globals) contexts
+//! and are dropped along with the terminal environment. 
+//! 
+//! 
+//! # Examples
+//! 
+//! Preserving the selected row of a table between two runs of a state: 
+//! ```no_run
+//! use std::cell::RefCell;
+//! use tundra::{prelude::*, remember::Remembered};
+//! use tundra::ratatui::widgets::TableState;
+//! 
+//! const KEY: &str = "manager";
+//! 
+//! struct Manager {
+//!     table_state: RefCell<TableState>, 
+//! }
+//! 
+//! impl State for Manager {
+//!     type Result<T> = T;
+//!     type Out = ();
+//!     type Global = ();
+//! 
+//!     fn draw(&self, frame: &mut Frame) {
+//!         // ...
+//!     }
+//! 
+//!     fn input(self, key: KeyEvent, ctx: &mut Context) -> Signal<Self> {
+//!         match key.code {
+//!             KeyCode::Esc => {
+//!                 // stash the table state before the manager is destroyed
+//!                 Remembered::store(ctx, KEY, self.table_state.take());
+//!                 Signal::Return(())
+//!             }
+//!             _ => Signal::Continue(self), 
+//!         }
+//!     }
+//! }
+//! 
+//! fn manager(ctx: &mut Context) {
+//!     // retrieve the table state from the last time the manager was run, if any
+//!     let table_state = Remembered::<TableState>::take(ctx, KEY);
+//!     Manager{ table_state: RefCell::new(table_state) }.run(ctx)
+//! }
+//! ```
+
+use std::{
+    any::{self, Any}, 
+    collections::HashMap, 
+    fmt, 
+    marker::PhantomData, 
+};
+use crate::Context;
+
+/// Typed accessor for values stashed inside a [`Context`]. See the [module-level](self) documentation for
+/// more information. 
+/// 
+/// This type is never instantiated; it only serves to name the type of the value being accessed. 
+pub struct Remembered<T>(PhantomData<T>);
+
+impl<T: Default + 'static> Remembered<T> {
+    /// Removes the value stored with given key from the context, and returns it. If no value is stored with
+    /// the key, [`Default::default`] is returned. 
+    /// 
+    /// 
+    /// # Panics
+    /// 
+    /// In debug builds, when the value stored with the key has another type than `T`. 
+    pub fn take<G>(ctx: &mut Context<G>, key: &'static str) -> T {
+        ctx.memory
+            .borrow_mut()
+            .take(key)
+            .unwrap_or_default()
+    }
+
+    /// Stores a value with given key in the context, replacing any value previously stored with the same key. 
+    /// 
+    /// 
+    /// # Panics
+    /// 
+    /// In debug builds, when the value previously stored with the key has another type than `T`. 
+    pub fn store<G>(ctx: &mut Context<G>, key: &'static str, value: T) {
+        ctx.memory
+            .borrow_mut()
+            .store(key, value)
+    }
+}
+
+/// Type-erased storage of the values stashed inside a [`Context`]. 
+#[derive(Default)]
+pub(crate) struct Memory(HashMap<&'static str, Entry>);
+
+/// A value stashed inside a [`Context`], along with the name of its type for reporting mismatches. 
+struct Entry {
+    value: Box<dyn Any>, 
+    type_name: &'static str, 
+}
+
+impl Memory {
+    /// Removes and returns the value stored with given key, if any. A value of another type than `T` is left
+    /// in place. 
+    fn take<T: 'static>(&mut self, key: &'static str) -> Option<T> {
+        let Entry{ value, type_name } = self.0.remove(key)?;
+        match value.downcast() {
+            Ok(value) => Some(*value), 
+            Err(value) => {
+                Self::mismatch::<T>(key, type_name);
+                self.0.insert(key, Entry{ value, type_name });
+                None
+            }
+        }
+    }
+
+    /// Stores a value with given key. 
+    fn store<T: 'static>(&mut self, key: &'static str, value: T) {
+        let entry = Entry {
+            value: Box::new(value), 
+            type_name: any::type_name::<T>(), 
+        };
+        if let Some(previous) = self.0.insert(key, entry) {
+            if !previous.value.is::<T>() {
+                Self::mismatch::<T>(key, previous.type_name);
+            }
+        }
+    }
+
+    /// Flags that a key is used with more than one type, which is a bug in the application. 
+    fn mismatch<T>(key: &str, stored: &str) {
+        debug_assert!(
+            false, 
+            "Remembered value {key:?} is stored as {stored} but accessed as {}", 
+            any::type_name::<T>(), 
+        );
+    }
+}
+
+impl fmt::Debug for Memory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.0.keys())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Memory;
+
+    #[test]
+    fn take_and_store() {
+        let mut memory = Memory::default();
+        assert_eq!(memory.take::<usize>("selected"), None);
+
+        memory.store("selected", 3usize);
+        assert_eq!(memory.take::<usize>("selected"), Some(3));
+
+        // values are removed once taken
+        assert_eq!(memory.take::<usize>("selected"), None);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "is stored as alloc::string::String"))]
+    fn type_mismatch() {
+        let mut memory = Memory::default();
+        memory.store("filter", String::from("Svalbard"));
+
+        // same key but different type yields nothing in release builds, and leaves the stored value untouched
+        assert_eq!(memory.take::<usize>("filter"), None);
+        assert_eq!(memory.take::<String>("filter").as_deref(), Some("Svalbard"));
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "is stored as u16 but accessed as u32"))]
+    fn type_mismatch_store() {
+        let mut memory = Memory::default();
+        memory.store("offset", 1u16);
+        memory.store("offset", 2u32);
+        assert_eq!(memory.take::<u32>("offset"), Some(2));
+    }
+
+    #[test]
+    fn replace() {
+        let mut memory = Memory::default();
+        memory.store("offset", 1u16);
+        memory.store("offset", 2u16);
+        assert_eq!(memory.take::<u16>("offset"), Some(2));
+    }
+}
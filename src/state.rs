@@ -1,6 +1,11 @@
-use std::convert::Infallible;
+use std::{
+    borrow::Cow, 
+    convert::Infallible, 
+    time::{Duration, Instant}, 
+};
+use ratatui::layout::Rect;
 use crate::{
-    crossterm::event::{self, Event}, 
+    crossterm::event::Event, 
     prelude::*, 
 };
 
@@ -8,7 +13,7 @@ use crate::{
 /// 
 /// This is parameterised over the state `S` and the value type `T` (corresponding to the `Ok` type of a
 /// result). 
-type Error<S, T> = <<S as State>::Result<T> as ResultLike<T>>::Error;
+pub(crate) type Error<S, T> = <<S as State>::Result<T> as ResultLike<T>>::Error;
 
 /// Dictates when and what to return from a running [`State`]. 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
@@ -37,6 +42,9 @@ pub enum Signal<T: State> {
 /// The interface provided by [`State::run`] is fairly low-level. In most cases, a wrapper function should be
 /// used to provide a more bespoke interface. 
 /// 
+/// See the [cookbook](crate::cookbook) for ready-made functions for common patterns in states, such as
+/// confirming before quitting. 
+/// 
 /// 
 /// # Error Handling
 /// 
@@ -65,12 +73,48 @@ pub enum Signal<T: State> {
 /// [`Signal::Continue`], representing the "continuation" of the state. 
 /// 
 /// 
+/// # Exiting
+/// 
+/// An application can be quit from within any number of nested states with [`Context::request_exit`]. While
+/// an exit is requested, [`State::run`] calls [`State::exit`] before handling each event, giving the state
+/// the chance to return. Since the value to return with depends on the state, states must opt in to exiting
+/// by implementing [`State::exit`]; states that don't keep running as usual, and so stop the request from
+/// unwinding any further. 
+/// 
+/// For states with a `Result` [result type](State::Result), exiting may instead be represented by an error, 
+/// which is then propagated through the states above it like any other error: 
+/// ```no_run
+/// # use tundra::prelude::*;
+/// # struct Editor;
+/// enum Error {
+///     Exit, 
+///     // ...
+/// }
+/// 
+/// impl State for Editor {
+///     type Result<T> = Result<T, Error>;
+///     // ...
+/// #   type Out = ();
+/// #   type Global = ();
+/// #   fn draw(&self, _frame: &mut Frame) {}
+/// 
+///     fn exit(self, _ctx: &mut Context) -> Result<Signal<Self>, Error> {
+///         Err(Error::Exit)
+///     }
+/// }
+/// ```
+/// 
+/// The request stays in effect until cancelled with [`Context::cancel_exit`], e.g. by the top-level state
+/// once it has returned. 
+/// 
+/// 
 /// # Dummy state
 /// 
 /// A dummy (or no-nop) state is implemented through `()`. This is useful when a state is expected but not
 /// used; e.g. to display a [`dialog`] without a background. 
 /// 
-/// The dummy state draws nothing and exits as soon as a key is pressed. 
+/// The dummy state draws nothing and exits as soon as a key is pressed, or an [exit](State#exiting) is
+/// requested. 
 /// 
 /// 
 /// # Examples 
@@ -125,12 +169,70 @@ pub trait State: Sized {
     type Out;
 
     /// Type of the application-defined global inside [`Context`]. This should be set to the same type as the
-    /// one used when initializing the [`Context`]. If no global is used, this may be set to `()`. 
+    /// one used when initializing the [`Context`]. If no global is used, this may be set to `()`. See
+    /// [mismatched globals](Context#mismatched-globals) for how to resolve errors when the two differ. 
     type Global;
 
+    /// Interval at which [`State::tick`] is called by [`State::run`]. Set this to update the state while no
+    /// input arrives, e.g. to animate a spinner or to refresh a clock. 
+    /// 
+    /// Ticks are scheduled independently of events, and a tick that is due is delivered before any pending
+    /// event, so a steady stream of input does not delay them. If the state falls behind (e.g. since
+    /// handling an event took longer than the interval), the missed ticks are skipped rather than delivered
+    /// in a burst. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// `None`; the state is never ticked, and [`State::run`] blocks until an event arrives. 
+    const TICK_RATE: Option<Duration> = None;
+
     /// Draw the state to a [`Frame`]. See [Ratatui's documentation](ratatui) for how to construct and render
     /// widgets. 
     fn draw(&self, frame: &mut Frame);
+
+    /// Draw the state to a given area of a [`Frame`]. This is called instead of [`State::draw`] when the
+    /// [context](Context::draw_state) reserves part of the frame for itself; e.g. the bottom row for the
+    /// [hint](State::hint) of the state. 
+    /// 
+    /// States that may be drawn in a reduced area should implement this, and implement [`State::draw`] by
+    /// calling it with the whole frame: 
+    /// ```no_run
+    /// # use tundra::{prelude::*, ratatui::layout::Rect};
+    /// # struct MyState;
+    /// # impl MyState {
+    /// fn draw(&self, frame: &mut Frame) {
+    ///     self.draw_in(frame, frame.area())
+    /// }
+    /// # fn draw_in(&self, _: &mut Frame, _: Rect) {}
+    /// # }
+    /// ```
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Ignores the area and delegates to [`State::draw`]. Anything the context draws in the reserved part of
+    /// the frame is then drawn over the state. 
+    #[allow(unused_variables)]
+    fn draw_in(&self, frame: &mut Frame, area: Rect) {
+        self.draw(frame)
+    }
+
+    /// A persistent hint for the user, such as the available key bindings. When `Some`, the
+    /// [context](Context::draw_state) reserves the bottom row of the frame and draws the hint there in the
+    /// same italic style as the hints of [dialogs](crate::dialog), and draws the state in the remaining area
+    /// with [`State::draw_in`]. 
+    /// 
+    /// The hint of a state is not drawn while the state is the background of a dialog, since the hint of the
+    /// dialog takes precedence. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Returns `None`; the whole frame is given to [`State::draw`]. 
+    fn hint(&self) -> Option<Cow<'_, str>> {
+        None
+    }
     
     /// Update the state with a key press input. This is called by the default implementation of
     /// [`State::event`] when a key input event is read. 
@@ -145,20 +247,113 @@ pub trait State: Sized {
         ResultLike::from_result(Ok(Signal::Continue(self)))
     }
 
+    /// Update the state with text pasted by the user, e.g. to insert it into a command palette as a whole. 
+    /// This is called by the default implementation of [`State::event`] when a paste event is read. 
+    /// 
+    /// Paste events only arrive while [bracketed paste](Context::set_bracketed_paste) is enabled; otherwise,
+    /// pasted text arrives as individual key presses through [`State::input`], if at all. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Ignores the pasted text. 
+    #[allow(unused_variables)]
+    fn paste(self, text: String, ctx: &mut Context<Self::Global>) -> Self::Result<Signal<Self>> {
+        ResultLike::from_result(Ok(Signal::Continue(self)))
+    }
+
+    /// Update the state once the [tick rate](State::TICK_RATE) has elapsed. This is called by the default
+    /// implementation of [`State::run`], after which the state is redrawn. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Always returns `Signal::Continue(self)`. 
+    #[allow(unused_variables)]
+    fn tick(self, ctx: &mut Context<Self::Global>) -> Self::Result<Signal<Self>> {
+        ResultLike::from_result(Ok(Signal::Continue(self)))
+    }
+
+    /// Update the state with the new size of the terminal. This is called by the default implementation of
+    /// [`State::event`] when the terminal is resized, after which the state is redrawn at the new size. States
+    /// that cache layouts or scroll offsets computed from the size of the frame may update them here. 
+    /// 
+    /// A state shown in the background of a [dialog](crate::dialog::Dialog::run_over) is only borrowed by the
+    /// dialog, and is therefore not resized while the dialog is shown; the resize is instead delivered to the
+    /// dialog through [`Dialog::resize`](crate::dialog::Dialog::resize). The background is still drawn at the
+    /// new size, and a state shown with [`Dialog::run_over_refresh`](crate::dialog::Dialog::run_over_refresh)
+    /// may rebuild its caches in [`State::refresh_cache`] once the dialog closes. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Always returns `Signal::Continue(self)`. 
+    #[allow(unused_variables)]
+    fn resize(self, width: u16, height: u16, ctx: &mut Context<Self::Global>) -> Self::Result<Signal<Self>> {
+        ResultLike::from_result(Ok(Signal::Continue(self)))
+    }
+
+    /// Update the state when the terminal window gains or loses focus, e.g. to pause an auto-refreshing view
+    /// while the user is looking elsewhere. This is called by the default implementation of [`State::event`]
+    /// when a focus event is read. 
+    /// 
+    /// Focus events only arrive while [focus reporting](Context::set_focus_reporting) is enabled, and only if
+    /// the terminal supports it. While a [dialog](crate::dialog::Dialog::run_over) is shown over the state,
+    /// the state is neither [ticked](State::tick) nor informed of focus changes; they are instead delivered
+    /// to the dialog through [`Dialog::focus_changed`](crate::dialog::Dialog::focus_changed). 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Always returns `Signal::Continue(self)`. 
+    #[allow(unused_variables)]
+    fn focus_changed(self, focused: bool, ctx: &mut Context<Self::Global>) -> Self::Result<Signal<Self>> {
+        ResultLike::from_result(Ok(Signal::Continue(self)))
+    }
+
+    /// Rebuild any data the state has derived from elsewhere, such as from the global of the context. This is
+    /// called once a dialog shown over the state with [`Dialog::run_over_refresh`](crate::dialog::Dialog::run_over_refresh)
+    /// closes, since the dialog may have changed what the data was derived from. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Does nothing. 
+    #[allow(unused_variables)]
+    fn refresh_cache(&mut self, ctx: &mut Context<Self::Global>) {}
+
+    /// Ask the state to exit. This is called by the default implementation of [`State::run`] while an exit is
+    /// [requested](Context::request_exit), before each event is handled. Returning [`Signal::Return`] stops
+    /// the state, whereas returning [`Signal::Continue`] keeps it running as usual. See the
+    /// [trait-level](State#exiting) documentation for more information. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Always returns `Signal::Continue(self)`; the state ignores the request. 
+    #[allow(unused_variables)]
+    fn exit(self, ctx: &mut Context<Self::Global>) -> Self::Result<Signal<Self>> {
+        ResultLike::from_result(Ok(Signal::Continue(self)))
+    }
+
     /// Update the state with an event. This is called by the default implementation of [`State::run`] when
     /// an event is read. 
     /// 
     /// 
     /// # Default
     /// 
-    /// Simply delegates key press events to [`State::input`], representing the most common use case. All
-    /// other events are discarded. States that only care about key press events should implement
-    /// [`State::input`] instead. 
+    /// Simply delegates key press events to [`State::input`], representing the most common use case, paste
+    /// events to [`State::paste`], resize events to [`State::resize`], and focus events to
+    /// [`State::focus_changed`]. All other events are discarded. States that only care about these events
+    /// should implement the corresponding methods instead. 
     fn event(self, event: Event, ctx: &mut Context<Self::Global>) -> Self::Result<Signal<Self>> {
-        if let Event::Key(key_event) = event {
-            self.input(key_event, ctx)
-        } else {
-            ResultLike::from_result(Ok(Signal::Continue(self)))
+        match event {
+            Event::Key(key_event) => self.input(key_event, ctx), 
+            Event::Paste(text) => self.paste(text, ctx), 
+            Event::Resize(width, height) => self.resize(width, height, ctx), 
+            Event::FocusGained => self.focus_changed(true, ctx), 
+            Event::FocusLost => self.focus_changed(false, ctx), 
+            _ => ResultLike::from_result(Ok(Signal::Continue(self))), 
         }
     }
 
@@ -167,26 +362,62 @@ pub trait State: Sized {
     /// 
     /// # Default
     /// 
-    /// Calls [`State::draw`] and [`State::event`] until the latter returns [`Signal::Return`]. 
+    /// Calls [`State::draw`] and [`State::event`] until the latter returns [`Signal::Return`]. If a
+    /// [tick rate](State::TICK_RATE) is set, [`State::tick`] is called whenever it elapses while waiting for
+    /// an event, and may likewise return [`Signal::Return`]. While an [exit](State#exiting) is requested, 
+    /// [`State::exit`] is called before each event is handled, and may also return [`Signal::Return`]. The
+    /// state is also redrawn whenever a [toast](crate::toast) expires while waiting. 
     /// 
     /// 
     /// # Panics
     /// 
-    /// When [`ratatui::Terminal::draw`] or [`crossterm::event::read`](event::read()) fails. 
+    /// When [`ratatui::Terminal::draw`] or [`crossterm::event::read`](crossterm::event::read()) fails. 
     fn run(mut self, ctx: &mut Context<Self::Global>) -> Self::Result<Self::Out>
     where
         Error<Self, Self::Out>: From<Error<Self, Signal<Self>>>
     {
+        let mut next_tick = Self::TICK_RATE.map(|rate| Instant::now() + rate);
         let result = loop {
+            if ctx.exit_requested() {
+                let signal = match ResultLike::into_result(self.exit(ctx)) {
+                    Ok(signal) => signal, 
+                    Err(err) => break Err(err.into()), 
+                };
+                match signal {
+                    Signal::Return(out) => break Ok(out), 
+                    Signal::Continue(new_self) => self = new_self, 
+                }
+            }
+
             // we're intentionally panicking on `io::Error` here to simplify application code (we would
             // otherwise have to force the application-defined error to implement `From<io::Error>`). these
             // errors should be extremely rare and only occur in extraneous circumstances. applications that
             // wish to handle `io::Error` explicitly can override `State::run` to do so
             ctx.draw_state(&self).unwrap();
-            let event = event::read().unwrap();
+
+            // without a tick rate, this blocks on reading the next event just like it always has, save for
+            // redrawing as toasts expire. a tick that is due is delivered before any pending event, so that a
+            // steady stream of input doesn't delay it
+            let ticked = match (next_tick, Self::TICK_RATE) {
+                (Some(deadline), Some(rate)) => {
+                    let timeout = deadline.saturating_duration_since(Instant::now());
+                    let ticked = timeout.is_zero() || !ctx.wait_event(&self, Some(timeout)).unwrap();
+                    if ticked {
+                        next_tick = Some(reschedule(deadline, rate));
+                    }
+                    ticked
+                }
+                _ => !ctx.wait_event(&self, None).unwrap(), 
+            };
+            let result = match ticked {
+                true => self.tick(ctx), 
+                false => {
+                    let event = ctx.read_event().unwrap();
+                    self.event(event, ctx)
+                }
+            };
 
             // generalized version of `let signal = self.event(...)?`
-            let result = self.event(event, ctx);
             let signal = match ResultLike::into_result(result) {
                 Ok(signal) => signal, 
                 Err(err) => break Err(err.into()), 
@@ -211,13 +442,15 @@ impl State for () {
     type Out = ();
     type Global = ();
 
-    fn draw(&self, _frame: &mut Frame) {
-        ()
-    }
+    fn draw(&self, _frame: &mut Frame) {}
 
     fn input(self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
         Signal::Return(())
     }
+
+    fn exit(self, _ctx: &mut Context) -> Signal<Self> {
+        Signal::Return(())
+    }
 }
 
 /// Generalisation over data-carrying [`Result`]-like types. 
@@ -285,3 +518,414 @@ impl<T> ResultLike<T> for Option<T> {
         self.ok_or(())
     }
 }
+
+/// Deadline of the tick following the one due at `deadline`. Ticks missed since are skipped, in which case the
+/// next one is a full interval away, so that a state that falls behind isn't ticked twice in a row. 
+pub(crate) fn reschedule(deadline: Instant, rate: Duration) -> Instant {
+    let now = Instant::now();
+    match deadline + rate {
+        next if next > now => next, 
+        _ => now + rate, 
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use ratatui::{layout::Rect, text::Line};
+    use crate::{prelude::*, crossterm::event::Event};
+    use super::Cow;
+
+    /// Records the area it was last drawn in. 
+    struct Hinted {
+        hint: Option<&'static str>, 
+        area: Cell<Rect>, 
+    }
+
+    impl Hinted {
+        fn new(hint: Option<&'static str>) -> Self {
+            Hinted{ hint, area: Cell::default() }
+        }
+    }
+
+    impl State for Hinted {
+        type Result<T> = T;
+        type Out = ();
+        type Global = ();
+
+        fn draw(&self, frame: &mut Frame) {
+            self.draw_in(frame, frame.area())
+        }
+
+        fn draw_in(&self, frame: &mut Frame, area: Rect) {
+            self.area.set(area);
+            frame.render_widget(Line::from("state"), area);
+        }
+
+        fn hint(&self) -> Option<Cow<'_, str>> {
+            self.hint.map(Into::into)
+        }
+    }
+
+    fn rows(ctx: &Context) -> Vec<String> {
+        ctx.screen()
+            .lines()
+            .map(|row| row.trim_end().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn hint() {
+        // without a hint, the whole frame is given to the state
+        let ctx = &mut Context::headless(40, 10, []);
+        let state = Hinted::new(None);
+        ctx.draw_state(&state).unwrap();
+        assert_eq!(state.area.get(), Rect::new(0, 0, 40, 10));
+        assert_eq!(rows(ctx)[9], "");
+
+        // with a hint, the bottom row is reserved for it
+        let state = Hinted::new(Some("Press (q) to quit..."));
+        ctx.draw_state(&state).unwrap();
+        assert_eq!(state.area.get(), Rect::new(0, 0, 40, 9));
+        let rows = rows(ctx);
+        assert_eq!(rows[0], "state");
+        assert_eq!(rows[9], "Press (q) to quit...");
+        assert!(ctx.buffer()[(0, 9)].modifier.contains(ratatui::style::Modifier::ITALIC));
+    }
+
+    /// Records when it is drawn and refreshed. 
+    #[derive(Default)]
+    struct Recording {
+        calls: RefCell<Vec<&'static str>>, 
+    }
+
+    impl State for Recording {
+        type Result<T> = T;
+        type Out = ();
+        type Global = u32;
+
+        fn draw(&self, _frame: &mut Frame) {
+            self.calls.borrow_mut().push("draw");
+        }
+
+        fn refresh_cache(&mut self, ctx: &mut Context<u32>) {
+            self.calls.borrow_mut().push(match ctx.global {
+                0 => "refresh", 
+                _ => "refresh with global", 
+            });
+        }
+    }
+
+    /// Dialog incrementing the global of the context on input, and then closing. 
+    struct Increment;
+
+    impl crate::dialog::Dialog for Increment {
+        type Out = ();
+        type Global = u32;
+
+        fn format(&self) -> crate::dialog::DrawInfo<'_> {
+            Default::default()
+        }
+
+        fn input(self, _key: KeyEvent, ctx: &mut Context<u32>) -> Signal<Self> {
+            ctx.global += 1;
+            Signal::Return(())
+        }
+    }
+
+    #[test]
+    fn refresh_cache() {
+        use crate::dialog::Dialog;
+        let key = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+
+        // the background is drawn while the dialog is shown, and refreshed once after it closes
+        let ctx = &mut Context::headless(40, 10, [key.clone()]).chain_with_global(0);
+        let mut background = Recording::default();
+        Increment.run_over_refresh(&mut background, ctx);
+        assert_eq!(*background.calls.borrow(), ["draw", "refresh with global"]);
+        assert_eq!(ctx.global, 1);
+
+        // the same through the form macro
+        let ctx = &mut Context::headless(40, 10, [key]).chain_with_global(0);
+        let mut background = Recording::default();
+        let values = dialog::form!{
+            name: crate::field::Textbox{ name: "Name" }, 
+            [title]: "Form", 
+            [context]: ctx, 
+            [background_mut]: &mut background, 
+        };
+        assert!(values.is_none());
+        assert_eq!(*background.calls.borrow(), ["draw", "refresh"]);
+    }
+
+    #[test]
+    fn hint_behind_dialog() {
+        let key = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        let ctx = &mut Context::headless(40, 10, [key]);
+        let state = Hinted::new(Some("Press (q) to quit..."));
+        dialog::info("Note", &state, ctx);
+
+        // the background is drawn, but its hint is not
+        let rows = rows(ctx);
+        assert!(rows[0].starts_with("state"));
+        assert!(rows.iter().all(|row| !row.contains("quit")));
+    }
+
+    /// Records the keys it receives and returns after three ticks. 
+    #[derive(Default)]
+    struct Ticking {
+        calls: Vec<String>, 
+        ticks: usize, 
+    }
+
+    impl State for Ticking {
+        type Result<T> = T;
+        type Out = Vec<String>;
+        type Global = ();
+
+        const TICK_RATE: Option<std::time::Duration> = Some(std::time::Duration::from_secs(1));
+
+        fn draw(&self, _frame: &mut Frame) {}
+
+        fn input(mut self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+            if let KeyCode::Char(c) = key.code {
+                self.calls.push(c.to_string());
+            }
+            Signal::Continue(self)
+        }
+
+        fn tick(mut self, _ctx: &mut Context) -> Signal<Self> {
+            self.ticks += 1;
+            self.calls.push(format!("tick {}", self.ticks));
+            match self.ticks {
+                3 => Signal::Return(self.calls), 
+                _ => Signal::Continue(self), 
+            }
+        }
+    }
+
+    #[test]
+    fn tick() {
+        let key = |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+
+        // pending events are handled first; the headless context never waits for the tick rate
+        let ctx = &mut Context::headless(10, 2, [key('a'), key('b')]);
+        let calls = Ticking::default().run(ctx);
+        assert_eq!(calls, ["a", "b", "tick 1", "tick 2", "tick 3"]);
+        assert!(ctx.script_finished());
+    }
+
+    /// Takes longer to handle each key than the interval between its ticks. 
+    #[derive(Default)]
+    struct Lagging(Ticking);
+
+    impl Lagging {
+        fn wrap(signal: Signal<Ticking>) -> Signal<Lagging> {
+            match signal {
+                Signal::Return(calls) => Signal::Return(calls), 
+                Signal::Continue(ticking) => Signal::Continue(Lagging(ticking)), 
+            }
+        }
+    }
+
+    impl State for Lagging {
+        type Result<T> = T;
+        type Out = Vec<String>;
+        type Global = ();
+
+        const TICK_RATE: Option<std::time::Duration> = Some(std::time::Duration::from_millis(20));
+
+        fn draw(&self, _frame: &mut Frame) {}
+
+        fn input(self, key: KeyEvent, ctx: &mut Context) -> Signal<Self> {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            Lagging::wrap(self.0.input(key, ctx))
+        }
+
+        fn tick(self, ctx: &mut Context) -> Signal<Self> {
+            Lagging::wrap(self.0.tick(ctx))
+        }
+    }
+
+    #[test]
+    fn tick_due() {
+        let key = |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+
+        // the deadline passes while each key is handled, so the tick is delivered before the next key
+        let ctx = &mut Context::headless(10, 2, [key('a'), key('b'), key('c')]);
+        let calls = Lagging::default().run(ctx);
+        assert_eq!(calls, ["a", "tick 1", "b", "tick 2", "c", "tick 3"]);
+        assert!(ctx.script_finished());
+    }
+
+    /// Runs a nested instance of itself on `n`, requests an exit on `q`, and returns on `enter`. Counts the keys
+    /// it receives. 
+    struct Nested {
+        keys: usize, 
+        exits: bool, 
+    }
+
+    impl State for Nested {
+        type Result<T> = T;
+        type Out = usize;
+        type Global = ();
+
+        fn draw(&self, _frame: &mut Frame) {}
+
+        fn input(mut self, key: KeyEvent, ctx: &mut Context) -> Signal<Self> {
+            self.keys += 1;
+            match key.code {
+                KeyCode::Char('n') => self.keys += Nested{ keys: 0, exits: true }.run(ctx), 
+                KeyCode::Char('q') => ctx.request_exit(), 
+                KeyCode::Enter => return Signal::Return(self.keys), 
+                _ => (), 
+            }
+            Signal::Continue(self)
+        }
+
+        fn exit(self, _ctx: &mut Context) -> Signal<Self> {
+            match self.exits {
+                true => Signal::Return(self.keys), 
+                false => Signal::Continue(self), 
+            }
+        }
+    }
+
+    #[test]
+    fn exit() {
+        let key = |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+
+        // the exit unwinds both states without any more events being read
+        let ctx = &mut Context::headless(10, 2, [key('a'), key('n'), key('b'), key('q'), key('c')]);
+        assert_eq!(Nested{ keys: 0, exits: true }.run(ctx), 4);
+        assert!(ctx.exit_requested());
+        assert!(!ctx.script_finished());
+
+        // a state ignoring the request keeps running
+        let enter = Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        let ctx = &mut Context::headless(10, 2, [key('n'), key('q'), key('a'), enter]);
+        assert_eq!(Nested{ keys: 0, exits: false }.run(ctx), 4);
+        assert!(ctx.script_finished());
+
+        // dialogs and forms shown while the request is in effect close as if cancelled
+        let ctx = &mut Context::headless(40, 10, []);
+        ctx.chain_without_global().request_exit();
+        assert!(!dialog::confirm("Sure?", &(), ctx));
+        assert_eq!(dialog::input("Name?", &(), ctx), None);
+        let values = dialog::form!{
+            name: crate::field::Textbox{ name: "Name" }, 
+            [title]: "Form", 
+            [context]: ctx, 
+            [background]: &(), 
+        };
+        assert!(values.is_none());
+
+        ctx.cancel_exit();
+        assert!(!ctx.exit_requested());
+    }
+
+    /// Records the sizes it is resized to and the width of the frame it is drawn in, and returns on any key. 
+    #[derive(Default)]
+    struct Resizing {
+        sizes: Vec<(u16, u16)>, 
+        drawn: Cell<u16>, 
+    }
+
+    impl State for Resizing {
+        type Result<T> = T;
+        type Out = Self;
+        type Global = ();
+
+        fn draw(&self, frame: &mut Frame) {
+            self.drawn.set(frame.area().width);
+        }
+
+        fn input(self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+            Signal::Return(self)
+        }
+
+        fn resize(mut self, width: u16, height: u16, _ctx: &mut Context) -> Signal<Self> {
+            self.sizes.push((width, height));
+            Signal::Continue(self)
+        }
+    }
+
+    #[test]
+    fn resize() {
+        // the state is redrawn at the new size before the next key arrives
+        let key = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        let ctx = &mut Context::headless(10, 2, [Event::Resize(20, 4), key]);
+        let state = Resizing::default().run(ctx);
+        assert_eq!(state.sizes, [(20, 4)]);
+        assert_eq!(state.drawn.get(), 20);
+        assert_eq!(ctx.buffer().area, Rect::new(0, 0, 20, 4));
+    }
+
+    /// Collects typed characters and pasted text, returning on enter. 
+    #[derive(Default)]
+    struct Palette {
+        command: String, 
+    }
+
+    impl State for Palette {
+        type Result<T> = T;
+        type Out = String;
+        type Global = ();
+
+        fn draw(&self, _frame: &mut Frame) {}
+
+        fn input(mut self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+            match key.code {
+                KeyCode::Char(c) => self.command.push(c), 
+                KeyCode::Enter => return Signal::Return(self.command), 
+                _ => (), 
+            }
+            Signal::Continue(self)
+        }
+
+        fn paste(mut self, text: String, _ctx: &mut Context) -> Signal<Self> {
+            self.command.push_str(&text);
+            Signal::Continue(self)
+        }
+    }
+
+    #[test]
+    fn paste() {
+        let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+        let events = [key(KeyCode::Char(':')), Event::Paste("open ~/notes".into()), key(KeyCode::Enter)];
+        let ctx = &mut Context::headless(10, 2, events);
+        assert_eq!(Palette::default().run(ctx), ":open ~/notes");
+        assert!(ctx.script_finished());
+    }
+
+    /// Records the focus changes it is informed of, returning them on any key. 
+    #[derive(Default)]
+    struct Focus {
+        changes: Vec<bool>, 
+    }
+
+    impl State for Focus {
+        type Result<T> = T;
+        type Out = Vec<bool>;
+        type Global = ();
+
+        fn draw(&self, _frame: &mut Frame) {}
+
+        fn input(self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+            Signal::Return(self.changes)
+        }
+
+        fn focus_changed(mut self, focused: bool, _ctx: &mut Context) -> Signal<Self> {
+            self.changes.push(focused);
+            Signal::Continue(self)
+        }
+    }
+
+    #[test]
+    fn focus_changed() {
+        let key = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        let ctx = &mut Context::headless(10, 2, [Event::FocusLost, Event::FocusGained, key]);
+        assert_eq!(Focus::default().run(ctx), [false, true]);
+        assert!(ctx.script_finished());
+    }
+}
@@ -0,0 +1,254 @@
+use std::time::Instant;
+use crate::{
+    crossterm::event::Event, 
+    prelude::*, 
+    state::{self, Error}, 
+    ResultLike, 
+};
+
+/// Defines an asynchronous event loop of an application state, for applications running on [Tokio](tokio). 
+/// 
+/// The state itself is defined through [`State`] as usual --- which decides how it is drawn, what it
+/// [outputs](State::Out), and so on --- while this trait lets it await while handling events, e.g. on a
+/// network request. The state is then run with [`StateAsync::run_async`] in place of [`State::run`]. Like in
+/// the synchronous event loop, the state is not redrawn and no other events are read while an event is being
+/// handled. 
+/// 
+/// The methods are suffixed with `_async` so as not to clash with those of [`State`], and each defaults to its
+/// synchronous counterpart; only the ones that need to await have to be implemented. Dialogs are shown from
+/// asynchronous states with [`Dialog::run_over_async`](crate::dialog::Dialog::run_over_async), 
+/// [`dialog::progress_async`], and [`dialog::busy_async`]; the other dialogs block the thread while shown, 
+/// just like in synchronous states. 
+/// 
+/// Since [contexts](Context) are not [`Send`], neither are the futures returned by these methods. The states
+/// should therefore be run on the main task (e.g. with `#[tokio::main]`), or on a
+/// [`LocalSet`](https://docs.rs/tokio/latest/tokio/task/struct.LocalSet.html). 
+/// 
+/// This trait requires the `tokio` feature. 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use std::{io, time::Duration};
+/// use tundra::{prelude::*, StateAsync};
+/// 
+/// # async fn fetch_count() -> u32 { 0 }
+/// struct Counter {
+///     count: u32, 
+/// }
+/// 
+/// impl State for Counter {
+///     type Result<T> = T;
+///     type Out = u32;
+///     type Global = ();
+/// 
+///     fn draw(&self, frame: &mut Frame) {
+///         // ...
+///     }
+/// }
+/// 
+/// impl StateAsync for Counter {
+///     async fn input_async(mut self, key: KeyEvent, ctx: &mut Context) -> Signal<Self> {
+///         match key.code {
+///             KeyCode::Char('r') => self.count = fetch_count().await, 
+///             KeyCode::Enter => return Signal::Return(self.count), 
+///             _ => (), 
+///         }
+///         Signal::Continue(self)
+///     }
+/// }
+/// 
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() -> io::Result<()> {
+///     let ctx = &mut Context::new()?;
+///     let count = Counter{ count: 0 }.run_async(ctx).await;
+///     Ok(())
+/// }
+/// ```
+// the futures cannot be `Send` anyway, since they hold on to the context
+#[allow(async_fn_in_trait)]
+pub trait StateAsync: State {
+    /// Asynchronous counterpart of [`State::input`]. This is called by the default implementation of
+    /// [`StateAsync::event_async`] when a key press is read. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Delegates to [`State::input`]. 
+    async fn input_async(self, key: KeyEvent, ctx: &mut Context<Self::Global>) -> Self::Result<Signal<Self>> {
+        self.input(key, ctx)
+    }
+
+    /// Asynchronous counterpart of [`State::tick`]. This is called by the default implementation of
+    /// [`StateAsync::run_async`] once the [tick rate](State::TICK_RATE) has elapsed. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Delegates to [`State::tick`]. 
+    async fn tick_async(self, ctx: &mut Context<Self::Global>) -> Self::Result<Signal<Self>> {
+        self.tick(ctx)
+    }
+
+    /// Asynchronous counterpart of [`State::event`]. This is called by the default implementation of
+    /// [`StateAsync::run_async`] when an event is read. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Delegates key press events to [`StateAsync::input_async`], and all other events to [`State::event`]. 
+    async fn event_async(self, event: Event, ctx: &mut Context<Self::Global>) -> Self::Result<Signal<Self>> {
+        match event {
+            Event::Key(key) => self.input_async(key, ctx).await, 
+            event => self.event(event, ctx), 
+        }
+    }
+
+    /// Enters the event loop, waiting for events without blocking the thread. 
+    /// 
+    /// 
+    /// # Default
+    /// 
+    /// Same as the default implementation of [`State::run`], but calls [`StateAsync::event_async`] and
+    /// [`StateAsync::tick_async`] in place of their synchronous counterparts. 
+    /// 
+    /// 
+    /// # Panics
+    /// 
+    /// When [`ratatui::Terminal::draw`] or reading the next event fails. 
+    async fn run_async(mut self, ctx: &mut Context<Self::Global>) -> Self::Result<Self::Out>
+    where
+        Error<Self, Self::Out>: From<Error<Self, Signal<Self>>>
+    {
+        let mut next_tick = Self::TICK_RATE.map(|rate| Instant::now() + rate);
+        let result = loop {
+            if ctx.exit_requested() {
+                let signal = match ResultLike::into_result(self.exit(ctx)) {
+                    Ok(signal) => signal, 
+                    Err(err) => break Err(err.into()), 
+                };
+                match signal {
+                    Signal::Return(out) => break Ok(out), 
+                    Signal::Continue(new_self) => self = new_self, 
+                }
+            }
+
+            // panicking on `io::Error` for the same reasons as `State::run`
+            ctx.draw_state(&self).unwrap();
+
+            // a tick that is due is delivered before any pending event, just like in `State::run`
+            let timeout = next_tick.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+            let event = match timeout {
+                Some(timeout) if timeout.is_zero() => None, 
+                _ => ctx.next_event_async(&self, timeout).await.unwrap(), 
+            };
+            let result = match event {
+                Some(event) => self.event_async(event, ctx).await, 
+                None => {
+                    next_tick = next_tick
+                        .zip(Self::TICK_RATE)
+                        .map(|(deadline, rate)| state::reschedule(deadline, rate));
+                    self.tick_async(ctx).await
+                }
+            };
+
+            let signal = match ResultLike::into_result(result) {
+                Ok(signal) => signal, 
+                Err(err) => break Err(err.into()), 
+            };
+            match signal {
+                Signal::Return(out) => break Ok(out), 
+                Signal::Continue(new_self) => self = new_self, 
+            }
+        };
+        ResultLike::from_result(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::{prelude::*, context::script, StateAsync};
+
+    /// Counts key presses, awaiting a timer on each one. 
+    struct Awaiting {
+        presses: u32, 
+    }
+
+    impl State for Awaiting {
+        type Result<T> = T;
+        type Out = u32;
+        type Global = ();
+
+        fn draw(&self, _frame: &mut Frame) {}
+    }
+
+    impl StateAsync for Awaiting {
+        async fn input_async(mut self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            match key.code {
+                KeyCode::Enter => Signal::Return(self.presses), 
+                _ => {
+                    self.presses += 1;
+                    Signal::Continue(self)
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn run() {
+        let keys = [KeyCode::Char('a'), KeyCode::Char('b'), KeyCode::Enter];
+        let ctx = &mut Context::headless(20, 5, script(keys));
+        let presses = Awaiting{ presses: 0 }.run_async(ctx).await;
+        assert_eq!(presses, 2);
+        assert!(ctx.script_finished());
+    }
+
+    /// Records the keys and ticks it receives, taking longer to handle each key than the interval between
+    /// its ticks. 
+    #[derive(Default)]
+    struct Lagging {
+        calls: Vec<String>, 
+    }
+
+    impl State for Lagging {
+        type Result<T> = T;
+        type Out = Vec<String>;
+        type Global = ();
+
+        const TICK_RATE: Option<Duration> = Some(Duration::from_millis(20));
+
+        fn draw(&self, _frame: &mut Frame) {}
+    }
+
+    impl StateAsync for Lagging {
+        async fn input_async(mut self, key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            match key.code {
+                KeyCode::Enter => Signal::Return(self.calls), 
+                KeyCode::Char(c) => {
+                    self.calls.push(c.to_string());
+                    Signal::Continue(self)
+                }
+                _ => Signal::Continue(self), 
+            }
+        }
+
+        async fn tick_async(mut self, _ctx: &mut Context) -> Signal<Self> {
+            self.calls.push(format!("tick {}", self.calls.len() / 2 + 1));
+            Signal::Continue(self)
+        }
+    }
+
+    #[tokio::test]
+    async fn tick_due() {
+        // the deadline passes while each key is handled, so the tick is delivered before the next key
+        let keys = [KeyCode::Char('a'), KeyCode::Char('b'), KeyCode::Enter];
+        let ctx = &mut Context::headless(20, 5, script(keys));
+        let calls = Lagging::default().run_async(ctx).await;
+        assert_eq!(calls, ["a", "tick 1", "b", "tick 2"]);
+        assert!(ctx.script_finished());
+    }
+}
@@ -0,0 +1,191 @@
+//! Transient notifications shown over any state, e.g. "Saved ✓". 
+//! 
+//! Toasts are shown with [`Context::notify`](crate::Context::notify), and are drawn in the top-right corner
+//! of the frame by [`Context::draw_state`](crate::Context::draw_state) until they expire. States need no code
+//! of their own to show them, and the event loops of [states](crate::State::run) and
+//! [dialogs](crate::dialog::Dialog::run_over) redraw the frame once a toast expires, even if no input
+//! arrives. Unlike [dialogs](crate::dialog), toasts don't take any input. 
+//! 
+//! 
+//! # Examples
+//! 
+//! ```no_run
+//! use std::time::Duration;
+//! use tundra::{prelude::*, toast};
+//! 
+//! # let ctx = &mut Context::new().unwrap();
+//! // let ctx: &mut Context<_>
+//! ctx.notify("Saved ✓", toast::Level::Success, Duration::from_secs(2));
+//! ```
+
+use std::time::{Duration, Instant};
+use ratatui::{
+    layout::Rect, 
+    style::{Color, Style, Stylize}, 
+    text::Line, 
+    widgets::Clear, 
+    Frame, 
+};
+
+/// The kind of a toast, deciding its colour. Without [colours](crate::color), toasts are instead prefixed
+/// with their level, e.g. `[!]` for warnings. 
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum Level {
+    /// Drawn in cyan. 
+    #[default]
+    Info, 
+    /// Drawn in green. 
+    Success, 
+    /// Drawn in yellow. 
+    Warning, 
+    /// Drawn in red. 
+    Error, 
+}
+
+impl Level {
+    fn color(self) -> Color {
+        match self {
+            Level::Info => Color::Cyan, 
+            Level::Success => Color::Green, 
+            Level::Warning => Color::Yellow, 
+            Level::Error => Color::Red, 
+        }
+    }
+
+    /// Prefix standing in for the colour when colours are disabled. 
+    fn marker(self) -> &'static str {
+        match self {
+            Level::Info => "[i]", 
+            Level::Success => "[✓]", 
+            Level::Warning => "[!]", 
+            Level::Error => "[✗]", 
+        }
+    }
+}
+
+/// A toast waiting to expire. 
+#[derive(Clone, Debug)]
+struct Toast {
+    msg: String, 
+    level: Level, 
+    expires: Instant, 
+}
+
+/// The toasts shown by a context, shared between all contexts chained from it. 
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Toasts {
+    /// Oldest first. 
+    toasts: Vec<Toast>, 
+}
+
+impl Toasts {
+    /// The maximum number of toasts shown at once; the most recent ones are shown. 
+    const MAX_VISIBLE: usize = 3;
+
+    pub fn push(&mut self, msg: String, level: Level, duration: Duration) {
+        let expires = Instant::now() + duration;
+        self.toasts.push(Toast{ msg, level, expires });
+    }
+
+    /// When the next toast expires, if any are shown. 
+    pub fn expiry(&self) -> Option<Instant> {
+        self.toasts
+            .iter()
+            .map(|toast| toast.expires)
+            .min()
+    }
+
+    /// Removes the expired toasts, and draws the remaining ones in the top-right corner of the frame, most
+    /// recent first. 
+    pub fn draw(&mut self, frame: &mut Frame) {
+        let now = Instant::now();
+        self.toasts.retain(|toast| toast.expires > now);
+
+        let area = frame.area();
+        let visible = self.toasts
+            .iter()
+            .rev()
+            .take(Self::MAX_VISIBLE)
+            .zip(area.top()..area.bottom());
+        for (toast, y) in visible {
+            let line = match crate::color::enabled() {
+                true => Line::from(format!(" {} ", toast.msg))
+                    .style(Style::new().fg(Color::Black).bg(toast.level.color())), 
+                false => Line::from(format!(" {} {} ", toast.level.marker(), toast.msg))
+                    .reversed(), 
+            };
+            let width = (line.width() as u16).min(area.width);
+            let toast_area = Rect::new(area.right() - width, y, width, 1);
+            frame.render_widget(Clear, toast_area);
+            frame.render_widget(line, toast_area);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use ratatui::style::{Color, Modifier};
+    use crate::{prelude::*, color::ColorMode};
+    use super::Level;
+
+    fn rows(ctx: &Context) -> Vec<String> {
+        ctx.screen()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn draw() {
+        let ctx = &mut Context::headless(40, 6, []);
+        let hour = Duration::from_secs(3600);
+        ctx.notify("Saved ✓", Level::Success, hour);
+        ctx.chain_with_global(1).notify("Disk almost full", Level::Warning, hour);
+        ctx.draw_state(&()).unwrap();
+
+        // the toasts are shared with chained contexts, and are drawn in the corner, most recent first
+        let screen = rows(ctx);
+        assert_eq!(screen[0], format!("{} Disk almost full ", " ".repeat(22)));
+        assert_eq!(screen[1], format!("{} Saved ✓ ", " ".repeat(31)));
+        assert_eq!(ctx.buffer()[(39, 1)].bg, Color::Green);
+
+        // at most three toasts are shown
+        for i in 0..3 {
+            ctx.notify(format!("Toast {i}"), Level::Info, hour);
+        }
+        ctx.draw_state(&()).unwrap();
+        let screen = rows(ctx);
+        assert!(screen[0].ends_with(" Toast 2 "));
+        assert!(screen[2].ends_with(" Toast 0 "));
+        assert_eq!(screen[3].trim(), "");
+    }
+
+    #[test]
+    fn monochrome() {
+        // without colours, the level is shown as a prefix, and the setting is shared with chained contexts
+        let ctx = &mut Context::headless(40, 6, []);
+        ctx.chain_with_global(1).set_color_mode(ColorMode::Never);
+        ctx.notify("Disk almost full", Level::Warning, Duration::from_secs(3600));
+        ctx.draw_state(&()).unwrap();
+        assert!(rows(ctx)[0].ends_with(" [!] Disk almost full "));
+        assert_eq!(ctx.buffer()[(39, 0)].bg, Color::Reset);
+        assert!(ctx.buffer()[(39, 0)].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn expire() {
+        // expired toasts are removed on the next draw
+        let ctx = &mut Context::headless(40, 6, []);
+        ctx.notify("Saved ✓", Level::Success, Duration::ZERO);
+        ctx.draw_state(&()).unwrap();
+        assert!(rows(ctx).iter().all(|row| row.trim().is_empty()));
+
+        // and are removed while waiting for input, even if none arrives
+        ctx.notify("Saved ✓", Level::Success, Duration::from_millis(10));
+        ctx.draw_state(&()).unwrap();
+        assert!(rows(ctx)[0].contains("Saved"));
+        assert!(ctx.wait_event(&(), None).unwrap());
+        assert!(rows(ctx).iter().all(|row| row.trim().is_empty()));
+    }
+}
@@ -0,0 +1,9 @@
+//! Compile-time errors produced by the crate, checked against the expected compiler output in `tests/ui`. 
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+    #[cfg(feature = "derive")]
+    cases.compile_fail("tests/ui/derive/*.rs");
+}
@@ -0,0 +1,9 @@
+use tundra::dialog::Form;
+
+#[derive(Form)]
+struct Listing {
+    address: String, 
+    monthly_rent: u32, 
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use tundra::prelude::*;
+
+struct User;
+
+struct Tally {
+    value: u32, 
+}
+
+impl State for Tally {
+    type Result<T> = T;
+    type Out = u32;
+    type Global = ();
+
+    fn draw(&self, _frame: &mut Frame) {}
+
+    fn input(self, _key: KeyEvent, _ctx: &mut Context) -> Signal<Self> {
+        Signal::Return(self.value)
+    }
+}
+
+fn tally(ctx: &mut Context<User>) -> u32 {
+    Tally{ value: 0 }.run(ctx.expect_global::<()>())
+}
+
+fn main() {
+    let _ = tally;
+}
@@ -0,0 +1,281 @@
+//! Derive macros for [Tundra](https://docs.rs/tundra). These are re-exported by Tundra when the `derive`
+//! feature is enabled, and should be used through it. 
+
+// doc list items are continued without indentation, like in the main crate
+#![allow(clippy::doc_lazy_continuation)]
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Expr, ExprLit, Fields, Ident, Lit, Meta, Token, 
+    Type, punctuated::Punctuated, 
+};
+
+/// Derives an inherent `edit` method for a struct, opening a form dialog pre-populated from the struct. 
+/// 
+/// This generates a `dialog::form!` invocation with one input field per struct field, so that settings
+/// screens don't have to mirror the struct field-for-field by hand. The generated method has the signature: 
+/// ```text
+/// pub fn edit<G>(&self, title: impl Into<Cow<str>>, background: &impl State, ctx: &mut Context<G>)
+///     -> Option<Self>
+/// ```
+/// It returns an edited copy of the struct if the user submits the form, and `None` if the user cancels
+/// it. 
+/// 
+/// 
+/// # Field attributes
+/// 
+/// Each struct field is edited with an input field, whose kind is inferred from the type of the struct
+/// field: `String` is edited with a `Textbox`, `bool` with a `Checkbox`, and the primitive numerical types
+/// with a `Slider`. The kind and the arguments given to the field builder can be specified with the `field`
+/// attribute: 
+/// - `#[field(textbox)]`, `#[field(checkbox)]`, or `#[field(slider)]` selects the kind of input field. 
+/// - `#[field(key = value)]` gives an argument to the field builder, just like `key: value` would in the
+/// form macro. For example, `name = "Location"` or `step = 50`. The range of a slider can be given as a
+/// string, e.g. `range = "1..=5000"`, and must be given for sliders inferred from the type of the struct
+/// field. The value cannot be given, since it's taken from the struct field. 
+/// - `#[field(skip)]` excludes the struct field from the form. Its value is cloned into the returned copy. 
+/// 
+/// If no name is given, one is created from the identifier of the struct field, e.g. `monthly_rent` is
+/// named "Monthly rent". 
+/// 
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use tundra::{prelude::*, dialog::Form};
+/// 
+/// #[derive(Form)]
+/// struct Listing {
+///     #[field(name = "Location")]
+///     address: String, 
+///     #[field(slider, range = "1..=5000", step = 50, prefix = "$")]
+///     monthly_rent: u32, 
+///     pets_allowed: bool, 
+///     #[field(skip)]
+///     id: u64, 
+/// }
+/// 
+/// # let ctx = &mut Context::new().unwrap();
+/// # let listing = Listing{ address: String::new(), monthly_rent: 50, pets_allowed: false, id: 0 };
+/// // let ctx: &mut Context<_>
+/// // let listing: Listing
+/// if let Some(edited) = listing.edit("Edit listing", &(), ctx) {
+///     // ...
+/// }
+/// ```
+#[proc_macro_derive(Form, attributes(field))]
+pub fn derive_form(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The kind of input field used to edit a struct field. 
+enum Kind {
+    Textbox, 
+    Checkbox, 
+    Slider, 
+}
+
+impl Kind {
+    /// Parses the kind from the first argument of a `#[field(...)]` attribute. 
+    fn parse(ident: &Ident) -> Option<Kind> {
+        match ident.to_string().as_str() {
+            "textbox" => Some(Kind::Textbox), 
+            "checkbox" => Some(Kind::Checkbox), 
+            "slider" => Some(Kind::Slider), 
+            _ => None, 
+        }
+    }
+
+    /// Infers the kind from the type of a struct field: `String` is edited with a textbox, `bool` with a
+    /// checkbox, and the primitive numerical types with a slider. 
+    fn infer(ty: &Type) -> Option<Kind> {
+        let Type::Path(path) = ty else {
+            return None
+        };
+        let ident = path.path
+            .segments
+            .last()?
+            .ident
+            .to_string();
+        match ident.as_str() {
+            "String" => Some(Kind::Textbox), 
+            "bool" => Some(Kind::Checkbox), 
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" |
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize" |
+            "f32" | "f64" => Some(Kind::Slider), 
+            _ => None, 
+        }
+    }
+
+    /// The field type given to the form macro. 
+    fn field_type(&self, ty: &Type) -> TokenStream {
+        match self {
+            Kind::Textbox => quote!(::tundra::field::Textbox), 
+            Kind::Checkbox => quote!(::tundra::field::Checkbox), 
+            Kind::Slider => quote!(::tundra::field::Slider<#ty>), 
+        }
+    }
+}
+
+/// How a struct field is edited, parsed from its `#[field(...)]` attribute. 
+enum Edit {
+    Skip, 
+    Field {
+        kind: Kind, 
+        /// Arguments given to the field builder, e.g. `name` and `range`. 
+        args: Vec<(Ident, Expr)>, 
+    }, 
+}
+
+/// Parses the `#[field(...)]` attribute of a struct field, if any. 
+fn parse_field(field: &syn::Field) -> syn::Result<Edit> {
+    let ident = field.ident
+        .as_ref()
+        .expect("Fields are named");
+    let mut kind = None;
+    let mut args = Vec::new();
+
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("field")) {
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in metas {
+            match meta {
+                Meta::Path(path) if path.is_ident("skip") => return Ok(Edit::Skip), 
+                Meta::Path(path) => {
+                    let parsed = path
+                        .get_ident()
+                        .and_then(Kind::parse)
+                        .ok_or_else(|| syn::Error::new(
+                            path.span(), 
+                            "expected `skip`, or one of the field kinds `textbox`, `checkbox`, `slider`", 
+                        ))?;
+                    kind = Some(parsed);
+                }
+                Meta::NameValue(pair) => {
+                    let name = pair.path
+                        .get_ident()
+                        .cloned()
+                        .ok_or_else(|| syn::Error::new(pair.path.span(), "expected a builder argument"))?;
+                    if name == "value" {
+                        return Err(syn::Error::new(name.span(), "the value is taken from the struct field"))
+                    }
+                    // a range is most naturally written as a string, e.g. `range = "1..=5000"`
+                    let value = match (name == "range", pair.value) {
+                        (true, Expr::Lit(ExprLit{ lit: Lit::Str(range), .. })) => range.parse()?, 
+                        (_, value) => value, 
+                    };
+                    args.push((name, value));
+                }
+                Meta::List(list) => {
+                    return Err(syn::Error::new(list.span(), "expected `key = value` or a field kind"))
+                }
+            }
+        }
+    }
+
+    let kind = match (kind, Kind::infer(&field.ty)) {
+        (Some(kind), _) => kind, 
+        // the full range of a numerical type is rarely what's intended, so an inferred slider needs a range
+        (None, Some(Kind::Slider)) if !args.iter().any(|(name, _)| name == "range") => {
+            return Err(syn::Error::new(
+                field.span(), 
+                "a slider needs a range; specify it with `#[field(range = \"...\")]`, e.g. \
+                `#[field(range = \"1..=100\")]`", 
+            ))
+        }
+        (None, Some(kind)) => kind, 
+        (None, None) => return Err(syn::Error::new(
+            field.ty.span(), 
+            "cannot infer the field kind from this type; specify it with `#[field(textbox)]`, \
+            `#[field(checkbox)]`, or `#[field(slider)]`, or exclude the field with `#[field(skip)]`", 
+        )), 
+    };
+    if !args.iter().any(|(name, _)| name == "name") {
+        let name = display_name(ident);
+        args.insert(0, (format_ident!("name"), syn::parse_quote!(#name)));
+    }
+    Ok(Edit::Field{ kind, args })
+}
+
+/// Turns the identifier of a struct field into a user-visible name, e.g. `monthly_rent` into "Monthly rent". 
+fn display_name(ident: &Ident) -> String {
+    let name = ident
+        .to_string()
+        .trim_start_matches("r#")
+        .replace('_', " ");
+    let mut chars = name.trim().chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars)
+            .collect(), 
+        None => name, 
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(input.span(), "`Form` can only be derived for structs"))
+    };
+    let Fields::Named(fields) = &data.fields else {
+        let msg = "`Form` can only be derived for structs with named fields";
+        return Err(syn::Error::new(data.fields.span(), msg))
+    };
+
+    let mut form_fields = Vec::new();
+    let mut assignments = Vec::new();
+    for field in &fields.named {
+        let ident = &field.ident;
+        match parse_field(field)? {
+            Edit::Skip => assignments.push(quote!(#ident: ::std::clone::Clone::clone(&self.#ident))), 
+            Edit::Field{ kind, args } => {
+                let ty = kind.field_type(&field.ty);
+                let args = args
+                    .iter()
+                    .map(|(name, value)| quote!(#name: #value));
+                form_fields.push(quote! {
+                    #ident: #ty{ #(#args, )* value: ::std::clone::Clone::clone(&self.#ident) }
+                });
+                assignments.push(quote!(#ident: values.#ident));
+            }
+        }
+    }
+    if form_fields.is_empty() {
+        let msg = "`Form` requires at least one field that is not skipped";
+        return Err(syn::Error::new(input.ident.span(), msg))
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let doc = format!(
+        "Opens a form dialog for editing the fields of the [`{name}`], pre-populated with the current \
+        values. \n\nReturns the edited copy if the user submits the form, and `None` if the user cancels \
+        it. Generated by `#[derive(Form)]`. ", 
+    );
+    let edit = quote! {
+        #[doc = #doc]
+        pub fn edit<'__title, __G>(
+            &self, 
+            title: impl ::std::convert::Into<::std::borrow::Cow<'__title, str>>, 
+            background: &impl ::tundra::State, 
+            ctx: &mut ::tundra::Context<__G>, 
+        ) -> ::std::option::Option<Self> {
+            let title: ::std::borrow::Cow<'__title, str> = title.into();
+            let values = ::tundra::dialog::form!{
+                #(#form_fields, )*
+                [title]: title, 
+                [context]: ctx, 
+                [background]: background, 
+            }?;
+            ::std::option::Option::Some(#name{ #(#assignments, )* })
+        }
+    };
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #edit
+        }
+    }.into_token_stream())
+}